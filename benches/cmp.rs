@@ -73,3 +73,17 @@ fn bench_eq_type_(b: &mut Bencher) {
         assert_eq!(mime.type_(), name);
     });
 }
+
+#[bench]
+fn bench_hash_multiple_parameters(b: &mut Bencher) {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    let mime = "text/foo; aaa=bbb; ccc=ddd; eee=fff; ggg=hhh".parse::<MediaType>().unwrap();
+    b.bytes = mime.as_ref().len() as u64;
+    b.iter(|| {
+        let mut hasher = DefaultHasher::new();
+        mime.hash(&mut hasher);
+        hasher.finish()
+    });
+}
@@ -41,3 +41,10 @@ fn text_plain_charset_utf8_extended(b: &mut Bencher) {
     b.bytes = s.as_bytes().len() as u64;
     b.iter(|| s.parse::<MediaType>())
 }
+
+#[bench]
+fn text_plain_charset_utf8_extended_ref(b: &mut Bencher) {
+    let s = "text/plain; charset=utf-8; foo=bar";
+    b.bytes = s.as_bytes().len() as u64;
+    b.iter(|| mime::MediaTypeRef::parse(s))
+}
@@ -0,0 +1,105 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::io;
+
+/// The preferred type of an extension that more than one type claims,
+/// where the order in the sources would be wrong. `mime.types` is sorted
+/// by type, which says nothing about which is more common.
+const PREFERRED: &[(&str, &str)] = &[
+    ("asf", "video/x-ms-asf"),
+    ("mid", "audio/midi"),
+    ("ts", "video/mp2t"),
+];
+
+/// Generates `src/extensions/table.rs`.
+///
+/// Candidates for an extension are ranked in the order they are found:
+/// first the IANA based `mime.types`, then nginx's `mime.types`. Any
+/// extension in `PREFERRED` then has its preferred type moved first.
+pub(crate) fn generate() -> io::Result<()> {
+    let mut by_ext = BTreeMap::<String, Vec<String>>::new();
+    let mut by_type = BTreeMap::<String, Vec<String>>::new();
+
+    let sources = [
+        parse_mime_types(&crate::read_data("mime.types")?),
+        parse_nginx(&crate::read_data("nginx.mime.types")?),
+    ];
+
+    for (mt, ext) in sources.iter().flatten() {
+        let types = by_ext.entry(ext.clone()).or_default();
+        if !types.contains(mt) {
+            types.push(mt.clone());
+        }
+        let exts = by_type.entry(mt.clone()).or_default();
+        if !exts.contains(ext) {
+            exts.push(ext.clone());
+        }
+    }
+
+    for &(ext, mt) in PREFERRED {
+        let types = by_ext.get_mut(ext).expect("preferred extension is in the sources");
+        let i = types
+            .iter()
+            .position(|t| t == mt)
+            .expect("preferred type is a candidate");
+        let preferred = types.remove(i);
+        types.insert(0, preferred);
+    }
+
+    let mut out = String::from(crate::HEADER);
+    write_table(&mut out, "BY_EXTENSION", &by_ext);
+    write_table(&mut out, "BY_MEDIA_TYPE", &by_type);
+    crate::write_src("extensions/table.rs", &out)
+}
+
+fn write_table(out: &mut String, name: &str, table: &BTreeMap<String, Vec<String>>) {
+    writeln!(out).unwrap();
+    writeln!(out, "pub(super) static {}: &[(&str, &[&str])] = &[", name).unwrap();
+    for (key, values) in table {
+        write!(out, "    ({:?}, &[", key).unwrap();
+        for (i, value) in values.iter().enumerate() {
+            if i > 0 {
+                out.push_str(", ");
+            }
+            write!(out, "{:?}", value).unwrap();
+        }
+        out.push_str("]),\n");
+    }
+    out.push_str("];\n");
+}
+
+/// Parses the Apache / media-types format, `type ext1 ext2`, one type per
+/// line.
+fn parse_mime_types(src: &str) -> Vec<(String, String)> {
+    let mut pairs = Vec::new();
+    for line in src.lines() {
+        if line.starts_with('#') {
+            continue;
+        }
+        let mut words = line.split_whitespace();
+        if let Some(mt) = words.next() {
+            for ext in words {
+                pairs.push((mt.to_ascii_lowercase(), ext.to_ascii_lowercase()));
+            }
+        }
+    }
+    pairs
+}
+
+/// Parses the nginx format, `types { type ext1 ext2; }`, where an entry can
+/// span multiple lines.
+fn parse_nginx(src: &str) -> Vec<(String, String)> {
+    let start = src.find('{').expect("nginx types block") + 1;
+    let end = src.rfind('}').expect("nginx types block");
+
+    let mut pairs = Vec::new();
+    for entry in src[start..end].split(';') {
+        let mut words = entry.split_whitespace();
+        if let Some(mt) = words.next() {
+            for ext in words {
+                pairs.push((mt.to_ascii_lowercase(), ext.to_ascii_lowercase()));
+            }
+        }
+    }
+    pairs
+}
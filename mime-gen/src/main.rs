@@ -0,0 +1,39 @@
+//! Generates the data tables of the `mime` crate from the vendored
+//! snapshots in `mime-gen/data`.
+//!
+//! Run with `cargo run -p mime-gen` from anywhere in the workspace.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+mod extensions;
+mod registry;
+
+fn main() -> io::Result<()> {
+    extensions::generate()?;
+    registry::generate()?;
+    Ok(())
+}
+
+fn data_path(name: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("data").join(name)
+}
+
+fn read_data(name: &str) -> io::Result<String> {
+    fs::read_to_string(data_path(name))
+}
+
+fn write_src(name: &str, contents: &str) -> io::Result<()> {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join("src")
+        .join(name);
+    fs::write(&path, contents)?;
+    println!("generated {}", path.display());
+    Ok(())
+}
+
+const HEADER: &str = "\
+// This file is @generated by `cargo run -p mime-gen`, do not edit it by hand.
+";
@@ -0,0 +1,157 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::io;
+
+/// The top level types, each with a CSV in `data/iana`.
+const TOP_LEVEL: &[&str] = &[
+    "application",
+    "audio",
+    "font",
+    "haptics",
+    "image",
+    "message",
+    "model",
+    "multipart",
+    "text",
+    "video",
+];
+
+struct Entry {
+    template: String,
+    reference: Option<String>,
+    rfc: Option<u16>,
+    status: &'static str,
+    replacement: Option<String>,
+    required: Vec<String>,
+    optional: Vec<String>,
+}
+
+/// Generates `src/registry/table.rs`.
+///
+/// Entries are sorted by their lowercased template, so they can be
+/// binary searched by the essence of a parsed `MediaType`.
+pub(crate) fn generate() -> io::Result<()> {
+    let mut params = BTreeMap::new();
+    for row in parse_csv(&crate::read_data("parameters.csv")?) {
+        let words = |s: &str| s.split_whitespace().map(String::from).collect::<Vec<_>>();
+        params.insert(row[0].to_ascii_lowercase(), (words(&row[1]), words(&row[2])));
+    }
+
+    let mut entries = BTreeMap::new();
+    for top in TOP_LEVEL {
+        let src = crate::read_data(&format!("iana/{}.csv", top))?;
+        for row in parse_csv(&src) {
+            let entry = parse_entry(top, &row, &mut params);
+            let key = entry.template.to_ascii_lowercase();
+            assert!(entries.insert(key, entry).is_none(), "duplicate {:?}", row);
+        }
+    }
+    assert!(params.is_empty(), "parameters for unknown types: {:?}", params.keys());
+
+    let mut out = String::from(crate::HEADER);
+    writeln!(out).unwrap();
+    writeln!(out, "use super::{{Entry, Status}};").unwrap();
+    writeln!(out).unwrap();
+    writeln!(out, "pub(super) static ENTRIES: &[Entry] = &[").unwrap();
+    for entry in entries.values() {
+        write_entry(&mut out, entry);
+    }
+    out.push_str("];\n");
+    crate::write_src("registry/table.rs", &out)
+}
+
+/// Parses a row of an IANA CSV, `Name,Template,Reference`.
+///
+/// The status of a registration is only recorded in its name, such as
+/// `javascript (OBSOLETED in favor of text/javascript)` or
+/// `font-woff - DEPRECATED in favor of font/woff`. A few replacements leave
+/// out the top level type.
+fn parse_entry(top: &str, row: &[String], params: &mut BTreeMap<String, (Vec<String>, Vec<String>)>) -> Entry {
+    let (name, template, reference) = (&row[0], &row[1], &row[2]);
+
+    let template = if template.is_empty() {
+        let subtype = name.split_whitespace().next().expect("name");
+        format!("{}/{}", top, subtype)
+    } else {
+        template.clone()
+    };
+    assert!(template.starts_with(top) && template[top.len()..].starts_with('/'), "{:?}", row);
+
+    let lower = name.to_ascii_lowercase();
+    let status = if lower.contains("obsolete") {
+        "Obsolete"
+    } else if lower.contains("deprecated") {
+        "Deprecated"
+    } else {
+        "Current"
+    };
+    let replacement = lower.find("in favor of ").map(|i| {
+        let rest = name[i + "in favor of ".len()..].trim_end_matches(')').trim();
+        if rest.contains('/') {
+            rest.to_owned()
+        } else {
+            format!("{}/{}", top, rest)
+        }
+    });
+
+    let rfc = reference
+        .find("[RFC")
+        .map(|i| &reference[i + 4..])
+        .and_then(|rest| rest[..rest.find(']')?].parse().ok());
+
+    let (required, optional) = params.remove(&template.to_ascii_lowercase()).unwrap_or_default();
+
+    Entry {
+        template,
+        reference: if reference.is_empty() { None } else { Some(reference.clone()) },
+        rfc,
+        status,
+        replacement,
+        required,
+        optional,
+    }
+}
+
+fn write_entry(out: &mut String, entry: &Entry) {
+    writeln!(
+        out,
+        "    Entry {{ template: {:?}, reference: {:?}, rfc: {:?}, status: Status::{}, replacement: {:?}, required: &{:?}, optional: &{:?} }},",
+        entry.template,
+        entry.reference,
+        entry.rfc,
+        entry.status,
+        entry.replacement,
+        entry.required,
+        entry.optional,
+    )
+    .unwrap();
+}
+
+/// Parses a CSV with a header row, where fields may be quoted.
+fn parse_csv(src: &str) -> Vec<Vec<String>> {
+    let mut rows = Vec::new();
+    for line in src.lines().skip(1) {
+        if line.is_empty() {
+            continue;
+        }
+        let mut row = Vec::new();
+        let mut field = String::new();
+        let mut quoted = false;
+        let mut chars = line.chars().peekable();
+        while let Some(c) = chars.next() {
+            match c {
+                '"' if quoted && chars.peek() == Some(&'"') => {
+                    chars.next();
+                    field.push('"');
+                },
+                '"' => quoted = !quoted,
+                ',' if !quoted => row.push(std::mem::take(&mut field)),
+                c => field.push(c),
+            }
+        }
+        row.push(field);
+        assert_eq!(row.len(), 3, "{:?}", line);
+        rows.push(row);
+    }
+    rows
+}
@@ -10,13 +10,42 @@ pub fn media_type(tokens: TokenStream) -> TokenStream {
     let lit_str = syn::parse_macro_input!(tokens as syn::LitStr);
 
     let mime = match parse_mime_lit(&lit_str.value()) {
-        Ok(mime) => mime,
+        Ok(mime) => quote_mime(&mime),
         Err(msg) => {
             let err = syn::Error::new(Span::call_site(), msg);
             return err.to_compile_error().into();
         }
     };
 
+    let out = quote! {
+        unsafe {
+            $crate::MediaType::private_from_proc_macro(#mime)
+        }
+    };
+    out.into()
+}
+
+#[proc_macro_hack]
+pub fn media_range(tokens: TokenStream) -> TokenStream {
+    let lit_str = syn::parse_macro_input!(tokens as syn::LitStr);
+
+    let mime = match parse_range_lit(&lit_str.value()) {
+        Ok(mime) => quote_mime(&mime),
+        Err(msg) => {
+            let err = syn::Error::new(Span::call_site(), msg);
+            return err.to_compile_error().into();
+        }
+    };
+
+    let out = quote! {
+        unsafe {
+            $crate::MediaRange::private_from_proc_macro(#mime)
+        }
+    };
+    out.into()
+}
+
+fn quote_mime(mime: &mime_parse::Mime) -> proc_macro2::TokenStream {
     let source = match mime.private_atom() {
         0 => {
             let s = mime.as_ref();
@@ -40,39 +69,73 @@ pub fn media_type(tokens: TokenStream) -> TokenStream {
     let params = match mime.private_params_source() {
         mime_parse::ParamSource::None => quote! { $crate::private::ParamSource::None },
         mime_parse::ParamSource::Utf8(sc) => quote! { $crate::private::ParamSource::Utf8(#sc) },
-        mime_parse::ParamSource::One(sc, ((na, nz), (va, vz))) => quote! {
-            $crate::private::ParamSource::One(#sc, ((#na, #nz), (#va, #vz)))
+        mime_parse::ParamSource::One(sc, a) => {
+            let a = quote_pair(a);
+            quote! {
+                $crate::private::ParamSource::One(#sc, #a)
+            }
         },
-        _ => unreachable!("custom params quote"),
+        mime_parse::ParamSource::Two(sc, a, b) => {
+            let a = quote_pair(a);
+            let b = quote_pair(b);
+            quote! {
+                $crate::private::ParamSource::Two(#sc, #a, #b)
+            }
+        },
+        // a `Vec` can't be made in a const, so the pairs are borrowed
+        // from a promoted array instead
+        mime_parse::ParamSource::Custom(sc, pairs) => {
+            let pairs = pairs.iter().map(quote_pair).collect::<Vec<_>>();
+            quote! {
+                $crate::private::ParamSource::Static(#sc, &[#(#pairs),*])
+            }
+        },
+        mime_parse::ParamSource::Static(..) => unreachable!("parsing never makes static params"),
     };
 
-    let out = quote! {
-        unsafe {
-            $crate::MediaType::private_from_proc_macro(
-                $crate::private::Mime::private_from_proc_macro(
-                    #source,
-                    #slash,
-                    #plus,
-                    #params,
-                )
-            )
-        }
-    };
-    out.into()
+    quote! {
+        $crate::private::Mime::private_from_proc_macro(
+            #source,
+            #slash,
+            #plus,
+            #params,
+        )
+    }
 }
 
-fn parse_mime_lit(value: &str) -> Result<mime_parse::Mime, String> {
-    let mime = mime_parse::Parser::cannot_range().parse(value);
+fn quote_pair(&((na, nz), (va, vz)): &((u16, u16), (u16, u16))) -> proc_macro2::TokenStream {
+    quote! { ((#na, #nz), (#va, #vz)) }
+}
 
-    match mime {
-        Ok(mime) => match mime.private_params_source() {
-            mime_parse::ParamSource::None |
-            mime_parse::ParamSource::Utf8(_) => Ok(mime),
-            mime_parse::ParamSource::One(..) => Ok(mime),
-            _ => Err("multiple parameters not supported yet".into())
-        },
+fn parse_mime_lit(value: &str) -> Result<mime_parse::Mime, String> {
+    match mime_parse::Parser::cannot_range().parse(value) {
+        Ok(mime) => check_params(mime),
         Err(err) => {
             Err(format!("invalid MediaType: {}", err))
         }
     }
 }
+
+fn parse_range_lit(value: &str) -> Result<mime_parse::Mime, String> {
+    let mime = match mime_parse::Parser::can_range().parse(value) {
+        Ok(mime) => check_params(mime)?,
+        Err(err) => return Err(format!("invalid MediaRange: {}", err)),
+    };
+
+    // the same check as `MediaRange::parse`
+    if let Some(q) = mime.param("q") {
+        if let Err(err) = mime_parse::parse_quality(q) {
+            return Err(format!("invalid MediaRange: {}", err));
+        }
+    }
+    Ok(mime)
+}
+
+// RFC 2231 extended parameters are decoded into a separate list when
+// parsed, which can't be made in a const.
+fn check_params(mime: mime_parse::Mime) -> Result<mime_parse::Mime, String> {
+    if mime.params().any(|(name, _)| name.contains('*')) {
+        return Err("extended parameters not supported".into());
+    }
+    Ok(mime)
+}
@@ -0,0 +1,161 @@
+use crate::{constants::Atoms, InternParams, Mime, ParamSource, Parser};
+
+// Legacy and duplicate essences, and the essence that should be used
+// instead. Most are `x-` types from before registration, or obsoleted by
+// a later RFC, like the JavaScript types in RFC 9239.
+//
+// Sorted, so it can be binary searched.
+static ALIASES: &[(&str, &str)] = &[
+    ("application/csv", "text/csv"),
+    ("application/ecmascript", "text/javascript"),
+    ("application/font-sfnt", "font/sfnt"),
+    ("application/font-woff", "font/woff"),
+    ("application/font-woff2", "font/woff2"),
+    ("application/javascript", "text/javascript"),
+    ("application/vnd.geo+json", "application/geo+json"),
+    ("application/x-ecmascript", "text/javascript"),
+    ("application/x-font-opentype", "font/otf"),
+    ("application/x-font-otf", "font/otf"),
+    ("application/x-font-truetype", "font/ttf"),
+    ("application/x-font-ttf", "font/ttf"),
+    ("application/x-font-woff", "font/woff"),
+    ("application/x-gzip", "application/gzip"),
+    ("application/x-javascript", "text/javascript"),
+    ("application/x-json", "application/json"),
+    ("application/x-msgpack", "application/msgpack"),
+    ("application/x-pdf", "application/pdf"),
+    ("application/x-yaml", "application/yaml"),
+    ("application/x-zip-compressed", "application/zip"),
+    ("application/x-zstd", "application/zstd"),
+
+    ("audio/mp3", "audio/mpeg"),
+    ("audio/mpeg3", "audio/mpeg"),
+    ("audio/x-aac", "audio/aac"),
+    ("audio/x-flac", "audio/flac"),
+    ("audio/x-m4a", "audio/mp4"),
+    ("audio/x-mp3", "audio/mpeg"),
+    ("audio/x-mpeg", "audio/mpeg"),
+    ("audio/x-mpeg-3", "audio/mpeg"),
+
+    ("image/jpg", "image/jpeg"),
+    ("image/pjpeg", "image/jpeg"),
+    ("image/x-bmp", "image/bmp"),
+    ("image/x-icon", "image/vnd.microsoft.icon"),
+    ("image/x-ms-bmp", "image/bmp"),
+    ("image/x-png", "image/png"),
+
+    ("text/comma-separated-values", "text/csv"),
+    ("text/ecmascript", "text/javascript"),
+    ("text/javascript1.0", "text/javascript"),
+    ("text/javascript1.1", "text/javascript"),
+    ("text/javascript1.2", "text/javascript"),
+    ("text/javascript1.3", "text/javascript"),
+    ("text/javascript1.4", "text/javascript"),
+    ("text/javascript1.5", "text/javascript"),
+    ("text/jscript", "text/javascript"),
+    ("text/json", "application/json"),
+    ("text/livescript", "text/javascript"),
+    ("text/x-csv", "text/csv"),
+    ("text/x-ecmascript", "text/javascript"),
+    ("text/x-javascript", "text/javascript"),
+    ("text/x-json", "application/json"),
+    ("text/x-markdown", "text/markdown"),
+    ("text/x-vcard", "text/vcard"),
+    ("text/x-yaml", "application/yaml"),
+    ("text/yaml", "application/yaml"),
+];
+
+impl Mime {
+    /// Returns the preferred form of this MIME, if its essence is an alias.
+    ///
+    /// Parameters are kept. Without any, the preferred essence is interned,
+    /// so aliases of the constants are atoms too.
+    pub fn canonicalize(&self) -> Option<Mime> {
+        let essence = self.essence();
+        let canonical = ALIASES
+            .binary_search_by(|&(alias, _)| alias.cmp(essence))
+            .map(|i| ALIASES[i].1)
+            .ok()?;
+        let slash = canonical.find('/').expect("aliases have a slash");
+
+        let semicolon = match self.semicolon() {
+            None => {
+                return Some(Mime {
+                    source: Atoms::intern(canonical, slash as u16, InternParams::None),
+                    slash: slash as u16,
+                    plus: canonical.find('+').map(|i| i as u16),
+                    params: ParamSource::None,
+                    ext_params: None,
+                });
+            },
+            Some(i) => i,
+        };
+
+        // The parameters were already valid, so parsing them after the new
+        // essence can't fail, and takes care of shifting every index.
+        let mut s = String::with_capacity(canonical.len() + self.as_ref().len() - semicolon);
+        s.push_str(canonical);
+        s.push_str(&self.as_ref()[semicolon..]);
+        Some(Parser::can_range().parse(s).expect("canonical essence with valid params"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{constants, Parser};
+    use super::ALIASES;
+
+    #[test]
+    fn aliases_are_sorted() {
+        for pair in ALIASES.windows(2) {
+            assert!(pair[0].0 < pair[1].0, "{:?} sorted before {:?}", pair[0].0, pair[1].0);
+        }
+    }
+
+    #[test]
+    fn aliases_are_canonical() {
+        for &(alias, canonical) in ALIASES {
+            let mime = Parser::cannot_range().parse(alias).expect(alias);
+            assert_eq!(mime.essence(), alias);
+            let canon = mime.canonicalize().expect(alias);
+            assert_eq!(canon.as_ref(), canonical);
+            assert_eq!(canon.as_ref(), Parser::cannot_range().parse(canonical).unwrap().as_ref());
+            // no chains
+            assert!(canon.canonicalize().is_none(), "{:?} is an alias", canonical);
+        }
+    }
+
+    #[test]
+    fn canonical_atoms() {
+        let mime = Parser::cannot_range().parse("Image/JPG").unwrap();
+        let canon = mime.canonicalize().unwrap();
+        assert_eq!(canon.private_atom(), constants::IMAGE_JPEG.private_atom());
+
+        let mime = Parser::cannot_range().parse("audio/mp3").unwrap();
+        let canon = mime.canonicalize().unwrap();
+        assert_eq!(canon.as_ref(), "audio/mpeg");
+        assert_eq!(canon.private_atom(), 0);
+    }
+
+    #[test]
+    fn canonical_params() {
+        let mime = Parser::cannot_range().parse("application/x-javascript; charset=utf-8").unwrap();
+        let canon = mime.canonicalize().unwrap();
+        assert_eq!(canon.as_ref(), "text/javascript; charset=utf-8");
+        assert_eq!(canon.param("charset"), Some("utf-8"));
+
+        let mime = Parser::cannot_range().parse("text/x-json; a=1; name*=utf-8''%E2%82%AC").unwrap();
+        let canon = mime.canonicalize().unwrap();
+        assert_eq!(canon.essence(), "application/json");
+        assert_eq!(canon.param("a"), Some("1"));
+        assert_eq!(canon.ext_param("name").unwrap().value, "\"\u{20ac}\"");
+    }
+
+    #[test]
+    fn not_aliases() {
+        for src in &["image/jpeg", "text/plain; charset=utf-8", "application/x-tar", "image/jpg+xml"] {
+            let mime = Parser::cannot_range().parse(*src).unwrap();
+            assert!(mime.canonicalize().is_none(), "{:?}", src);
+        }
+    }
+}
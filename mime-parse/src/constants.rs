@@ -1,7 +1,7 @@
 use super::{InternParams, Mime, ParamSource, Source};
 
 macro_rules! mimes {
-    ($($id:ident, $($piece:expr),+;)+) => (
+    ($($id:ident, $src:expr $(, $piece:expr)*;)+) => (
         #[allow(non_camel_case_types)]
         enum __Atoms {
             __Dynamic,
@@ -12,15 +12,25 @@ macro_rules! mimes {
 
         $(
             mime_constant! {
-                $id, $($piece),+
+                $id, $src $(, $piece)*
             }
         )+
 
+        impl Atoms {
+            // Every atom with its source, for interning in a const fn,
+            // which can't use the tables in `intern`.
+            pub(super) const ALL: &'static [(u8, &'static str)] = &[
+                $(
+                    (__Atoms::$id as u8, $src),
+                )+
+            ];
+        }
+
         #[test]
         fn test_mimes_macro_consts() {
             $(
             mime_constant_test! {
-                $id, $($piece),*
+                $id, $src $(, $piece)*
             }
             )+
         }
@@ -56,6 +66,7 @@ macro_rules! mime_constant {
             slash: $slash,
             plus: $plus,
             params: $params,
+            ext_params: None,
         };
     )
 }
@@ -124,6 +135,9 @@ macro_rules! mime_constant_test {
     })
 }
 
+// The interning tables switch on lengths first and compare strings second,
+// which clippy would rather see collapsed.
+#[allow(clippy::collapsible_if, clippy::collapsible_match, clippy::single_match)]
 impl Atoms {
     pub(super) fn intern(s: &str, slash: u16, params: InternParams) -> Source {
         let slash = slash as usize;
@@ -181,6 +195,11 @@ impl Atoms {
         let sub = &s[slash + 1..];
 
         match slash {
+            1 => {
+                if top == STAR && sub == STAR {
+                    return Atoms::STAR_STAR;
+                }
+            },
             4 => {
                 if top == TEXT {
                     match sub.len() {
@@ -340,6 +359,38 @@ impl Atoms {
                     }
                 }
             }
+            9 => {
+                if top == MULTIPART {
+                    match sub.len() {
+                        5 => {
+                            if sub == MIXED {
+                                return Atoms::MULTIPART_MIXED;
+                            }
+                        },
+                        7 => {
+                            if sub == RELATED {
+                                return Atoms::MULTIPART_RELATED;
+                            }
+                        },
+                        9 => {
+                            if sub == FORM_DATA {
+                                return Atoms::MULTIPART_FORM_DATA;
+                            }
+                        },
+                        10 => {
+                            if sub == BYTERANGES {
+                                return Atoms::MULTIPART_BYTERANGES;
+                            }
+                        },
+                        11 => {
+                            if sub == ALTERNATIVE {
+                                return Atoms::MULTIPART_ALTERNATIVE;
+                            }
+                        },
+                        _ => (),
+                    }
+                }
+            },
             _ => (),
         }
 
@@ -413,6 +464,10 @@ names! {
 
     // multipart/*
     FORM_DATA, "form-data";
+    MIXED, "mixed";
+    ALTERNATIVE, "alternative";
+    RELATED, "related";
+    BYTERANGES, "byteranges";
 
     // common image/*
     BMP, "bmp";
@@ -430,6 +485,7 @@ names! {
     // parameters
     CHARSET, "charset";
     BOUNDARY, "boundary";
+    Q, "q";
 }
 
 mimes! {
@@ -467,6 +523,12 @@ mimes! {
     APPLICATION_PDF, "application/pdf", 11;
     APPLICATION_DNS, "application/dns-message", 11;
 
+    MULTIPART_FORM_DATA, "multipart/form-data", 9;
+    MULTIPART_MIXED, "multipart/mixed", 9;
+    MULTIPART_ALTERNATIVE, "multipart/alternative", 9;
+    MULTIPART_RELATED, "multipart/related", 9;
+    MULTIPART_BYTERANGES, "multipart/byteranges", 9;
+
     // media-ranges
     //@ MediaRange:
     STAR_STAR, "*/*", 1;
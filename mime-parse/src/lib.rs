@@ -2,17 +2,34 @@
 //!
 //! Nothing to see here. Move along.
 
+use std::borrow::Cow;
 use std::error::Error;
 use std::{fmt, slice};
 
+mod alias;
 pub mod constants;
+mod rfc2045;
+mod rfc2231;
 mod rfc7231;
+mod whatwg;
 
 use self::constants::Atoms;
+use self::rfc2231::ExtParam;
+pub use self::rfc2231::ExtValue;
 use self::sealed::Sealed;
 
 pub struct Parser {
     can_range: bool,
+    // allows `type/*+suffix`, which isn't part of any standard
+    can_suffix_range: bool,
+    syntax: Syntax,
+}
+
+#[derive(Clone, Copy)]
+enum Syntax {
+    Rfc7231,
+    Rfc2045,
+    Whatwg,
 }
 
 #[derive(Clone)]
@@ -21,6 +38,19 @@ pub struct Mime {
     slash: u16,
     plus: Option<u16>,
     params: ParamSource,
+    // decoded RFC 2231 parameters, if there were any
+    ext_params: Option<Box<[ExtParam]>>,
+}
+
+/// A validated MIME that borrows its source, without any allocations.
+///
+/// Nothing is lowercased, so comparisons must ignore ASCII case.
+#[derive(Clone, Copy)]
+pub struct MimeRef<'a> {
+    source: &'a str,
+    slash: u16,
+    plus: Option<u16>,
+    semicolon: Option<u16>,
 }
 
 #[derive(Clone)]
@@ -48,6 +78,8 @@ pub enum ParamSource {
     One(u16, IndexedPair),
     Two(u16, IndexedPair, IndexedPair),
     Custom(u16, Vec<IndexedPair>),
+    /// The same as `Custom`, for the proc macros, which can't allocate.
+    Static(u16, &'static [IndexedPair]),
 }
 
 pub enum InternParams {
@@ -60,11 +92,14 @@ pub enum ParseError {
     MissingSlash,
     MissingEqual,
     MissingQuote,
+    MissingParen,
     InvalidToken {
         pos: usize,
         byte: Byte,
     },
     InvalidRange,
+    InvalidQuality,
+    MissingToken,
     TooLong,
 }
 
@@ -94,8 +129,11 @@ impl fmt::Display for ParseError {
             ParseError::MissingSlash => "a slash (/) was missing between the type and subtype",
             ParseError::MissingEqual => "an equals sign (=) was missing between a parameter and its value",
             ParseError::MissingQuote => "a quote (\") was missing from a parameter value",
+            ParseError::MissingParen => "a closing parenthesis ()) was missing from a comment",
             ParseError::InvalidToken { .. } => "invalid token",
             ParseError::InvalidRange => "unexpected asterisk",
+            ParseError::InvalidQuality => "the quality (q) parameter was not a valid weight",
+            ParseError::MissingToken => "a type, subtype, or parameter name was empty",
             ParseError::TooLong => "the string is too long",
         };
         if let ParseError::InvalidToken { pos, byte } = *self {
@@ -148,6 +186,12 @@ impl Mime {
                     params: params.iter(),
                 }
             }
+            ParamSource::Static(_, params) => {
+                ParamsInner::Custom {
+                    source: &self.source,
+                    params: params.iter(),
+                }
+            }
             ParamSource::None => ParamsInner::None,
         };
 
@@ -163,6 +207,23 @@ impl Mime {
         self.params().find(|e| attr == e.0).map(|e| e.1)
     }
 
+    /// Get the decoded value of an RFC 2231 extended parameter, such as
+    /// `name*=utf-8''...` or `name*0`, `name*1` continuations.
+    pub fn ext_param<'a>(&'a self, attr: &str) -> Option<ExtValue<'a>> {
+        self.ext_params
+            .as_ref()?
+            .iter()
+            .map(|p| p.get(self.source.as_ref()))
+            .find(|e| attr == e.0)
+            .map(|e| e.1)
+    }
+
+    /// Removes the parameters named `attr`, returning whether there were
+    /// any.
+    pub fn remove_param(&mut self, attr: &str) -> bool {
+        rfc7231::remove_param(self, attr)
+    }
+
     #[inline]
     pub fn has_params(&self) -> bool {
         self.semicolon().is_some()
@@ -177,6 +238,7 @@ impl Mime {
 
         let mut mtype = self;
         mtype.params = ParamSource::None;
+        mtype.ext_params = None;
         mtype.source = Atoms::intern(
             &mtype.source.as_ref()[..semicolon],
             mtype.slash,
@@ -191,7 +253,8 @@ impl Mime {
             ParamSource::Utf8(i) |
             ParamSource::One(i, ..) |
             ParamSource::Two(i, ..) |
-            ParamSource::Custom(i, _) => Some(i as usize),
+            ParamSource::Custom(i, _) |
+            ParamSource::Static(i, _) => Some(i as usize),
             ParamSource::None => None,
         }
     }
@@ -217,6 +280,18 @@ impl Mime {
         &self.source.as_ref()[..self.semicolon_or_end()]
     }
 
+    /// Unwraps the source, so a `Source::Dynamic` string can be reused.
+    #[inline]
+    pub fn into_source(self) -> Source {
+        self.source
+    }
+
+    /// Parses a static string in a const context, panicking if it isn't
+    /// valid, or isn't already in the form `parse` would store it.
+    pub const fn from_static(s: &'static str) -> Mime {
+        rfc7231::parse_static(s)
+    }
+
     #[doc(hidden)]
     pub const unsafe fn private_from_proc_macro(
         source: Source,
@@ -229,10 +304,73 @@ impl Mime {
             slash,
             plus,
             params,
+            ext_params: None,
         }
     }
 }
 
+// ===== impl MimeRef =====
+
+impl<'a> MimeRef<'a> {
+    #[inline]
+    pub fn type_(&self) -> &'a str {
+        &self.source[..self.slash as usize]
+    }
+
+    #[inline]
+    pub fn subtype(&self) -> &'a str {
+        &self.source[self.slash as usize + 1..self.semicolon_or_end()]
+    }
+
+    #[inline]
+    pub fn suffix(&self) -> Option<&'a str> {
+        let end = self.semicolon_or_end();
+        self.plus.map(|idx| &self.source[idx as usize + 1..end])
+    }
+
+    #[inline]
+    pub fn essence(&self) -> &'a str {
+        &self.source[..self.semicolon_or_end()]
+    }
+
+    /// The parameters, with their names and values as they were written.
+    #[inline]
+    pub fn params(&self) -> ParamsRef<'a> {
+        ParamsRef {
+            source: self.source,
+            pos: self.semicolon_or_end(),
+        }
+    }
+
+    #[inline]
+    pub fn has_params(&self) -> bool {
+        self.params().next().is_some()
+    }
+
+    #[inline]
+    pub fn as_str(&self) -> &'a str {
+        self.source
+    }
+
+    /// Copies into an owned `Mime`, lowercasing and interning it the same
+    /// as if it had been parsed by `Parser::parse`.
+    pub fn to_mime(&self) -> Mime {
+        rfc7231::from_ref(self)
+    }
+
+    #[inline]
+    fn semicolon_or_end(&self) -> usize {
+        self.semicolon.map_or(self.source.len(), |i| i as usize)
+    }
+}
+
+impl<'a> fmt::Debug for MimeRef<'a> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(self.source, f)
+    }
+}
+
 impl AsRef<str> for Mime {
     #[inline]
     fn as_ref(&self) -> &str {
@@ -272,6 +410,8 @@ impl Parser {
     pub fn can_range() -> Self {
         Parser {
             can_range: true,
+            can_suffix_range: false,
+            syntax: Syntax::Rfc7231,
         }
     }
 
@@ -279,17 +419,81 @@ impl Parser {
     pub fn cannot_range() -> Self {
         Parser {
             can_range: false,
+            can_suffix_range: false,
+            syntax: Syntax::Rfc7231,
+        }
+    }
+
+    /// A range parser that also allows a wildcard subtype with a suffix,
+    /// such as `application/*+json`.
+    #[inline]
+    pub fn can_suffix_range() -> Self {
+        Parser {
+            can_range: true,
+            can_suffix_range: true,
+            syntax: Syntax::Rfc7231,
+        }
+    }
+
+    /// A parser following the WHATWG "parse a MIME type" algorithm, which
+    /// skips invalid parameters instead of failing.
+    #[inline]
+    pub fn lenient() -> Self {
+        Parser {
+            can_range: false,
+            can_suffix_range: false,
+            syntax: Syntax::Whatwg,
+        }
+    }
+
+    /// A parser for MIME headers, as in email, which allows comments and
+    /// folding whitespace.
+    #[inline]
+    pub fn rfc2045() -> Self {
+        Parser {
+            can_range: false,
+            can_suffix_range: false,
+            syntax: Syntax::Rfc2045,
         }
     }
 
     pub fn parse(&self, src: impl Parse) -> Result<Mime, ParseError> {
-        rfc7231::parse(self, src)
+        match self.syntax {
+            Syntax::Rfc7231 => rfc7231::parse(self, src),
+            Syntax::Rfc2045 => rfc2045::parse(self, src),
+            Syntax::Whatwg => whatwg::parse(src),
+        }
+    }
+
+    /// Parses with the HTTP grammar, borrowing `src` instead of
+    /// allocating. The `syntax` of this parser is ignored.
+    pub fn parse_ref<'a>(&self, src: &'a str) -> Result<MimeRef<'a>, ParseError> {
+        rfc7231::parse_ref(self, src)
+    }
+
+    pub fn parse_list(&self, src: impl Parse) -> Result<Vec<Mime>, ParseError> {
+        rfc7231::parse_list(self, src)
     }
 }
 
 
-fn lower_ascii_with_params(s: &str, semi: usize, params: &[IndexedPair]) -> String {
-    let mut owned = s.to_owned();
+/// Appends `token` to `dst`, checking that it is a valid token.
+pub fn write_token(dst: &mut String, token: &str) -> Result<(), ParseError> {
+    rfc7231::write_token(dst, token)
+}
+
+/// Appends `value` to `dst` as a parameter value, quoting it if it is not
+/// a valid token.
+pub fn write_value(dst: &mut String, value: &str) -> Result<(), ParseError> {
+    rfc7231::write_value(dst, value)
+}
+
+/// Parses a `q` parameter value, returning it in thousandths.
+pub fn parse_quality(value: &str) -> Result<u16, ParseError> {
+    rfc7231::parse_quality(value)
+}
+
+fn lower_ascii_with_params(mut owned: String, semi: usize, params: &[IndexedPair]) -> String {
     owned[..semi].make_ascii_lowercase();
 
     for &(name, value) in params {
@@ -390,27 +594,212 @@ impl<'a> Iterator for Params<'a> {
     }
 }
 
+/// An iterator over the parameters of a `MimeRef`.
+///
+/// The source was already validated, so this only needs to find where each
+/// name and value ends.
+#[derive(Clone)]
+pub struct ParamsRef<'a> {
+    source: &'a str,
+    pos: usize,
+}
+
+impl<'a> fmt::Debug for ParamsRef<'a> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.debug_struct("ParamsRef").finish()
+    }
+}
+
+impl<'a> Iterator for ParamsRef<'a> {
+    type Item = (&'a str, &'a str);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let bytes = self.source.as_bytes();
+        let mut pos = self.pos;
+
+        // OWS and empty params
+        while pos < bytes.len() && (bytes[pos] == b' ' || bytes[pos] == b';') {
+            pos += 1;
+        }
+        if pos == bytes.len() {
+            self.pos = pos;
+            return None;
+        }
+
+        let name_start = pos;
+        while bytes[pos] != b'=' {
+            pos += 1;
+        }
+        let name = &self.source[name_start..pos];
+        pos += 1;
+
+        let value_start = pos;
+        if bytes[pos] == b'"' {
+            pos += 1;
+            loop {
+                match bytes[pos] {
+                    b'\\' => pos += 2,
+                    b'"' => {
+                        pos += 1;
+                        break;
+                    },
+                    _ => pos += 1,
+                }
+            }
+        } else {
+            while pos < bytes.len() && bytes[pos] != b' ' && bytes[pos] != b';' {
+                pos += 1;
+            }
+        }
+        let value = &self.source[value_start..pos];
+
+        self.pos = pos;
+        Some((name, value))
+    }
+}
+
 mod sealed {
+    use std::borrow::Cow;
+
+    use crate::ParseError;
+
     pub trait Sealed {
-        fn as_str(&self) -> &str;
+        // Owned strings are passed through, so the buffer can be reused
+        // as the `Source::Dynamic` of the parsed `Mime`.
+        fn into_cow<'a>(self) -> Result<Cow<'a, str>, ParseError>
+        where
+            Self: 'a;
+
+        // The HTTP grammar only allows visible ASCII outside of a
+        // quoted-string, so bytes don't need a full UTF-8 check.
+        fn into_http_cow<'a>(self) -> Result<Cow<'a, str>, ParseError>
+        where
+            Self: Sized + 'a,
+        {
+            self.into_cow()
+        }
     }
 }
 
 pub trait Parse: Sealed {}
 
-impl<'a> Sealed for &'a str {
-    fn as_str(&self) -> &str {
-        self
+impl Sealed for &str {
+    fn into_cow<'a>(self) -> Result<Cow<'a, str>, ParseError>
+    where
+        Self: 'a,
+    {
+        Ok(Cow::Borrowed(self))
     }
 }
 
 impl<'a> Parse for &'a str {}
 
-impl<'a> Sealed for &'a String {
-    fn as_str(&self) -> &str {
-        *self
+impl Sealed for &String {
+    fn into_cow<'a>(self) -> Result<Cow<'a, str>, ParseError>
+    where
+        Self: 'a,
+    {
+        Ok(Cow::Borrowed(self))
     }
 }
 
 impl<'a> Parse for &'a String {}
 
+impl Sealed for String {
+    fn into_cow<'a>(self) -> Result<Cow<'a, str>, ParseError>
+    where
+        Self: 'a,
+    {
+        Ok(Cow::Owned(self))
+    }
+}
+
+impl Parse for String {}
+
+impl Sealed for Box<str> {
+    fn into_cow<'a>(self) -> Result<Cow<'a, str>, ParseError>
+    where
+        Self: 'a,
+    {
+        Ok(Cow::Owned(self.into_string()))
+    }
+}
+
+impl Parse for Box<str> {}
+
+impl Sealed for Cow<'_, str> {
+    fn into_cow<'a>(self) -> Result<Cow<'a, str>, ParseError>
+    where
+        Self: 'a,
+    {
+        Ok(self)
+    }
+}
+
+impl Parse for Cow<'_, str> {}
+
+impl Sealed for &[u8] {
+    fn into_cow<'a>(self) -> Result<Cow<'a, str>, ParseError>
+    where
+        Self: 'a,
+    {
+        // Only UTF-8 is checked here, the grammar checks everything else,
+        // with the same positions as for a `&str`.
+        utf8(self).map(Cow::Borrowed)
+    }
+
+    fn into_http_cow<'a>(self) -> Result<Cow<'a, str>, ParseError>
+    where
+        Self: 'a,
+    {
+        http_bytes_to_str(self).map(Cow::Borrowed)
+    }
+}
+
+impl Parse for &[u8] {}
+
+fn utf8(bytes: &[u8]) -> Result<&str, ParseError> {
+    std::str::from_utf8(bytes).map_err(|err| {
+        let pos = err.valid_up_to();
+        ParseError::InvalidToken {
+            pos,
+            byte: Byte(bytes[pos]),
+        }
+    })
+}
+
+// Header values usually arrive as bytes. Outside of a quoted-string, the
+// HTTP grammar only allows visible ASCII, so only a quoted-string can
+// contain anything that needs to be checked for UTF-8.
+fn http_bytes_to_str(bytes: &[u8]) -> Result<&str, ParseError> {
+    let mut is_quoted = false;
+    let mut is_quoted_pair = false;
+    let mut is_ascii = true;
+
+    for (pos, &c) in bytes.iter().enumerate() {
+        if !c.is_ascii() {
+            if !is_quoted {
+                return Err(ParseError::InvalidToken {
+                    pos,
+                    byte: Byte(c),
+                });
+            }
+            is_ascii = false;
+        }
+
+        if is_quoted_pair {
+            is_quoted_pair = false;
+        } else if c == b'"' {
+            is_quoted = !is_quoted;
+        } else if c == b'\\' && is_quoted {
+            is_quoted_pair = true;
+        }
+    }
+
+    if is_ascii {
+        // SAFETY: ASCII is always valid UTF-8
+        Ok(unsafe { std::str::from_utf8_unchecked(bytes) })
+    } else {
+        utf8(bytes)
+    }
+}
@@ -0,0 +1,392 @@
+use crate::{
+    rfc7231::{self, is_restricted_quoted_char, is_token},
+    Byte,
+    Mime,
+    Parse,
+    Parser,
+    ParseError,
+    Syntax,
+};
+
+// From [RFC2045](https://tools.ietf.org/html/rfc2045#section-5.1):
+//
+// >     content := "Content-Type" ":" type "/" subtype
+// >                *(";" parameter)
+// >
+// >     parameter := attribute "=" value
+// >
+// >     value := token / quoted-string
+//
+// > Note that the definition of "tspecials" is the same as the RFC 822
+// > definition of "specials" with the addition of the three characters
+// > "/", "?", and "=", and the removal of ".".
+// >
+// > ... comments are permitted, and are to be treated as whitespace.
+//
+// And [RFC5322](https://tools.ietf.org/html/rfc5322#section-3.2.2):
+//
+// >     FWS      = ([*WSP CRLF] 1*WSP)
+// >     comment  = "(" *([FWS] ccontent) [FWS] ")"
+// >     CFWS     = (1*([FWS] comment) [FWS]) / FWS
+//
+// So folding whitespace and comments may appear between any of the parts,
+// including around the `/`, `;`, and `=`. Folds inside a quoted-string are
+// removed. The parts are written back out the way `rfc7231` would, and
+// parsed by it, so the `Mime` has the same layout as any other.
+
+pub(crate) fn parse(opts: &Parser, src: impl Parse) -> Result<Mime, ParseError> {
+    let src = src.into_cow()?;
+    let mut cursor = Cursor {
+        s: src.as_bytes(),
+        pos: 0,
+    };
+
+    let mut out = Output {
+        dst: String::new(),
+        offsets: Vec::new(),
+    };
+
+    cursor.skip_cfws()?;
+    out.push(cursor.pos, cursor.token()?);
+    cursor.skip_cfws()?;
+    match cursor.next() {
+        Some(b'/') => out.push(cursor.pos - 1, "/"),
+        None => return Err(ParseError::MissingSlash),
+        Some(byte) => return Err(cursor.invalid(byte)),
+    }
+    cursor.skip_cfws()?;
+    out.push(cursor.pos, cursor.token()?);
+    cursor.skip_cfws()?;
+
+    loop {
+        match cursor.next() {
+            Some(b';') => (),
+            None => break,
+            Some(byte) => return Err(cursor.invalid(byte)),
+        }
+        let semicolon = cursor.pos - 1;
+        cursor.skip_cfws()?;
+
+        // a trailing `;` is allowed
+        if cursor.peek().is_none() {
+            break;
+        }
+
+        out.push(semicolon, "; ");
+        out.push(cursor.pos, cursor.token()?);
+        cursor.skip_cfws()?;
+        match cursor.next() {
+            Some(b'=') => out.push(cursor.pos - 1, "="),
+            None => return Err(ParseError::MissingEqual),
+            Some(byte) => return Err(cursor.invalid(byte)),
+        }
+        cursor.skip_cfws()?;
+
+        let pos = cursor.pos;
+        if cursor.peek() == Some(b'"') {
+            let value = cursor.quoted_string()?;
+            out.offsets.push((out.dst.len(), pos));
+            rfc7231::write_value(&mut out.dst, &value)?;
+        } else {
+            out.push(pos, cursor.token()?);
+        }
+        cursor.skip_cfws()?;
+    }
+
+    let opts = Parser {
+        syntax: Syntax::Rfc7231,
+        ..*opts
+    };
+    let Output { dst, offsets } = out;
+    match rfc7231::parse(&opts, dst) {
+        // the position is in the normalized string, not `src`
+        Err(ParseError::InvalidToken { pos, byte }) => Err(ParseError::InvalidToken {
+            pos: src_pos(&offsets, pos),
+            byte,
+        }),
+        result => result,
+    }
+}
+
+// The string written for `rfc7231` to parse, and where each part of it
+// came from in the source.
+struct Output {
+    dst: String,
+    // (position in `dst`, position in the source)
+    offsets: Vec<(usize, usize)>,
+}
+
+impl Output {
+    fn push(&mut self, src_pos: usize, part: &str) {
+        self.offsets.push((self.dst.len(), src_pos));
+        self.dst.push_str(part);
+    }
+}
+
+// Maps a position in the normalized string back to the source. Tokens
+// are copied as is, so a position inside one maps exactly.
+fn src_pos(offsets: &[(usize, usize)], pos: usize) -> usize {
+    match offsets.iter().rev().find(|&&(dst, _)| dst <= pos) {
+        Some(&(dst, src)) => src + (pos - dst),
+        None => pos,
+    }
+}
+
+struct Cursor<'a> {
+    s: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Cursor<'a> {
+    fn peek(&self) -> Option<u8> {
+        self.s.get(self.pos).copied()
+    }
+
+    fn next(&mut self) -> Option<u8> {
+        let byte = self.peek()?;
+        self.pos += 1;
+        Some(byte)
+    }
+
+    // An error for the byte just consumed.
+    fn invalid(&self, byte: u8) -> ParseError {
+        ParseError::InvalidToken {
+            pos: self.pos - 1,
+            byte: Byte(byte),
+        }
+    }
+
+    // A `CRLF` (or a bare `LF`) is only whitespace if it is followed by
+    // more whitespace. Returns the length of the fold.
+    fn fold_len(&self) -> Option<usize> {
+        let rest = &self.s[self.pos..];
+        let newline = if rest.starts_with(b"\r\n") {
+            2
+        } else if rest.starts_with(b"\n") {
+            1
+        } else {
+            return None;
+        };
+        match rest.get(newline) {
+            Some(b' ') | Some(b'\t') => Some(newline),
+            _ => None,
+        }
+    }
+
+    fn skip_cfws(&mut self) -> Result<(), ParseError> {
+        loop {
+            match self.peek() {
+                Some(b' ') | Some(b'\t') => self.pos += 1,
+                Some(b'\r') | Some(b'\n') => match self.fold_len() {
+                    Some(len) => self.pos += len,
+                    None => return Ok(()),
+                },
+                Some(b'(') => self.skip_comment()?,
+                _ => return Ok(()),
+            }
+        }
+    }
+
+    // Comments can be nested, and contain quoted-pairs.
+    fn skip_comment(&mut self) -> Result<(), ParseError> {
+        debug_assert_eq!(self.peek(), Some(b'('));
+        let mut depth = 0;
+        loop {
+            match self.next() {
+                Some(b'(') => depth += 1,
+                Some(b')') => {
+                    depth -= 1;
+                    if depth == 0 {
+                        return Ok(());
+                    }
+                },
+                Some(b'\\') => {
+                    self.next().ok_or(ParseError::MissingParen)?;
+                },
+                Some(b'\r') | Some(b'\n') => {
+                    self.pos -= 1;
+                    match self.fold_len() {
+                        Some(len) => self.pos += len,
+                        None => return Err(self.invalid_here()),
+                    }
+                },
+                Some(_) => (),
+                None => return Err(ParseError::MissingParen),
+            }
+        }
+    }
+
+    fn invalid_here(&self) -> ParseError {
+        ParseError::InvalidToken {
+            pos: self.pos,
+            byte: Byte(self.s[self.pos]),
+        }
+    }
+
+    // `*` isn't a tchar, but is used by ranges and RFC 2231 parameter
+    // names, which `rfc7231` will check.
+    fn token(&mut self) -> Result<&'a str, ParseError> {
+        let start = self.pos;
+        while let Some(c) = self.peek() {
+            if is_token(c) || c == b'*' {
+                self.pos += 1;
+            } else {
+                break;
+            }
+        }
+
+        if self.pos > start {
+            Ok(std::str::from_utf8(&self.s[start..self.pos]).expect("tokens are ascii"))
+        } else if self.pos < self.s.len() {
+            Err(self.invalid_here())
+        } else {
+            Err(ParseError::MissingToken)
+        }
+    }
+
+    // Returns the content of the quoted-string, with quoted-pairs and
+    // folds removed.
+    fn quoted_string(&mut self) -> Result<String, ParseError> {
+        debug_assert_eq!(self.peek(), Some(b'"'));
+        self.pos += 1;
+
+        let mut content = Vec::new();
+        loop {
+            match self.next() {
+                Some(b'"') => break,
+                Some(b'\\') => match self.next() {
+                    Some(c) if is_restricted_quoted_char(c) => content.push(c),
+                    Some(byte) => return Err(self.invalid(byte)),
+                    None => return Err(ParseError::MissingQuote),
+                },
+                Some(b'\r') | Some(b'\n') => {
+                    self.pos -= 1;
+                    match self.fold_len() {
+                        Some(len) => self.pos += len,
+                        None => return Err(self.invalid_here()),
+                    }
+                },
+                Some(c) if is_restricted_quoted_char(c) => content.push(c),
+                Some(byte) => return Err(self.invalid(byte)),
+                None => return Err(ParseError::MissingQuote),
+            }
+        }
+
+        // only ascii bytes were removed, so this is still utf-8
+        Ok(String::from_utf8(content).expect("quoted-string content is utf-8"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Mime, ParseError, Parser};
+
+    fn parse(src: &str) -> Result<Mime, ParseError> {
+        Parser::rfc2045().parse(src)
+    }
+
+    #[test]
+    fn text_plain() {
+        let mime = parse("text/plain").unwrap();
+        assert_eq!(mime.as_ref(), "text/plain");
+        assert_ne!(mime.private_atom(), 0);
+    }
+
+    #[test]
+    fn whitespace_around_separators() {
+        let mime = parse(" text / plain ; charset = \"utf-8\" ").unwrap();
+        assert_eq!(mime.as_ref(), "text/plain; charset=utf-8");
+        assert_ne!(mime.private_atom(), 0);
+    }
+
+    #[test]
+    fn folding() {
+        let mime = parse("multipart/mixed;\r\n boundary=\"simple\r\n\tboundary\"").unwrap();
+        assert_eq!(mime.as_ref(), "multipart/mixed; boundary=\"simple\tboundary\"");
+
+        let mime = parse("text/plain;\n charset=us-ascii").unwrap();
+        assert_eq!(mime.param("charset"), Some("us-ascii"));
+    }
+
+    #[test]
+    fn comments() {
+        let mime = parse("text/plain; charset=us-ascii (Plain text (nested \\) paren))").unwrap();
+        assert_eq!(mime.as_ref(), "text/plain; charset=us-ascii");
+
+        let mime = parse("(leading)text(a)/(b)html(c);(d)level(e)=(f)1(g)").unwrap();
+        assert_eq!(mime.as_ref(), "text/html; level=1");
+
+        let mime = parse("text/plain; (a comment\r\n folded) format=flowed").unwrap();
+        assert_eq!(mime.param("format"), Some("flowed"));
+    }
+
+    #[test]
+    fn quoted_pairs() {
+        let mime = parse("text/plain; a=\"\\q\\\"\\\\\"").unwrap();
+        assert_eq!(mime.as_ref(), "text/plain; a=\"q\\\"\\\\\"");
+    }
+
+    #[test]
+    fn extended_params() {
+        let mime = parse("application/pdf; name*0*=utf-8''%E2%82%AC;\r\n name*1 = \".pdf\"").unwrap();
+        assert_eq!(mime.ext_param("name").unwrap().value, "\"\u{20ac}.pdf\"");
+    }
+
+    #[test]
+    fn ranges() {
+        let mime = Parser {
+            can_range: true,
+            ..Parser::rfc2045()
+        }.parse("text / * ; q=0.5").unwrap();
+        assert_eq!(mime.as_ref(), "text/*; q=0.5");
+
+        parse("text/*").unwrap_err();
+    }
+
+    #[test]
+    fn errors() {
+        parse("").unwrap_err();
+        parse("(only a comment)").unwrap_err();
+        parse("text").unwrap_err();
+        parse("text/").unwrap_err();
+        parse("te xt/plain").unwrap_err();
+        parse("text/\r\nplain").unwrap_err();
+        parse("text/plain;\r\ncharset=utf-8").unwrap_err();
+        parse("text/plain;charset=\"\r\nutf-8\"").unwrap_err();
+        parse("text/plain; charset").unwrap_err();
+        parse("text/plain; charset=").unwrap_err();
+        parse("text/plain; charset=\"utf-8").unwrap_err();
+        parse("text/plain; a=\"\0\"").unwrap_err();
+
+        match parse("text/plain (unclosed").unwrap_err() {
+            ParseError::MissingParen => (),
+            err => panic!("unexpected error: {:?}", err),
+        }
+        match parse("text/plain; a=b c").unwrap_err() {
+            ParseError::InvalidToken { pos, .. } => assert_eq!(pos, 16),
+            err => panic!("unexpected error: {:?}", err),
+        }
+    }
+
+    #[test]
+    fn error_positions() {
+        // positions are in the source, not the normalized string
+        let cases = [
+            ("text/plain; a=b c", 16),
+            ("text/plain (a comment) ; a=\"\0\"", 28),
+            ("text/plain (a comment) ; a=\"x\\\0\"", 30),
+            ("(a comment) text / pl*in", 21),
+            ("text/plain;\r\n (a comment) a = b*c", 31),
+            ("(a) */plain", 4),
+        ];
+        for &(src, expected) in &cases {
+            match parse(src).unwrap_err() {
+                ParseError::InvalidToken { pos, byte } => {
+                    assert_eq!(pos, expected, "{:?}", src);
+                    assert_eq!(byte.0, src.as_bytes()[pos], "{:?}", src);
+                },
+                err => panic!("unexpected error for {:?}: {:?}", src, err),
+            }
+        }
+    }
+}
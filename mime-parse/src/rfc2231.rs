@@ -0,0 +1,337 @@
+use crate::{as_u16, range, rfc7231, Indexed, IndexedPair};
+
+// From [RFC2231](https://tools.ietf.org/html/rfc2231), as updated for HTTP
+// by [RFC8187](https://tools.ietf.org/html/rfc8187):
+//
+// > Parameter values may be broken into several sections, and may declare
+// > their charset and language:
+// >
+// >     title*0*=us-ascii'en'This%20is%20even%20more%20
+// >     title*1*=%2A%2A%2Afun%2A%2A%2A%20
+// >     title*2="isn't it!"
+// >
+// >     ext-value     = charset  "'" [ language ] "'" value-chars
+// >     value-chars   = *( pct-encoded / attr-char )
+//
+// Only the first section declares a charset and language. Sections with a
+// trailing `*` are percent-encoded, the rest are plain (maybe quoted)
+// values. The sections are numbered from 0, and anything after a missing
+// section is ignored.
+//
+// The joined and decoded value is stored as a quoted-string, so it can be
+// handled the same way as the value of a regular parameter.
+
+#[derive(Clone)]
+pub struct ExtParam {
+    name: Indexed,
+    charset: Option<Indexed>,
+    language: Option<Indexed>,
+    value: String,
+}
+
+/// The decoded value of an extended parameter.
+#[derive(Clone, Copy, Debug)]
+pub struct ExtValue<'a> {
+    /// The decoded value, written as a token or quoted-string.
+    pub value: &'a str,
+    /// The charset the value was declared in.
+    pub charset: Option<&'a str>,
+    /// The language tag of the value.
+    pub language: Option<&'a str>,
+}
+
+impl ExtParam {
+    pub(crate) fn get<'a>(&'a self, source: &'a str) -> (&'a str, ExtValue<'a>) {
+        let value = ExtValue {
+            value: &self.value,
+            charset: self.charset.map(|r| &source[range(r)]),
+            language: self.language.map(|r| &source[range(r)]),
+        };
+        (&source[range(self.name)], value)
+    }
+}
+
+struct Section {
+    index: Option<u16>,
+    is_encoded: bool,
+    value: Indexed,
+}
+
+pub(crate) fn decode(source: &str, params: &[IndexedPair]) -> Option<Box<[ExtParam]>> {
+    if !params.iter().any(|&(name, _)| source[range(name)].contains('*')) {
+        return None;
+    }
+
+    // group the sections by their name, in the order they first appear
+    let mut groups: Vec<(Indexed, Vec<Section>)> = Vec::new();
+    for &(name, value) in params {
+        let (base, index, is_encoded) = match split_name(&source[range(name)]) {
+            Some(parts) => parts,
+            None => continue,
+        };
+        let base = (name.0, name.0 + as_u16(base.len()));
+        let section = Section {
+            index,
+            is_encoded,
+            value,
+        };
+
+        match groups.iter_mut().find(|(n, _)| source[range(*n)] == source[range(base)]) {
+            Some((_, sections)) => sections.push(section),
+            None => groups.push((base, vec![section])),
+        }
+    }
+
+    let decoded = groups
+        .into_iter()
+        .filter_map(|(name, sections)| decode_sections(source, name, sections))
+        .collect::<Vec<_>>();
+
+    if decoded.is_empty() {
+        None
+    } else {
+        Some(decoded.into_boxed_slice())
+    }
+}
+
+// Splits `name*2*` into `("name", Some(2), true)`. Returns `None` if the
+// name is not an extended parameter.
+fn split_name(name: &str) -> Option<(&str, Option<u16>, bool)> {
+    let (name, is_encoded) = match name.strip_suffix('*') {
+        Some(name) => (name, true),
+        None => (name, false),
+    };
+
+    let (base, index) = match name.rfind('*') {
+        Some(star) => {
+            let digits = &name[star + 1..];
+            let is_number = !digits.is_empty()
+                && digits.bytes().all(|b| b.is_ascii_digit())
+                && (digits == "0" || !digits.starts_with('0'));
+            if !is_number {
+                return None;
+            }
+            (&name[..star], Some(digits.parse().ok()?))
+        },
+        None if is_encoded => (name, None),
+        None => return None,
+    };
+
+    if base.is_empty() || base.contains('*') {
+        None
+    } else {
+        Some((base, index, is_encoded))
+    }
+}
+
+fn decode_sections(source: &str, name: Indexed, mut sections: Vec<Section>) -> Option<ExtParam> {
+    // a single `name*=` wins over any numbered sections
+    match sections.iter().position(|s| s.index.is_none()) {
+        Some(i) => {
+            let section = sections.swap_remove(i);
+            sections = vec![section];
+        },
+        None => {
+            sections.sort_by_key(|s| s.index);
+            let len = sections
+                .iter()
+                .enumerate()
+                .take_while(|&(i, s)| s.index == Some(as_u16(i)))
+                .count();
+            sections.truncate(len);
+        },
+    }
+
+    let first = sections.first()?;
+    let (mut charset, mut language) = (None, None);
+    let mut bytes = Vec::new();
+
+    for (i, section) in sections.iter().enumerate() {
+        let (start, end) = unquote(source, section.value);
+        let mut value = &source[start..end];
+
+        if !section.is_encoded {
+            push_unescaped(&mut bytes, value);
+            continue;
+        }
+
+        if i == 0 && first.is_encoded {
+            // charset'language'value
+            let quote1 = value.find('\'')?;
+            let quote2 = quote1 + 1 + value[quote1 + 1..].find('\'')?;
+            if quote1 > 0 {
+                charset = Some((as_u16(start), as_u16(start + quote1)));
+            }
+            if quote2 > quote1 + 1 {
+                language = Some((as_u16(start + quote1 + 1), as_u16(start + quote2)));
+            }
+            value = &value[quote2 + 1..];
+        }
+
+        percent_decode(&mut bytes, value)?;
+    }
+
+    let content = decode_charset(bytes, charset.map(|r| &source[range(r)]))?;
+    let mut value = String::new();
+    rfc7231::write_value(&mut value, &content).ok()?;
+
+    Some(ExtParam {
+        name,
+        charset,
+        language,
+        value,
+    })
+}
+
+fn unquote(source: &str, value: Indexed) -> (usize, usize) {
+    let (start, end) = (value.0 as usize, value.1 as usize);
+    if source[start..end].starts_with('"') {
+        (start + 1, end - 1)
+    } else {
+        (start, end)
+    }
+}
+
+fn push_unescaped(dst: &mut Vec<u8>, value: &str) {
+    let mut bytes = value.bytes();
+    while let Some(b) = bytes.next() {
+        match b {
+            b'\\' => dst.extend(bytes.next()),
+            b => dst.push(b),
+        }
+    }
+}
+
+fn percent_decode(dst: &mut Vec<u8>, value: &str) -> Option<()> {
+    let mut bytes = value.bytes();
+    while let Some(b) = bytes.next() {
+        if b == b'%' {
+            let hi = (bytes.next()? as char).to_digit(16)?;
+            let lo = (bytes.next()? as char).to_digit(16)?;
+            dst.push((hi * 16 + lo) as u8);
+        } else {
+            dst.push(b);
+        }
+    }
+    Some(())
+}
+
+// Without a conversion library, only the charsets RFC 8187 requires to be
+// supported are decoded.
+fn decode_charset(bytes: Vec<u8>, charset: Option<&str>) -> Option<String> {
+    let charset = charset.unwrap_or("utf-8");
+    if charset.eq_ignore_ascii_case("utf-8") {
+        String::from_utf8(bytes).ok()
+    } else if charset.eq_ignore_ascii_case("us-ascii") {
+        if bytes.is_ascii() {
+            String::from_utf8(bytes).ok()
+        } else {
+            None
+        }
+    } else if charset.eq_ignore_ascii_case("iso-8859-1") {
+        Some(bytes.into_iter().map(char::from).collect())
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ExtValue, Mime, ParseError, Parser};
+
+    fn parse(src: &str) -> Result<Mime, ParseError> {
+        Parser::cannot_range().parse(src)
+    }
+
+    fn ext<'a>(mime: &'a Mime, name: &str) -> Option<ExtValue<'a>> {
+        mime.ext_param(name)
+    }
+
+    #[test]
+    fn split_name() {
+        use super::split_name;
+
+        assert_eq!(split_name("name*"), Some(("name", None, true)));
+        assert_eq!(split_name("name*0"), Some(("name", Some(0), false)));
+        assert_eq!(split_name("name*0*"), Some(("name", Some(0), true)));
+        assert_eq!(split_name("name*12*"), Some(("name", Some(12), true)));
+        assert_eq!(split_name("name"), None);
+        assert_eq!(split_name("*"), None);
+        assert_eq!(split_name("name*01"), None);
+        assert_eq!(split_name("name*x*"), None);
+        assert_eq!(split_name("name**"), None);
+        assert_eq!(split_name("a*b*1"), None);
+    }
+
+    #[test]
+    fn single() {
+        let mime = parse("attachment/x; filename*=UTF-8'en'%E2%82%AC%20rates.pdf").unwrap();
+        let value = ext(&mime, "filename").unwrap();
+        assert_eq!(value.value, "\"\u{20ac} rates.pdf\"");
+        assert_eq!(value.charset, Some("UTF-8"));
+        assert_eq!(value.language, Some("en"));
+
+        // the raw parameter is still there
+        assert_eq!(mime.param("filename*"), Some("UTF-8'en'%E2%82%AC%20rates.pdf"));
+        assert!(ext(&mime, "filename*").is_none());
+    }
+
+    #[test]
+    fn continuations() {
+        let mime = parse("message/external-body; access-type=URL; \
+            URL*0=\"ftp://\"; URL*1=\"cs.utk.edu/pub/moore/bulk-mailer/bulk-mailer.tar\"").unwrap();
+        let value = ext(&mime, "url").unwrap();
+        assert_eq!(value.value, "\"ftp://cs.utk.edu/pub/moore/bulk-mailer/bulk-mailer.tar\"");
+        assert_eq!(value.charset, None);
+        assert_eq!(value.language, None);
+        assert!(ext(&mime, "access-type").is_none());
+    }
+
+    #[test]
+    fn encoded_continuations() {
+        let mime = parse("application/x-stuff; \
+            title*2=\"isn't it!\"; \
+            title*1*=%2A%2A%2Afun%2A%2A%2A%20; \
+            title*0*=us-ascii'en-us'This%20is%20even%20more%20").unwrap();
+        let value = ext(&mime, "title").unwrap();
+        assert_eq!(value.value, "\"This is even more ***fun*** isn't it!\"");
+        assert_eq!(value.charset, Some("us-ascii"));
+        assert_eq!(value.language, Some("en-us"));
+
+        let mime = parse("text/plain; name*0*=utf-8''%E2%82%AC; name*1=rate.pdf").unwrap();
+        assert_eq!(ext(&mime, "name").unwrap().value, "\"\u{20ac}rate.pdf\"");
+    }
+
+    #[test]
+    fn missing_sections() {
+        let mime = parse("text/plain; a*0=x; a*2=z; b*1=y").unwrap();
+        assert_eq!(ext(&mime, "a").unwrap().value, "x");
+        assert!(ext(&mime, "b").is_none());
+    }
+
+    #[test]
+    fn single_wins_over_sections() {
+        let mime = parse("text/plain; a*0=x; a*=''y; a*1=z").unwrap();
+        assert_eq!(ext(&mime, "a").unwrap().value, "y");
+    }
+
+    #[test]
+    fn charsets() {
+        let mime = parse("text/plain; a*=iso-8859-1''caf%E9; b*=''%E2%82%AC; c*=us-ascii''%E9; d*=koi8-r''%C1").unwrap();
+        assert_eq!(ext(&mime, "a").unwrap().value, "\"caf\u{e9}\"");
+        assert_eq!(ext(&mime, "b").unwrap().value, "\"\u{20ac}\"");
+        assert_eq!(ext(&mime, "b").unwrap().charset, None);
+        assert!(ext(&mime, "c").is_none());
+        assert!(ext(&mime, "d").is_none());
+    }
+
+    #[test]
+    fn invalid_encodings() {
+        let mime = parse("text/plain; a*=utf-8'%E2; b*=utf-8''%E2%82; c*=utf-8''%zz; d*=utf-8''%0A").unwrap();
+        assert!(ext(&mime, "a").is_none());
+        assert!(ext(&mime, "b").is_none());
+        assert!(ext(&mime, "c").is_none());
+        assert!(ext(&mime, "d").is_none());
+        assert_eq!(mime.params().count(), 4);
+    }
+}
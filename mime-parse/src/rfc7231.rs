@@ -1,16 +1,22 @@
+use std::borrow::Cow;
+
 use crate::{
     as_u16,
     constants,
     Atoms,
     Byte,
+    Indexed,
+    IndexedPair,
     InternParams,
     lower_ascii_with_params,
     Mime,
+    MimeRef,
     Parse,
     Parser,
     ParseError,
     ParamSource,
     range,
+    rfc2231,
     Source,
 };
 
@@ -52,8 +58,9 @@ use crate::{
 // So, clearly, ¯\_(Ä_/¯
 
 pub(crate) fn parse(opts: &Parser, src: impl Parse) -> Result<Mime, ParseError> {
-    let s = src.as_str();
-    if s.len() > std::u16::MAX as usize {
+    let src = src.into_http_cow()?;
+    let s = &*src;
+    if s.len() > u16::MAX as usize {
         return Err(ParseError::TooLong);
     }
 
@@ -66,12 +73,98 @@ pub(crate) fn parse(opts: &Parser, src: impl Parse) -> Result<Mime, ParseError>
     }
 
     let mut iter = s.bytes().enumerate();
+    let (slash, plus, start) = match essence(opts, s, &mut iter)? {
+        (slash, plus, Some(start)) => (slash, plus, start),
+        (slash, plus, None) => return Ok(Mime {
+            source: Atoms::intern(s, slash, InternParams::None),
+            slash,
+            plus,
+            params: ParamSource::None,
+            ext_params: None,
+        }),
+    };
+
+    // params
+    let params = params_from_str(s, &mut iter, start)?;
+    Ok(with_params(src, slash, plus, start, params))
+}
+
+// Parses without allocating, keeping only the offsets into `s`. The whole
+// string is validated the same as `parse`, but the parameters are not
+// stored, and so must be scanned again when needed.
+pub(crate) fn parse_ref<'a>(opts: &Parser, s: &'a str) -> Result<MimeRef<'a>, ParseError> {
+    if s.len() > u16::MAX as usize {
+        return Err(ParseError::TooLong);
+    }
+
+    if s == "*/*" && !opts.can_range {
+        return Err(ParseError::InvalidRange);
+    }
+
+    let mut iter = s.bytes().enumerate();
+    let (slash, plus, start) = essence(opts, s, &mut iter)?;
+    if let Some(start) = start {
+        visit_params(s, &mut iter, start, |_, _| ())?;
+    }
+
+    Ok(MimeRef {
+        source: s,
+        slash,
+        plus,
+        semicolon: start.map(as_u16),
+    })
+}
+
+// Builds the `Mime` for a validated `MimeRef`.
+pub(crate) fn from_ref(mime: &MimeRef<'_>) -> Mime {
+    let s = mime.source;
+    match mime.semicolon {
+        None => Mime {
+            source: Atoms::intern(s, mime.slash, InternParams::None),
+            slash: mime.slash,
+            plus: mime.plus,
+            params: ParamSource::None,
+            ext_params: None,
+        },
+        Some(start) => {
+            let start = start as usize;
+            let mut iter = s.bytes().enumerate().skip(start + 1);
+            let params = params_from_str(s, &mut iter, start)
+                .expect("MimeRef params were validated");
+            with_params(Cow::Borrowed(s), mime.slash, mime.plus, start, params)
+        },
+    }
+}
+
+// Validates the type and subtype, returning the slash, the plus, and the
+// start of the params, if the string doesn't end after the subtype.
+fn essence(
+    opts: &Parser,
+    s: &str,
+    iter: &mut impl Iterator<Item=(usize, u8)>,
+) -> Result<(u16, Option<u16>, Option<usize>), ParseError> {
     // toplevel
     let mut start;
     let slash;
     loop {
         match iter.next() {
             Some((_, c)) if is_token(c) => (),
+            // `*/*` followed by parameters, the subtype star is checked
+            // by the sublevel loop
+            Some((0, b'*')) if opts.can_range => {
+                match iter.next() {
+                    Some((1, b'/')) if s.as_bytes().get(2) == Some(&b'*') => {
+                        slash = 1;
+                        start = 2;
+                        break;
+                    },
+                    None => return Err(ParseError::MissingSlash),
+                    Some((pos, byte)) => return Err(ParseError::InvalidToken {
+                        pos,
+                        byte: Byte(byte),
+                    }),
+                }
+            },
             Some((i, b'/')) if i > 0 => {
                 slash = as_u16(i);
                 start = i + 1;
@@ -79,7 +172,7 @@ pub(crate) fn parse(opts: &Parser, src: impl Parse) -> Result<Mime, ParseError>
             },
             None => return Err(ParseError::MissingSlash), // EOF and no toplevel is no Mime
             Some((pos, byte)) => return Err(ParseError::InvalidToken {
-                pos: pos,
+                pos,
                 byte: Byte(byte),
             }),
         };
@@ -87,7 +180,7 @@ pub(crate) fn parse(opts: &Parser, src: impl Parse) -> Result<Mime, ParseError>
 
     // sublevel
     let mut plus = None;
-    loop {
+    'sublevel: loop {
         match iter.next() {
             Some((i, b'+')) if i > start => {
                 plus = Some(as_u16(i));
@@ -104,16 +197,34 @@ pub(crate) fn parse(opts: &Parser, src: impl Parse) -> Result<Mime, ParseError>
                 // sublevel star can only be the first character, and the next
                 // must either be the end, or `;`
                 match iter.next() {
-                    Some((i, b';')) => {
+                    Some((i, b';')) |
+                    Some((i, b' ')) => {
                         start = i;
                         break;
                     },
-                    None => return Ok(Mime {
-                        source: Atoms::intern(s, slash, InternParams::None),
-                        slash,
-                        plus,
-                        params: ParamSource::None,
-                    }),
+                    None => return Ok((slash, plus, None)),
+                    // `type/*+suffix`, but not `*/*+suffix`
+                    Some((i, b'+')) if opts.can_suffix_range && !s.starts_with('*') => {
+                        plus = Some(as_u16(i));
+                        // the suffix is a token, without another `+`
+                        let mut is_empty = true;
+                        loop {
+                            match iter.next() {
+                                Some((i, b';')) |
+                                Some((i, b' ')) if !is_empty => {
+                                    start = i;
+                                    break 'sublevel;
+                                },
+                                Some((_, c)) if is_token(c) && c != b'+' => is_empty = false,
+                                None if !is_empty => return Ok((slash, plus, None)),
+                                None => return Err(ParseError::MissingToken),
+                                Some((pos, byte)) => return Err(ParseError::InvalidToken {
+                                    pos,
+                                    byte: Byte(byte),
+                                }),
+                            }
+                        }
+                    },
                     Some((pos, byte)) => return Err(ParseError::InvalidToken {
                         pos,
                         byte: Byte(byte),
@@ -122,54 +233,241 @@ pub(crate) fn parse(opts: &Parser, src: impl Parse) -> Result<Mime, ParseError>
             },
 
             Some((_, c)) if is_token(c) => (),
-            None => {
-                return Ok(Mime {
-                    source: Atoms::intern(s, slash, InternParams::None),
-                    slash,
-                    plus,
-                    params: ParamSource::None,
-                });
-            },
+            None => return Ok((slash, plus, None)),
             Some((pos, byte)) => return Err(ParseError::InvalidToken {
-                pos: pos,
+                pos,
                 byte: Byte(byte),
             })
         };
     }
 
-    // params
-    let params = params_from_str(s, &mut iter, start)?;
+    Ok((slash, plus, Some(start)))
+}
 
+// An owned `src` is reused for the `Source::Dynamic`, instead of copied.
+fn with_params(src: Cow<'_, str>, slash: u16, plus: Option<u16>, start: usize, params: ParamSource) -> Mime {
     let source = match params {
         ParamSource::None => {
             // Getting here means there *was* a `;`, but then no parameters
             // after it... So let's just chop off the empty param list.
-            debug_assert_ne!(s.len(), start);
+            debug_assert_ne!(src.len(), start);
             debug_assert!({
-                let b = s.as_bytes()[start];
+                let b = src.as_bytes()[start];
                 b == b';' || b == b' '
             });
-            Atoms::intern(&s[..start], slash, InternParams::None)
+            Atoms::intern(&src[..start], slash, InternParams::None)
         },
-        ParamSource::Utf8(params_start) => Atoms::intern(s, slash, InternParams::Utf8(params_start as usize)),
-        ParamSource::One(params_start, a) => Source::Dynamic(lower_ascii_with_params(s, params_start as usize, &[a])),
-        ParamSource::Two(params_start, a, b) => Source::Dynamic(lower_ascii_with_params(s, params_start as usize, &[a, b])),
-        ParamSource::Custom(params_start, ref indices) => Source::Dynamic(lower_ascii_with_params(s, params_start as usize, indices)),
+        ParamSource::Utf8(params_start) => Atoms::intern(&src, slash, InternParams::Utf8(params_start as usize)),
+        ParamSource::One(params_start, a) => Source::Dynamic(lower_ascii_with_params(src.into_owned(), params_start as usize, &[a])),
+        ParamSource::Two(params_start, a, b) => Source::Dynamic(lower_ascii_with_params(src.into_owned(), params_start as usize, &[a, b])),
+        ParamSource::Custom(params_start, ref indices) => Source::Dynamic(lower_ascii_with_params(src.into_owned(), params_start as usize, indices)),
+        ParamSource::Static(params_start, indices) => Source::Dynamic(lower_ascii_with_params(src.into_owned(), params_start as usize, indices)),
+    };
+
+    let ext_params = match params {
+        ParamSource::One(_, a) => rfc2231::decode(source.as_ref(), &[a]),
+        ParamSource::Two(_, a, b) => rfc2231::decode(source.as_ref(), &[a, b]),
+        ParamSource::Custom(_, ref indices) => rfc2231::decode(source.as_ref(), indices),
+        ParamSource::Static(_, indices) => rfc2231::decode(source.as_ref(), indices),
+        ParamSource::None | ParamSource::Utf8(_) => None,
     };
 
-    Ok(Mime {
+    Mime {
         source,
         slash,
         plus,
         params,
-    })
+        ext_params,
+    }
+}
+
+// Removes the params named `name`, keeping the rest with the separators
+// they had. Everything kept was already validated, so nothing is parsed
+// again, and the source can only get shorter.
+pub(crate) fn remove_param(mime: &mut Mime, name: &str) -> bool {
+    let semicolon = match mime.semicolon() {
+        Some(i) => i,
+        None => return false,
+    };
+    let pairs = match mime.params {
+        ParamSource::None => return false,
+        ParamSource::Utf8(i) => {
+            let i = i + 2;
+            let charset = (i, "charset".len() as u16 + i);
+            let utf8 = (charset.1 + 1, charset.1 + "utf-8".len() as u16 + 1);
+            vec![(charset, utf8)]
+        },
+        ParamSource::One(_, a) => vec![a],
+        ParamSource::Two(_, a, b) => vec![a, b],
+        ParamSource::Custom(_, ref params) => params.clone(),
+        ParamSource::Static(_, params) => params.to_vec(),
+    };
+
+    let src = mime.source.as_ref();
+    if !pairs.iter().any(|&(n, _)| src[range(n)].eq_ignore_ascii_case(name)) {
+        return false;
+    }
+
+    let mut s = String::with_capacity(src.len());
+    s.push_str(&src[..semicolon]);
+    let mut kept: Vec<IndexedPair> = Vec::with_capacity(pairs.len() - 1);
+    let mut prev_end = as_u16(semicolon);
+    let mut removed = 0;
+    for &(n, v) in &pairs {
+        if src[range(n)].eq_ignore_ascii_case(name) {
+            removed += v.1 - prev_end;
+        } else {
+            s.push_str(&src[range((prev_end, v.1))]);
+            kept.push(((n.0 - removed, n.1 - removed), (v.0 - removed, v.1 - removed)));
+        }
+        prev_end = v.1;
+    }
+
+    let params_start = as_u16(semicolon);
+    let params = match kept[..] {
+        [] => {
+            *mime = Mime {
+                source: Atoms::intern(&s, mime.slash, InternParams::None),
+                slash: mime.slash,
+                plus: mime.plus,
+                params: ParamSource::None,
+                ext_params: None,
+            };
+            return true;
+        },
+        [(n, v)] if params_start + 2 == n.0 &&
+            "charset".eq_ignore_ascii_case(&s[range(n)]) &&
+            "utf-8".eq_ignore_ascii_case(&s[range(v)]) => ParamSource::Utf8(params_start),
+        [a] => ParamSource::One(params_start, a),
+        [a, b] => ParamSource::Two(params_start, a, b),
+        _ => ParamSource::Custom(params_start, kept),
+    };
+    *mime = with_params(Cow::Owned(s), mime.slash, mime.plus, semicolon, params);
+    true
 }
 
 
-fn params_from_str(s: &str, iter: &mut impl Iterator<Item=(usize, u8)>, mut start: usize) -> Result<ParamSource, ParseError> {
+// From [RFC7230](https://tools.ietf.org/html/rfc7230#section-7):
+//
+// > For compatibility with legacy list rules, a recipient MUST parse and
+// > ignore a reasonable number of empty list elements: enough to handle
+// > common mistakes by senders that merge values, but not so much that
+// > they could be used as a denial-of-service mechanism.
+//
+// A comma inside a quoted-string is part of the parameter value, not an
+// element separator, so quotes and quoted-pairs are tracked while splitting.
+
+pub(crate) fn parse_list(opts: &Parser, src: impl Parse) -> Result<Vec<Mime>, ParseError> {
+    let src = src.into_http_cow()?;
+    let s = &*src;
+    let mut list = Vec::new();
+    let mut start = 0;
+    let mut is_quoted = false;
+    let mut is_quoted_pair = false;
+
+    for (i, c) in s.bytes().enumerate() {
+        if is_quoted {
+            if is_quoted_pair {
+                is_quoted_pair = false;
+            } else if c == b'\\' {
+                is_quoted_pair = true;
+            } else if c == b'"' {
+                is_quoted = false;
+            }
+        } else if c == b'"' {
+            is_quoted = true;
+        } else if c == b',' {
+            parse_list_element(opts, s, start, i, &mut list)?;
+            start = i + 1;
+        }
+    }
+
+    if is_quoted {
+        return Err(ParseError::MissingQuote);
+    }
+
+    parse_list_element(opts, s, start, s.len(), &mut list)?;
+    Ok(list)
+}
+
+fn parse_list_element(
+    opts: &Parser,
+    s: &str,
+    mut start: usize,
+    mut end: usize,
+    list: &mut Vec<Mime>,
+) -> Result<(), ParseError> {
+    let bytes = s.as_bytes();
+    while start < end && is_ows(bytes[start]) {
+        start += 1;
+    }
+    while end > start && is_ows(bytes[end - 1]) {
+        end -= 1;
+    }
+
+    // empty elements are skipped
+    if start == end {
+        return Ok(());
+    }
+
+    match parse(opts, &s[start..end]) {
+        Ok(mime) => {
+            list.push(mime);
+            Ok(())
+        },
+        Err(ParseError::InvalidToken { pos, byte }) => Err(ParseError::InvalidToken {
+            pos: pos + start,
+            byte,
+        }),
+        Err(err) => Err(err),
+    }
+}
+
+
+fn params_from_str(s: &str, iter: &mut impl Iterator<Item=(usize, u8)>, start: usize) -> Result<ParamSource, ParseError> {
     let params_start = as_u16(start);
-    start += 1;
     let mut params = ParamSource::None;
+    visit_params(s, iter, start, |name, value| {
+        match params {
+            ParamSource::Utf8(i) => {
+                let i = i + 2;
+                let charset = (i, "charset".len() as u16 + i);
+                let utf8 = (charset.1 + 1, charset.1 + "utf-8".len() as u16 + 1);
+                params = ParamSource::Two(params_start, (charset, utf8), (name, value));
+            },
+            ParamSource::One(sc, a) => {
+                params = ParamSource::Two(sc, a, (name, value));
+            },
+            ParamSource::Two(sc, a, b) => {
+                params = ParamSource::Custom(sc, vec![a, b, (name, value)]);
+            },
+            ParamSource::Custom(_, ref mut vec) => {
+                vec.push((name, value));
+            },
+            ParamSource::Static(..) => unreachable!("parsing never makes static params"),
+            ParamSource::None => {
+                if params_start + 2 == name.0 &&
+                    "charset".eq_ignore_ascii_case(&s[range(name)]) &&
+                    "utf-8".eq_ignore_ascii_case(&s[range(value)]) {
+                    params = ParamSource::Utf8(params_start);
+                } else {
+                    params = ParamSource::One(params_start, (name, value));
+                }
+            },
+        }
+    })?;
+    Ok(params)
+}
+
+// Validates the params starting at the `;` (or space) at `start`, calling
+// `f` with the name and value of each one.
+fn visit_params(
+    s: &str,
+    iter: &mut impl Iterator<Item=(usize, u8)>,
+    mut start: usize,
+    mut f: impl FnMut(Indexed, Indexed),
+) -> Result<(), ParseError> {
+    start += 1;
     'params: while start < s.len() {
         let name;
         // name
@@ -185,7 +483,8 @@ fn params_from_str(s: &str, iter: &mut impl Iterator<Item=(usize, u8)>, mut star
                     start = i + 1;
                     continue 'params;
                 },
-                Some((_, c)) if is_token(c) => (),
+                // `*` is a tchar, and marks RFC 2231 extended parameters
+                Some((_, c)) if is_token(c) || c == b'*' => (),
                 Some((i, b'=')) if i > start => {
                     name = (as_u16(start), as_u16(i));
                     start = i + 1;
@@ -193,7 +492,7 @@ fn params_from_str(s: &str, iter: &mut impl Iterator<Item=(usize, u8)>, mut star
                 },
                 None => return Err(ParseError::MissingEqual),
                 Some((pos, byte)) => return Err(ParseError::InvalidToken {
-                    pos: pos,
+                    pos,
                     byte: Byte(byte),
                 }),
             }
@@ -211,7 +510,7 @@ fn params_from_str(s: &str, iter: &mut impl Iterator<Item=(usize, u8)>, mut star
                     match iter.next() {
                         Some((_, ch)) if is_restricted_quoted_char(ch) => (),
                         Some((pos, byte)) => return Err(ParseError::InvalidToken {
-                            pos: pos,
+                            pos,
                             byte: Byte(byte),
                         }),
                         None => return Err(ParseError::MissingQuote),
@@ -228,7 +527,7 @@ fn params_from_str(s: &str, iter: &mut impl Iterator<Item=(usize, u8)>, mut star
                         Some((_, c)) if is_restricted_quoted_char(c) => (),
                         None => return Err(ParseError::MissingQuote),
                         Some((pos, byte)) => return Err(ParseError::InvalidToken {
-                            pos: pos,
+                            pos,
                             byte: Byte(byte),
                         }),
                     }
@@ -253,49 +552,339 @@ fn params_from_str(s: &str, iter: &mut impl Iterator<Item=(usize, u8)>, mut star
                     },
 
                     Some((pos, byte)) => return Err(ParseError::InvalidToken {
-                        pos: pos,
+                        pos,
                         byte: Byte(byte),
                     }),
                 }
             }
         }
 
-        match params {
-            ParamSource::Utf8(i) => {
-                let i = i + 2;
-                let charset = (i, "charset".len() as u16 + i);
-                let utf8 = (charset.1 + 1, charset.1 + "utf-8".len() as u16 + 1);
-                params = ParamSource::Two(params_start, (charset, utf8), (name, value));
-            },
-            ParamSource::One(sc, a) => {
-                params = ParamSource::Two(sc, a, (name, value));
-            },
-            ParamSource::Two(sc, a, b) => {
-                params = ParamSource::Custom(sc, vec![a, b, (name, value)]);
+        f(name, value);
+    }
+    Ok(())
+}
+
+pub(crate) fn write_token(dst: &mut String, token: &str) -> Result<(), ParseError> {
+    if token.is_empty() {
+        return Err(ParseError::MissingToken);
+    }
+
+    if let Some(pos) = token.bytes().position(|c| !is_token(c)) {
+        return Err(ParseError::InvalidToken {
+            pos: dst.len() + pos,
+            byte: Byte(token.as_bytes()[pos]),
+        });
+    }
+
+    dst.push_str(token);
+    Ok(())
+}
+
+pub(crate) fn write_value(dst: &mut String, value: &str) -> Result<(), ParseError> {
+    if !value.is_empty() && value.bytes().all(is_token) {
+        dst.push_str(value);
+        return Ok(());
+    }
+
+    if let Some(pos) = value.bytes().position(|c| !is_restricted_quoted_char(c)) {
+        return Err(ParseError::InvalidToken {
+            pos: dst.len() + 1 + pos,
+            byte: Byte(value.as_bytes()[pos]),
+        });
+    }
+
+    dst.push('"');
+    for c in value.chars() {
+        if c == '"' || c == '\\' {
+            dst.push('\\');
+        }
+        dst.push(c);
+    }
+    dst.push('"');
+    Ok(())
+}
+
+// The `qvalue` of RFC 7231 section 5.3.1, here so the proc macros can
+// check it too.
+pub(crate) fn parse_quality(s: &str) -> Result<u16, ParseError> {
+    let bytes = s.as_bytes();
+    let (&first, rest) = bytes.split_first().ok_or(ParseError::InvalidQuality)?;
+
+    let decimals = match rest.split_first() {
+        None => &[][..],
+        Some((b'.', decimals)) if decimals.len() <= 3 => decimals,
+        Some(_) => return Err(ParseError::InvalidQuality),
+    };
+
+    let mut thousandths = 0;
+    for i in 0..3 {
+        thousandths *= 10;
+        match decimals.get(i) {
+            Some(&c @ b'0'..=b'9') => thousandths += u16::from(c - b'0'),
+            Some(_) => return Err(ParseError::InvalidQuality),
+            None => (),
+        }
+    }
+
+    match first {
+        b'0' => Ok(thousandths),
+        b'1' if thousandths == 0 => Ok(1000),
+        _ => Err(ParseError::InvalidQuality),
+    }
+}
+
+// ===== const parsing =====
+//
+// The same state machine as `essence` and `visit_params`, written with
+// indices so it can run in a const fn. There's no allocating, so the
+// source has to already be what the parser would store: lowercase, with
+// at most two parameters, and none of them RFC 2231 extended. Anything
+// else panics, which fails compilation when evaluated in a const.
+
+pub(crate) const fn parse_static(s: &'static str) -> Mime {
+    let b = s.as_bytes();
+    if b.len() > u16::MAX as usize {
+        panic!("invalid MediaType: too long");
+    }
+
+    // toplevel
+    let mut i = 0;
+    while i < b.len() && b[i] != b'/' {
+        if !is_token(b[i]) {
+            panic!("invalid MediaType: invalid token in type");
+        }
+        if b[i].is_ascii_uppercase() {
+            panic!("invalid MediaType: type must be lowercase");
+        }
+        i += 1;
+    }
+    if i == b.len() {
+        panic!("invalid MediaType: missing slash");
+    }
+    if i == 0 {
+        panic!("invalid MediaType: missing type");
+    }
+    let slash = i as u16;
+    i += 1;
+
+    // sublevel
+    let sub = i;
+    let mut plus = None;
+    let mut semicolon = None;
+    while i < b.len() {
+        match b[i] {
+            b'+' if i > sub => plus = Some(i as u16),
+            b';' | b' ' if i > sub => {
+                semicolon = Some(i);
+                break;
             },
-            ParamSource::Custom(_, ref mut vec) => {
-                vec.push((name, value));
+            c if is_token(c) => {
+                if c.is_ascii_uppercase() {
+                    panic!("invalid MediaType: subtype must be lowercase");
+                }
             },
-            ParamSource::None => {
-                if params_start + 2 == name.0 &&
-                    "charset".eq_ignore_ascii_case(&s[range(name)]) &&
-                    "utf-8".eq_ignore_ascii_case(&s[range(value)]) {
-                    params = ParamSource::Utf8(params_start);
+            _ => panic!("invalid MediaType: invalid token in subtype"),
+        }
+        i += 1;
+    }
+
+    let start = match semicolon {
+        Some(start) => start,
+        None => return Mime {
+            source: intern_static(s, b.len(), false),
+            slash,
+            plus,
+            params: ParamSource::None,
+            ext_params: None,
+        },
+    };
+
+    // params, without a `ParamSource` until the end, since it can't be
+    // dropped in a const fn
+    let params_start = start as u16;
+    let mut count = 0;
+    let mut is_utf8 = false;
+    let mut pairs = [((0, 0), (0, 0)); 2];
+    let mut start = start + 1;
+    i = start;
+    'params: while start < b.len() {
+        let name;
+        loop {
+            if i == b.len() {
+                panic!("invalid MediaType: missing equals");
+            }
+            match b[i] {
+                b' ' | b';' if i == start => {
+                    i += 1;
+                    start = i;
                     continue 'params;
+                },
+                b'=' if i > start => {
+                    name = (start as u16, i as u16);
+                    i += 1;
+                    start = i;
+                    break;
+                },
+                b'*' => panic!("invalid MediaType: extended parameters are not supported"),
+                c if is_token(c) => {
+                    if c.is_ascii_uppercase() {
+                        panic!("invalid MediaType: parameter names must be lowercase");
+                    }
+                    i += 1;
+                },
+                _ => panic!("invalid MediaType: invalid token in parameter name"),
+            }
+        }
+
+        let value;
+        let mut is_quoted = false;
+        let mut is_quoted_pair = false;
+        loop {
+            if is_quoted {
+                if i == b.len() {
+                    panic!("invalid MediaType: missing quote");
                 }
-                params = ParamSource::One(params_start, (name, value));
-            },
+                let c = b[i];
+                if is_quoted_pair {
+                    is_quoted_pair = false;
+                } else if c == b'"' {
+                    value = (start as u16, i as u16 + 1);
+                    i += 1;
+                    start = i;
+                    break;
+                } else if c == b'\\' {
+                    is_quoted_pair = true;
+                }
+                if !is_restricted_quoted_char(c) {
+                    panic!("invalid MediaType: invalid character in quoted string");
+                }
+            } else {
+                if i == b.len() {
+                    value = (start as u16, i as u16);
+                    start = i;
+                    break;
+                }
+                match b[i] {
+                    b'"' if i == start => is_quoted = true,
+                    b' ' | b';' if i > start => {
+                        value = (start as u16, i as u16);
+                        i += 1;
+                        start = i;
+                        break;
+                    },
+                    c if is_token(c) => (),
+                    _ => panic!("invalid MediaType: invalid token in parameter value"),
+                }
+            }
+            i += 1;
         }
+
+        let is_charset = bytes_eq(slice(b, name), b"charset");
+        if is_charset && has_uppercase(slice(b, value)) {
+            panic!("invalid MediaType: charset must be lowercase");
+        }
+
+        if count == 0 && params_start + 2 == name.0 && is_charset && bytes_eq(slice(b, value), b"utf-8") {
+            is_utf8 = true;
+        } else if is_utf8 {
+            let i = params_start + 2;
+            let charset = (i, "charset".len() as u16 + i);
+            let utf8 = (charset.1 + 1, charset.1 + "utf-8".len() as u16 + 1);
+            pairs[0] = (charset, utf8);
+            pairs[1] = (name, value);
+            is_utf8 = false;
+        } else if count < 2 {
+            pairs[count] = (name, value);
+        } else {
+            panic!("invalid MediaType: more than two parameters are not supported");
+        }
+        count += 1;
     }
-    Ok(params)
+
+    let params = match count {
+        0 => ParamSource::None,
+        1 if is_utf8 => ParamSource::Utf8(params_start),
+        1 => ParamSource::One(params_start, pairs[0]),
+        _ => ParamSource::Two(params_start, pairs[0], pairs[1]),
+    };
+    let source = match params {
+        // only a `;` and whitespace, so chop it off like `with_params`
+        ParamSource::None => intern_static(s, params_start as usize, false),
+        ParamSource::Utf8(_) => intern_static(s, params_start as usize, true),
+        _ => Source::Atom(0, s),
+    };
+    Mime {
+        source,
+        slash,
+        plus,
+        params,
+        ext_params: None,
+    }
+}
+
+// The constant with the essence `s[..end]`, and either no params or only
+// `charset=utf-8`, the same as `Atoms::intern` would find.
+const fn intern_static(s: &'static str, end: usize, is_utf8: bool) -> Source {
+    let essence = s.as_bytes().split_at(end).0;
+    let params: &[u8] = if is_utf8 { b"; charset=utf-8" } else { b"" };
+    let mut i = 0;
+    while i < Atoms::ALL.len() {
+        let (atom, src) = Atoms::ALL[i];
+        if src.len() == end + params.len() {
+            let (head, tail) = src.as_bytes().split_at(end);
+            if bytes_eq(head, essence) && bytes_eq(tail, params) {
+                return Source::Atom(atom, src);
+            }
+        }
+        i += 1;
+    }
+
+    if is_utf8 {
+        Source::Atom(0, s)
+    } else {
+        match std::str::from_utf8(essence) {
+            Ok(essence) => Source::Atom(0, essence),
+            Err(_) => panic!("invalid MediaType: not UTF-8"),
+        }
+    }
+}
+
+const fn slice(b: &[u8], index: Indexed) -> &[u8] {
+    b.split_at(index.1 as usize).0.split_at(index.0 as usize).1
 }
+
+const fn bytes_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+const fn has_uppercase(b: &[u8]) -> bool {
+    let mut i = 0;
+    while i < b.len() {
+        if b[i].is_ascii_uppercase() {
+            return true;
+        }
+        i += 1;
+    }
+    false
+}
+
 macro_rules! byte_map {
     ($($flag:expr,)*) => ([
         $($flag != 0,)*
     ])
 }
 
-static TOKEN_MAP: [bool; 256] = byte_map![
+const TOKEN_MAP: [bool; 256] = byte_map![
     0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
     0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
     0, 1, 0, 1, 1, 1, 1, 1, 0, 0, 0, 1, 0, 1, 1, 0,
@@ -314,14 +903,18 @@ static TOKEN_MAP: [bool; 256] = byte_map![
     0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
 ];
 
-fn is_token(c: u8) -> bool {
+pub(crate) const fn is_token(c: u8) -> bool {
     TOKEN_MAP[c as usize]
 }
 
-fn is_restricted_quoted_char(c: u8) -> bool {
+pub(crate) const fn is_restricted_quoted_char(c: u8) -> bool {
     c == 9 || (c > 31 && c != 127)
 }
 
+fn is_ows(c: u8) -> bool {
+    c == b' ' || c == b'\t'
+}
+
 #[cfg(test)]
 mod tests {
     fn parse(src: impl super::Parse) -> Result<super::Mime, super::ParseError> {
@@ -332,7 +925,7 @@ mod tests {
     fn test_lookup_tables() {
         for (i, &valid) in super::TOKEN_MAP.iter().enumerate() {
             let i = i as u8;
-            let should = match i {
+            let should = matches!(i,
                 b'a'..=b'z' |
                 b'A'..=b'Z' |
                 b'0'..=b'9' |
@@ -349,9 +942,8 @@ mod tests {
                 b'_' |
                 b'`' |
                 b'|' |
-                b'~' => true,
-                _ => false
-            };
+                b'~'
+            );
             assert_eq!(valid, should, "{:?} ({}) should be {}", i as char, i, should);
         }
     }
@@ -455,7 +1047,7 @@ mod tests {
 
     #[test]
     fn semi_colon_but_empty_params() {
-        static CASES: &'static [&'static str] = &[
+        static CASES: &[&str] = &[
             "text/event-stream;",
             "text/event-stream; ",
             "text/event-stream;       ",
@@ -471,6 +1063,121 @@ mod tests {
         }
     }
 
+    fn parse_list(src: impl super::Parse) -> Result<Vec<super::Mime>, super::ParseError> {
+        super::Parser::can_range().parse_list(src)
+    }
+
+    #[test]
+    fn star_star_params() {
+        let mime = parse("*/*; q=0.1").unwrap();
+        assert_eq!(mime.type_(), "*");
+        assert_eq!(mime.subtype(), "*");
+        assert_eq!(mime.param("q"), Some("0.1"));
+
+        let mime = parse("*/* ;q=0.1").unwrap();
+        assert_eq!(mime.subtype(), "*");
+        assert_eq!(mime.param("q"), Some("0.1"));
+
+        let mime = parse("text/* ; q=0.1").unwrap();
+        assert_eq!(mime.subtype(), "*");
+        assert_eq!(mime.param("q"), Some("0.1"));
+
+        let mime = parse("*/*;").unwrap();
+        assert!(!mime.has_params());
+        assert_eq!(mime.private_atom(), super::constants::STAR_STAR.private_atom());
+
+        parse("*/plain").unwrap_err();
+        parse("*/plain; q=1").unwrap_err();
+        parse("*/*plain").unwrap_err();
+        parse("*").unwrap_err();
+        super::Parser::cannot_range().parse("*/*; q=1").unwrap_err();
+    }
+
+    #[test]
+    fn list_of_ranges() {
+        let list = parse_list("text/html, text/*;q=0.5,*/*; q=0.1").unwrap();
+        assert_eq!(list.len(), 3);
+        assert_eq!(list[0].as_ref(), "text/html");
+        assert_eq!(list[1].as_ref(), "text/*;q=0.5");
+        assert_eq!(list[1].param("q"), Some("0.5"));
+        assert_eq!(list[2].as_ref(), "*/*; q=0.1");
+    }
+
+    #[test]
+    fn list_quoted_comma() {
+        let list = parse_list(r#"text/plain; foo="a, b", text/html; bar="\",""#).unwrap();
+        assert_eq!(list.len(), 2);
+        assert_eq!(list[0].param("foo"), Some(r#""a, b""#));
+        assert_eq!(list[1].param("bar"), Some(r#""\",""#));
+    }
+
+    #[test]
+    fn list_empty_elements() {
+        assert_eq!(parse_list("").unwrap().len(), 0);
+        assert_eq!(parse_list(" , ,\t").unwrap().len(), 0);
+
+        let list = parse_list(",text/plain,, ,image/png ,").unwrap();
+        assert_eq!(list.len(), 2);
+        assert_eq!(list[0].as_ref(), "text/plain");
+        assert_eq!(list[1].as_ref(), "image/png");
+    }
+
+    #[test]
+    fn error_list_position() {
+        match parse_list("text/plain, te xt/html") {
+            Err(super::ParseError::InvalidToken { pos, .. }) => assert_eq!(pos, 14),
+            other => panic!("unexpected result: {:?}", other.map(|l| l.len())),
+        }
+    }
+
+    #[test]
+    fn error_list_missing_quote() {
+        parse_list(r#"text/plain; foo="a, text/html"#).unwrap_err();
+    }
+
+    fn write_value(value: &str) -> Result<String, super::ParseError> {
+        let mut dst = String::new();
+        super::write_value(&mut dst, value).map(|()| dst)
+    }
+
+    #[test]
+    fn write_token() {
+        let mut dst = String::from("text/");
+        super::write_token(&mut dst, "plain").unwrap();
+        assert_eq!(dst, "text/plain");
+
+        match super::write_token(&mut dst, "pl ain") {
+            Err(super::ParseError::InvalidToken { pos, .. }) => assert_eq!(pos, 12),
+            other => panic!("unexpected result: {:?}", other),
+        }
+        super::write_token(&mut dst, "").unwrap_err();
+        super::write_token(&mut dst, "*").unwrap_err();
+        super::write_token(&mut dst, "a;b").unwrap_err();
+        assert_eq!(dst, "text/plain");
+    }
+
+    #[test]
+    fn write_value_quoting() {
+        assert_eq!(write_value("utf-8").unwrap(), "utf-8");
+        assert_eq!(write_value("").unwrap(), r#""""#);
+        assert_eq!(write_value("a b").unwrap(), r#""a b""#);
+        assert_eq!(write_value("a;b").unwrap(), r#""a;b""#);
+        assert_eq!(write_value(r#"say "hi" \o/"#).unwrap(), r#""say \"hi\" \\o/""#);
+        assert_eq!(write_value("Straße").unwrap(), "\"Straße\"");
+        write_value("a\r\nb").unwrap_err();
+        write_value("\0").unwrap_err();
+    }
+
+    #[test]
+    fn write_value_roundtrip() {
+        for &value in &["a b", "a, b", r#"x"y"#, r#"x\y"#, "\t"] {
+            let mut s = String::from("text/plain; p=");
+            super::write_value(&mut s, value).unwrap();
+            let mime = parse(&s[..]).expect(&s);
+            assert!(mime.param("p").is_some(), "{:?}", s);
+        }
+    }
+
     // parse errors
 
     #[test]
@@ -518,4 +1225,234 @@ mod tests {
     fn error_param_space_after_equals() {
         parse("text/plain; charset= utf-8").unwrap_err();
     }
+
+    fn parse_ref(src: &str) -> Result<super::MimeRef<'_>, super::ParseError> {
+        super::Parser::can_range().parse_ref(src)
+    }
+
+    #[test]
+    fn ref_parts() {
+        let mime = parse_ref("Image/SVG+XML ; Charset=\"UTF-8\";;q=1 ").unwrap();
+        assert_eq!(mime.type_(), "Image");
+        assert_eq!(mime.subtype(), "SVG+XML");
+        assert_eq!(mime.suffix(), Some("XML"));
+        assert_eq!(mime.essence(), "Image/SVG+XML");
+        assert_eq!(mime.params().collect::<Vec<_>>(), [("Charset", "\"UTF-8\""), ("q", "1")]);
+
+        let mime = parse_ref("text/plain; a=\"b\\\";c\"d=e").unwrap();
+        assert_eq!(mime.params().collect::<Vec<_>>(), [("a", "\"b\\\";c\""), ("d", "e")]);
+
+        let mime = parse_ref("text/plain;").unwrap();
+        assert_eq!(mime.essence(), "text/plain");
+        assert!(!mime.has_params());
+
+        let mime = parse_ref("*/*").unwrap();
+        assert_eq!(mime.type_(), "*");
+        assert_eq!(mime.subtype(), "*");
+    }
+
+    #[test]
+    fn ref_to_mime() {
+        let sources = [
+            "text/plain",
+            "TEXT/PLAIN",
+            "text/plain; charset=UTF-8",
+            "text/plain;",
+            "image/svg+xml; a=1; b=\"2\"",
+            "text/html; a=1; b=2; c=3; charset=utf-8",
+            "text/plain; name*=utf-8''%E2%82%AC",
+            "*/*",
+            "*/*; q=0.5",
+            "text/*",
+        ];
+        for src in &sources {
+            let owned = parse(*src).unwrap();
+            let from_ref = parse_ref(src).unwrap().to_mime();
+            assert_eq!(owned.as_ref(), from_ref.as_ref());
+            assert_eq!(owned.private_atom(), from_ref.private_atom());
+            assert_eq!(owned.params().collect::<Vec<_>>(), from_ref.params().collect::<Vec<_>>());
+            assert_eq!(
+                owned.ext_param("name").map(|e| e.value),
+                from_ref.ext_param("name").map(|e| e.value),
+            );
+        }
+    }
+
+    #[test]
+    fn ref_errors() {
+        let sources = [
+            "",
+            "text",
+            "/plain",
+            "te xt/plain",
+            "text/plain; charset",
+            "text/plain; charset=\"utf-8",
+            "text/plain; charset= utf-8",
+            "text/*x",
+        ];
+        for src in &sources {
+            assert_eq!(
+                format!("{:?}", parse(*src).unwrap_err()),
+                format!("{:?}", parse_ref(src).unwrap_err()),
+            );
+        }
+
+        super::Parser::cannot_range().parse_ref("*/*").unwrap_err();
+        super::Parser::cannot_range().parse_ref("text/*").unwrap_err();
+    }
+
+    #[test]
+    fn parse_bytes() {
+        let mime = parse(&b"text/plain; charset=UTF-8"[..]).unwrap();
+        assert_eq!(mime.as_ref(), "text/plain; charset=utf-8");
+        assert_ne!(mime.private_atom(), 0);
+
+        let mime = parse(&b"Text/Plain; Foo=Bar"[..]).unwrap();
+        assert_eq!(mime.as_ref(), "text/plain; foo=Bar");
+
+        let mime = parse(&b"text/plain; name=\"caf\xc3\xa9\""[..]).unwrap();
+        assert_eq!(mime.param("name"), Some("\"caf\u{e9}\""));
+
+        let mime = parse(&b"text/plain; a=\"\\\"\xc3\xa9\"; b=c"[..]).unwrap();
+        assert_eq!(mime.param("b"), Some("c"));
+    }
+
+    #[test]
+    fn error_bytes_non_ascii() {
+        match parse(&b"text/pl\xc3\xa9in"[..]) {
+            Err(super::ParseError::InvalidToken { pos, .. }) => assert_eq!(pos, 7),
+            other => panic!("unexpected result: {:?}", other),
+        }
+        match parse(&b"text/plain; a=\"\xff\""[..]) {
+            Err(super::ParseError::InvalidToken { pos, .. }) => assert_eq!(pos, 15),
+            other => panic!("unexpected result: {:?}", other),
+        }
+        // after the closing quote
+        parse(&b"text/plain; a=\"b\"; c=\xc3\xa9"[..]).unwrap_err();
+        parse(&b"text/plain; a=\"\\\"; c=\xc3\xa9"[..]).unwrap_err();
+    }
+
+    #[test]
+    fn parse_owned_reuses_buffer() {
+        let src = String::from("text/plain; Foo=Bar");
+        let ptr = src.as_ptr();
+        let mime = parse(src).unwrap();
+        assert_eq!(mime.as_ref(), "text/plain; foo=Bar");
+        assert_eq!(mime.as_ref().as_ptr(), ptr);
+
+        let src: Box<str> = "TEXT/HTML; a=1; b=2; c=3".into();
+        let ptr = src.as_ptr();
+        let mime = parse(src).unwrap();
+        assert_eq!(mime.as_ref(), "text/html; a=1; b=2; c=3");
+        assert_eq!(mime.as_ref().as_ptr(), ptr);
+
+        let src: std::borrow::Cow<str> = String::from("image/png; q=1").into();
+        let ptr = src.as_ptr();
+        assert_eq!(parse(src).unwrap().as_ref().as_ptr(), ptr);
+
+        let mime = parse(std::borrow::Cow::Borrowed("text/plain; charset=utf-8")).unwrap();
+        assert_ne!(mime.private_atom(), 0);
+    }
+
+    #[test]
+    fn suffix_range() {
+        let parse = |src| super::Parser::can_suffix_range().parse(src);
+
+        let mime = parse("application/*+json").unwrap();
+        assert_eq!(mime.subtype(), "*+json");
+        assert_eq!(mime.suffix(), Some("json"));
+
+        let mime = parse("Application/*+JSON; q=0.5").unwrap();
+        assert_eq!(mime.as_ref(), "application/*+json; q=0.5");
+        assert_eq!(mime.suffix(), Some("json"));
+
+        // still a range parser
+        assert_eq!(parse("*/*").unwrap().as_ref(), "*/*");
+        assert_eq!(parse("text/*; q=1").unwrap().as_ref(), "text/*; q=1");
+        assert_eq!(parse("image/svg+xml").unwrap().suffix(), Some("xml"));
+
+        parse("application/*+").unwrap_err();
+        parse("application/*+;q=1").unwrap_err();
+        parse("application/*+ld+json").unwrap_err();
+        parse("application/*+js@n").unwrap_err();
+        parse("*/*+json").unwrap_err();
+
+        // opt-in
+        super::Parser::can_range().parse("application/*+json").unwrap_err();
+        super::Parser::can_range().parse_ref("application/*+json").unwrap_err();
+        super::Parser::can_suffix_range().parse_ref("application/*+json").unwrap();
+    }
+
+    #[test]
+    fn parse_static() {
+        use super::ParamSource;
+
+        fn layout(mime: &super::Mime) -> (u8, &str, u16, Option<u16>, &'static str, Option<u16>, Vec<crate::IndexedPair>) {
+            let (kind, sc, pairs) = match mime.params {
+                ParamSource::None => ("none", None, vec![]),
+                ParamSource::Utf8(sc) => ("utf8", Some(sc), vec![]),
+                ParamSource::One(sc, a) => ("one", Some(sc), vec![a]),
+                ParamSource::Two(sc, a, b) => ("two", Some(sc), vec![a, b]),
+                ParamSource::Custom(sc, ref v) => ("custom", Some(sc), v.clone()),
+                ParamSource::Static(sc, v) => ("custom", Some(sc), v.to_vec()),
+            };
+            (mime.private_atom(), mime.as_ref(), mime.slash, mime.plus, kind, sc, pairs)
+        }
+
+        const SVG: super::Mime = super::Mime::from_static("image/svg+xml");
+        assert_ne!(SVG.private_atom(), 0);
+        assert!(SVG.ext_params.is_none());
+
+        let cases = [
+            "text/plain",
+            "text/plain; charset=utf-8",
+            "text/plain ;charset=utf-8",
+            "text/plain; charset=utf-8; ",
+            "text/plain;",
+            "text/plain ",
+            "text/foo",
+            "text/foo; charset=utf-8",
+            "text/plain;charset=utf-8",
+            "text/plain; charset=\"utf-8\"",
+            "text/plain; charset=latin1",
+            "text/plain; charset=utf-8; format=flowed",
+            "image/svg+xml",
+            "application/vnd.api+json; ext=\"a b\"",
+            "multipart/form-data; boundary=\"\\\"x\\\"\"; name=q",
+            "text/html; a=1;b=2",
+            "text/html;; a=",
+            "application/+json",
+            "text/",
+        ];
+        for &src in &cases {
+            let parsed = super::Parser::cannot_range().parse(src).unwrap();
+            let mime = super::Mime::from_static(src);
+            assert_eq!(layout(&mime), layout(&parsed), "{:?}", src);
+            assert_eq!(mime.essence(), parsed.essence(), "{:?}", src);
+            assert_eq!(mime.params().collect::<Vec<_>>(), parsed.params().collect::<Vec<_>>(), "{:?}", src);
+        }
+
+        let invalid = [
+            "",
+            "text",
+            "/plain",
+            "*/*",
+            "text/*",
+            "Text/plain",
+            "text/Plain",
+            "text/plain; Charset=utf-8",
+            "text/plain; charset=UTF-8",
+            "text/plain; a=1; b=2; c=3",
+            "text/plain; charset=utf-8; a=1; b=2",
+            "text/plain; title*=utf-8''x",
+            "text/plain; a",
+            "text/plain; a=\"b",
+            "text/plain; a=b c",
+            "text/plain; =b",
+            "text/pl@in",
+        ];
+        for &src in &invalid {
+            assert!(std::panic::catch_unwind(|| super::Mime::from_static(src)).is_err(), "{:?}", src);
+        }
+    }
 }
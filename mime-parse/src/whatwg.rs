@@ -0,0 +1,229 @@
+use crate::{
+    rfc7231::{self, is_token},
+    Byte,
+    Mime,
+    Parse,
+    Parser,
+    ParseError,
+};
+
+// From the [WHATWG MIME Sniffing Standard](https://mimesniff.spec.whatwg.org/#parsing-a-mime-type),
+// which is how browsers parse a `Content-Type`:
+//
+// > 1. Remove any leading and trailing HTTP whitespace from input.
+// > ...
+// > 11. While position is not past the end of input:
+// >     ...
+// >     10. If all of the following are true
+// >         - parameterName is not the empty string.
+// >         - parameterName solely contains HTTP token code points.
+// >         - parameterValue solely contains HTTP quoted-string token code points.
+// >         - mimeType's parameters[parameterName] does not exist.
+// >
+// >         then set mimeType's parameters[parameterName] to parameterValue.
+//
+// The type and subtype must still be tokens, but invalid parameters are
+// skipped instead of failing the whole parse. The parsed parts are written
+// back out the way `rfc7231` would, and then parsed by it, so the resulting
+// `Mime` is laid out the same as if it had been strict to begin with.
+
+pub(crate) fn parse(src: impl Parse) -> Result<Mime, ParseError> {
+    let src = src.into_cow()?;
+    let s = &*src;
+    let offset = s.len() - s.trim_start_matches(is_http_whitespace).len();
+    let s = s.trim_matches(is_http_whitespace);
+
+    let slash = s.find('/').ok_or(ParseError::MissingSlash)?;
+    check_token(&s[..slash], offset)?;
+
+    let rest = &s[slash + 1..];
+    let subtype_end = rest.find(';').unwrap_or(rest.len());
+    check_token(rest[..subtype_end].trim_end_matches(is_http_whitespace), offset + slash + 1)?;
+
+    let mut dst = String::with_capacity(s.len());
+    dst.push_str(&s[..slash + 1]);
+    dst.push_str(rest[..subtype_end].trim_end_matches(is_http_whitespace));
+
+    let mut params: Vec<(String, String)> = Vec::new();
+    let bytes = rest.as_bytes();
+    let mut pos = subtype_end;
+    while pos < rest.len() {
+        // skip the `;`, and any whitespace after it
+        pos += 1;
+        while pos < rest.len() && is_http_whitespace(bytes[pos] as char) {
+            pos += 1;
+        }
+
+        let name_end = pos + rest[pos..].find(&[';', '='][..]).unwrap_or(rest.len() - pos);
+        let name = &rest[pos..name_end];
+        pos = name_end;
+
+        if pos < rest.len() {
+            if bytes[pos] == b';' {
+                continue;
+            }
+            pos += 1;
+        }
+        if pos >= rest.len() {
+            break;
+        }
+
+        let value = if bytes[pos] == b'"' {
+            let (value, end) = collect_quoted(rest, pos);
+            // anything after the closing quote is ignored
+            pos = end + rest[end..].find(';').unwrap_or(rest.len() - end);
+            value
+        } else {
+            let end = pos + rest[pos..].find(';').unwrap_or(rest.len() - pos);
+            let value = rest[pos..end].trim_end_matches(is_http_whitespace);
+            pos = end;
+            if value.is_empty() {
+                continue;
+            }
+            value.to_owned()
+        };
+
+        let is_valid = !name.is_empty()
+            && name.bytes().all(|c| is_token(c) || c == b'*')
+            && value.chars().all(is_quoted_string_token)
+            // the first duplicate wins
+            && !params.iter().any(|(n, _)| n.eq_ignore_ascii_case(name));
+
+        if is_valid {
+            params.push((name.to_ascii_lowercase(), value));
+        }
+    }
+
+    for (name, value) in &params {
+        dst.push_str("; ");
+        dst.push_str(name);
+        dst.push('=');
+        rfc7231::write_value(&mut dst, value)?;
+    }
+
+    rfc7231::parse(&Parser::cannot_range(), dst)
+}
+
+fn check_token(s: &str, offset: usize) -> Result<(), ParseError> {
+    if s.is_empty() {
+        return Err(ParseError::MissingToken);
+    }
+    match s.bytes().position(|c| !is_token(c)) {
+        Some(pos) => Err(ParseError::InvalidToken {
+            pos: offset + pos,
+            byte: Byte(s.as_bytes()[pos]),
+        }),
+        None => Ok(()),
+    }
+}
+
+// Collects a quoted-string starting at `start`, returning the unescaped
+// value and where it ended. A missing closing quote just ends the value.
+fn collect_quoted(s: &str, start: usize) -> (String, usize) {
+    debug_assert_eq!(s.as_bytes()[start], b'"');
+
+    let mut value = String::new();
+    let mut chars = s[start + 1..].char_indices();
+    while let Some((_, c)) = chars.next() {
+        match c {
+            '"' => break,
+            '\\' => match chars.next() {
+                Some((_, escaped)) => value.push(escaped),
+                None => value.push('\\'),
+            },
+            c => value.push(c),
+        }
+    }
+
+    let end = match chars.next() {
+        Some((i, _)) => start + 1 + i,
+        None => s.len(),
+    };
+    (value, end)
+}
+
+fn is_http_whitespace(c: char) -> bool {
+    matches!(c, '\t' | '\n' | '\r' | ' ')
+}
+
+fn is_quoted_string_token(c: char) -> bool {
+    matches!(c, '\t' | ' '..='~' | '\u{80}'..='\u{FF}')
+}
+
+#[cfg(test)]
+mod tests {
+    fn parse(src: &str) -> Result<super::Mime, super::ParseError> {
+        super::Parser::lenient().parse(src)
+    }
+
+    #[test]
+    fn text_plain() {
+        let mime = parse("text/plain").unwrap();
+        assert_eq!(mime.as_ref(), "text/plain");
+        assert_ne!(mime.private_atom(), 0);
+    }
+
+    #[test]
+    fn http_whitespace() {
+        let mime = parse("\r\n\t TEXT/Plain \t;\n Charset=UTF-8 \r\n").unwrap();
+        assert_eq!(mime.as_ref(), "text/plain; charset=utf-8");
+
+        let mime = parse(" text/plain ;charset=utf-8\t").unwrap();
+        assert_ne!(mime.private_atom(), 0);
+    }
+
+    #[test]
+    fn invalid_params_ignored() {
+        let mime = parse("text/html;;; a b=c; =d; e; f=; g=\"h\"").unwrap();
+        assert_eq!(mime.as_ref(), "text/html; g=h");
+
+        let mime = parse("text/html; charset =utf-8; x=\u{2603}").unwrap();
+        assert_eq!(mime.as_ref(), "text/html");
+    }
+
+    #[test]
+    fn first_duplicate_wins() {
+        let mime = parse("text/plain; charset=utf-8; CHARSET=latin1; charset=ascii").unwrap();
+        assert_eq!(mime.param("charset"), Some("utf-8"));
+        assert_eq!(mime.params().count(), 1);
+    }
+
+    #[test]
+    fn quoted_values() {
+        let mime = parse("text/plain; a=\"b; c\"junk; d=\"e\\\"f\"; g=\"\"; h=\"unterminated\\").unwrap();
+        assert_eq!(mime.as_ref(), "text/plain; a=\"b; c\"; d=\"e\\\"f\"; g=\"\"; h=\"unterminated\\\\\"");
+        let params = mime.params().collect::<Vec<_>>();
+        assert_eq!(params[0], ("a", "\"b; c\""));
+        assert_eq!(params[2], ("g", "\"\""));
+    }
+
+    #[test]
+    fn value_whitespace() {
+        let mime = parse("text/plain; a= b c ;d=e").unwrap();
+        assert_eq!(mime.as_ref(), "text/plain; a=\" b c\"; d=e");
+    }
+
+    #[test]
+    fn latin1_values() {
+        let mime = parse("text/plain; a=caf\u{e9}").unwrap();
+        assert_eq!(mime.as_ref(), "text/plain; a=\"caf\u{e9}\"");
+    }
+
+    #[test]
+    fn errors() {
+        parse("").unwrap_err();
+        parse("text").unwrap_err();
+        parse("/plain").unwrap_err();
+        parse("text/").unwrap_err();
+        parse("text/;charset=utf-8").unwrap_err();
+        parse("te xt/plain").unwrap_err();
+        parse("text/pl ain").unwrap_err();
+        parse("*/*").unwrap_err();
+        parse("text/\u{e9}").unwrap_err();
+
+        match parse("  text/pl@in").unwrap_err() {
+            super::ParseError::InvalidToken { pos, .. } => assert_eq!(pos, 9),
+            err => panic!("unexpected error: {:?}", err),
+        }
+    }
+}
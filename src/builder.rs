@@ -0,0 +1,303 @@
+use mime_parse::ParseError;
+
+use crate::{InvalidMime, MediaType};
+
+/// A builder to construct a `MediaType`.
+///
+/// Created with [`MediaType::builder`]. Any errors are reported when
+/// calling [`build`](Builder::build).
+///
+/// # Example
+///
+/// ```
+/// let mt = mime::MediaType::builder("application", "vnd.myapp")
+///     .suffix("json")
+///     .param("version", "2")
+///     .param("title", "Hello, world!")
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(mt.suffix(), Some(mime::JSON));
+/// assert_eq!(mt, r#"application/vnd.myapp+json; version=2; title="Hello, world!""#);
+/// ```
+#[derive(Debug)]
+pub struct Builder {
+    type_: String,
+    subtype: String,
+    suffix: Option<String>,
+    params: Vec<(String, String)>,
+}
+
+impl Builder {
+    /// Set the +suffix of the subtype.
+    pub fn suffix(mut self, suffix: &str) -> Builder {
+        self.suffix = Some(suffix.to_owned());
+        self
+    }
+
+    /// Add a parameter.
+    ///
+    /// If the value is not a valid token, it will be written as a
+    /// quoted-string. Setting the same name again replaces the value.
+    pub fn param(mut self, name: &str, value: &str) -> Builder {
+        let name = name.to_ascii_lowercase();
+        match self.params.iter_mut().find(|(n, _)| *n == name) {
+            Some(param) => param.1 = value.to_owned(),
+            None => self.params.push((name, value.to_owned())),
+        }
+        self
+    }
+
+    /// Build the `MediaType`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the type, subtype, suffix, or any parameter name
+    /// is not a valid token, or a parameter value contains characters that
+    /// cannot be quoted, such as newlines.
+    pub fn build(self) -> Result<MediaType, InvalidMime> {
+        let mut s = String::new();
+        build(&mut s, &self).map_err(|e| InvalidMime { inner: e })?;
+        MediaType::parse(&s)
+    }
+}
+
+fn build(dst: &mut String, builder: &Builder) -> Result<(), ParseError> {
+    mime_parse::write_token(dst, &builder.type_)?;
+    dst.push('/');
+    mime_parse::write_token(dst, &builder.subtype)?;
+    if let Some(ref suffix) = builder.suffix {
+        dst.push('+');
+        mime_parse::write_token(dst, suffix)?;
+    }
+    for (name, value) in &builder.params {
+        dst.push_str("; ");
+        mime_parse::write_token(dst, name)?;
+        dst.push('=');
+        mime_parse::write_value(dst, value)?;
+    }
+    Ok(())
+}
+
+fn write_with_param(dst: &mut String, mt: &MediaType, name: &str, value: &str) -> Result<(), ParseError> {
+    dst.push_str(mt.mime.essence());
+
+    let mut found = false;
+    for (n, v) in mt.params() {
+        dst.push_str("; ");
+        dst.push_str(n);
+        dst.push('=');
+        if n == name {
+            found = true;
+            mime_parse::write_value(dst, value)?;
+        } else {
+            dst.push_str(v.as_str_repr());
+        }
+    }
+
+    if !found {
+        dst.push_str("; ");
+        mime_parse::write_token(dst, name)?;
+        dst.push('=');
+        mime_parse::write_value(dst, value)?;
+    }
+    Ok(())
+}
+
+impl MediaType {
+    /// Start building a `MediaType` from a type and subtype.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mt = mime::MediaType::builder(mime::TEXT, mime::PLAIN)
+    ///     .param(mime::CHARSET, "utf-8")
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(mt, mime::TEXT_PLAIN_UTF_8);
+    /// ```
+    pub fn builder(type_: &str, subtype: &str) -> Builder {
+        Builder {
+            type_: type_.to_owned(),
+            subtype: subtype.to_owned(),
+            suffix: None,
+            params: Vec::new(),
+        }
+    }
+
+    /// Returns this `MediaType` with a parameter set to a new value.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mt = mime::TEXT_HTML.with_param(mime::CHARSET, "utf-8").unwrap();
+    /// assert_eq!(mt, mime::TEXT_HTML_UTF_8);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the name is not a valid token, or the value
+    /// contains characters that cannot be quoted.
+    pub fn with_param(mut self, name: &str, value: &str) -> Result<MediaType, InvalidMime> {
+        self.set_param(name, value)?;
+        Ok(self)
+    }
+
+    /// Set a parameter to a new value.
+    ///
+    /// If the parameter already exists, its value is replaced in place.
+    /// Otherwise, it is added after the existing parameters. Values that
+    /// are not valid tokens are written as quoted-strings.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut mt = mime::MediaType::parse("multipart/form-data; boundary=a").unwrap();
+    /// mt.set_param(mime::BOUNDARY, "b c").unwrap();
+    /// mt.set_param(mime::CHARSET, "utf-8").unwrap();
+    ///
+    /// assert_eq!(mt.to_string(), r#"multipart/form-data; boundary="b c"; charset=utf-8"#);
+    /// assert_eq!(mt.param(mime::BOUNDARY).unwrap(), "b c");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the name is not a valid token, or the value
+    /// contains characters that cannot be quoted. The `MediaType` is left
+    /// unchanged.
+    pub fn set_param(&mut self, name: &str, value: &str) -> Result<(), InvalidMime> {
+        let mut s = String::new();
+        write_with_param(&mut s, self, &name.to_ascii_lowercase(), value)
+            .map_err(|e| InvalidMime { inner: e })?;
+        *self = MediaType::parse(&s)?;
+        Ok(())
+    }
+
+    /// Remove a parameter, returning whether it was present.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut mt = mime::TEXT_PLAIN_UTF_8;
+    ///
+    /// assert!(mt.remove_param(mime::CHARSET));
+    /// assert_eq!(mt, mime::TEXT_PLAIN);
+    ///
+    /// assert!(!mt.remove_param(mime::CHARSET));
+    /// ```
+    pub fn remove_param(&mut self, name: &str) -> bool {
+        self.mime.remove_param(name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn builder_atoms() {
+        let mt = MediaType::builder("text", "plain").build().unwrap();
+        assert_eq!(mt, TEXT_PLAIN);
+        assert_eq!(mt.mime.private_atom(), TEXT_PLAIN.mime.private_atom());
+
+        let mt = MediaType::builder("TEXT", "Plain").build().unwrap();
+        assert_eq!(mt, TEXT_PLAIN);
+
+        let mt = MediaType::builder("image", "svg").suffix("xml").build().unwrap();
+        assert_eq!(mt, IMAGE_SVG);
+        assert_eq!(mt.mime.private_atom(), IMAGE_SVG.mime.private_atom());
+    }
+
+    #[test]
+    fn builder_params() {
+        let mt = MediaType::builder("text", "plain")
+            .param("Format", "flowed")
+            .param("title", "a \"quoted\" title")
+            .param("format", "fixed")
+            .build()
+            .unwrap();
+
+        assert_eq!(mt.to_string(), r#"text/plain; format=fixed; title="a \"quoted\" title""#);
+        assert_eq!(mt.param("format").unwrap(), "fixed");
+        assert_eq!(mt.param("title").unwrap(), "a \"quoted\" title");
+    }
+
+    #[test]
+    fn builder_errors() {
+        MediaType::builder("", "plain").build().unwrap_err();
+        MediaType::builder("text", "").build().unwrap_err();
+        MediaType::builder("*", "*").build().unwrap_err();
+        MediaType::builder("text", "*").build().unwrap_err();
+        MediaType::builder("text", "plain; charset=utf-8").build().unwrap_err();
+        MediaType::builder("text/plain", "plain").build().unwrap_err();
+        MediaType::builder("image", "svg").suffix("x ml").build().unwrap_err();
+        MediaType::builder("text", "plain").param("a b", "c").build().unwrap_err();
+        MediaType::builder("text", "plain").param("", "c").build().unwrap_err();
+        MediaType::builder("text", "plain").param("a", "b\r\nc").build().unwrap_err();
+    }
+
+    #[test]
+    fn set_param_replace_keeps_order() {
+        let mut mt = MediaType::parse("text/plain; a=1; b=\"two\"; c=3").unwrap();
+        mt.set_param("A", "one").unwrap();
+        assert_eq!(mt.to_string(), "text/plain; a=one; b=\"two\"; c=3");
+
+        mt.set_param("c", "").unwrap();
+        assert_eq!(mt.to_string(), "text/plain; a=one; b=\"two\"; c=\"\"");
+    }
+
+    #[test]
+    fn set_param_error_leaves_unchanged() {
+        let mut mt = TEXT_PLAIN_UTF_8;
+        mt.set_param("charset", "utf-8\n").unwrap_err();
+        mt.set_param("char set", "utf-8").unwrap_err();
+        assert_eq!(mt, TEXT_PLAIN_UTF_8);
+    }
+
+    #[test]
+    fn with_param_atom() {
+        let mt = TEXT_PLAIN.with_param("charset", "UTF-8").unwrap();
+        assert_eq!(mt, TEXT_PLAIN_UTF_8);
+    }
+
+    #[test]
+    fn remove_param() {
+        let mut mt = MediaType::parse("text/plain; a=1; b=\"2 2\"; c=3").unwrap();
+        assert!(mt.remove_param("B"));
+        assert_eq!(mt.to_string(), "text/plain; a=1; c=3");
+        assert!(mt.remove_param("a"));
+        assert!(mt.remove_param("c"));
+        assert_eq!(mt, TEXT_PLAIN);
+        assert!(!mt.has_params());
+        assert!(!mt.remove_param("c"));
+
+        // extended params are kept as they were
+        let mut mt = MediaType::parse("text/plain; title*=utf-8''%E2%82%AC; a=1").unwrap();
+        assert!(mt.remove_param("a"));
+        assert_eq!(mt.param("title").unwrap(), "\u{20ac}");
+    }
+
+    #[test]
+    fn remove_param_same_as_parsed() {
+        let cases = [
+            (MediaType::parse("text/plain;a=1;charset=UTF-8").unwrap(), "a", "text/plain;charset=utf-8"),
+            (MediaType::parse("text/plain; charset=utf-8; a=1").unwrap(), "a", "text/plain; charset=utf-8"),
+            (MediaType::parse("text/plain; a=1; b=2; c=3").unwrap(), "a", "text/plain; b=2; c=3"),
+            (MediaType::parse("text/plain; a=\"1;2\"; a=3; b=4").unwrap(), "A", "text/plain; b=4"),
+            (MediaType::parse_lenient(" Text/HTML ; a=\"x\" ; b=y").unwrap(), "b", "text/html; a=x"),
+            (MediaType::parse_with("text/plain (c) ; a=1; b=2", Syntax::Mime).unwrap(), "a", "text/plain; b=2"),
+        ];
+        for (mut mt, name, expected) in cases.iter().cloned() {
+            assert!(mt.remove_param(name), "{}", mt);
+            let parsed = MediaType::parse(expected).unwrap();
+            assert_eq!(mt, parsed);
+            assert_eq!(mt.to_string(), expected);
+            assert_eq!(mt.params().collect::<Vec<_>>(), parsed.params().collect::<Vec<_>>());
+        }
+
+        let mut mt = MediaType::parse("text/plain; charset=utf-8").unwrap();
+        assert!(mt.remove_param("charset"));
+        assert_eq!(mt.mime.private_atom(), TEXT_PLAIN.mime.private_atom());
+    }
+}
@@ -0,0 +1,576 @@
+use std::borrow::Cow;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::mem;
+
+use crate::{MediaType, StructuredSuffix, Value, TEXT};
+
+/// A character set, from the `charset` parameter of a media type.
+///
+/// Charset names are looked up case-insensitively, and aliases from the
+/// [IANA registry](https://www.iana.org/assignments/character-sets/),
+/// plus a few common ones like `cp1252`, resolve to the same charset.
+///
+/// # Example
+///
+/// ```
+/// use mime::{Charset, MediaType};
+///
+/// let mt = MediaType::parse("text/html; charset=latin1").unwrap();
+/// assert_eq!(mt.charset(), Some(Charset::Iso8859_1));
+/// assert_eq!(mt.charset().unwrap().as_str(), "ISO-8859-1");
+///
+/// let mt = MediaType::parse("text/html; charset=x-klingon").unwrap();
+/// assert_eq!(mt.charset(), Some(Charset::Unknown("x-klingon".into())));
+/// ```
+#[derive(Clone, Debug)]
+pub enum Charset<'a> {
+    /// `US-ASCII`
+    UsAscii,
+    /// `UTF-8`
+    Utf8,
+    /// `UTF-16`, with a byte order mark.
+    Utf16,
+    /// `UTF-16BE`
+    Utf16Be,
+    /// `UTF-16LE`
+    Utf16Le,
+    /// `ISO-8859-1`, Latin-1.
+    Iso8859_1,
+    /// `ISO-8859-2`, Latin-2.
+    Iso8859_2,
+    /// `ISO-8859-3`, Latin-3.
+    Iso8859_3,
+    /// `ISO-8859-4`, Latin-4.
+    Iso8859_4,
+    /// `ISO-8859-5`, Cyrillic.
+    Iso8859_5,
+    /// `ISO-8859-6`, Arabic.
+    Iso8859_6,
+    /// `ISO-8859-7`, Greek.
+    Iso8859_7,
+    /// `ISO-8859-8`, Hebrew.
+    Iso8859_8,
+    /// `ISO-8859-9`, Latin-5.
+    Iso8859_9,
+    /// `ISO-8859-10`, Latin-6.
+    Iso8859_10,
+    /// `ISO-8859-13`, Latin-7.
+    Iso8859_13,
+    /// `ISO-8859-14`, Latin-8.
+    Iso8859_14,
+    /// `ISO-8859-15`, Latin-9.
+    Iso8859_15,
+    /// `ISO-8859-16`, Latin-10.
+    Iso8859_16,
+    /// `windows-874`, Thai.
+    Windows874,
+    /// `windows-1250`, Central European.
+    Windows1250,
+    /// `windows-1251`, Cyrillic.
+    Windows1251,
+    /// `windows-1252`, Western European.
+    Windows1252,
+    /// `windows-1253`, Greek.
+    Windows1253,
+    /// `windows-1254`, Turkish.
+    Windows1254,
+    /// `windows-1255`, Hebrew.
+    Windows1255,
+    /// `windows-1256`, Arabic.
+    Windows1256,
+    /// `windows-1257`, Baltic.
+    Windows1257,
+    /// `windows-1258`, Vietnamese.
+    Windows1258,
+    /// `KOI8-R`, Russian.
+    Koi8R,
+    /// `KOI8-U`, Ukrainian.
+    Koi8U,
+    /// `IBM866`, DOS Cyrillic.
+    Ibm866,
+    /// `macintosh`, Mac OS Roman.
+    Macintosh,
+    /// `Shift_JIS`, Japanese.
+    ShiftJis,
+    /// `EUC-JP`, Japanese.
+    EucJp,
+    /// `ISO-2022-JP`, Japanese.
+    Iso2022Jp,
+    /// `EUC-KR`, Korean.
+    EucKr,
+    /// `GB2312`, Simplified Chinese.
+    Gb2312,
+    /// `GBK`, Simplified Chinese.
+    Gbk,
+    /// `GB18030`, Chinese.
+    Gb18030,
+    /// `Big5`, Traditional Chinese.
+    Big5,
+    /// Any other charset, as it was written, but without any quotes or
+    /// quoted-pairs. Compared ignoring ASCII case.
+    Unknown(Cow<'a, str>),
+}
+
+impl<'a> Charset<'a> {
+    /// Get the `Charset` for a name or alias.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mime::Charset;
+    ///
+    /// assert_eq!(Charset::new("utf-8"), Charset::Utf8);
+    /// assert_eq!(Charset::new("ISO_8859-1:1987"), Charset::Iso8859_1);
+    /// assert_eq!(Charset::new("cp1252"), Charset::Windows1252);
+    /// assert_eq!(Charset::new("ascii"), Charset::UsAscii);
+    /// assert_eq!(Charset::new("utf-7"), Charset::Unknown("utf-7".into()));
+    /// ```
+    pub fn new(name: &'a str) -> Charset<'a> {
+        lookup(name).unwrap_or(Charset::Unknown(Cow::Borrowed(name)))
+    }
+
+    /// Get the preferred MIME name of this charset.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mime::Charset;
+    ///
+    /// assert_eq!(Charset::new("sjis").as_str(), "Shift_JIS");
+    /// assert_eq!(Charset::new("x-klingon").as_str(), "x-klingon");
+    /// ```
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Charset::UsAscii => "US-ASCII",
+            Charset::Utf8 => "UTF-8",
+            Charset::Utf16 => "UTF-16",
+            Charset::Utf16Be => "UTF-16BE",
+            Charset::Utf16Le => "UTF-16LE",
+            Charset::Iso8859_1 => "ISO-8859-1",
+            Charset::Iso8859_2 => "ISO-8859-2",
+            Charset::Iso8859_3 => "ISO-8859-3",
+            Charset::Iso8859_4 => "ISO-8859-4",
+            Charset::Iso8859_5 => "ISO-8859-5",
+            Charset::Iso8859_6 => "ISO-8859-6",
+            Charset::Iso8859_7 => "ISO-8859-7",
+            Charset::Iso8859_8 => "ISO-8859-8",
+            Charset::Iso8859_9 => "ISO-8859-9",
+            Charset::Iso8859_10 => "ISO-8859-10",
+            Charset::Iso8859_13 => "ISO-8859-13",
+            Charset::Iso8859_14 => "ISO-8859-14",
+            Charset::Iso8859_15 => "ISO-8859-15",
+            Charset::Iso8859_16 => "ISO-8859-16",
+            Charset::Windows874 => "windows-874",
+            Charset::Windows1250 => "windows-1250",
+            Charset::Windows1251 => "windows-1251",
+            Charset::Windows1252 => "windows-1252",
+            Charset::Windows1253 => "windows-1253",
+            Charset::Windows1254 => "windows-1254",
+            Charset::Windows1255 => "windows-1255",
+            Charset::Windows1256 => "windows-1256",
+            Charset::Windows1257 => "windows-1257",
+            Charset::Windows1258 => "windows-1258",
+            Charset::Koi8R => "KOI8-R",
+            Charset::Koi8U => "KOI8-U",
+            Charset::Ibm866 => "IBM866",
+            Charset::Macintosh => "macintosh",
+            Charset::ShiftJis => "Shift_JIS",
+            Charset::EucJp => "EUC-JP",
+            Charset::Iso2022Jp => "ISO-2022-JP",
+            Charset::EucKr => "EUC-KR",
+            Charset::Gb2312 => "GB2312",
+            Charset::Gbk => "GBK",
+            Charset::Gb18030 => "GB18030",
+            Charset::Big5 => "Big5",
+            Charset::Unknown(ref s) => s,
+        }
+    }
+
+    fn from_value(value: Value<'a>) -> Charset<'a> {
+        let name = value.to_content();
+        lookup(&name).unwrap_or(Charset::Unknown(name))
+    }
+}
+
+// Unknown names compare and hash ignoring ASCII case, like the names of
+// the known charsets are looked up.
+impl PartialEq for Charset<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Charset::Unknown(a), Charset::Unknown(b)) => a.eq_ignore_ascii_case(b),
+            _ => mem::discriminant(self) == mem::discriminant(other),
+        }
+    }
+}
+
+impl Eq for Charset<'_> {}
+
+impl Hash for Charset<'_> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        mem::discriminant(self).hash(state);
+        if let Charset::Unknown(ref name) = *self {
+            for b in name.bytes() {
+                state.write_u8(b.to_ascii_lowercase());
+            }
+        }
+    }
+}
+
+fn lookup(name: &str) -> Option<Charset<'static>> {
+    KNOWN
+        .iter()
+        .find(|known| known.as_str().eq_ignore_ascii_case(name))
+        .or_else(|| {
+            ALIASES
+                .iter()
+                .find(|&&(alias, _)| alias.eq_ignore_ascii_case(name))
+                .map(|(_, charset)| charset)
+        })
+        .cloned()
+}
+
+#[cfg(feature = "encoding_rs")]
+impl<'a> Charset<'a> {
+    /// Get the `encoding_rs::Encoding` for this charset.
+    ///
+    /// The charset name is looked up as a label of the
+    /// [Encoding Standard](https://encoding.spec.whatwg.org/), so like in
+    /// a browser, `ISO-8859-1` and `US-ASCII` are decoded as
+    /// `windows-1252`. Returns `None` if there is no such encoding.
+    ///
+    /// This requires the `encoding_rs` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mime::Charset;
+    ///
+    /// assert_eq!(Charset::Utf8.encoding(), Some(encoding_rs::UTF_8));
+    /// assert_eq!(Charset::new("latin1").encoding(), Some(encoding_rs::WINDOWS_1252));
+    /// assert_eq!(Charset::new("x-klingon").encoding(), None);
+    /// ```
+    pub fn encoding(&self) -> Option<&'static encoding_rs::Encoding> {
+        encoding_rs::Encoding::for_label(self.as_str().as_bytes())
+    }
+}
+
+#[cfg(feature = "encoding_rs")]
+impl From<&'static encoding_rs::Encoding> for Charset<'static> {
+    fn from(encoding: &'static encoding_rs::Encoding) -> Charset<'static> {
+        Charset::new(encoding.name())
+    }
+}
+
+impl<'a> fmt::Display for Charset<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+pub(crate) fn charset(mt: &MediaType) -> Option<Charset<'_>> {
+    mt.param(crate::CHARSET).map(Charset::from_value)
+}
+
+// The charset of a type without a `charset` parameter, if its
+// registration defines one.
+pub(crate) fn default_charset(mt: &MediaType) -> Option<Charset<'static>> {
+    match mt.mime.essence() {
+        // RFC 8259, RFC 9239, RFC 5545, and RFC 6350
+        "application/json" | "text/javascript" | "text/calendar" | "text/vcard" => Some(Charset::Utf8),
+        // RFC 6839, `+json` has the same encoding considerations
+        _ if mt.structured_suffix() == Some(StructuredSuffix::Json) => Some(Charset::Utf8),
+        // RFC 2046, section 4.1.2
+        _ if mt.type_() == TEXT => Some(Charset::UsAscii),
+        _ => None,
+    }
+}
+
+static KNOWN: &[Charset<'static>] = &[
+    Charset::UsAscii,
+    Charset::Utf8,
+    Charset::Utf16,
+    Charset::Utf16Be,
+    Charset::Utf16Le,
+    Charset::Iso8859_1,
+    Charset::Iso8859_2,
+    Charset::Iso8859_3,
+    Charset::Iso8859_4,
+    Charset::Iso8859_5,
+    Charset::Iso8859_6,
+    Charset::Iso8859_7,
+    Charset::Iso8859_8,
+    Charset::Iso8859_9,
+    Charset::Iso8859_10,
+    Charset::Iso8859_13,
+    Charset::Iso8859_14,
+    Charset::Iso8859_15,
+    Charset::Iso8859_16,
+    Charset::Windows874,
+    Charset::Windows1250,
+    Charset::Windows1251,
+    Charset::Windows1252,
+    Charset::Windows1253,
+    Charset::Windows1254,
+    Charset::Windows1255,
+    Charset::Windows1256,
+    Charset::Windows1257,
+    Charset::Windows1258,
+    Charset::Koi8R,
+    Charset::Koi8U,
+    Charset::Ibm866,
+    Charset::Macintosh,
+    Charset::ShiftJis,
+    Charset::EucJp,
+    Charset::Iso2022Jp,
+    Charset::EucKr,
+    Charset::Gb2312,
+    Charset::Gbk,
+    Charset::Gb18030,
+    Charset::Big5,
+];
+
+// Aliases from the IANA registry, and the common `cpNNNN` and `x-` ones
+// that browsers also accept.
+static ALIASES: &[(&str, Charset<'static>)] = &[
+    ("ansi_x3.4-1968", Charset::UsAscii),
+    ("ansi_x3.4-1986", Charset::UsAscii),
+    ("ascii", Charset::UsAscii),
+    ("cp367", Charset::UsAscii),
+    ("csascii", Charset::UsAscii),
+    ("ibm367", Charset::UsAscii),
+    ("iso-ir-6", Charset::UsAscii),
+    ("iso646-us", Charset::UsAscii),
+    ("iso_646.irv:1991", Charset::UsAscii),
+    ("us", Charset::UsAscii),
+
+    ("csutf8", Charset::Utf8),
+    ("unicode-1-1-utf-8", Charset::Utf8),
+    ("utf8", Charset::Utf8),
+    ("csutf16", Charset::Utf16),
+    ("utf16", Charset::Utf16),
+    ("csutf16be", Charset::Utf16Be),
+    ("csutf16le", Charset::Utf16Le),
+
+    ("cp819", Charset::Iso8859_1),
+    ("csisolatin1", Charset::Iso8859_1),
+    ("ibm819", Charset::Iso8859_1),
+    ("iso-ir-100", Charset::Iso8859_1),
+    ("iso8859-1", Charset::Iso8859_1),
+    ("iso_8859-1", Charset::Iso8859_1),
+    ("iso_8859-1:1987", Charset::Iso8859_1),
+    ("l1", Charset::Iso8859_1),
+    ("latin1", Charset::Iso8859_1),
+    ("csisolatin2", Charset::Iso8859_2),
+    ("iso-ir-101", Charset::Iso8859_2),
+    ("iso8859-2", Charset::Iso8859_2),
+    ("iso_8859-2", Charset::Iso8859_2),
+    ("iso_8859-2:1987", Charset::Iso8859_2),
+    ("l2", Charset::Iso8859_2),
+    ("latin2", Charset::Iso8859_2),
+    ("csisolatin3", Charset::Iso8859_3),
+    ("iso-ir-109", Charset::Iso8859_3),
+    ("iso_8859-3", Charset::Iso8859_3),
+    ("l3", Charset::Iso8859_3),
+    ("latin3", Charset::Iso8859_3),
+    ("csisolatin4", Charset::Iso8859_4),
+    ("iso-ir-110", Charset::Iso8859_4),
+    ("iso_8859-4", Charset::Iso8859_4),
+    ("l4", Charset::Iso8859_4),
+    ("latin4", Charset::Iso8859_4),
+    ("csisolatincyrillic", Charset::Iso8859_5),
+    ("cyrillic", Charset::Iso8859_5),
+    ("iso-ir-144", Charset::Iso8859_5),
+    ("iso_8859-5", Charset::Iso8859_5),
+    ("arabic", Charset::Iso8859_6),
+    ("asmo-708", Charset::Iso8859_6),
+    ("csisolatinarabic", Charset::Iso8859_6),
+    ("ecma-114", Charset::Iso8859_6),
+    ("iso-ir-127", Charset::Iso8859_6),
+    ("iso_8859-6", Charset::Iso8859_6),
+    ("csisolatingreek", Charset::Iso8859_7),
+    ("ecma-118", Charset::Iso8859_7),
+    ("elot_928", Charset::Iso8859_7),
+    ("greek", Charset::Iso8859_7),
+    ("greek8", Charset::Iso8859_7),
+    ("iso-ir-126", Charset::Iso8859_7),
+    ("iso_8859-7", Charset::Iso8859_7),
+    ("csisolatinhebrew", Charset::Iso8859_8),
+    ("hebrew", Charset::Iso8859_8),
+    ("iso-ir-138", Charset::Iso8859_8),
+    ("iso_8859-8", Charset::Iso8859_8),
+    ("csisolatin5", Charset::Iso8859_9),
+    ("iso-ir-148", Charset::Iso8859_9),
+    ("iso_8859-9", Charset::Iso8859_9),
+    ("l5", Charset::Iso8859_9),
+    ("latin5", Charset::Iso8859_9),
+    ("csisolatin6", Charset::Iso8859_10),
+    ("iso-ir-157", Charset::Iso8859_10),
+    ("l6", Charset::Iso8859_10),
+    ("latin6", Charset::Iso8859_10),
+    ("csiso885913", Charset::Iso8859_13),
+    ("csiso885914", Charset::Iso8859_14),
+    ("iso-ir-199", Charset::Iso8859_14),
+    ("iso_8859-14", Charset::Iso8859_14),
+    ("l8", Charset::Iso8859_14),
+    ("latin8", Charset::Iso8859_14),
+    ("csiso885915", Charset::Iso8859_15),
+    ("iso_8859-15", Charset::Iso8859_15),
+    ("latin-9", Charset::Iso8859_15),
+    ("csiso885916", Charset::Iso8859_16),
+    ("iso-ir-226", Charset::Iso8859_16),
+    ("iso_8859-16", Charset::Iso8859_16),
+    ("l10", Charset::Iso8859_16),
+    ("latin10", Charset::Iso8859_16),
+
+    ("cp874", Charset::Windows874),
+    ("tis-620", Charset::Windows874),
+    ("cp1250", Charset::Windows1250),
+    ("x-cp1250", Charset::Windows1250),
+    ("cp1251", Charset::Windows1251),
+    ("x-cp1251", Charset::Windows1251),
+    ("cp1252", Charset::Windows1252),
+    ("x-cp1252", Charset::Windows1252),
+    ("cp1253", Charset::Windows1253),
+    ("x-cp1253", Charset::Windows1253),
+    ("cp1254", Charset::Windows1254),
+    ("x-cp1254", Charset::Windows1254),
+    ("cp1255", Charset::Windows1255),
+    ("x-cp1255", Charset::Windows1255),
+    ("cp1256", Charset::Windows1256),
+    ("x-cp1256", Charset::Windows1256),
+    ("cp1257", Charset::Windows1257),
+    ("x-cp1257", Charset::Windows1257),
+    ("cp1258", Charset::Windows1258),
+    ("x-cp1258", Charset::Windows1258),
+
+    ("cskoi8r", Charset::Koi8R),
+    ("koi8", Charset::Koi8R),
+    ("cp866", Charset::Ibm866),
+    ("csibm866", Charset::Ibm866),
+    ("csmacintosh", Charset::Macintosh),
+    ("mac", Charset::Macintosh),
+
+    ("csshiftjis", Charset::ShiftJis),
+    ("ms_kanji", Charset::ShiftJis),
+    ("sjis", Charset::ShiftJis),
+    ("windows-31j", Charset::ShiftJis),
+    ("x-sjis", Charset::ShiftJis),
+    ("cseucpkdfmtjapanese", Charset::EucJp),
+    ("x-euc-jp", Charset::EucJp),
+    ("csiso2022jp", Charset::Iso2022Jp),
+    ("cseuckr", Charset::EucKr),
+    ("ks_c_5601-1987", Charset::EucKr),
+    ("windows-949", Charset::EucKr),
+    ("csgb2312", Charset::Gb2312),
+    ("cp936", Charset::Gbk),
+    ("ms936", Charset::Gbk),
+    ("windows-936", Charset::Gbk),
+    ("csgb18030", Charset::Gb18030),
+    ("csbig5", Charset::Big5),
+    ("x-x-big5", Charset::Big5),
+];
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_known_roundtrip() {
+        for known in super::KNOWN {
+            assert_eq!(Charset::new(known.as_str()), *known);
+            assert_eq!(Charset::new(&known.as_str().to_lowercase()), *known);
+            assert_ne!(*known, Charset::Unknown(known.as_str().into()));
+        }
+        assert_eq!(Charset::new(""), Charset::Unknown("".into()));
+    }
+
+    #[test]
+    fn test_unknown_ignores_case() {
+        use std::collections::hash_map::DefaultHasher;
+        use std::hash::{Hash, Hasher};
+
+        fn hash(charset: &Charset<'_>) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            charset.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        let upper = Charset::new("X-Klingon");
+        let mt = MediaType::parse("text/plain; charset=\"x-klingon\"").unwrap();
+        let lower = mt.charset().unwrap();
+        assert_eq!(upper, lower);
+        assert_eq!(hash(&upper), hash(&lower));
+        assert_eq!(upper.as_str(), "X-Klingon");
+        assert_ne!(upper, Charset::new("x-vulcan"));
+    }
+
+    #[test]
+    fn test_aliases() {
+        for &(alias, ref charset) in super::ALIASES {
+            assert_eq!(alias, alias.to_lowercase());
+            assert!(!super::KNOWN.iter().any(|known| known.as_str().eq_ignore_ascii_case(alias)), "{}", alias);
+            assert_eq!(Charset::new(alias), *charset);
+            assert_eq!(Charset::new(&alias.to_uppercase()), *charset);
+        }
+    }
+
+    #[test]
+    fn test_media_type_charset() {
+        let cases = [
+            ("text/plain; charset=utf-8", Some(Charset::Utf8)),
+            ("text/plain; charset=\"UTF-8\"", Some(Charset::Utf8)),
+            ("text/plain; charset=\"UTF\\-8\"", Some(Charset::Utf8)),
+            ("text/plain; charset*=''Latin1", Some(Charset::Iso8859_1)),
+            ("text/plain; charset=x-klingon", Some(Charset::Unknown("x-klingon".into()))),
+            ("text/plain; charset=\"x-klingon\"", Some(Charset::Unknown("x-klingon".into()))),
+            ("text/plain; charset=\"x-\\klingon\"", Some(Charset::Unknown("x-klingon".into()))),
+            ("text/plain", None),
+            ("application/octet-stream", None),
+        ];
+        for &(src, ref charset) in &cases {
+            assert_eq!(MediaType::parse(src).unwrap().charset(), *charset, "{}", src);
+        }
+    }
+
+    #[test]
+    fn test_effective_charset() {
+        let cases = [
+            ("text/plain", Some(Charset::UsAscii)),
+            ("text/html", Some(Charset::UsAscii)),
+            ("text/plain; charset=cp1252", Some(Charset::Windows1252)),
+            ("application/json", Some(Charset::Utf8)),
+            ("application/vnd.api+json", Some(Charset::Utf8)),
+            ("application/json; charset=utf-16", Some(Charset::Utf16)),
+            ("text/javascript", Some(Charset::Utf8)),
+            ("text/vcard", Some(Charset::Utf8)),
+            ("application/xml", None),
+            ("image/png", None),
+        ];
+        for &(src, ref charset) in &cases {
+            assert_eq!(MediaType::parse(src).unwrap().effective_charset(), *charset, "{}", src);
+        }
+    }
+
+    #[cfg(feature = "encoding_rs")]
+    #[test]
+    fn test_encoding() {
+        for known in super::KNOWN {
+            let encoding = known.encoding().unwrap_or_else(|| panic!("{} has no encoding", known));
+            // the Encoding Standard merges some charsets into others
+            let merged = [
+                Charset::UsAscii,
+                Charset::Iso8859_1,
+                Charset::Iso8859_9,
+                Charset::Utf16,
+                Charset::Gb2312,
+            ];
+            if !merged.contains(known) {
+                assert_eq!(Charset::from(encoding), *known);
+            }
+        }
+        assert_eq!(Charset::from(encoding_rs::WINDOWS_1252), Charset::Windows1252);
+        assert_eq!(Charset::from(encoding_rs::X_USER_DEFINED), Charset::Unknown("x-user-defined".into()));
+        assert_eq!(Charset::Unknown("utf-7".into()).encoding(), None);
+    }
+}
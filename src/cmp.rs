@@ -1,4 +1,11 @@
-use mime_parse::{Mime, Parser};
+use std::borrow::Cow;
+use std::cmp::Ordering;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+use mime_parse::{Mime, MimeRef, Parser};
+
+use crate::Value;
 
 pub(crate) fn str_eq(mime: &Mime, s: &str) -> bool {
     if mime.has_params() {
@@ -37,7 +44,9 @@ fn params_eq(a: &Mime, b: &Mime) -> bool {
     }
 
     // Order doesn't matter, so we must check simply check that each param
-    // exists in both.
+    // appears as many times in both. Counting, instead of looking up the
+    // first param with the same name, keeps this symmetric even if a name
+    // is repeated.
     //
     // Most mime types have a small-ish amount of parameters, so
     // scanning the iterators multiple times costs less than creating
@@ -46,10 +55,229 @@ fn params_eq(a: &Mime, b: &Mime) -> bool {
     // A simple benchmark suggests a hashmap is faster after about
     // 10 parameters...
     for (name, value) in crate::value::params(a) {
-        if crate::value::param(b, name) != Some(value) {
+        let count = |mime| {
+            crate::value::params(mime)
+                .filter(|&(n, v)| n == name && v == value)
+                .count()
+        };
+        if count(a) != count(b) {
             return false;
         }
     }
 
     true
 }
+
+// A `MimeRef` keeps the case it was written in, so names are compared
+// ignoring ASCII case, instead of relying on them being lowercased.
+pub(crate) fn ref_eq(a: &MimeRef<'_>, b: &Mime) -> bool {
+    a.essence().eq_ignore_ascii_case(b.essence())
+        && raw_params_eq(|| crate::value::ref_params(a), || crate::value::params(b))
+}
+
+pub(crate) fn refs_eq(a: &MimeRef<'_>, b: &MimeRef<'_>) -> bool {
+    a.essence().eq_ignore_ascii_case(b.essence())
+        && raw_params_eq(|| crate::value::ref_params(a), || crate::value::ref_params(b))
+}
+
+pub(crate) fn ref_str_eq(a: &MimeRef<'_>, s: &str) -> bool {
+    Parser::can_range()
+        .parse_ref(s)
+        .map(|b| refs_eq(a, &b))
+        .unwrap_or(false)
+}
+
+// The same as `params_eq`, but the iterators don't know their length.
+fn raw_params_eq<'a, 'b, A, B>(a: impl Fn() -> A, b: impl Fn() -> B) -> bool
+where
+    A: Iterator<Item = (&'a str, Value<'a>)>,
+    B: Iterator<Item = (&'b str, Value<'b>)>,
+{
+    if a().count() != b().count() {
+        return false;
+    }
+
+    for (name, value) in a() {
+        let in_a = a()
+            .filter(|&(n, v)| n.eq_ignore_ascii_case(name) && v == value)
+            .count();
+        let in_b = b()
+            .filter(|&(n, v)| n.eq_ignore_ascii_case(name) && v == value)
+            .count();
+        if in_a != in_b {
+            return false;
+        }
+    }
+
+    true
+}
+
+// Hashes what `mime_eq` compares: the essence, and the parameters in any
+// order, with their values unquoted.
+pub(crate) fn mime_hash<H: Hasher>(mime: &Mime, state: &mut H) {
+    mime.essence().hash(state);
+
+    // Each param is hashed on its own, and the hashes summed, so that the
+    // order of the params doesn't change the result.
+    let mut count = 0usize;
+    let mut sum = 0u64;
+    for (name, value) in crate::value::params(mime) {
+        let mut hasher = DefaultHasher::new();
+        name.hash(&mut hasher);
+        normalize(name, value).hash(&mut hasher);
+        sum = sum.wrapping_add(hasher.finish());
+        count += 1;
+    }
+    state.write_usize(count);
+    state.write_u64(sum);
+}
+
+// Orders by the essence, and then by the sorted parameters, so that it
+// agrees with `mime_eq`.
+pub(crate) fn mime_cmp(a: &Mime, b: &Mime) -> Ordering {
+    if mime_eq(a, b) {
+        return Ordering::Equal;
+    }
+
+    a.essence()
+        .cmp(b.essence())
+        .then_with(|| sorted_params(a).cmp(&sorted_params(b)))
+}
+
+fn sorted_params(mime: &Mime) -> Vec<(&str, Cow<'_, str>)> {
+    let mut params = crate::value::params(mime)
+        .map(|(name, value)| (name, normalize(name, value)))
+        .collect::<Vec<_>>();
+    params.sort();
+    params
+}
+
+// The content of a value, lowercased if it is compared case-insensitively.
+fn normalize<'a>(name: &str, value: Value<'a>) -> Cow<'a, str> {
+    let content = value.to_content();
+    if name == crate::CHARSET && content.bytes().any(|b| b.is_ascii_uppercase()) {
+        Cow::Owned(content.to_ascii_lowercase())
+    } else {
+        content
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cmp::Ordering;
+    use std::collections::hash_map::DefaultHasher;
+    use std::collections::{BTreeSet, HashSet};
+    use std::hash::{Hash, Hasher};
+
+    use crate::*;
+
+    fn hash<T: Hash>(t: &T) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        t.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    fn assert_consistent(a: &MediaType, b: &MediaType) {
+        assert_eq!(a, b);
+        assert_eq!(b, a);
+        assert_eq!(hash(a), hash(b), "{:?} and {:?} hash differently", a, b);
+        assert_eq!(a.cmp(b), Ordering::Equal);
+        assert_eq!(b.cmp(a), Ordering::Equal);
+    }
+
+    #[test]
+    fn hash_atom_and_dynamic() {
+        let dynamic = MediaType::parse("text/plain; charset=\"UTF-8\"").unwrap();
+        assert_eq!(dynamic.mime.private_atom(), 0);
+        assert_consistent(&TEXT_PLAIN_UTF_8, &dynamic);
+
+        let dynamic = MediaType::parse("image/svg+xml; x=y").unwrap().without_params();
+        assert_consistent(&IMAGE_SVG, &dynamic);
+
+        let interned = MediaType::parse("text/plain; charset=utf-8").unwrap();
+        assert_ne!(interned.mime.private_atom(), 0);
+        assert_consistent(&TEXT_PLAIN_UTF_8, &interned);
+    }
+
+    #[test]
+    fn hash_param_order_and_quotes() {
+        let a = MediaType::parse("text/html; level=1; charset=ISO-8859-1; a=\"b c\"").unwrap();
+        let b = MediaType::parse("text/html; a=\"b c\"; charset=iso-8859-1; level=\"1\"").unwrap();
+        assert_consistent(&a, &b);
+
+        let c = MediaType::parse("text/html; a=\"B c\"; charset=iso-8859-1; level=1").unwrap();
+        assert_ne!(a, c);
+        assert_ne!(a.cmp(&c), Ordering::Equal);
+    }
+
+    #[test]
+    fn eq_repeated_params() {
+        let a = MediaType::parse("text/plain; x=1; x=1").unwrap();
+        let b = MediaType::parse("text/plain; x=1; y=2").unwrap();
+        assert_eq!(a, a.clone());
+        assert_ne!(a, b);
+        assert_ne!(b, a);
+
+        let c = MediaType::parse("text/plain; x=2; x=1").unwrap();
+        let d = MediaType::parse("text/plain; x=1; x=2").unwrap();
+        assert_consistent(&c, &d);
+    }
+
+    #[test]
+    fn hash_set() {
+        let mut set = HashSet::new();
+        set.insert(TEXT_PLAIN_UTF_8);
+        set.insert(TEXT_HTML);
+        set.insert(MediaType::parse("application/json; a=1; b=2").unwrap());
+
+        assert!(set.contains(&MediaType::parse("text/plain; charset=\"utf-8\"").unwrap()));
+        assert!(set.contains(&MediaType::parse("TEXT/HTML").unwrap()));
+        assert!(set.contains(&MediaType::parse("application/json; b=2; a=1").unwrap()));
+        assert!(!set.contains(&TEXT_PLAIN));
+
+        assert!(!set.insert(MediaType::parse("text/html").unwrap()));
+        assert_eq!(set.len(), 3);
+    }
+
+    #[test]
+    fn ord_total() {
+        let mut types = vec![
+            MediaType::parse("text/plain; b=1").unwrap(),
+            TEXT_PLAIN_UTF_8,
+            MediaType::parse("text/plain; a=2").unwrap(),
+            TEXT_PLAIN,
+            APPLICATION_JSON,
+            MediaType::parse("text/plain; charset=\"utf-8\"").unwrap(),
+        ];
+        types.sort();
+
+        let sorted = types.iter().map(|mt| mt.to_string()).collect::<Vec<_>>();
+        assert_eq!(sorted, [
+            "application/json",
+            "text/plain",
+            "text/plain; a=2",
+            "text/plain; b=1",
+            "text/plain; charset=utf-8",
+            "text/plain; charset=\"utf-8\"",
+        ]);
+
+        let set = types.into_iter().collect::<BTreeSet<_>>();
+        assert_eq!(set.len(), 5);
+    }
+
+    #[test]
+    fn media_range_hash_ord() {
+        let a = MediaRange::parse("text/*; level=1; q=0.5").unwrap();
+        let b = MediaRange::parse("text/*;q=0.5;level=\"1\"").unwrap();
+        assert_eq!(a, b);
+        assert_eq!(hash(&a), hash(&b));
+
+        let set = vec![TEXT_STAR, STAR_STAR, IMAGE_STAR, TEXT_STAR]
+            .into_iter()
+            .collect::<HashSet<_>>();
+        assert_eq!(set.len(), 3);
+
+        assert!(STAR_STAR < TEXT_STAR);
+        assert_eq!(TEXT_STAR.cmp(&MediaRange::parse("text/*").unwrap()), Ordering::Equal);
+    }
+}
@@ -0,0 +1,422 @@
+//! The `codecs` parameter of audio and video media types.
+//!
+//! [RFC 6381](https://tools.ietf.org/html/rfc6381) defines a `codecs`
+//! parameter, listing the codecs needed to play a resource, such as
+//! `video/mp4; codecs="avc1.42E01E, mp4a.40.2"`. Each codec starts with
+//! the four character code of its sample entry, followed by details like
+//! the profile and level, in a format that depends on the codec.
+//!
+//! # Example
+//!
+//! ```
+//! use mime::codecs::Kind;
+//!
+//! let mt = mime::MediaType::parse(r#"video/mp4; codecs="avc1.42E01E, mp4a.40.2""#).unwrap();
+//! let mut codecs = mt.codecs();
+//!
+//! let avc = codecs.next().unwrap();
+//! assert_eq!(avc.fourcc(), "avc1");
+//! assert_eq!(avc.kind(), Kind::Avc { profile: 66, constraints: 0xE0, level: 30 });
+//!
+//! let aac = codecs.next().unwrap();
+//! assert_eq!(aac.as_str(), "mp4a.40.2");
+//! assert_eq!(aac.kind(), Kind::Aac { object_type: 2 });
+//!
+//! assert!(codecs.next().is_none());
+//! ```
+
+use std::borrow::Cow;
+use std::fmt;
+
+use crate::MediaType;
+
+/// A codec from a `codecs` parameter.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Codec<'a> {
+    raw: Cow<'a, str>,
+    kind: Kind,
+}
+
+/// What a [`Codec`] is, with the details parsed from it.
+///
+/// Codecs that aren't known, or whose details aren't valid, are
+/// `Unknown`, and only available as a string.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Kind {
+    /// H.264, such as `avc1.64001F`.
+    ///
+    /// From [RFC 6381](https://tools.ietf.org/html/rfc6381#section-3.3),
+    /// the `profile_idc`, constraint flags, and `level_idc`.
+    Avc {
+        /// The `profile_idc`, such as 66 for Baseline or 100 for High.
+        profile: u8,
+        /// The byte of constraint set flags.
+        constraints: u8,
+        /// The `level_idc`, 10 times the level, such as 31 for 3.1.
+        level: u8,
+    },
+    /// H.265, such as `hvc1.1.6.L93.B0`.
+    ///
+    /// From ISO/IEC 14496-15, annex E.
+    Hevc {
+        /// The `general_profile_space`, from 0 to 3.
+        profile_space: u8,
+        /// The `general_profile_idc`, such as 1 for Main or 2 for Main 10.
+        profile: u8,
+        /// The `general_profile_compatibility_flags`, as written, which is
+        /// in reverse bit order.
+        compatibility: u32,
+        /// True for the High tier, false for the Main tier.
+        high_tier: bool,
+        /// The `general_level_idc`, 30 times the level, such as 93 for 3.1.
+        level: u8,
+        /// The 6 bytes of constraint flags, with trailing zero bytes
+        /// filled in.
+        constraints: [u8; 6],
+    },
+    /// AV1, such as `av01.0.04M.10`.
+    ///
+    /// From the [AV1 ISOBMFF binding](https://aomediacodec.github.io/av1-isobmff/#codecsparam).
+    /// The optional color details after the bit depth aren't parsed.
+    Av1 {
+        /// The `seq_profile`, such as 0 for Main.
+        profile: u8,
+        /// The `seq_level_idx`.
+        level: u8,
+        /// True for the High tier, false for the Main tier.
+        high_tier: bool,
+        /// The bit depth, such as 8 or 10.
+        bit_depth: u8,
+    },
+    /// VP9, such as `vp09.00.10.08`.
+    ///
+    /// From the [VP codec ISO media file format binding](https://www.webmproject.org/vp9/mp4/#codecs-parameter-string).
+    /// The optional color details after the bit depth aren't parsed.
+    Vp9 {
+        /// The profile, from 0 to 3.
+        profile: u8,
+        /// 10 times the level, such as 31 for 3.1.
+        level: u8,
+        /// The bit depth, such as 8 or 10.
+        bit_depth: u8,
+    },
+    /// Opus, `opus`.
+    Opus,
+    /// AAC, such as `mp4a.40.2`.
+    ///
+    /// From [RFC 6381](https://tools.ietf.org/html/rfc6381#section-3.3),
+    /// the MPEG-4 audio object type indication, `40`, and the audio
+    /// object type.
+    Aac {
+        /// The audio object type, such as 2 for AAC-LC or 5 for HE-AAC.
+        object_type: u8,
+    },
+    /// Any other codec.
+    Unknown,
+}
+
+/// An iterator over the codecs of a `MediaType`.
+///
+/// Created by [`MediaType::codecs`].
+#[derive(Clone, Debug)]
+pub struct Iter<'a> {
+    content: Option<Cow<'a, str>>,
+    pos: usize,
+}
+
+impl<'a> Codec<'a> {
+    /// Parse a single codec, such as `avc1.42E01E`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mime::codecs::{Codec, Kind};
+    ///
+    /// let vp9 = Codec::parse("vp09.02.10.10.01.09.16.09.01");
+    /// assert_eq!(vp9.kind(), Kind::Vp9 { profile: 2, level: 10, bit_depth: 10 });
+    ///
+    /// let flac = Codec::parse("fLaC");
+    /// assert_eq!(flac.kind(), Kind::Unknown);
+    /// assert_eq!(flac.as_str(), "fLaC");
+    /// ```
+    pub fn parse(codec: impl Into<Cow<'a, str>>) -> Codec<'a> {
+        let raw = codec.into();
+        let kind = parse(&raw).unwrap_or(Kind::Unknown);
+        Codec {
+            raw,
+            kind,
+        }
+    }
+
+    /// Get the codec as it was written.
+    pub fn as_str(&self) -> &str {
+        &self.raw
+    }
+
+    /// Get the four character code of the codec, before the first `.`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let hevc = mime::codecs::Codec::parse("hev1.1.6.L93.B0");
+    /// assert_eq!(hevc.fourcc(), "hev1");
+    /// ```
+    pub fn fourcc(&self) -> &str {
+        self.raw.split('.').next().expect("split has a first item")
+    }
+
+    /// Get what this codec is.
+    pub fn kind(&self) -> Kind {
+        self.kind
+    }
+}
+
+impl<'a> fmt::Display for Codec<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.raw)
+    }
+}
+
+impl<'a> Iterator for Iter<'a> {
+    type Item = Codec<'a>;
+
+    fn next(&mut self) -> Option<Codec<'a>> {
+        let content = self.content.as_ref()?;
+        loop {
+            let rest = content.get(self.pos..).filter(|rest| !rest.is_empty())?;
+            let len = rest.find(',').unwrap_or(rest.len());
+            let start = self.pos + (len - rest[..len].trim_start().len());
+            let end = self.pos + rest[..len].trim_end().len();
+            self.pos += len + 1;
+
+            if start < end {
+                // a quoted-pair is silly here, but then the content had to
+                // be unescaped into a new string
+                let codec = match *content {
+                    Cow::Borrowed(s) => Cow::Borrowed(&s[start..end]),
+                    Cow::Owned(ref s) => Cow::Owned(s[start..end].to_owned()),
+                };
+                return Some(Codec::parse(codec));
+            }
+        }
+    }
+}
+
+pub(crate) fn codecs(mt: &MediaType) -> Iter<'_> {
+    Iter {
+        content: mt.param("codecs").map(|value| value.to_content()),
+        pos: 0,
+    }
+}
+
+fn parse(codec: &str) -> Option<Kind> {
+    let mut fields = codec.split('.');
+    let fourcc = fields.next()?;
+    let fields = fields.collect::<Vec<_>>();
+
+    match (fourcc, &fields[..]) {
+        ("avc1", &[idc]) | ("avc2", &[idc]) | ("avc3", &[idc]) | ("avc4", &[idc]) if idc.len() == 6 => Some(Kind::Avc {
+            profile: hex(idc.get(..2)?)?,
+            constraints: hex(idc.get(2..4)?)?,
+            level: hex(idc.get(4..)?)?,
+        }),
+        ("hev1", &[profile, compatibility, tier_level, ref constraints @ ..]) |
+        ("hvc1", &[profile, compatibility, tier_level, ref constraints @ ..]) => {
+            let (profile_space, profile) = match profile.as_bytes().first()? {
+                c @ b'A'..=b'C' => (c - b'A' + 1, &profile[1..]),
+                _ => (0, profile),
+            };
+            if compatibility.len() > 8 || constraints.len() > 6 {
+                return None;
+            }
+            let mut constraint_bytes = [0; 6];
+            for (byte, constraint) in constraint_bytes.iter_mut().zip(constraints) {
+                *byte = hex(constraint)?;
+            }
+            Some(Kind::Hevc {
+                profile_space,
+                profile: decimal(profile)?,
+                compatibility: u32::from_str_radix(compatibility, 16).ok().filter(|_| is_hex(compatibility))?,
+                high_tier: tier(tier_level.get(..1)?, "L")?,
+                level: decimal(tier_level.get(1..)?)?,
+                constraints: constraint_bytes,
+            })
+        },
+        ("av01", &[profile, level_tier, bit_depth, ref color @ ..]) if level_tier.len() == 3 && color.len() <= 6 => {
+            if !color.iter().all(|field| decimal(field).is_some()) {
+                return None;
+            }
+            Some(Kind::Av1 {
+                profile: decimal(profile).filter(|_| profile.len() == 1)?,
+                level: decimal(level_tier.get(..2)?)?,
+                high_tier: tier(level_tier.get(2..)?, "M")?,
+                bit_depth: two_digits(bit_depth)?,
+            })
+        },
+        ("vp09", &[profile, level, bit_depth, ref color @ ..]) if color.len() <= 5 => {
+            if !color.iter().all(|field| two_digits(field).is_some()) {
+                return None;
+            }
+            Some(Kind::Vp9 {
+                profile: two_digits(profile)?,
+                level: two_digits(level)?,
+                bit_depth: two_digits(bit_depth)?,
+            })
+        },
+        ("opus", &[]) | ("Opus", &[]) => Some(Kind::Opus),
+        ("mp4a", &["40", object_type]) => Some(Kind::Aac {
+            object_type: decimal(object_type)?,
+        }),
+        _ => None,
+    }
+}
+
+fn is_hex(s: &str) -> bool {
+    !s.is_empty() && s.bytes().all(|b| b.is_ascii_hexdigit())
+}
+
+fn hex(s: &str) -> Option<u8> {
+    if is_hex(s) {
+        u8::from_str_radix(s, 16).ok()
+    } else {
+        None
+    }
+}
+
+fn decimal(s: &str) -> Option<u8> {
+    if !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit()) {
+        s.parse().ok()
+    } else {
+        None
+    }
+}
+
+// AV1 and VP9 fields are always two digits, such as `08`.
+fn two_digits(s: &str) -> Option<u8> {
+    decimal(s).filter(|_| s.len() == 2)
+}
+
+// The main tier is `L` for HEVC, and `M` for AV1.
+fn tier(s: &str, main: &str) -> Option<bool> {
+    if s == main {
+        Some(false)
+    } else if s == "H" {
+        Some(true)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Codec, Kind};
+    use crate::MediaType;
+
+    fn kind(codec: &str) -> Kind {
+        Codec::parse(codec).kind()
+    }
+
+    #[test]
+    fn test_avc() {
+        assert_eq!(kind("avc1.42E01E"), Kind::Avc { profile: 0x42, constraints: 0xE0, level: 0x1E });
+        assert_eq!(kind("avc3.640028"), Kind::Avc { profile: 100, constraints: 0, level: 40 });
+        assert_eq!(kind("avc1.4d401f"), Kind::Avc { profile: 77, constraints: 0x40, level: 31 });
+        assert_eq!(kind("avc1.42E01"), Kind::Unknown);
+        assert_eq!(kind("avc1.42E01E.1"), Kind::Unknown);
+        assert_eq!(kind("avc1.+2E01E"), Kind::Unknown);
+        // the old decimal form
+        assert_eq!(kind("avc1.66.30"), Kind::Unknown);
+        assert_eq!(kind("avc5.42E01E"), Kind::Unknown);
+    }
+
+    #[test]
+    fn test_hevc() {
+        assert_eq!(kind("hev1.1.6.L93.B0"), Kind::Hevc {
+            profile_space: 0,
+            profile: 1,
+            compatibility: 6,
+            high_tier: false,
+            level: 93,
+            constraints: [0xB0, 0, 0, 0, 0, 0],
+        });
+        assert_eq!(kind("hvc1.A4.FFFFFFFF.H186.90.1.2.3.4.5"), Kind::Hevc {
+            profile_space: 1,
+            profile: 4,
+            compatibility: 0xFFFF_FFFF,
+            high_tier: true,
+            level: 186,
+            constraints: [0x90, 1, 2, 3, 4, 5],
+        });
+        assert_eq!(kind("hvc1.2.4.L120"), Kind::Hevc {
+            profile_space: 0,
+            profile: 2,
+            compatibility: 4,
+            high_tier: false,
+            level: 120,
+            constraints: [0; 6],
+        });
+        assert_eq!(kind("hvc1.2.4.M120.B0"), Kind::Unknown);
+        assert_eq!(kind("hvc1.2.4.L"), Kind::Unknown);
+        assert_eq!(kind("hvc1.D2.4.L120"), Kind::Unknown);
+        assert_eq!(kind("hvc1.2.100000000.L120"), Kind::Unknown);
+        assert_eq!(kind("hvc1.2.4.L120.1.2.3.4.5.6.7"), Kind::Unknown);
+        assert_eq!(kind("hvc1.2.4"), Kind::Unknown);
+        assert_eq!(kind("hvc1"), Kind::Unknown);
+    }
+
+    #[test]
+    fn test_av1() {
+        assert_eq!(kind("av01.0.04M.10"), Kind::Av1 { profile: 0, level: 4, high_tier: false, bit_depth: 10 });
+        assert_eq!(kind("av01.2.19H.12.0.000.01.13.00.1"), Kind::Av1 { profile: 2, level: 19, high_tier: true, bit_depth: 12 });
+        assert_eq!(kind("av01.0.04L.10"), Kind::Unknown);
+        assert_eq!(kind("av01.0.4M.10"), Kind::Unknown);
+        assert_eq!(kind("av01.0.04M.8"), Kind::Unknown);
+        assert_eq!(kind("av01.0.04M.10.x"), Kind::Unknown);
+        assert_eq!(kind("av01.2.19H.12.0.000.01.13.00.1.0"), Kind::Unknown);
+    }
+
+    #[test]
+    fn test_vp9() {
+        assert_eq!(kind("vp09.00.10.08"), Kind::Vp9 { profile: 0, level: 10, bit_depth: 8 });
+        assert_eq!(kind("vp09.02.10.10.01.09.16.09.01"), Kind::Vp9 { profile: 2, level: 10, bit_depth: 10 });
+        assert_eq!(kind("vp09.0.10.08"), Kind::Unknown);
+        assert_eq!(kind("vp09.00.10"), Kind::Unknown);
+        assert_eq!(kind("vp09.00.10.08.01.09.16.09.01.00"), Kind::Unknown);
+        // the old WebM names don't have any details
+        assert_eq!(kind("vp9"), Kind::Unknown);
+    }
+
+    #[test]
+    fn test_audio() {
+        assert_eq!(kind("opus"), Kind::Opus);
+        assert_eq!(kind("Opus"), Kind::Opus);
+        assert_eq!(kind("OPUS"), Kind::Unknown);
+        assert_eq!(kind("mp4a.40.2"), Kind::Aac { object_type: 2 });
+        assert_eq!(kind("mp4a.40.29"), Kind::Aac { object_type: 29 });
+        assert_eq!(kind("mp4a.40"), Kind::Unknown);
+        // MP3 in MP4
+        assert_eq!(kind("mp4a.6B"), Kind::Unknown);
+        assert_eq!(kind("mp4a.69"), Kind::Unknown);
+    }
+
+    #[test]
+    fn test_media_type_codecs() {
+        let codecs = |src: &str| {
+            let mt = MediaType::parse(src).unwrap();
+            mt.codecs().map(|c| c.as_str().to_owned()).collect::<Vec<_>>()
+        };
+
+        assert_eq!(codecs(r#"video/mp4; codecs="avc1.42E01E, mp4a.40.2""#), ["avc1.42E01E", "mp4a.40.2"]);
+        assert_eq!(codecs(r#"video/webm; codecs="vp9,opus""#), ["vp9", "opus"]);
+        assert_eq!(codecs("audio/ogg; codecs=opus"), ["opus"]);
+        assert_eq!(codecs(r#"video/mp4; codecs=" , avc1.42E01E ,, ""#), ["avc1.42E01E"]);
+        assert_eq!(codecs(r#"video/mp4; codecs="av01.0.04M.10\,\ fLaC""#), ["av01.0.04M.10", "fLaC"]);
+        assert_eq!(codecs(r#"video/mp4; codecs="""#), Vec::<String>::new());
+        assert_eq!(codecs("video/mp4"), Vec::<String>::new());
+
+        let mt = MediaType::parse(r#"video/mp4; codecs="hvc1.1.6.L93.B0, fLaC""#).unwrap();
+        let kinds = mt.codecs().map(|c| c.kind()).collect::<Vec<_>>();
+        assert!(matches!(kinds[0], Kind::Hevc { level: 93, .. }));
+        assert_eq!(kinds[1], Kind::Unknown);
+    }
+}
@@ -110,6 +110,12 @@ mimes! {
     APPLICATION_PDF, "application/pdf";
     APPLICATION_DNS, "application/dns-message";
 
+    MULTIPART_FORM_DATA, "multipart/form-data";
+    MULTIPART_MIXED, "multipart/mixed";
+    MULTIPART_ALTERNATIVE, "multipart/alternative";
+    MULTIPART_RELATED, "multipart/related";
+    MULTIPART_BYTERANGES, "multipart/byteranges";
+
     // media-ranges
     @ MediaRange:
     STAR_STAR, "*/*";
@@ -0,0 +1,163 @@
+use std::path::Path;
+
+use crate::MediaType;
+
+mod table;
+
+impl MediaType {
+    /// Get the most likely `MediaType` for a file extension.
+    ///
+    /// The extension is matched case-insensitively, and should not include
+    /// the leading dot.
+    ///
+    /// This requires the `extensions` feature enabled on the mime crate.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mime::MediaType;
+    ///
+    /// assert_eq!(MediaType::from_extension("svg"), Some(mime::IMAGE_SVG));
+    /// assert_eq!(MediaType::from_extension("JSON"), Some(mime::APPLICATION_JSON));
+    /// assert_eq!(MediaType::from_extension("not-an-extension"), None);
+    /// ```
+    pub fn from_extension(ext: &str) -> Option<MediaType> {
+        candidates(ext).first().map(|&s| from_table(s))
+    }
+
+    /// Guess the `MediaType`s of a path from its extension.
+    ///
+    /// Candidates are ranked from most to least likely. The first is the
+    /// same as [`MediaType::from_extension`].
+    ///
+    /// This requires the `extensions` feature enabled on the mime crate.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::path::Path;
+    /// use mime::MediaType;
+    ///
+    /// let mut guesses = MediaType::guess_from_path(Path::new("static/app.js"));
+    /// assert_eq!(guesses.next(), Some(mime::TEXT_JAVASCRIPT));
+    /// assert_eq!(guesses.next(), Some(mime::APPLICATION_JAVASCRIPT));
+    /// assert_eq!(guesses.next(), None);
+    ///
+    /// assert_eq!(MediaType::guess_from_path(Path::new("Makefile")).count(), 0);
+    /// ```
+    pub fn guess_from_path(path: &Path) -> impl Iterator<Item = MediaType> {
+        path.extension()
+            .and_then(|ext| ext.to_str())
+            .map(candidates)
+            .unwrap_or(&[])
+            .iter()
+            .map(|&s| from_table(s))
+    }
+
+    /// Get the file extensions known for this `MediaType`.
+    ///
+    /// Parameters are ignored. The most common extension is usually first.
+    ///
+    /// This requires the `extensions` feature enabled on the mime crate.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// assert!(mime::IMAGE_JPEG.extensions().contains(&"jpg"));
+    /// assert_eq!(mime::TEXT_PLAIN_UTF_8.extensions()[0], "txt");
+    /// ```
+    pub fn extensions(&self) -> &'static [&'static str] {
+        lookup(table::BY_MEDIA_TYPE, self.mime.essence())
+    }
+}
+
+fn candidates(ext: &str) -> &'static [&'static str] {
+    if ext.bytes().any(|b| b.is_ascii_uppercase()) {
+        lookup(table::BY_EXTENSION, &ext.to_ascii_lowercase())
+    } else {
+        lookup(table::BY_EXTENSION, ext)
+    }
+}
+
+fn lookup(table: &'static [(&'static str, &'static [&'static str])], key: &str) -> &'static [&'static str] {
+    table
+        .binary_search_by(|&(k, _)| k.cmp(key))
+        .map(|i| table[i].1)
+        .unwrap_or(&[])
+}
+
+fn from_table(s: &'static str) -> MediaType {
+    MediaType::parse(s).expect("extensions table has valid media types")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use crate::*;
+    use super::table;
+
+    #[test]
+    fn tables_are_sorted() {
+        for t in &[table::BY_EXTENSION, table::BY_MEDIA_TYPE] {
+            for pair in t.windows(2) {
+                assert!(pair[0].0 < pair[1].0, "{:?} sorted before {:?}", pair[0].0, pair[1].0);
+            }
+        }
+    }
+
+    #[test]
+    fn tables_parse() {
+        for &(ext, types) in table::BY_EXTENSION {
+            assert!(!types.is_empty(), "{:?} has no types", ext);
+            for &mt in types {
+                let parsed = MediaType::parse(mt).expect(mt);
+                assert!(parsed.extensions().contains(&ext), "{:?} missing {:?}", mt, ext);
+            }
+        }
+    }
+
+    #[test]
+    fn from_extension_constants() {
+        assert_eq!(MediaType::from_extension("html"), Some(TEXT_HTML));
+        assert_eq!(MediaType::from_extension("css"), Some(TEXT_CSS));
+        assert_eq!(MediaType::from_extension("csv"), Some(TEXT_CSV));
+        assert_eq!(MediaType::from_extension("png"), Some(IMAGE_PNG));
+        assert_eq!(MediaType::from_extension("jpg"), Some(IMAGE_JPEG));
+        assert_eq!(MediaType::from_extension("gif"), Some(IMAGE_GIF));
+        assert_eq!(MediaType::from_extension("pdf"), Some(APPLICATION_PDF));
+        assert_eq!(MediaType::from_extension("woff2"), Some(FONT_WOFF2));
+
+        let json = MediaType::from_extension("json").unwrap();
+        assert_eq!(json.mime.private_atom(), APPLICATION_JSON.mime.private_atom());
+    }
+
+    #[test]
+    fn from_extension_preferred() {
+        assert_eq!(MediaType::from_extension("ts").unwrap(), "video/mp2t");
+        assert_eq!(MediaType::from_extension("mid").unwrap(), "audio/midi");
+        assert_eq!(MediaType::from_extension("asf").unwrap(), "video/x-ms-asf");
+    }
+
+    #[test]
+    fn from_extension_nginx_only() {
+        assert_eq!(MediaType::from_extension("jardiff").unwrap(), "application/x-java-archive-diff");
+    }
+
+    #[test]
+    fn guess_from_path() {
+        let guesses = MediaType::guess_from_path(Path::new("/tmp/video.TS")).collect::<Vec<_>>();
+        assert_eq!(guesses, ["video/mp2t", "text/vnd.trolltech.linguist"]);
+
+        assert_eq!(MediaType::guess_from_path(Path::new("archive.tar.gz")).next().unwrap(), "application/gzip");
+        assert_eq!(MediaType::guess_from_path(Path::new(".bashrc")).count(), 0);
+        assert_eq!(MediaType::guess_from_path(Path::new("no-ext")).count(), 0);
+    }
+
+    #[test]
+    fn extensions() {
+        assert!(TEXT_HTML.extensions().contains(&"htm"));
+        assert_eq!(TEXT_VCARD.extensions(), &["vcf", "vcard"]);
+        assert!(MediaType::parse("application/x-unknown").unwrap().extensions().is_empty());
+    }
+}
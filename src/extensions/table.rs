@@ -0,0 +1,2770 @@
+// This file is @generated by `cargo run -p mime-gen`, do not edit it by hand.
+
+pub(super) static BY_EXTENSION: &[(&str, &[&str])] = &[
+    ("%", &["application/x-trash"]),
+    ("123", &["application/vnd.lotus-1-2-3"]),
+    ("1905.1", &["application/vnd.ieee.1905"]),
+    ("1clr", &["application/clr"]),
+    ("1km", &["application/vnd.1000minds.decision-model+xml"]),
+    ("210", &["application/p21"]),
+    ("3dm", &["text/vnd.in3d.3dml"]),
+    ("3dml", &["text/vnd.in3d.3dml"]),
+    ("3gp", &["video/3gpp"]),
+    ("3gpp", &["video/3gpp"]),
+    ("3mf", &["application/vnd.ms-3mfdocument"]),
+    ("3tz", &["application/vnd.maxar.archive.3tz+zip"]),
+    ("726", &["audio/32kadpcm"]),
+    ("7z", &["application/x-7z-compressed"]),
+    ("a", &["text/vnd.a"]),
+    ("a2l", &["application/a2l"]),
+    ("aa3", &["audio/atrac3"]),
+    ("aac", &["audio/aac"]),
+    ("aal", &["audio/atrac-advanced-lossless"]),
+    ("abc", &["text/vnd.abc"]),
+    ("abw", &["application/x-abiword"]),
+    ("ac", &["application/pkix-attr-cert"]),
+    ("ac2", &["application/vnd.banana-accounting"]),
+    ("ac3", &["audio/ac3"]),
+    ("acc", &["application/vnd.americandynamics.acc"]),
+    ("acn", &["audio/asc"]),
+    ("acu", &["application/vnd.acucobol"]),
+    ("acutc", &["application/vnd.acucorp"]),
+    ("adts", &["audio/aac"]),
+    ("aep", &["application/vnd.audiograph"]),
+    ("afp", &["application/vnd.afpc.modca"]),
+    ("age", &["application/vnd.age"]),
+    ("ahead", &["application/vnd.ahead.space"]),
+    ("ai", &["application/postscript"]),
+    ("aif", &["audio/x-aiff"]),
+    ("aifc", &["audio/x-aiff"]),
+    ("aiff", &["audio/x-aiff"]),
+    ("aion", &["application/vnd.veritone.aion+json"]),
+    ("ait", &["application/vnd.dvb.ait"]),
+    ("alc", &["chemical/x-alchemy"]),
+    ("ami", &["application/vnd.amiga.ami"]),
+    ("aml", &["application/aml"]),
+    ("amlx", &["application/automationml-amlx+zip"]),
+    ("amr", &["audio/amr"]),
+    ("anx", &["application/annodex"]),
+    ("apex", &["application/vnd.apexlang"]),
+    ("apexlang", &["application/vnd.apexlang"]),
+    ("apk", &["application/vnd.android.package-archive"]),
+    ("apkg", &["application/vnd.anki"]),
+    ("apng", &["image/apng"]),
+    ("appcache", &["text/cache-manifest"]),
+    ("apr", &["application/vnd.lotus-approach"]),
+    ("apxml", &["application/auth-policy+xml"]),
+    ("arrow", &["application/vnd.apache.arrow.file"]),
+    ("arrows", &["application/vnd.apache.arrow.stream"]),
+    ("art", &["image/x-jg", "message/rfc822"]),
+    ("artisan", &["application/vnd.artisan+json"]),
+    ("asc", &["application/pgp-keys"]),
+    ("ascii", &["text/vnd.ascii-art"]),
+    ("asf", &["video/x-ms-asf", "application/vnd.ms-asf"]),
+    ("asice", &["application/vnd.etsi.asic-e+zip"]),
+    ("asics", &["application/vnd.etsi.asic-s+zip"]),
+    ("asn", &["chemical/x-ncbi-asn1", "chemical/x-ncbi-asn1-spec"]),
+    ("aso", &["application/vnd.accpac.simply.aso", "chemical/x-ncbi-asn1-binary"]),
+    ("ass", &["audio/aac"]),
+    ("asx", &["video/x-ms-asf"]),
+    ("at3", &["audio/atrac3"]),
+    ("atc", &["application/vnd.acucorp"]),
+    ("atf", &["application/atf"]),
+    ("atfx", &["application/atfx"]),
+    ("atom", &["application/atom+xml"]),
+    ("atomcat", &["application/atomcat+xml"]),
+    ("atomdeleted", &["application/atomdeleted+xml"]),
+    ("atomsrv", &["application/atomserv+xml"]),
+    ("atomsvc", &["application/atomsvc+xml"]),
+    ("atx", &["audio/atrac-x"]),
+    ("atxml", &["application/atxml"]),
+    ("au", &["audio/basic"]),
+    ("auc", &["application/tamp-apex-update-confirm"]),
+    ("avci", &["image/avci"]),
+    ("avcs", &["image/avcs"]),
+    ("avi", &["video/x-msvideo"]),
+    ("avif", &["image/avif"]),
+    ("awb", &["audio/amr-wb"]),
+    ("axa", &["audio/annodex"]),
+    ("axv", &["video/annodex"]),
+    ("azf", &["application/vnd.airzip.filesecure.azf"]),
+    ("azs", &["application/vnd.airzip.filesecure.azs"]),
+    ("azv", &["image/vnd.airzip.accelerator.azv"]),
+    ("azw3", &["application/vnd.amazon.mobi8-ebook"]),
+    ("b", &["chemical/x-molconn-z"]),
+    ("b16", &["image/vnd.pco.b16"]),
+    ("bak", &["application/x-trash"]),
+    ("bar", &["application/vnd.qualcomm.brew-app-res"]),
+    ("bat", &["application/x-msdos-program"]),
+    ("bcpio", &["application/x-bcpio"]),
+    ("bdm", &["application/vnd.syncml.dm+wbxml"]),
+    ("bed", &["application/vnd.realvnc.bed"]),
+    ("bh2", &["application/vnd.fujitsu.oasysprs"]),
+    ("bib", &["text/x-bibtex"]),
+    ("bik", &["video/vnd.radgamettools.bink"]),
+    ("bin", &["application/octet-stream"]),
+    ("bk2", &["video/vnd.radgamettools.bink"]),
+    ("bkm", &["application/vnd.nervana"]),
+    ("bmed", &["multipart/vnd.bint.med-plus"]),
+    ("bmi", &["application/vnd.bmi"]),
+    ("bmml", &["application/vnd.balsamiq.bmml+xml"]),
+    ("bmp", &["image/bmp", "image/x-ms-bmp"]),
+    ("bmpr", &["application/vnd.balsamiq.bmpr"]),
+    ("boo", &["text/x-boo"]),
+    ("book", &["application/x-maker"]),
+    ("box", &["application/vnd.previewsystems.box"]),
+    ("bpd", &["application/vnd.hbci"]),
+    ("brf", &["text/plain"]),
+    ("bsd", &["chemical/x-crossfire"]),
+    ("bsp", &["model/vnd.valve.source.compiled-map"]),
+    ("btf", &["image/prs.btif"]),
+    ("btif", &["image/prs.btif"]),
+    ("c", &["text/x-csrc"]),
+    ("c++", &["text/x-c++src"]),
+    ("c11amc", &["application/vnd.cluetrust.cartomobile-config"]),
+    ("c11amz", &["application/vnd.cluetrust.cartomobile-config-pkg"]),
+    ("c3d", &["chemical/x-chem3d"]),
+    ("c3ex", &["application/cccex"]),
+    ("c4d", &["application/vnd.clonk.c4group"]),
+    ("c4f", &["application/vnd.clonk.c4group"]),
+    ("c4g", &["application/vnd.clonk.c4group"]),
+    ("c4p", &["application/vnd.clonk.c4group"]),
+    ("c4u", &["application/vnd.clonk.c4group"]),
+    ("c9r", &["application/vnd.cryptomator.encrypted"]),
+    ("c9s", &["application/vnd.cryptomator.encrypted"]),
+    ("cab", &["application/vnd.ms-cab-compressed"]),
+    ("cac", &["chemical/x-cache"]),
+    ("cache", &["chemical/x-cache"]),
+    ("cap", &["application/vnd.tcpdump.pcap"]),
+    ("car", &["application/vnd.ipld.car"]),
+    ("carjson", &["application/vnd.eu.kasparian.car+json"]),
+    ("cascii", &["chemical/x-cactvs-binary"]),
+    ("cat", &["application/vnd.ms-pki.seccat"]),
+    ("cbin", &["chemical/x-cactvs-binary"]),
+    ("cbor", &["application/cbor"]),
+    ("cbr", &["application/vnd.comicbook-rar"]),
+    ("cbz", &["application/vnd.comicbook+zip"]),
+    ("cc", &["text/x-c++src"]),
+    ("ccc", &["text/vnd.net2phone.commcenter.command"]),
+    ("ccmp", &["application/ccmp+xml"]),
+    ("cco", &["application/x-cocoa"]),
+    ("ccxml", &["application/ccxml+xml"]),
+    ("cda", &["application/x-cdf"]),
+    ("cdbcmsg", &["application/vnd.contact.cmsg"]),
+    ("cdf", &["application/x-cdf"]),
+    ("cdfx", &["application/cdfx+xml"]),
+    ("cdkey", &["application/vnd.mediastation.cdkey"]),
+    ("cdmia", &["application/cdmi-capability"]),
+    ("cdmic", &["application/cdmi-container"]),
+    ("cdmid", &["application/cdmi-domain"]),
+    ("cdmio", &["application/cdmi-object"]),
+    ("cdmiq", &["application/cdmi-queue"]),
+    ("cdr", &["image/x-coreldraw"]),
+    ("cdt", &["image/x-coreldrawtemplate"]),
+    ("cdx", &["chemical/x-cdx"]),
+    ("cdxml", &["application/vnd.chemdraw+xml"]),
+    ("cdy", &["application/vnd.cinderella"]),
+    ("cea", &["application/cea"]),
+    ("cef", &["chemical/x-cxf"]),
+    ("cellml", &["application/cellml+xml"]),
+    ("cer", &["application/pkix-cert"]),
+    ("cgm", &["image/cgm"]),
+    ("chm", &["application/vnd.ms-htmlhelp", "chemical/x-chemdraw"]),
+    ("chrt", &["application/vnd.kde.kchart"]),
+    ("cif", &["application/vnd.multiad.creator.cif", "chemical/x-cif"]),
+    ("cii", &["application/vnd.anser-web-certificate-issue-initiation"]),
+    ("cil", &["application/vnd.ms-artgalry"]),
+    ("cl", &["application/simple-filter+xml"]),
+    ("cla", &["application/vnd.claymore"]),
+    ("class", &["application/java-vm"]),
+    ("cld", &["model/vnd.cld"]),
+    ("clkk", &["application/vnd.crick.clicker.keyboard"]),
+    ("clkp", &["application/vnd.crick.clicker.palette"]),
+    ("clkt", &["application/vnd.crick.clicker.template"]),
+    ("clkw", &["application/vnd.crick.clicker.wordbank"]),
+    ("clkx", &["application/vnd.crick.clicker"]),
+    ("cls", &["text/x-tex"]),
+    ("clue", &["application/clue_info+xml"]),
+    ("cmc", &["application/vnd.cosmocaller"]),
+    ("cmdf", &["chemical/x-cmdf"]),
+    ("cml", &["application/cellml+xml", "chemical/x-cml"]),
+    ("cmp", &["application/vnd.yellowriver-custom-menu"]),
+    ("cmsc", &["application/cms"]),
+    ("cnd", &["text/jcr-cnd"]),
+    ("cod", &["application/vnd.rim.cod"]),
+    ("coffee", &["application/vnd.coffeescript"]),
+    ("com", &["application/x-msdos-program"]),
+    ("copyright", &["text/vnd.debian.copyright"]),
+    ("coswid", &["application/swid+cbor"]),
+    ("cpa", &["chemical/x-compass"]),
+    ("cpio", &["application/x-cpio"]),
+    ("cpkg", &["application/vnd.xmpie.cpkg"]),
+    ("cpl", &["application/cpl+xml"]),
+    ("cpp", &["text/x-c++src"]),
+    ("cpt", &["application/mac-compactpro", "image/x-corelphotopaint"]),
+    ("cql", &["text/cql"]),
+    ("cr2", &["image/x-canon-cr2"]),
+    ("crl", &["application/pkix-crl"]),
+    ("crt", &["application/x-x509-ca-cert"]),
+    ("crtr", &["application/vnd.multiad.creator"]),
+    ("crw", &["image/x-canon-crw"]),
+    ("cryptomator", &["application/vnd.cryptomator.vault"]),
+    ("cryptonote", &["application/vnd.rig.cryptonote"]),
+    ("csd", &["audio/csound"]),
+    ("csf", &["chemical/x-cache-csf"]),
+    ("csh", &["application/x-csh", "text/x-csh"]),
+    ("csl", &["application/vnd.citationstyles.style+xml"]),
+    ("csm", &["chemical/x-csml"]),
+    ("csml", &["chemical/x-csml"]),
+    ("csp", &["application/vnd.commonspace"]),
+    ("csrattrs", &["application/csrattrs"]),
+    ("css", &["text/css"]),
+    ("cst", &["application/vnd.commonspace"]),
+    ("csv", &["text/csv"]),
+    ("csvs", &["text/csv-schema"]),
+    ("ctab", &["chemical/x-cactvs-binary"]),
+    ("ctx", &["chemical/x-ctx"]),
+    ("cu", &["application/cu-seeme"]),
+    ("cub", &["chemical/x-gaussian-cube"]),
+    ("cuc", &["application/tamp-community-update-confirm"]),
+    ("curl", &["text/vnd.curl"]),
+    ("cw", &["application/prs.cww"]),
+    ("cwl", &["application/cwl"]),
+    ("cwl.json", &["application/cwl+json"]),
+    ("cww", &["application/prs.cww"]),
+    ("cxf", &["chemical/x-cxf"]),
+    ("cxx", &["text/x-c++src"]),
+    ("d", &["text/x-dsrc"]),
+    ("dae", &["model/vnd.collada+xml"]),
+    ("daf", &["application/vnd.mobius.daf"]),
+    ("dart", &["application/vnd.dart"]),
+    ("dataless", &["application/vnd.fdsn.seed"]),
+    ("davmount", &["application/davmount+xml"]),
+    ("dbf", &["application/vnd.dbf"]),
+    ("dcd", &["application/dcd"]),
+    ("dcm", &["application/dicom"]),
+    ("dcr", &["application/x-director"]),
+    ("dd2", &["application/vnd.oma.dd2+xml"]),
+    ("ddd", &["application/vnd.fujixerox.ddd"]),
+    ("ddeb", &["application/vnd.debian.binary-package"]),
+    ("ddf", &["application/vnd.syncml.dmddf+xml"]),
+    ("deb", &["application/vnd.debian.binary-package", "application/octet-stream"]),
+    ("deploy", &["application/octet-stream"]),
+    ("der", &["application/x-x509-ca-cert"]),
+    ("dfac", &["application/vnd.dreamfactory"]),
+    ("dif", &["video/dv"]),
+    ("diff", &["text/x-diff"]),
+    ("dii", &["application/dii"]),
+    ("dim", &["application/vnd.fastcopy-disk-image"]),
+    ("dir", &["application/x-director"]),
+    ("dis", &["application/vnd.mobius.dis"]),
+    ("dist", &["application/vnd.apple.installer+xml"]),
+    ("distz", &["application/vnd.apple.installer+xml"]),
+    ("dit", &["application/dit"]),
+    ("dive", &["application/vnd.patentdive"]),
+    ("djv", &["image/vnd.djvu"]),
+    ("djvu", &["image/vnd.djvu"]),
+    ("dl", &["application/vnd.datalog"]),
+    ("dll", &["application/x-msdos-program", "application/octet-stream"]),
+    ("dls", &["audio/dls"]),
+    ("dmg", &["application/x-apple-diskimage", "application/octet-stream"]),
+    ("dmp", &["application/vnd.tcpdump.pcap"]),
+    ("dms", &["text/vnd.dmclientscript"]),
+    ("dna", &["application/vnd.dna"]),
+    ("doc", &["application/msword"]),
+    ("docjson", &["application/vnd.document+json"]),
+    ("docm", &["application/vnd.ms-word.document.macroenabled.12"]),
+    ("docx", &["application/vnd.openxmlformats-officedocument.wordprocessingml.document"]),
+    ("dor", &["model/vnd.gdl"]),
+    ("dot", &["text/vnd.graphviz"]),
+    ("dotm", &["application/vnd.ms-word.template.macroenabled.12"]),
+    ("dotx", &["application/vnd.openxmlformats-officedocument.wordprocessingml.template"]),
+    ("dp", &["application/vnd.osgi.dp"]),
+    ("dpg", &["application/vnd.dpgraph"]),
+    ("dpgraph", &["application/vnd.dpgraph"]),
+    ("dpkg", &["application/vnd.xmpie.dpkg"]),
+    ("dpx", &["image/dpx"]),
+    ("drle", &["image/dicom-rle"]),
+    ("dsc", &["text/prs.lines.tag"]),
+    ("dsm", &["application/vnd.desmume.movie"]),
+    ("dssc", &["application/dssc+der"]),
+    ("dtd", &["application/xml-dtd"]),
+    ("dts", &["audio/vnd.dts"]),
+    ("dtshd", &["audio/vnd.dts.hd"]),
+    ("dv", &["video/dv"]),
+    ("dvb", &["video/vnd.dvb.file"]),
+    ("dvc", &["application/dvcs"]),
+    ("dvi", &["application/x-dvi"]),
+    ("dwd", &["application/atsc-dwd+xml"]),
+    ("dwf", &["model/vnd.dwf"]),
+    ("dwg", &["image/vnd.dwg"]),
+    ("dx", &["chemical/x-jcamp-dx"]),
+    ("dxf", &["image/vnd.dxf"]),
+    ("dxp", &["application/vnd.spotfire.dxp"]),
+    ("dxr", &["application/x-director"]),
+    ("dzr", &["application/vnd.dzr"]),
+    ("ear", &["application/java-archive"]),
+    ("ebuild", &["application/vnd.gentoo.ebuild"]),
+    ("ecelp4800", &["audio/vnd.nuera.ecelp4800"]),
+    ("ecelp7470", &["audio/vnd.nuera.ecelp7470"]),
+    ("ecelp9600", &["audio/vnd.nuera.ecelp9600"]),
+    ("ecig", &["application/vnd.evolv.ecig.settings"]),
+    ("ecigprofile", &["application/vnd.evolv.ecig.profile"]),
+    ("ecigtheme", &["application/vnd.evolv.ecig.theme"]),
+    ("eclass", &["application/vnd.gentoo.eclass"]),
+    ("edm", &["application/vnd.novadigm.edm"]),
+    ("edx", &["application/vnd.novadigm.edx"]),
+    ("efi", &["application/efi"]),
+    ("efif", &["application/vnd.picsel"]),
+    ("ei6", &["application/vnd.pg.osasli"]),
+    ("eln", &["application/vnd.eln+zip"]),
+    ("emb", &["chemical/x-embl-dl-nucleotide"]),
+    ("embl", &["chemical/x-embl-dl-nucleotide"]),
+    ("emf", &["image/emf"]),
+    ("eml", &["message/rfc822"]),
+    ("emm", &["application/vnd.ibm.electronic-media"]),
+    ("emma", &["application/emma+xml"]),
+    ("emotionml", &["application/emotionml+xml"]),
+    ("ent", &["application/xml-external-parsed-entity"]),
+    ("entity", &["application/vnd.nervana"]),
+    ("enw", &["audio/evrcnw"]),
+    ("eol", &["audio/vnd.digital-winds"]),
+    ("eot", &["application/vnd.ms-fontobject"]),
+    ("ep", &["application/vnd.bluetooth.ep.oob"]),
+    ("eps", &["application/postscript"]),
+    ("eps2", &["application/postscript"]),
+    ("eps3", &["application/postscript"]),
+    ("epsf", &["application/postscript"]),
+    ("epsi", &["application/postscript"]),
+    ("epub", &["application/epub+zip"]),
+    ("erf", &["image/x-epson-erf"]),
+    ("es", &["text/javascript"]),
+    ("es3", &["application/vnd.eszigno3+xml"]),
+    ("esa", &["application/vnd.osgi.subsystem"]),
+    ("esf", &["application/vnd.epson.esf"]),
+    ("espass", &["application/vnd.espass-espass+zip"]),
+    ("et3", &["application/vnd.eszigno3+xml"]),
+    ("etx", &["text/x-setext"]),
+    ("evb", &["audio/evrcb"]),
+    ("evc", &["audio/evrc"]),
+    ("evw", &["audio/evrcwb"]),
+    ("exe", &["application/x-msdos-program", "application/octet-stream"]),
+    ("exi", &["application/exi"]),
+    ("exp", &["application/express"]),
+    ("exr", &["image/aces"]),
+    ("ext", &["application/vnd.novadigm.ext"]),
+    ("ez", &["application/andrew-inset"]),
+    ("ez2", &["application/vnd.ezpix-album"]),
+    ("ez3", &["application/vnd.ezpix-package"]),
+    ("fb", &["application/x-maker"]),
+    ("fbdoc", &["application/x-maker"]),
+    ("fbs", &["image/vnd.fastbidsheet"]),
+    ("fcdt", &["application/vnd.adobe.formscentral.fcdt"]),
+    ("fch", &["chemical/x-gaussian-checkpoint"]),
+    ("fchk", &["chemical/x-gaussian-checkpoint"]),
+    ("fcs", &["application/vnd.isac.fcs"]),
+    ("fdf", &["application/fdf"]),
+    ("fdt", &["application/fdt+xml"]),
+    ("fe_launch", &["application/vnd.denovo.fcselayout-link"]),
+    ("fg5", &["application/vnd.fujitsu.oasysgp"]),
+    ("fig", &["application/x-xfig"]),
+    ("finf", &["application/fastinfoset"]),
+    ("fit", &["image/fits"]),
+    ("fits", &["image/fits"]),
+    ("fla", &["application/vnd.dtg.local.flash"]),
+    ("flac", &["audio/flac"]),
+    ("flb", &["application/vnd.ficlab.flb+zip"]),
+    ("fli", &["video/fli"]),
+    ("flo", &["application/vnd.micrografx.flo"]),
+    ("flt", &["text/vnd.ficlab.flt"]),
+    ("flv", &["video/x-flv"]),
+    ("flw", &["application/vnd.kde.kivio"]),
+    ("flx", &["text/vnd.fmi.flexstor"]),
+    ("fly", &["text/vnd.fly"]),
+    ("fm", &["application/vnd.framemaker", "application/x-maker"]),
+    ("fo", &["application/vnd.software602.filler.form+xml"]),
+    ("fpx", &["image/vnd.fpx"]),
+    ("frame", &["application/x-maker"]),
+    ("frm", &["application/vnd.ufdl", "application/x-maker"]),
+    ("fsc", &["application/vnd.fsc.weblaunch"]),
+    ("fst", &["image/vnd.fst"]),
+    ("ftc", &["application/vnd.fluxtime.clip"]),
+    ("fti", &["application/vnd.anser-web-funds-transfer-initiation"]),
+    ("fts", &["image/fits"]),
+    ("fvt", &["video/vnd.fvt"]),
+    ("fxp", &["application/vnd.adobe.fxp"]),
+    ("fxpl", &["application/vnd.adobe.fxp"]),
+    ("fzs", &["application/vnd.fuzzysheet"]),
+    ("g2w", &["application/vnd.geoplan"]),
+    ("g3w", &["application/vnd.geospace"]),
+    ("gac", &["application/vnd.groove-account"]),
+    ("gal", &["chemical/x-gaussian-log"]),
+    ("gam", &["chemical/x-gamess-input"]),
+    ("gamin", &["chemical/x-gamess-input"]),
+    ("gan", &["application/x-ganttproject"]),
+    ("gau", &["chemical/x-gaussian-input"]),
+    ("gbr", &["application/rpki-ghostbusters"]),
+    ("gcd", &["text/x-pcs-gcd"]),
+    ("gcf", &["application/x-graphing-calculator"]),
+    ("gcg", &["chemical/x-gcg8-sequence"]),
+    ("gdl", &["model/vnd.gdl"]),
+    ("gdz", &["application/vnd.familysearch.gedcom+zip"]),
+    ("ged", &["text/vnd.familysearch.gedcom"]),
+    ("gen", &["chemical/x-genbank"]),
+    ("genozip", &["application/vnd.genozip"]),
+    ("geo", &["application/vnd.dynageo"]),
+    ("geojson", &["application/geo+json"]),
+    ("gex", &["application/vnd.geometry-explorer"]),
+    ("gf", &["application/x-tex-gf"]),
+    ("gff3", &["text/gff3"]),
+    ("ggb", &["application/vnd.geogebra.file"]),
+    ("ggs", &["application/vnd.geogebra.slides"]),
+    ("ggt", &["application/vnd.geogebra.tool"]),
+    ("ghf", &["application/vnd.groove-help"]),
+    ("gif", &["image/gif"]),
+    ("gim", &["application/vnd.groove-identity-message"]),
+    ("gjc", &["chemical/x-gaussian-input"]),
+    ("gjf", &["chemical/x-gaussian-input"]),
+    ("gl", &["video/gl"]),
+    ("glb", &["model/gltf-binary"]),
+    ("glbin", &["application/gltf-buffer"]),
+    ("glbuf", &["application/gltf-buffer"]),
+    ("gltf", &["model/gltf+json"]),
+    ("gml", &["application/gml+xml"]),
+    ("gnumeric", &["application/x-gnumeric"]),
+    ("gph", &["application/vnd.flographit"]),
+    ("gpkg", &["application/geopackage+sqlite3"]),
+    ("gpkg.tar", &["application/vnd.gentoo.gpkg"]),
+    ("gpt", &["chemical/x-mopac-graph"]),
+    ("gqf", &["application/vnd.grafeq"]),
+    ("gqs", &["application/vnd.grafeq"]),
+    ("gram", &["application/srgs"]),
+    ("grd", &["application/vnd.gentics.grd+json"]),
+    ("gre", &["application/vnd.geometry-explorer"]),
+    ("grv", &["application/vnd.groove-injector"]),
+    ("grxml", &["application/srgs+xml"]),
+    ("gsf", &["application/x-font"]),
+    ("gsheet", &["application/urc-grpsheet+xml"]),
+    ("gsm", &["audio/x-gsm", "model/vnd.gdl"]),
+    ("gtar", &["application/x-gtar"]),
+    ("gtm", &["application/vnd.groove-tool-message"]),
+    ("gtw", &["model/vnd.gtw"]),
+    ("gv", &["text/vnd.graphviz"]),
+    ("gxt", &["application/vnd.geonext"]),
+    ("gz", &["application/gzip"]),
+    ("h", &["text/x-chdr"]),
+    ("h++", &["text/x-c++hdr"]),
+    ("hal", &["application/vnd.hal+xml"]),
+    ("hans", &["text/vnd.hans"]),
+    ("hbc", &["application/vnd.hbci"]),
+    ("hbci", &["application/vnd.hbci"]),
+    ("hdf", &["application/x-hdf"]),
+    ("hdr", &["image/vnd.radiance"]),
+    ("hdt", &["application/vnd.hdt"]),
+    ("heic", &["image/heic"]),
+    ("heics", &["image/heic-sequence"]),
+    ("heif", &["image/heif"]),
+    ("heifs", &["image/heif-sequence"]),
+    ("hej2", &["image/hej2k"]),
+    ("held", &["application/atsc-held+xml"]),
+    ("hgl", &["text/vnd.hgl"]),
+    ("hh", &["text/x-c++hdr"]),
+    ("hif", &["image/avif"]),
+    ("hin", &["chemical/x-hin"]),
+    ("hpgl", &["application/vnd.hp-hpgl"]),
+    ("hpi", &["application/vnd.hp-hpid"]),
+    ("hpid", &["application/vnd.hp-hpid"]),
+    ("hpp", &["text/x-c++hdr"]),
+    ("hps", &["application/vnd.hp-hps"]),
+    ("hpub", &["application/prs.hpub+zip"]),
+    ("hqx", &["application/mac-binhex40"]),
+    ("hs", &["text/x-haskell"]),
+    ("hsj2", &["image/hsj2"]),
+    ("hta", &["application/hta"]),
+    ("htc", &["text/x-component"]),
+    ("htke", &["application/vnd.kenameaapp"]),
+    ("htm", &["text/html"]),
+    ("html", &["text/html"]),
+    ("hvd", &["application/vnd.yamaha.hv-dic"]),
+    ("hvp", &["application/vnd.yamaha.hv-voice"]),
+    ("hvs", &["application/vnd.yamaha.hv-script"]),
+    ("hwp", &["application/x-hwp"]),
+    ("hxx", &["text/x-c++hdr"]),
+    ("i2g", &["application/vnd.intergeo"]),
+    ("ic0", &["application/vnd.commerce-battelle"]),
+    ("ic1", &["application/vnd.commerce-battelle"]),
+    ("ic2", &["application/vnd.commerce-battelle"]),
+    ("ic3", &["application/vnd.commerce-battelle"]),
+    ("ic4", &["application/vnd.commerce-battelle"]),
+    ("ic5", &["application/vnd.commerce-battelle"]),
+    ("ic6", &["application/vnd.commerce-battelle"]),
+    ("ic7", &["application/vnd.commerce-battelle"]),
+    ("ic8", &["application/vnd.commerce-battelle"]),
+    ("ica", &["application/x-ica"]),
+    ("icc", &["application/vnd.iccprofile"]),
+    ("icd", &["application/vnd.commerce-battelle"]),
+    ("icf", &["application/vnd.commerce-battelle"]),
+    ("icm", &["application/vnd.iccprofile"]),
+    ("ico", &["image/vnd.microsoft.icon", "image/x-icon"]),
+    ("ics", &["text/calendar"]),
+    ("ief", &["image/ief"]),
+    ("ifb", &["text/calendar"]),
+    ("ifc", &["application/p21"]),
+    ("ifm", &["application/vnd.shana.informed.formdata"]),
+    ("iges", &["model/iges"]),
+    ("igl", &["application/vnd.igloader"]),
+    ("igm", &["application/vnd.insors.igm"]),
+    ("ign", &["application/vnd.coreos.ignition+json"]),
+    ("ignition", &["application/vnd.coreos.ignition+json"]),
+    ("igs", &["model/iges"]),
+    ("igx", &["application/vnd.micrografx.igx"]),
+    ("iif", &["application/vnd.shana.informed.interchange"]),
+    ("iii", &["application/x-iphone"]),
+    ("imf", &["application/vnd.imagemeter.folder+zip"]),
+    ("img", &["application/octet-stream"]),
+    ("imgcal", &["application/vnd.3lightssoftware.imagescal"]),
+    ("imi", &["application/vnd.imagemeter.image+zip"]),
+    ("imp", &["application/vnd.accpac.simply.imp"]),
+    ("ims", &["application/vnd.ms-ims"]),
+    ("imscc", &["application/vnd.ims.imsccv1p1"]),
+    ("info", &["application/x-info"]),
+    ("ink", &["application/inkml+xml"]),
+    ("inkml", &["application/inkml+xml"]),
+    ("inp", &["chemical/x-gamess-input"]),
+    ("ins", &["application/x-internet-signup"]),
+    ("iota", &["application/vnd.astraea-software.iota"]),
+    ("ipfix", &["application/ipfix"]),
+    ("ipk", &["application/vnd.shana.informed.package"]),
+    ("irm", &["application/vnd.ibm.rights-management"]),
+    ("irp", &["application/vnd.irepository.package+xml"]),
+    ("ism", &["model/vnd.gdl"]),
+    ("iso", &["application/x-iso9660-image", "application/octet-stream"]),
+    ("isp", &["application/x-internet-signup"]),
+    ("ist", &["chemical/x-isostar"]),
+    ("istc", &["application/vnd.veryant.thin"]),
+    ("istr", &["chemical/x-isostar"]),
+    ("isws", &["application/vnd.veryant.thin"]),
+    ("itp", &["application/vnd.shana.informed.formtemplate"]),
+    ("its", &["application/its+xml"]),
+    ("ivp", &["application/vnd.immervision-ivp"]),
+    ("ivu", &["application/vnd.immervision-ivu"]),
+    ("jad", &["text/vnd.sun.j2me.app-descriptor"]),
+    ("jam", &["application/vnd.jam"]),
+    ("jar", &["application/java-archive"]),
+    ("jardiff", &["application/x-java-archive-diff"]),
+    ("java", &["text/x-java"]),
+    ("jdx", &["chemical/x-jcamp-dx"]),
+    ("jfif", &["image/jpeg"]),
+    ("jhc", &["image/jphc"]),
+    ("jisp", &["application/vnd.jisp"]),
+    ("jls", &["image/jls"]),
+    ("jlt", &["application/vnd.hp-jlyt"]),
+    ("jmz", &["application/x-jmol"]),
+    ("jng", &["image/x-jng"]),
+    ("jnlp", &["application/x-java-jnlp-file"]),
+    ("joda", &["application/vnd.joost.joda-archive"]),
+    ("jp2", &["image/jp2"]),
+    ("jpe", &["image/jpeg"]),
+    ("jpeg", &["image/jpeg"]),
+    ("jpf", &["image/jpx"]),
+    ("jpg", &["image/jpeg"]),
+    ("jpg2", &["image/jp2"]),
+    ("jpgm", &["image/jpm"]),
+    ("jph", &["image/jph"]),
+    ("jphc", &["image/jphc"]),
+    ("jpm", &["image/jpm"]),
+    ("jpx", &["image/jpx"]),
+    ("jrd", &["application/jrd+json"]),
+    ("js", &["text/javascript", "application/javascript"]),
+    ("json", &["application/json"]),
+    ("json-patch", &["application/json-patch+json"]),
+    ("jsonld", &["application/ld+json"]),
+    ("jsontd", &["application/td+json"]),
+    ("jsontm", &["application/tm+json"]),
+    ("jt", &["model/jt"]),
+    ("jtd", &["text/vnd.esmertec.theme-descriptor"]),
+    ("jxl", &["image/jxl"]),
+    ("jxr", &["image/jxr"]),
+    ("jxra", &["image/jxra"]),
+    ("jxrs", &["image/jxrs"]),
+    ("jxs", &["image/jxs"]),
+    ("jxsc", &["image/jxsc"]),
+    ("jxsi", &["image/jxsi"]),
+    ("jxss", &["image/jxss"]),
+    ("kar", &["audio/midi"]),
+    ("karbon", &["application/vnd.kde.karbon"]),
+    ("kcm", &["application/vnd.nervana"]),
+    ("key", &["application/pgp-keys"]),
+    ("keynote", &["application/vnd.apple.keynote"]),
+    ("kfo", &["application/vnd.kde.kformula"]),
+    ("kia", &["application/vnd.kidspiration"]),
+    ("kil", &["application/x-killustrator"]),
+    ("kin", &["chemical/x-kinemage"]),
+    ("kml", &["application/vnd.google-earth.kml+xml"]),
+    ("kmz", &["application/vnd.google-earth.kmz"]),
+    ("kne", &["application/vnd.kinar"]),
+    ("knp", &["application/vnd.kinar"]),
+    ("kom", &["application/vnd.hbci"]),
+    ("kon", &["application/vnd.kde.kontour"]),
+    ("koz", &["audio/vnd.audiokoz"]),
+    ("kpr", &["application/vnd.kde.kpresenter"]),
+    ("kpt", &["application/vnd.kde.kpresenter"]),
+    ("ksp", &["application/vnd.kde.kspread"]),
+    ("ktr", &["application/vnd.kahootz"]),
+    ("ktx", &["image/ktx"]),
+    ("ktx2", &["image/ktx2"]),
+    ("ktz", &["application/vnd.kahootz"]),
+    ("kwd", &["application/vnd.kde.kword"]),
+    ("kwt", &["application/vnd.kde.kword"]),
+    ("l16", &["audio/l16"]),
+    ("las", &["application/vnd.las"]),
+    ("lasjson", &["application/vnd.las.las+json"]),
+    ("lasxml", &["application/vnd.las.las+xml"]),
+    ("latex", &["application/x-latex"]),
+    ("lbc", &["audio/ilbc"]),
+    ("lbd", &["application/vnd.llamagraphics.life-balance.desktop"]),
+    ("lbe", &["application/vnd.llamagraphics.life-balance.exchange+xml"]),
+    ("lca", &["application/vnd.logipipe.circuit+zip"]),
+    ("lcs", &["application/vnd.logipipe.circuit+zip"]),
+    ("le", &["application/vnd.bluetooth.le.oob"]),
+    ("les", &["application/vnd.hhe.lesson-player"]),
+    ("lgr", &["application/lgr+xml"]),
+    ("lha", &["application/x-lha"]),
+    ("lhs", &["text/x-literate-haskell"]),
+    ("lhzd", &["application/vnd.belightsoft.lhzd+zip"]),
+    ("lhzl", &["application/vnd.belightsoft.lhzl+zip"]),
+    ("lin", &["application/bbolin"]),
+    ("line", &["application/vnd.nebumind.line"]),
+    ("link66", &["application/vnd.route66.link66+xml"]),
+    ("list3820", &["application/vnd.afpc.modca"]),
+    ("listafp", &["application/vnd.afpc.modca"]),
+    ("lmp", &["model/vnd.gdl"]),
+    ("loas", &["audio/usac"]),
+    ("loom", &["application/vnd.loom"]),
+    ("lostsyncxml", &["application/lostsync+xml"]),
+    ("lostxml", &["application/lost+xml"]),
+    ("lpf", &["application/lpf+zip"]),
+    ("lrm", &["application/vnd.ms-lrm"]),
+    ("lsf", &["video/x-la-asf"]),
+    ("lsx", &["video/x-la-asf"]),
+    ("ltx", &["text/x-tex"]),
+    ("lvp", &["audio/vnd.lucent.voice"]),
+    ("lwp", &["application/vnd.lotus-wordpro"]),
+    ("lxf", &["application/lxf"]),
+    ("ly", &["text/x-lilypond"]),
+    ("lyx", &["application/x-lyx"]),
+    ("lzh", &["application/x-lzh"]),
+    ("lzx", &["application/x-lzx"]),
+    ("m", &["application/vnd.wolfram.mathematica.package"]),
+    ("m1v", &["video/mpeg"]),
+    ("m21", &["application/mp21"]),
+    ("m2v", &["video/mpeg"]),
+    ("m3g", &["application/m3g"]),
+    ("m3u", &["audio/mpegurl"]),
+    ("m3u8", &["application/vnd.apple.mpegurl"]),
+    ("m4a", &["audio/mp4", "audio/x-m4a"]),
+    ("m4s", &["video/iso.segment"]),
+    ("m4u", &["video/vnd.mpegurl"]),
+    ("m4v", &["video/mp4", "video/x-m4v"]),
+    ("ma", &["application/mathematica"]),
+    ("mads", &["application/mads+xml"]),
+    ("maei", &["application/mmt-aei+xml"]),
+    ("mag", &["application/vnd.ecowin.chart"]),
+    ("mail", &["message/rfc822"]),
+    ("maker", &["application/x-maker"]),
+    ("man", &["application/x-troff-man"]),
+    ("manifest", &["text/cache-manifest"]),
+    ("markdown", &["text/markdown"]),
+    ("mb", &["application/mathematica"]),
+    ("mbk", &["application/vnd.mobius.mbk"]),
+    ("mbox", &["application/mbox"]),
+    ("mc1", &["application/vnd.medcalcdata"]),
+    ("mc2", &["text/vnd.senx.warpscript"]),
+    ("mcd", &["application/vnd.mcd"]),
+    ("mcif", &["chemical/x-mmcif"]),
+    ("mcm", &["chemical/x-macmolecule"]),
+    ("md", &["text/markdown"]),
+    ("mdb", &["application/msaccess"]),
+    ("mdc", &["application/vnd.marlin.drm.mdcf"]),
+    ("mdi", &["image/vnd.ms-modi"]),
+    ("me", &["application/x-troff-me"]),
+    ("mesh", &["model/mesh"]),
+    ("meta4", &["application/metalink4+xml"]),
+    ("mets", &["application/mets+xml"]),
+    ("mf4", &["application/mf4"]),
+    ("mfm", &["application/vnd.mfmp"]),
+    ("mft", &["application/rpki-manifest"]),
+    ("mgp", &["application/vnd.osgeo.mapguide.package"]),
+    ("mgz", &["application/vnd.proteus.magazine"]),
+    ("mhas", &["audio/mhas"]),
+    ("mid", &["audio/midi", "audio/sp-midi"]),
+    ("midi", &["audio/midi"]),
+    ("mif", &["application/vnd.mif"]),
+    ("miz", &["text/mizar"]),
+    ("mj2", &["video/mj2"]),
+    ("mjp2", &["video/mj2"]),
+    ("mjs", &["text/javascript"]),
+    ("mkv", &["video/x-matroska"]),
+    ("ml2", &["application/vnd.sybyl.mol2"]),
+    ("mlp", &["audio/vnd.dolby.mlp"]),
+    ("mm", &["application/x-freemind"]),
+    ("mmd", &["application/vnd.chipnuts.karaoke-mmd"]),
+    ("mmdb", &["application/vnd.maxmind.maxmind-db"]),
+    ("mmf", &["application/vnd.smaf"]),
+    ("mml", &["application/mathml+xml", "text/mathml"]),
+    ("mmod", &["chemical/x-macromodel-input"]),
+    ("mmr", &["image/vnd.fujixerox.edmics-mmr"]),
+    ("mng", &["video/x-mng"]),
+    ("moc", &["text/x-moc"]),
+    ("mod", &["application/xml-dtd"]),
+    ("model-inter", &["application/vnd.vd-study"]),
+    ("mods", &["application/mods+xml"]),
+    ("mol", &["chemical/x-mdl-molfile"]),
+    ("mol2", &["application/vnd.sybyl.mol2"]),
+    ("moml", &["model/vnd.moml+xml"]),
+    ("moo", &["chemical/x-mopac-out"]),
+    ("mop", &["chemical/x-mopac-input"]),
+    ("mopcrt", &["chemical/x-mopac-input"]),
+    ("mov", &["video/quicktime"]),
+    ("movie", &["video/x-sgi-movie"]),
+    ("mp1", &["audio/mpeg"]),
+    ("mp2", &["audio/mpeg"]),
+    ("mp21", &["application/mp21"]),
+    ("mp3", &["audio/mpeg"]),
+    ("mp4", &["video/mp4"]),
+    ("mpc", &["application/vnd.mophun.certificate", "chemical/x-mopac-input"]),
+    ("mpd", &["application/dash+xml"]),
+    ("mpdd", &["application/dashdelta"]),
+    ("mpe", &["video/mpeg"]),
+    ("mpeg", &["video/mpeg"]),
+    ("mpega", &["audio/mpeg"]),
+    ("mpf", &["text/vnd.ms-mediapackage"]),
+    ("mpg", &["video/mpeg"]),
+    ("mpg4", &["video/mp4"]),
+    ("mpga", &["audio/mpeg"]),
+    ("mph", &["application/x-comsol"]),
+    ("mpkg", &["application/vnd.apple.installer+xml"]),
+    ("mpm", &["application/vnd.blueice.multipass"]),
+    ("mpn", &["application/vnd.mophun.application"]),
+    ("mpp", &["application/vnd.ms-project"]),
+    ("mpt", &["application/vnd.ms-project"]),
+    ("mpv", &["video/x-matroska"]),
+    ("mpw", &["application/vnd.exstream-empower+zip"]),
+    ("mpy", &["application/vnd.ibm.minipay"]),
+    ("mqy", &["application/vnd.mobius.mqy"]),
+    ("mrc", &["application/marc"]),
+    ("mrcx", &["application/marcxml+xml"]),
+    ("ms", &["application/x-troff-ms"]),
+    ("msa", &["application/vnd.msa-disk-image"]),
+    ("msd", &["application/vnd.fdsn.mseed"]),
+    ("mseed", &["application/vnd.fdsn.mseed"]),
+    ("mseq", &["application/vnd.mseq"]),
+    ("msf", &["application/vnd.epson.msf"]),
+    ("msh", &["model/mesh"]),
+    ("msi", &["application/x-msi", "application/octet-stream"]),
+    ("msl", &["application/vnd.mobius.msl"]),
+    ("msm", &["model/vnd.gdl", "application/octet-stream"]),
+    ("msp", &["application/octet-stream"]),
+    ("msty", &["application/vnd.muvee.style"]),
+    ("msu", &["application/octet-stream"]),
+    ("mtl", &["model/mtl"]),
+    ("mts", &["model/vnd.mts"]),
+    ("multitrack", &["audio/vnd.presonus.multitrack"]),
+    ("mus", &["application/vnd.musician"]),
+    ("musd", &["application/mmt-usd+xml"]),
+    ("mvb", &["chemical/x-mopac-vib"]),
+    ("mvt", &["application/vnd.mapbox-vector-tile"]),
+    ("mwc", &["application/vnd.dpgraph"]),
+    ("mwf", &["application/vnd.mfer"]),
+    ("mxf", &["application/mxf"]),
+    ("mxi", &["application/vnd.vd-study"]),
+    ("mxl", &["application/vnd.recordare.musicxml"]),
+    ("mxmf", &["audio/mobile-xmf"]),
+    ("mxml", &["application/xv+xml"]),
+    ("mxs", &["application/vnd.triscape.mxs"]),
+    ("mxu", &["video/vnd.mpegurl"]),
+    ("n3", &["text/n3"]),
+    ("nb", &["application/vnd.wolfram.mathematica"]),
+    ("nbp", &["application/vnd.wolfram.player"]),
+    ("nc", &["application/x-netcdf"]),
+    ("ndc", &["application/vnd.osa.netdeploy"]),
+    ("ndl", &["application/vnd.lotus-notes"]),
+    ("nds", &["application/vnd.nintendo.nitro.rom"]),
+    ("nebul", &["application/vnd.nebumind.line"]),
+    ("nef", &["image/x-nikon-nef"]),
+    ("ngdat", &["application/vnd.nokia.n-gage.data"]),
+    ("nim", &["video/vnd.nokia.interleaved-multimedia"]),
+    ("nimn", &["application/vnd.nimn"]),
+    ("nitf", &["application/vnd.nitf"]),
+    ("nlu", &["application/vnd.neurolanguage.nlu"]),
+    ("nml", &["application/vnd.enliven"]),
+    ("nnd", &["application/vnd.noblenet-directory"]),
+    ("nns", &["application/vnd.noblenet-sealer"]),
+    ("nnw", &["application/vnd.noblenet-web"]),
+    ("notebook", &["application/vnd.smart.notebook"]),
+    ("nq", &["application/n-quads"]),
+    ("ns2", &["application/vnd.lotus-notes"]),
+    ("ns3", &["application/vnd.lotus-notes"]),
+    ("ns4", &["application/vnd.lotus-notes"]),
+    ("nsf", &["application/vnd.lotus-notes"]),
+    ("nsg", &["application/vnd.lotus-notes"]),
+    ("nsh", &["application/vnd.lotus-notes"]),
+    ("nt", &["application/n-triples"]),
+    ("ntf", &["application/vnd.lotus-notes"]),
+    ("numbers", &["application/vnd.apple.numbers"]),
+    ("nwc", &["application/x-nwc"]),
+    ("o", &["application/x-object"]),
+    ("oa2", &["application/vnd.fujitsu.oasys2"]),
+    ("oa3", &["application/vnd.fujitsu.oasys3"]),
+    ("oas", &["application/vnd.fujitsu.oasys"]),
+    ("obg", &["application/vnd.openblox.game-binary"]),
+    ("obgx", &["application/vnd.openblox.game+xml"]),
+    ("obj", &["model/obj"]),
+    ("oda", &["application/oda"]),
+    ("odb", &["application/vnd.oasis.opendocument.base"]),
+    ("odc", &["application/vnd.oasis.opendocument.chart"]),
+    ("odd", &["application/tei+xml"]),
+    ("odf", &["application/vnd.oasis.opendocument.formula"]),
+    ("odg", &["application/vnd.oasis.opendocument.graphics"]),
+    ("odi", &["application/vnd.oasis.opendocument.image"]),
+    ("odm", &["application/vnd.oasis.opendocument.text-master"]),
+    ("odp", &["application/vnd.oasis.opendocument.presentation"]),
+    ("ods", &["application/vnd.oasis.opendocument.spreadsheet"]),
+    ("odt", &["application/vnd.oasis.opendocument.text"]),
+    ("odx", &["application/odx"]),
+    ("oeb", &["application/vnd.openeye.oeb"]),
+    ("oga", &["audio/ogg"]),
+    ("ogex", &["model/vnd.opengex"]),
+    ("ogg", &["audio/ogg"]),
+    ("ogv", &["video/ogg"]),
+    ("ogx", &["application/ogg"]),
+    ("old", &["application/x-trash"]),
+    ("omg", &["audio/atrac3"]),
+    ("one", &["application/onenote"]),
+    ("onepkg", &["application/onenote"]),
+    ("onetmp", &["application/onenote"]),
+    ("onetoc2", &["application/onenote"]),
+    ("opf", &["application/oebps-package+xml"]),
+    ("oprc", &["application/vnd.palm"]),
+    ("opus", &["audio/ogg"]),
+    ("or2", &["application/vnd.lotus-organizer"]),
+    ("or3", &["application/vnd.lotus-organizer"]),
+    ("orc", &["audio/csound"]),
+    ("orf", &["image/x-olympus-orf"]),
+    ("org", &["application/vnd.lotus-organizer"]),
+    ("orq", &["application/ocsp-request"]),
+    ("ors", &["application/ocsp-response"]),
+    ("osf", &["application/vnd.yamaha.openscoreformat"]),
+    ("osm", &["application/vnd.openstreetmap.data+xml"]),
+    ("ota", &["application/vnd.android.ota"]),
+    ("otc", &["application/vnd.oasis.opendocument.chart-template"]),
+    ("otf", &["font/otf"]),
+    ("otg", &["application/vnd.oasis.opendocument.graphics-template"]),
+    ("oth", &["application/vnd.oasis.opendocument.text-web"]),
+    ("oti", &["application/vnd.oasis.opendocument.image-template"]),
+    ("otp", &["application/vnd.oasis.opendocument.presentation-template"]),
+    ("ots", &["application/vnd.oasis.opendocument.spreadsheet-template"]),
+    ("ott", &["application/vnd.oasis.opendocument.text-template"]),
+    ("ovl", &["application/vnd.afpc.modca-overlay"]),
+    ("oxlicg", &["application/vnd.oxli.countgraph"]),
+    ("oxps", &["application/oxps"]),
+    ("oxt", &["application/vnd.openofficeorg.extension"]),
+    ("oza", &["application/x-oz-application"]),
+    ("p", &["text/x-pascal"]),
+    ("p10", &["application/pkcs10"]),
+    ("p12", &["application/pkcs12"]),
+    ("p21", &["application/p21"]),
+    ("p2p", &["application/vnd.wfa.p2p"]),
+    ("p7c", &["application/pkcs7-mime"]),
+    ("p7m", &["application/pkcs7-mime"]),
+    ("p7r", &["application/x-pkcs7-certreqresp"]),
+    ("p7s", &["application/pkcs7-signature"]),
+    ("p7z", &["application/pkcs7-mime"]),
+    ("p8", &["application/pkcs8"]),
+    ("p8e", &["application/pkcs8-encrypted"]),
+    ("pac", &["application/x-ns-proxy-autoconfig"]),
+    ("package", &["application/vnd.autopackage"]),
+    ("pages", &["application/vnd.apple.pages"]),
+    ("pas", &["text/x-pascal"]),
+    ("pat", &["image/x-coreldrawpattern"]),
+    ("patch", &["text/x-diff"]),
+    ("paw", &["application/vnd.pawaafile"]),
+    ("pbd", &["application/vnd.powerbuilder6"]),
+    ("pbm", &["image/x-portable-bitmap"]),
+    ("pcap", &["application/vnd.tcpdump.pcap"]),
+    ("pcf", &["application/x-font-pcf"]),
+    ("pcf.z", &["application/x-font-pcf"]),
+    ("pcl", &["application/vnd.hp-pcl"]),
+    ("pcx", &["image/vnd.zbrush.pcx"]),
+    ("pdb", &["application/vnd.palm", "chemical/x-pdb", "application/x-pilot"]),
+    ("pdf", &["application/pdf"]),
+    ("pdx", &["application/pdx"]),
+    ("pem", &["application/pem-certificate-chain", "application/x-x509-ca-cert"]),
+    ("pfa", &["application/x-font"]),
+    ("pfb", &["application/x-font"]),
+    ("pfr", &["application/font-tdpfr"]),
+    ("pfx", &["application/pkcs12"]),
+    ("pgb", &["image/vnd.globalgraphics.pgb"]),
+    ("pgm", &["image/x-portable-graymap"]),
+    ("pgn", &["application/vnd.chess-pgn"]),
+    ("pgp", &["application/pgp-encrypted"]),
+    ("pil", &["application/vnd.piaccess.application-licence"]),
+    ("pk", &["application/x-tex-pk"]),
+    ("pkd", &["application/vnd.hbci"]),
+    ("pkg", &["application/vnd.apple.installer+xml"]),
+    ("pki", &["application/pkixcmp"]),
+    ("pkipath", &["application/pkix-pkipath"]),
+    ("pl", &["text/x-perl", "application/x-perl"]),
+    ("plb", &["application/vnd.3gpp.pic-bw-large"]),
+    ("plc", &["application/vnd.mobius.plc"]),
+    ("plf", &["application/vnd.pocketlearn"]),
+    ("plj", &["audio/vnd.everad.plj"]),
+    ("plp", &["application/vnd.panoply"]),
+    ("pls", &["audio/x-scpls"]),
+    ("pm", &["text/x-perl", "application/x-perl"]),
+    ("pml", &["application/vnd.ctc-posml"]),
+    ("png", &["image/png"]),
+    ("pnm", &["image/x-portable-anymap"]),
+    ("portpkg", &["application/vnd.macports.portpkg"]),
+    ("pot", &["text/plain"]),
+    ("potm", &["application/vnd.ms-powerpoint.template.macroenabled.12"]),
+    ("potx", &["application/vnd.openxmlformats-officedocument.presentationml.template"]),
+    ("ppam", &["application/vnd.ms-powerpoint.addin.macroenabled.12"]),
+    ("ppd", &["application/vnd.cups-ppd"]),
+    ("ppkg", &["application/vnd.xmpie.ppkg"]),
+    ("ppm", &["image/x-portable-pixmap"]),
+    ("pps", &["application/vnd.ms-powerpoint"]),
+    ("ppsm", &["application/vnd.ms-powerpoint.slideshow.macroenabled.12"]),
+    ("ppsx", &["application/vnd.openxmlformats-officedocument.presentationml.slideshow"]),
+    ("ppt", &["application/vnd.ms-powerpoint"]),
+    ("pptm", &["application/vnd.ms-powerpoint.presentation.macroenabled.12"]),
+    ("ppttc", &["application/vnd.think-cell.ppttc+json"]),
+    ("pptx", &["application/vnd.openxmlformats-officedocument.presentationml.presentation"]),
+    ("pqa", &["application/vnd.palm"]),
+    ("prc", &["model/prc", "application/x-pilot"]),
+    ("pre", &["application/vnd.lotus-freelance"]),
+    ("preminet", &["application/vnd.preminet"]),
+    ("prf", &["application/pics-rules"]),
+    ("provn", &["text/provenance-notation"]),
+    ("provx", &["application/provenance+xml"]),
+    ("prt", &["chemical/x-ncbi-asn1-ascii"]),
+    ("prz", &["application/vnd.lotus-freelance"]),
+    ("ps", &["application/postscript"]),
+    ("psb", &["application/vnd.3gpp.pic-bw-small"]),
+    ("psd", &["image/vnd.adobe.photoshop"]),
+    ("pseg3820", &["application/vnd.afpc.modca"]),
+    ("psfs", &["application/vnd.psfs"]),
+    ("psg", &["application/vnd.afpc.modca-pagesegment"]),
+    ("psid", &["audio/prs.sid"]),
+    ("pskcxml", &["application/pskc+xml"]),
+    ("pt", &["application/vnd.snesdev-page-table"]),
+    ("pti", &["image/prs.pti"]),
+    ("ptid", &["application/vnd.pvi.ptid1"]),
+    ("ptrom", &["application/vnd.snesdev-page-table"]),
+    ("pub", &["application/vnd.exstream-package"]),
+    ("pvb", &["application/vnd.3gpp.pic-bw-var"]),
+    ("pwn", &["application/vnd.3m.post-it-notes"]),
+    ("py", &["text/x-python"]),
+    ("pya", &["audio/vnd.ms-playready.media.pya"]),
+    ("pyc", &["application/x-python-code"]),
+    ("pyo", &["application/x-python-code"]),
+    ("pyox", &["model/vnd.pytha.pyox"]),
+    ("pyv", &["video/vnd.ms-playready.media.pyv"]),
+    ("qam", &["application/vnd.epson.quickanime"]),
+    ("qbo", &["application/vnd.intu.qbo"]),
+    ("qca", &["application/vnd.ericsson.quickcall"]),
+    ("qcall", &["application/vnd.ericsson.quickcall"]),
+    ("qcp", &["audio/evrc-qcp"]),
+    ("qfx", &["application/vnd.intu.qfx"]),
+    ("qgs", &["application/x-qgis"]),
+    ("qps", &["application/vnd.publishare-delta-tree"]),
+    ("qt", &["video/quicktime"]),
+    ("qtl", &["application/x-quicktimeplayer"]),
+    ("quiz", &["application/vnd.quobject-quoxdocument"]),
+    ("quox", &["application/vnd.quobject-quoxdocument"]),
+    ("qvd", &["application/vnd.theqvd"]),
+    ("qwd", &["application/vnd.quark.quarkxpress"]),
+    ("qwt", &["application/vnd.quark.quarkxpress"]),
+    ("qxb", &["application/vnd.quark.quarkxpress"]),
+    ("qxd", &["application/vnd.quark.quarkxpress"]),
+    ("qxl", &["application/vnd.quark.quarkxpress"]),
+    ("qxt", &["application/vnd.quark.quarkxpress"]),
+    ("ra", &["audio/x-pn-realaudio", "audio/x-realaudio"]),
+    ("ram", &["audio/x-pn-realaudio"]),
+    ("rapd", &["application/route-apd+xml"]),
+    ("rar", &["application/vnd.rar", "application/x-rar-compressed"]),
+    ("ras", &["image/x-cmu-raster"]),
+    ("rb", &["application/x-ruby"]),
+    ("rcprofile", &["application/vnd.ipunplugged.rcprofile"]),
+    ("rct", &["application/prs.nprend"]),
+    ("rd", &["chemical/x-mdl-rdfile"]),
+    ("rdf", &["application/rdf+xml"]),
+    ("rdf-crypt", &["application/prs.rdf-xml-crypt"]),
+    ("rdp", &["application/x-rdp"]),
+    ("rdz", &["application/vnd.data-vision.rdz"]),
+    ("relo", &["application/p2p-overlay+xml"]),
+    ("reload", &["application/vnd.resilient.logic"]),
+    ("rep", &["application/vnd.businessobjects"]),
+    ("request", &["application/vnd.nervana"]),
+    ("rfcxml", &["application/rfc+xml"]),
+    ("rgb", &["image/x-rgb"]),
+    ("rgbe", &["image/vnd.radiance"]),
+    ("rif", &["application/reginfo+xml"]),
+    ("rip", &["audio/vnd.rip"]),
+    ("rl", &["application/resource-lists+xml"]),
+    ("rlc", &["image/vnd.fujixerox.edmics-rlc"]),
+    ("rld", &["application/resource-lists-diff+xml"]),
+    ("rlm", &["application/vnd.resilient.logic"]),
+    ("rm", &["audio/x-pn-realaudio"]),
+    ("rms", &["application/vnd.jcp.javame.midlet-rms"]),
+    ("rnc", &["application/relax-ng-compact-syntax"]),
+    ("rnd", &["application/prs.nprend"]),
+    ("roa", &["application/rpki-roa"]),
+    ("roff", &["text/troff"]),
+    ("ros", &["chemical/x-rosdal"]),
+    ("rp9", &["application/vnd.cloanto.rp9"]),
+    ("rpm", &["application/x-redhat-package-manager"]),
+    ("rpss", &["application/vnd.nokia.radio-presets"]),
+    ("rpst", &["application/vnd.nokia.radio-preset"]),
+    ("rq", &["application/sparql-query"]),
+    ("rs", &["application/rls-services+xml"]),
+    ("rsat", &["application/atsc-rsat+xml"]),
+    ("rsheet", &["application/urc-ressheet+xml"]),
+    ("rsm", &["model/vnd.gdl"]),
+    ("rss", &["application/x-rss+xml", "application/rss+xml"]),
+    ("rst", &["text/prs.fallenstein.rst"]),
+    ("rtf", &["application/rtf"]),
+    ("run", &["application/x-makeself"]),
+    ("rusd", &["application/route-usd+xml"]),
+    ("rxn", &["chemical/x-mdl-rxnfile"]),
+    ("rxt", &["application/vnd.medicalholodeck.recordxr"]),
+    ("s11", &["video/vnd.sealed.mpeg1"]),
+    ("s14", &["video/vnd.sealed.mpeg4"]),
+    ("s1a", &["application/vnd.sealedmedia.softseal.pdf"]),
+    ("s1e", &["application/vnd.sealed.xls"]),
+    ("s1g", &["image/vnd.sealedmedia.softseal.gif"]),
+    ("s1h", &["application/vnd.sealedmedia.softseal.html"]),
+    ("s1j", &["image/vnd.sealedmedia.softseal.jpg"]),
+    ("s1m", &["audio/vnd.sealedmedia.softseal.mpeg"]),
+    ("s1n", &["image/vnd.sealed.png"]),
+    ("s1p", &["application/vnd.sealed.ppt"]),
+    ("s1q", &["video/vnd.sealedmedia.softseal.mov"]),
+    ("s1w", &["application/vnd.sealed.doc"]),
+    ("s3df", &["application/vnd.sealed.3df"]),
+    ("sac", &["application/tamp-sequence-adjust-confirm"]),
+    ("saf", &["application/vnd.yamaha.smaf-audio"]),
+    ("sam", &["application/vnd.lotus-wordpro"]),
+    ("sar", &["application/vnd.sar"]),
+    ("sarif", &["application/sarif+json"]),
+    ("sarif-external-properties", &["application/sarif-external-properties+json"]),
+    ("sarif-external-properties.json", &["application/sarif-external-properties+json"]),
+    ("sarif.json", &["application/sarif+json"]),
+    ("sc", &["application/vnd.ibm.secure-container"]),
+    ("scala", &["text/x-scala"]),
+    ("scd", &["application/vnd.scribus"]),
+    ("sce", &["application/vnd.etsi.asic-e+zip", "application/x-scilab"]),
+    ("sci", &["application/x-scilab"]),
+    ("scim", &["application/scim+json"]),
+    ("scl", &["application/vnd.sycle+xml"]),
+    ("scld", &["application/vnd.doremir.scorecloud-binary-document"]),
+    ("scm", &["application/vnd.lotus-screencam"]),
+    ("sco", &["audio/csound"]),
+    ("scq", &["application/scvp-cv-request"]),
+    ("scr", &["application/x-silverlight"]),
+    ("scs", &["application/scvp-cv-response"]),
+    ("scsf", &["application/vnd.sealed.csf"]),
+    ("sd", &["chemical/x-mdl-sdfile"]),
+    ("sd2", &["audio/x-sd2"]),
+    ("sda", &["application/vnd.stardivision.draw"]),
+    ("sdc", &["application/vnd.stardivision.calc"]),
+    ("sdd", &["application/vnd.stardivision.impress"]),
+    ("sdf", &["application/vnd.kinar", "chemical/x-mdl-sdfile"]),
+    ("sdkd", &["application/vnd.solent.sdkm+xml"]),
+    ("sdkm", &["application/vnd.solent.sdkm+xml"]),
+    ("sdo", &["application/vnd.sealed.doc"]),
+    ("sdoc", &["application/vnd.sealed.doc"]),
+    ("sdp", &["application/sdp"]),
+    ("sds", &["application/vnd.stardivision.chart"]),
+    ("sdw", &["application/vnd.stardivision.writer"]),
+    ("sea", &["application/x-sea"]),
+    ("see", &["application/vnd.seemail"]),
+    ("seed", &["application/vnd.fdsn.seed"]),
+    ("sem", &["application/vnd.sealed.eml"]),
+    ("sema", &["application/vnd.sema"]),
+    ("semd", &["application/vnd.semd"]),
+    ("semf", &["application/vnd.semf"]),
+    ("seml", &["application/vnd.sealed.eml"]),
+    ("senml", &["application/senml+json"]),
+    ("senml-etchc", &["application/senml-etch+cbor"]),
+    ("senml-etchj", &["application/senml-etch+json"]),
+    ("senmlc", &["application/senml+cbor"]),
+    ("senmle", &["application/senml-exi"]),
+    ("senmlx", &["application/senml+xml"]),
+    ("sensml", &["application/sensml+json"]),
+    ("sensmlc", &["application/sensml+cbor"]),
+    ("sensmle", &["application/sensml-exi"]),
+    ("sensmlx", &["application/sensml+xml"]),
+    ("ser", &["application/java-serialized-object"]),
+    ("sfc", &["application/vnd.nintendo.snes.rom"]),
+    ("sfd", &["application/vnd.font-fontforge-sfd"]),
+    ("sfd-hdstx", &["application/vnd.hydrostatix.sof-data"]),
+    ("sfs", &["application/vnd.spotfire.sfs"]),
+    ("sfv", &["text/x-sfv"]),
+    ("sgf", &["application/x-go-sgf"]),
+    ("sgi", &["image/vnd.sealedmedia.softseal.gif"]),
+    ("sgif", &["image/vnd.sealedmedia.softseal.gif"]),
+    ("sgl", &["application/vnd.stardivision.writer-global"]),
+    ("sgm", &["text/sgml"]),
+    ("sgml", &["text/sgml"]),
+    ("sh", &["application/x-sh", "text/x-sh"]),
+    ("shaclc", &["text/shaclc"]),
+    ("shar", &["application/x-shar"]),
+    ("shc", &["text/shaclc"]),
+    ("shex", &["text/shex"]),
+    ("shf", &["application/shf+xml"]),
+    ("shp", &["application/vnd.shp", "application/x-qgis"]),
+    ("shtml", &["text/html"]),
+    ("shx", &["application/vnd.shx", "application/x-qgis"]),
+    ("si", &["text/vnd.wap.si"]),
+    ("sic", &["application/vnd.wap.sic"]),
+    ("sid", &["audio/prs.sid"]),
+    ("sieve", &["application/sieve"]),
+    ("sig", &["application/pgp-signature"]),
+    ("sik", &["application/x-trash"]),
+    ("silo", &["model/mesh"]),
+    ("sis", &["application/vnd.symbian.install"]),
+    ("sit", &["application/x-stuffit"]),
+    ("sitx", &["application/x-stuffit"]),
+    ("siv", &["application/sieve"]),
+    ("sjp", &["image/vnd.sealedmedia.softseal.jpg"]),
+    ("sjpg", &["image/vnd.sealedmedia.softseal.jpg"]),
+    ("skd", &["application/vnd.koan"]),
+    ("skm", &["application/vnd.koan"]),
+    ("skp", &["application/vnd.koan"]),
+    ("skt", &["application/vnd.koan"]),
+    ("sl", &["text/vnd.wap.sl"]),
+    ("sla", &["application/vnd.scribus"]),
+    ("slaz", &["application/vnd.scribus"]),
+    ("slc", &["application/vnd.wap.slc"]),
+    ("sldm", &["application/vnd.ms-powerpoint.slide.macroenabled.12"]),
+    ("sldx", &["application/vnd.openxmlformats-officedocument.presentationml.slide"]),
+    ("sls", &["application/route-s-tsid+xml"]),
+    ("slt", &["application/vnd.epson.salt"]),
+    ("sm", &["application/vnd.stepmania.stepchart"]),
+    ("smc", &["application/vnd.nintendo.snes.rom"]),
+    ("smf", &["application/vnd.stardivision.math"]),
+    ("smh", &["application/vnd.sealed.mht"]),
+    ("smht", &["application/vnd.sealed.mht"]),
+    ("smi", &["application/smil+xml"]),
+    ("smil", &["application/smil+xml"]),
+    ("smk", &["video/vnd.radgamettools.smacker"]),
+    ("sml", &["application/smil+xml"]),
+    ("smo", &["video/vnd.sealedmedia.softseal.mov"]),
+    ("smov", &["video/vnd.sealedmedia.softseal.mov"]),
+    ("smp", &["audio/vnd.sealedmedia.softseal.mpeg"]),
+    ("smp3", &["audio/vnd.sealedmedia.softseal.mpeg"]),
+    ("smpg", &["video/vnd.sealed.mpeg1"]),
+    ("sms", &["application/vnd.3gpp2.sms"]),
+    ("smv", &["audio/smv"]),
+    ("smzip", &["application/vnd.stepmania.package"]),
+    ("snd", &["audio/basic"]),
+    ("soa", &["text/dns"]),
+    ("soc", &["application/sgml-open-catalog"]),
+    ("sofa", &["audio/sofa"]),
+    ("sos", &["text/vnd.sosi"]),
+    ("spc", &["chemical/x-galactic-spc"]),
+    ("spd", &["application/vnd.sealedmedia.softseal.pdf"]),
+    ("spdf", &["application/vnd.sealedmedia.softseal.pdf"]),
+    ("spdx", &["text/spdx"]),
+    ("spdx.json", &["application/spdx+json"]),
+    ("spf", &["application/vnd.yamaha.smaf-phrase"]),
+    ("spl", &["application/futuresplash"]),
+    ("spn", &["image/vnd.sealed.png"]),
+    ("spng", &["image/vnd.sealed.png"]),
+    ("spo", &["text/vnd.in3d.spot"]),
+    ("spot", &["text/vnd.in3d.spot"]),
+    ("spp", &["application/scvp-vp-response"]),
+    ("sppt", &["application/vnd.sealed.ppt"]),
+    ("spq", &["application/scvp-vp-request"]),
+    ("spx", &["audio/ogg"]),
+    ("sql", &["application/sql"]),
+    ("sqlite", &["application/vnd.sqlite3"]),
+    ("sqlite3", &["application/vnd.sqlite3"]),
+    ("sr", &["application/vnd.sigrok.session"]),
+    ("src", &["application/x-wais-source"]),
+    ("srt", &["text/plain"]),
+    ("sru", &["application/sru+xml"]),
+    ("srx", &["application/sparql-results+xml"]),
+    ("sse", &["application/vnd.kodak-descriptor"]),
+    ("ssf", &["application/vnd.epson.ssf"]),
+    ("ssml", &["application/ssml+xml"]),
+    ("ssv", &["application/vnd.shade-save-file"]),
+    ("ssvc", &["application/vnd.crypto-shade-file"]),
+    ("ssw", &["video/vnd.sealed.swf"]),
+    ("sswf", &["video/vnd.sealed.swf"]),
+    ("st", &["application/vnd.sailingtracker.track"]),
+    ("stc", &["application/vnd.sun.xml.calc.template"]),
+    ("std", &["application/vnd.sun.xml.draw.template"]),
+    ("step", &["model/step"]),
+    ("stf", &["application/vnd.wt.stf"]),
+    ("sti", &["application/vnd.sun.xml.impress.template"]),
+    ("stif", &["application/vnd.sealed.tiff"]),
+    ("stix", &["application/stix+json"]),
+    ("stk", &["application/hyperstudio"]),
+    ("stl", &["model/stl"]),
+    ("stml", &["application/vnd.sealedmedia.softseal.html"]),
+    ("stp", &["model/step"]),
+    ("stpnc", &["application/p21"]),
+    ("stpx", &["model/step+xml"]),
+    ("stpxz", &["model/step-xml+zip"]),
+    ("stpz", &["model/step+zip"]),
+    ("str", &["application/vnd.pg.format"]),
+    ("study-inter", &["application/vnd.vd-study"]),
+    ("stw", &["application/vnd.sun.xml.writer.template"]),
+    ("sty", &["text/x-tex"]),
+    ("sus", &["application/vnd.sus-calendar"]),
+    ("susp", &["application/vnd.sus-calendar"]),
+    ("sv4cpio", &["application/x-sv4cpio"]),
+    ("sv4crc", &["application/x-sv4crc"]),
+    ("svc", &["application/vnd.dvb.service"]),
+    ("svg", &["image/svg+xml"]),
+    ("svgz", &["image/svg+xml"]),
+    ("sw", &["chemical/x-swissprot"]),
+    ("swf", &["application/vnd.adobe.flash.movie", "application/x-shockwave-flash"]),
+    ("swi", &["application/vnd.aristanetworks.swi"]),
+    ("swidtag", &["application/swid+xml"]),
+    ("sxc", &["application/vnd.sun.xml.calc"]),
+    ("sxd", &["application/vnd.sun.xml.draw"]),
+    ("sxg", &["application/vnd.sun.xml.writer.global"]),
+    ("sxi", &["application/vnd.sun.xml.impress"]),
+    ("sxl", &["application/vnd.sealed.xls"]),
+    ("sxls", &["application/vnd.sealed.xls"]),
+    ("sxm", &["application/vnd.sun.xml.math"]),
+    ("sxw", &["application/vnd.sun.xml.writer"]),
+    ("sy2", &["application/vnd.sybyl.mol2"]),
+    ("syft.json", &["application/vnd.syft+json"]),
+    ("t", &["text/troff"]),
+    ("tag", &["text/prs.lines.tag"]),
+    ("taglet", &["application/vnd.mynfc"]),
+    ("tam", &["application/vnd.onepager"]),
+    ("tamp", &["application/vnd.onepagertamp"]),
+    ("tamx", &["application/vnd.onepagertamx"]),
+    ("tao", &["application/vnd.tao.intent-module-archive"]),
+    ("tap", &["image/vnd.tencent.tap"]),
+    ("tar", &["application/x-tar"]),
+    ("tat", &["application/vnd.onepagertat"]),
+    ("tatp", &["application/vnd.onepagertatp"]),
+    ("tatx", &["application/vnd.onepagertatx"]),
+    ("tau", &["application/tamp-apex-update"]),
+    ("taz", &["application/x-gtar-compressed"]),
+    ("tcap", &["application/vnd.3gpp2.tcap"]),
+    ("tcl", &["application/x-tcl", "text/x-tcl"]),
+    ("tcu", &["application/tamp-community-update"]),
+    ("td", &["application/urc-targetdesc+xml"]),
+    ("teacher", &["application/vnd.smart.teacher"]),
+    ("tei", &["application/tei+xml"]),
+    ("teicorpus", &["application/tei+xml"]),
+    ("ter", &["application/tamp-error"]),
+    ("tex", &["text/x-tex"]),
+    ("texi", &["application/x-texinfo"]),
+    ("texinfo", &["application/x-texinfo"]),
+    ("text", &["text/plain"]),
+    ("tfi", &["application/thraud+xml"]),
+    ("tfx", &["image/tiff-fx"]),
+    ("tgf", &["chemical/x-mdl-tgf"]),
+    ("tgz", &["application/x-gtar-compressed"]),
+    ("thmx", &["application/vnd.ms-officetheme"]),
+    ("tif", &["image/tiff"]),
+    ("tiff", &["image/tiff"]),
+    ("tk", &["text/x-tcl", "application/x-tcl"]),
+    ("tlclient", &["application/vnd.cendio.thinlinc.clientconf"]),
+    ("tm", &["text/texmacs"]),
+    ("tm.json", &["application/tm+json"]),
+    ("tm.jsonld", &["application/tm+json"]),
+    ("tmo", &["application/vnd.tmobile-livetv"]),
+    ("tnef", &["application/vnd.ms-tnef"]),
+    ("tnf", &["application/vnd.ms-tnef"]),
+    ("torrent", &["application/x-bittorrent"]),
+    ("tpl", &["application/vnd.groove-tool-template"]),
+    ("tpt", &["application/vnd.trid.tpt"]),
+    ("tr", &["text/troff"]),
+    ("tra", &["application/vnd.trueapp"]),
+    ("tree", &["application/vnd.rainstor.data"]),
+    ("trig", &["application/trig"]),
+    ("ts", &["video/mp2t", "text/vnd.trolltech.linguist"]),
+    ("tsa", &["application/tamp-sequence-adjust"]),
+    ("tsd", &["application/timestamped-data"]),
+    ("tsp", &["application/dsptype"]),
+    ("tsq", &["application/timestamp-query"]),
+    ("tsr", &["application/timestamp-reply"]),
+    ("tst", &["application/vnd.etsi.timestamp-token"]),
+    ("tsv", &["text/tab-separated-values"]),
+    ("ttc", &["font/collection"]),
+    ("ttf", &["font/ttf"]),
+    ("ttl", &["text/turtle"]),
+    ("ttml", &["application/ttml+xml"]),
+    ("tuc", &["application/tamp-update-confirm"]),
+    ("tur", &["application/tamp-update"]),
+    ("twd", &["application/vnd.simtech-mindmapper"]),
+    ("twds", &["application/vnd.simtech-mindmapper"]),
+    ("txd", &["application/vnd.genomatix.tuxedo"]),
+    ("txf", &["application/vnd.mobius.txf"]),
+    ("txt", &["text/plain"]),
+    ("u3d", &["model/u3d"]),
+    ("u8dsn", &["message/global-delivery-status"]),
+    ("u8hdr", &["message/global-headers"]),
+    ("u8mdn", &["message/global-disposition-notification"]),
+    ("u8msg", &["message/global"]),
+    ("udeb", &["application/vnd.debian.binary-package"]),
+    ("ufd", &["application/vnd.ufdl"]),
+    ("ufdl", &["application/vnd.ufdl"]),
+    ("uis", &["application/urc-uisocketdesc+xml"]),
+    ("umj", &["application/vnd.umajin"]),
+    ("unityweb", &["application/vnd.unity"]),
+    ("uo", &["application/vnd.uoml+xml"]),
+    ("uoml", &["application/vnd.uoml+xml"]),
+    ("upa", &["application/vnd.hbci"]),
+    ("uri", &["text/uri-list"]),
+    ("urim", &["application/vnd.uri-map"]),
+    ("urimap", &["application/vnd.uri-map"]),
+    ("uris", &["text/uri-list"]),
+    ("usda", &["model/vnd.usda"]),
+    ("usdz", &["model/vnd.usdz+zip"]),
+    ("ustar", &["application/x-ustar"]),
+    ("utz", &["application/vnd.uiq.theme"]),
+    ("uva", &["audio/vnd.dece.audio"]),
+    ("uvd", &["application/vnd.dece.data"]),
+    ("uvf", &["application/vnd.dece.data"]),
+    ("uvg", &["image/vnd.dece.graphic"]),
+    ("uvh", &["video/vnd.dece.hd"]),
+    ("uvi", &["image/vnd.dece.graphic"]),
+    ("uvm", &["video/vnd.dece.mobile"]),
+    ("uvp", &["video/vnd.dece.pd"]),
+    ("uvs", &["video/vnd.dece.sd"]),
+    ("uvt", &["application/vnd.dece.ttml+xml"]),
+    ("uvu", &["video/vnd.dece.mp4"]),
+    ("uvv", &["video/vnd.dece.video"]),
+    ("uvva", &["audio/vnd.dece.audio"]),
+    ("uvvd", &["application/vnd.dece.data"]),
+    ("uvvf", &["application/vnd.dece.data"]),
+    ("uvvg", &["image/vnd.dece.graphic"]),
+    ("uvvh", &["video/vnd.dece.hd"]),
+    ("uvvi", &["image/vnd.dece.graphic"]),
+    ("uvvm", &["video/vnd.dece.mobile"]),
+    ("uvvp", &["video/vnd.dece.pd"]),
+    ("uvvs", &["video/vnd.dece.sd"]),
+    ("uvvt", &["application/vnd.dece.ttml+xml"]),
+    ("uvvu", &["video/vnd.dece.mp4"]),
+    ("uvvv", &["video/vnd.dece.video"]),
+    ("uvvx", &["application/vnd.dece.unspecified"]),
+    ("uvvz", &["application/vnd.dece.zip"]),
+    ("uvx", &["application/vnd.dece.unspecified"]),
+    ("uvz", &["application/vnd.dece.zip"]),
+    ("val", &["chemical/x-ncbi-asn1-binary"]),
+    ("vbk", &["audio/vnd.nortel.vbk"]),
+    ("vbox", &["application/vnd.previewsystems.box"]),
+    ("vcard", &["text/vcard"]),
+    ("vcd", &["application/x-cdlink"]),
+    ("vcf", &["text/vcard"]),
+    ("vcg", &["application/vnd.groove-vcard"]),
+    ("vcj", &["application/voucher-cms+json"]),
+    ("vcs", &["text/x-vcalendar"]),
+    ("vcx", &["application/vnd.vcx"]),
+    ("vds", &["model/vnd.sap.vds"]),
+    ("ves", &["application/vnd.ves.encrypted"]),
+    ("vew", &["application/vnd.lotus-approach"]),
+    ("vfk", &["text/vnd.exchangeable"]),
+    ("vfr", &["application/vnd.tml"]),
+    ("viaframe", &["application/vnd.tml"]),
+    ("vis", &["application/vnd.visionary"]),
+    ("viv", &["video/vnd.vivo"]),
+    ("vmd", &["chemical/x-vmd"]),
+    ("vms", &["chemical/x-vamas-iso14976"]),
+    ("vmt", &["application/vnd.valve.source.material"]),
+    ("vpm", &["multipart/voice-message"]),
+    ("vrm", &["model/vrml"]),
+    ("vrml", &["model/vrml"]),
+    ("vsc", &["application/vnd.vidsoft.vidconference"]),
+    ("vsd", &["application/vnd.visio"]),
+    ("vsf", &["application/vnd.vsf"]),
+    ("vss", &["application/vnd.visio"]),
+    ("vst", &["application/vnd.visio"]),
+    ("vsw", &["application/vnd.visio"]),
+    ("vtf", &["image/vnd.valve.source.texture"]),
+    ("vtnstd", &["application/vnd.veritone.aion+json"]),
+    ("vtt", &["text/vtt"]),
+    ("vtu", &["model/vnd.vtu"]),
+    ("vwx", &["application/vnd.vectorworks"]),
+    ("vxml", &["application/voicexml+xml"]),
+    ("wad", &["application/x-doom"]),
+    ("wadl", &["application/vnd.sun.wadl+xml"]),
+    ("wafl", &["application/vnd.wasmflow.wafl"]),
+    ("war", &["application/java-archive"]),
+    ("wasm", &["application/wasm"]),
+    ("wav", &["audio/x-wav"]),
+    ("wax", &["audio/x-ms-wax"]),
+    ("wbmp", &["image/vnd.wap.wbmp"]),
+    ("wbs", &["application/vnd.criticaltools.wbs+xml"]),
+    ("wbxml", &["application/vnd.wap.wbxml"]),
+    ("wcm", &["application/vnd.ms-works"]),
+    ("wdb", &["application/vnd.ms-works"]),
+    ("webm", &["video/webm"]),
+    ("webmanifest", &["application/manifest+json"]),
+    ("webp", &["image/webp"]),
+    ("wg", &["application/vnd.pmi.widget"]),
+    ("wgsl", &["text/wgsl"]),
+    ("wgt", &["application/widget"]),
+    ("wif", &["application/watcherinfo+xml"]),
+    ("win", &["model/vnd.gdl"]),
+    ("wk", &["application/x-123"]),
+    ("wk1", &["application/vnd.lotus-1-2-3"]),
+    ("wk3", &["application/vnd.lotus-1-2-3"]),
+    ("wk4", &["application/vnd.lotus-1-2-3"]),
+    ("wks", &["application/vnd.ms-works"]),
+    ("wlnk", &["application/link-format"]),
+    ("wm", &["video/x-ms-wm"]),
+    ("wma", &["audio/x-ms-wma"]),
+    ("wmc", &["application/vnd.wmc"]),
+    ("wmd", &["application/x-ms-wmd"]),
+    ("wmf", &["image/wmf"]),
+    ("wml", &["text/vnd.wap.wml"]),
+    ("wmlc", &["application/vnd.wap.wmlc"]),
+    ("wmls", &["text/vnd.wap.wmlscript"]),
+    ("wmlsc", &["application/vnd.wap.wmlscriptc"]),
+    ("wmv", &["video/x-ms-wmv"]),
+    ("wmx", &["video/x-ms-wmx"]),
+    ("wmz", &["application/x-ms-wmz"]),
+    ("woff", &["font/woff"]),
+    ("woff2", &["font/woff2"]),
+    ("wpd", &["application/vnd.wordperfect"]),
+    ("wpl", &["application/vnd.ms-wpl"]),
+    ("wps", &["application/vnd.ms-works"]),
+    ("wqd", &["application/vnd.wqd"]),
+    ("wrl", &["model/vrml"]),
+    ("wsc", &["application/vnd.wfa.wsc"]),
+    ("wsdl", &["application/wsdl+xml"]),
+    ("wspolicy", &["application/wspolicy+xml"]),
+    ("wtb", &["application/vnd.webturbo"]),
+    ("wv", &["application/vnd.wv.csp+wbxml"]),
+    ("wvx", &["video/x-ms-wvx"]),
+    ("wz", &["application/x-wingz"]),
+    ("x3d", &["model/x3d+xml"]),
+    ("x3db", &["model/x3d+fastinfoset"]),
+    ("x3dv", &["model/x3d-vrml"]),
+    ("x3dvz", &["model/x3d-vrml"]),
+    ("x3dz", &["model/x3d+xml"]),
+    ("x_b", &["model/vnd.parasolid.transmit.binary"]),
+    ("x_t", &["model/vnd.parasolid.transmit.text"]),
+    ("xar", &["application/vnd.xara"]),
+    ("xav", &["application/xcap-att+xml"]),
+    ("xbd", &["application/vnd.fujixerox.docuworks.binder"]),
+    ("xbm", &["image/x-xbitmap"]),
+    ("xca", &["application/xcap-caps+xml"]),
+    ("xcf", &["image/x-xcf"]),
+    ("xcos", &["application/x-scilab-xcos"]),
+    ("xcs", &["application/calendar+xml"]),
+    ("xct", &["application/vnd.fujixerox.docuworks.container"]),
+    ("xdd", &["application/bacnet-xdd+zip"]),
+    ("xdf", &["application/xcap-diff+xml"]),
+    ("xdm", &["application/vnd.syncml.dm+xml"]),
+    ("xdp", &["application/vnd.adobe.xdp+xml"]),
+    ("xdssc", &["application/dssc+xml"]),
+    ("xdw", &["application/vnd.fujixerox.docuworks"]),
+    ("xel", &["application/xcap-el+xml"]),
+    ("xer", &["application/xcap-error+xml"]),
+    ("xfd", &["application/vnd.xfdl"]),
+    ("xfdf", &["application/xfdf"]),
+    ("xfdl", &["application/vnd.xfdl"]),
+    ("xhe", &["audio/usac"]),
+    ("xht", &["application/xhtml+xml"]),
+    ("xhtm", &["application/xhtml+xml"]),
+    ("xhtml", &["application/xhtml+xml"]),
+    ("xhvml", &["application/xv+xml"]),
+    ("xif", &["image/vnd.xiff"]),
+    ("xla", &["application/vnd.ms-excel"]),
+    ("xlam", &["application/vnd.ms-excel.addin.macroenabled.12"]),
+    ("xlc", &["application/vnd.ms-excel"]),
+    ("xlf", &["application/xliff+xml"]),
+    ("xlim", &["application/vnd.xmpie.xlim"]),
+    ("xlm", &["application/vnd.ms-excel"]),
+    ("xls", &["application/vnd.ms-excel"]),
+    ("xlsb", &["application/vnd.ms-excel.sheet.binary.macroenabled.12"]),
+    ("xlsm", &["application/vnd.ms-excel.sheet.macroenabled.12"]),
+    ("xlsx", &["application/vnd.openxmlformats-officedocument.spreadsheetml.sheet"]),
+    ("xlt", &["application/vnd.ms-excel"]),
+    ("xltm", &["application/vnd.ms-excel.template.macroenabled.12"]),
+    ("xltx", &["application/vnd.openxmlformats-officedocument.spreadsheetml.template"]),
+    ("xlw", &["application/vnd.ms-excel"]),
+    ("xml", &["application/xml", "text/xml"]),
+    ("xmls", &["application/dskpp+xml"]),
+    ("xmt_bin", &["model/vnd.parasolid.transmit.binary"]),
+    ("xmt_txt", &["model/vnd.parasolid.transmit.text"]),
+    ("xns", &["application/xcap-ns+xml"]),
+    ("xo", &["application/vnd.olpc-sugar"]),
+    ("xodp", &["application/vnd.collabio.xodocuments.presentation"]),
+    ("xods", &["application/vnd.collabio.xodocuments.spreadsheet"]),
+    ("xodt", &["application/vnd.collabio.xodocuments.document"]),
+    ("xop", &["application/xop+xml"]),
+    ("xotp", &["application/vnd.collabio.xodocuments.presentation-template"]),
+    ("xots", &["application/vnd.collabio.xodocuments.spreadsheet-template"]),
+    ("xott", &["application/vnd.collabio.xodocuments.document-template"]),
+    ("xpak", &["application/vnd.gentoo.xpak"]),
+    ("xpi", &["application/x-xpinstall"]),
+    ("xpm", &["image/x-xpixmap"]),
+    ("xpr", &["application/vnd.is-xpr"]),
+    ("xps", &["application/vnd.ms-xpsdocument"]),
+    ("xpw", &["application/vnd.intercon.formnet"]),
+    ("xpx", &["application/vnd.intercon.formnet"]),
+    ("xsf", &["application/prs.xsf+xml"]),
+    ("xsl", &["application/xslt+xml"]),
+    ("xslt", &["application/xslt+xml"]),
+    ("xsm", &["application/vnd.syncml+xml"]),
+    ("xspf", &["application/xspf+xml"]),
+    ("xtel", &["chemical/x-xtel"]),
+    ("xul", &["application/vnd.mozilla.xul+xml"]),
+    ("xvm", &["application/xv+xml"]),
+    ("xvml", &["application/xv+xml"]),
+    ("xwd", &["image/x-xwindowdump"]),
+    ("xyz", &["chemical/x-xyz"]),
+    ("xyze", &["image/vnd.radiance"]),
+    ("xz", &["application/x-xz"]),
+    ("yang", &["application/yang"]),
+    ("yin", &["application/yin+xml"]),
+    ("yme", &["application/vnd.yaoweme"]),
+    ("yt", &["video/vnd.youtube.yt"]),
+    ("zaz", &["application/vnd.zzazz.deck+xml"]),
+    ("zfc", &["application/vnd.filmit.zfc"]),
+    ("zfo", &["application/vnd.software602.filler.form-xml-zip"]),
+    ("zip", &["application/zip"]),
+    ("zir", &["application/vnd.zul"]),
+    ("zirz", &["application/vnd.zul"]),
+    ("zmm", &["application/vnd.handheld-entertainment+xml"]),
+    ("zmt", &["chemical/x-mopac-input"]),
+    ("zone", &["text/dns"]),
+    ("zst", &["application/zstd"]),
+    ("~", &["application/x-trash"]),
+];
+
+pub(super) static BY_MEDIA_TYPE: &[(&str, &[&str])] = &[
+    ("application/a2l", &["a2l"]),
+    ("application/aml", &["aml"]),
+    ("application/andrew-inset", &["ez"]),
+    ("application/annodex", &["anx"]),
+    ("application/atf", &["atf"]),
+    ("application/atfx", &["atfx"]),
+    ("application/atom+xml", &["atom"]),
+    ("application/atomcat+xml", &["atomcat"]),
+    ("application/atomdeleted+xml", &["atomdeleted"]),
+    ("application/atomserv+xml", &["atomsrv"]),
+    ("application/atomsvc+xml", &["atomsvc"]),
+    ("application/atsc-dwd+xml", &["dwd"]),
+    ("application/atsc-held+xml", &["held"]),
+    ("application/atsc-rsat+xml", &["rsat"]),
+    ("application/atxml", &["atxml"]),
+    ("application/auth-policy+xml", &["apxml"]),
+    ("application/automationml-amlx+zip", &["amlx"]),
+    ("application/bacnet-xdd+zip", &["xdd"]),
+    ("application/bbolin", &["lin"]),
+    ("application/calendar+xml", &["xcs"]),
+    ("application/cbor", &["cbor"]),
+    ("application/cccex", &["c3ex"]),
+    ("application/ccmp+xml", &["ccmp"]),
+    ("application/ccxml+xml", &["ccxml"]),
+    ("application/cdfx+xml", &["cdfx"]),
+    ("application/cdmi-capability", &["cdmia"]),
+    ("application/cdmi-container", &["cdmic"]),
+    ("application/cdmi-domain", &["cdmid"]),
+    ("application/cdmi-object", &["cdmio"]),
+    ("application/cdmi-queue", &["cdmiq"]),
+    ("application/cea", &["cea"]),
+    ("application/cellml+xml", &["cellml", "cml"]),
+    ("application/clr", &["1clr"]),
+    ("application/clue_info+xml", &["clue"]),
+    ("application/cms", &["cmsc"]),
+    ("application/cpl+xml", &["cpl"]),
+    ("application/csrattrs", &["csrattrs"]),
+    ("application/cu-seeme", &["cu"]),
+    ("application/cwl", &["cwl"]),
+    ("application/cwl+json", &["cwl.json"]),
+    ("application/dash+xml", &["mpd"]),
+    ("application/dashdelta", &["mpdd"]),
+    ("application/davmount+xml", &["davmount"]),
+    ("application/dcd", &["dcd"]),
+    ("application/dicom", &["dcm"]),
+    ("application/dii", &["dii"]),
+    ("application/dit", &["dit"]),
+    ("application/dskpp+xml", &["xmls"]),
+    ("application/dsptype", &["tsp"]),
+    ("application/dssc+der", &["dssc"]),
+    ("application/dssc+xml", &["xdssc"]),
+    ("application/dvcs", &["dvc"]),
+    ("application/efi", &["efi"]),
+    ("application/emma+xml", &["emma"]),
+    ("application/emotionml+xml", &["emotionml"]),
+    ("application/epub+zip", &["epub"]),
+    ("application/exi", &["exi"]),
+    ("application/express", &["exp"]),
+    ("application/fastinfoset", &["finf"]),
+    ("application/fdf", &["fdf"]),
+    ("application/fdt+xml", &["fdt"]),
+    ("application/font-tdpfr", &["pfr"]),
+    ("application/futuresplash", &["spl"]),
+    ("application/geo+json", &["geojson"]),
+    ("application/geopackage+sqlite3", &["gpkg"]),
+    ("application/gltf-buffer", &["glbin", "glbuf"]),
+    ("application/gml+xml", &["gml"]),
+    ("application/gzip", &["gz"]),
+    ("application/hta", &["hta"]),
+    ("application/hyperstudio", &["stk"]),
+    ("application/inkml+xml", &["ink", "inkml"]),
+    ("application/ipfix", &["ipfix"]),
+    ("application/its+xml", &["its"]),
+    ("application/java-archive", &["jar", "war", "ear"]),
+    ("application/java-serialized-object", &["ser"]),
+    ("application/java-vm", &["class"]),
+    ("application/javascript", &["js"]),
+    ("application/jrd+json", &["jrd"]),
+    ("application/json", &["json"]),
+    ("application/json-patch+json", &["json-patch"]),
+    ("application/ld+json", &["jsonld"]),
+    ("application/lgr+xml", &["lgr"]),
+    ("application/link-format", &["wlnk"]),
+    ("application/lost+xml", &["lostxml"]),
+    ("application/lostsync+xml", &["lostsyncxml"]),
+    ("application/lpf+zip", &["lpf"]),
+    ("application/lxf", &["lxf"]),
+    ("application/m3g", &["m3g"]),
+    ("application/mac-binhex40", &["hqx"]),
+    ("application/mac-compactpro", &["cpt"]),
+    ("application/mads+xml", &["mads"]),
+    ("application/manifest+json", &["webmanifest"]),
+    ("application/marc", &["mrc"]),
+    ("application/marcxml+xml", &["mrcx"]),
+    ("application/mathematica", &["ma", "mb"]),
+    ("application/mathml+xml", &["mml"]),
+    ("application/mbox", &["mbox"]),
+    ("application/metalink4+xml", &["meta4"]),
+    ("application/mets+xml", &["mets"]),
+    ("application/mf4", &["mf4"]),
+    ("application/mmt-aei+xml", &["maei"]),
+    ("application/mmt-usd+xml", &["musd"]),
+    ("application/mods+xml", &["mods"]),
+    ("application/mp21", &["m21", "mp21"]),
+    ("application/msaccess", &["mdb"]),
+    ("application/msword", &["doc"]),
+    ("application/mxf", &["mxf"]),
+    ("application/n-quads", &["nq"]),
+    ("application/n-triples", &["nt"]),
+    ("application/ocsp-request", &["orq"]),
+    ("application/ocsp-response", &["ors"]),
+    ("application/octet-stream", &["bin", "deploy", "msu", "msp", "exe", "dll", "deb", "dmg", "iso", "img", "msi", "msm"]),
+    ("application/oda", &["oda"]),
+    ("application/odx", &["odx"]),
+    ("application/oebps-package+xml", &["opf"]),
+    ("application/ogg", &["ogx"]),
+    ("application/onenote", &["one", "onetoc2", "onetmp", "onepkg"]),
+    ("application/oxps", &["oxps"]),
+    ("application/p21", &["p21", "stpnc", "210", "ifc"]),
+    ("application/p2p-overlay+xml", &["relo"]),
+    ("application/pdf", &["pdf"]),
+    ("application/pdx", &["pdx"]),
+    ("application/pem-certificate-chain", &["pem"]),
+    ("application/pgp-encrypted", &["pgp"]),
+    ("application/pgp-keys", &["asc", "key"]),
+    ("application/pgp-signature", &["sig"]),
+    ("application/pics-rules", &["prf"]),
+    ("application/pkcs10", &["p10"]),
+    ("application/pkcs12", &["p12", "pfx"]),
+    ("application/pkcs7-mime", &["p7m", "p7c", "p7z"]),
+    ("application/pkcs7-signature", &["p7s"]),
+    ("application/pkcs8", &["p8"]),
+    ("application/pkcs8-encrypted", &["p8e"]),
+    ("application/pkix-attr-cert", &["ac"]),
+    ("application/pkix-cert", &["cer"]),
+    ("application/pkix-crl", &["crl"]),
+    ("application/pkix-pkipath", &["pkipath"]),
+    ("application/pkixcmp", &["pki"]),
+    ("application/postscript", &["ps", "ai", "eps", "epsi", "epsf", "eps2", "eps3"]),
+    ("application/provenance+xml", &["provx"]),
+    ("application/prs.cww", &["cw", "cww"]),
+    ("application/prs.hpub+zip", &["hpub"]),
+    ("application/prs.nprend", &["rnd", "rct"]),
+    ("application/prs.rdf-xml-crypt", &["rdf-crypt"]),
+    ("application/prs.xsf+xml", &["xsf"]),
+    ("application/pskc+xml", &["pskcxml"]),
+    ("application/rdf+xml", &["rdf"]),
+    ("application/reginfo+xml", &["rif"]),
+    ("application/relax-ng-compact-syntax", &["rnc"]),
+    ("application/resource-lists+xml", &["rl"]),
+    ("application/resource-lists-diff+xml", &["rld"]),
+    ("application/rfc+xml", &["rfcxml"]),
+    ("application/rls-services+xml", &["rs"]),
+    ("application/route-apd+xml", &["rapd"]),
+    ("application/route-s-tsid+xml", &["sls"]),
+    ("application/route-usd+xml", &["rusd"]),
+    ("application/rpki-ghostbusters", &["gbr"]),
+    ("application/rpki-manifest", &["mft"]),
+    ("application/rpki-roa", &["roa"]),
+    ("application/rss+xml", &["rss"]),
+    ("application/rtf", &["rtf"]),
+    ("application/sarif+json", &["sarif", "sarif.json"]),
+    ("application/sarif-external-properties+json", &["sarif-external-properties", "sarif-external-properties.json"]),
+    ("application/scim+json", &["scim"]),
+    ("application/scvp-cv-request", &["scq"]),
+    ("application/scvp-cv-response", &["scs"]),
+    ("application/scvp-vp-request", &["spq"]),
+    ("application/scvp-vp-response", &["spp"]),
+    ("application/sdp", &["sdp"]),
+    ("application/senml+cbor", &["senmlc"]),
+    ("application/senml+json", &["senml"]),
+    ("application/senml+xml", &["senmlx"]),
+    ("application/senml-etch+cbor", &["senml-etchc"]),
+    ("application/senml-etch+json", &["senml-etchj"]),
+    ("application/senml-exi", &["senmle"]),
+    ("application/sensml+cbor", &["sensmlc"]),
+    ("application/sensml+json", &["sensml"]),
+    ("application/sensml+xml", &["sensmlx"]),
+    ("application/sensml-exi", &["sensmle"]),
+    ("application/sgml-open-catalog", &["soc"]),
+    ("application/shf+xml", &["shf"]),
+    ("application/sieve", &["siv", "sieve"]),
+    ("application/simple-filter+xml", &["cl"]),
+    ("application/smil+xml", &["smil", "smi", "sml"]),
+    ("application/sparql-query", &["rq"]),
+    ("application/sparql-results+xml", &["srx"]),
+    ("application/spdx+json", &["spdx.json"]),
+    ("application/sql", &["sql"]),
+    ("application/srgs", &["gram"]),
+    ("application/srgs+xml", &["grxml"]),
+    ("application/sru+xml", &["sru"]),
+    ("application/ssml+xml", &["ssml"]),
+    ("application/stix+json", &["stix"]),
+    ("application/swid+cbor", &["coswid"]),
+    ("application/swid+xml", &["swidtag"]),
+    ("application/tamp-apex-update", &["tau"]),
+    ("application/tamp-apex-update-confirm", &["auc"]),
+    ("application/tamp-community-update", &["tcu"]),
+    ("application/tamp-community-update-confirm", &["cuc"]),
+    ("application/tamp-error", &["ter"]),
+    ("application/tamp-sequence-adjust", &["tsa"]),
+    ("application/tamp-sequence-adjust-confirm", &["sac"]),
+    ("application/tamp-update", &["tur"]),
+    ("application/tamp-update-confirm", &["tuc"]),
+    ("application/td+json", &["jsontd"]),
+    ("application/tei+xml", &["tei", "teicorpus", "odd"]),
+    ("application/thraud+xml", &["tfi"]),
+    ("application/timestamp-query", &["tsq"]),
+    ("application/timestamp-reply", &["tsr"]),
+    ("application/timestamped-data", &["tsd"]),
+    ("application/tm+json", &["tm.jsonld", "tm.json", "jsontm"]),
+    ("application/trig", &["trig"]),
+    ("application/ttml+xml", &["ttml"]),
+    ("application/urc-grpsheet+xml", &["gsheet"]),
+    ("application/urc-ressheet+xml", &["rsheet"]),
+    ("application/urc-targetdesc+xml", &["td"]),
+    ("application/urc-uisocketdesc+xml", &["uis"]),
+    ("application/vnd.1000minds.decision-model+xml", &["1km"]),
+    ("application/vnd.3gpp.pic-bw-large", &["plb"]),
+    ("application/vnd.3gpp.pic-bw-small", &["psb"]),
+    ("application/vnd.3gpp.pic-bw-var", &["pvb"]),
+    ("application/vnd.3gpp2.sms", &["sms"]),
+    ("application/vnd.3gpp2.tcap", &["tcap"]),
+    ("application/vnd.3lightssoftware.imagescal", &["imgcal"]),
+    ("application/vnd.3m.post-it-notes", &["pwn"]),
+    ("application/vnd.accpac.simply.aso", &["aso"]),
+    ("application/vnd.accpac.simply.imp", &["imp"]),
+    ("application/vnd.acucobol", &["acu"]),
+    ("application/vnd.acucorp", &["atc", "acutc"]),
+    ("application/vnd.adobe.flash.movie", &["swf"]),
+    ("application/vnd.adobe.formscentral.fcdt", &["fcdt"]),
+    ("application/vnd.adobe.fxp", &["fxp", "fxpl"]),
+    ("application/vnd.adobe.xdp+xml", &["xdp"]),
+    ("application/vnd.afpc.modca", &["list3820", "listafp", "afp", "pseg3820"]),
+    ("application/vnd.afpc.modca-overlay", &["ovl"]),
+    ("application/vnd.afpc.modca-pagesegment", &["psg"]),
+    ("application/vnd.age", &["age"]),
+    ("application/vnd.ahead.space", &["ahead"]),
+    ("application/vnd.airzip.filesecure.azf", &["azf"]),
+    ("application/vnd.airzip.filesecure.azs", &["azs"]),
+    ("application/vnd.amazon.mobi8-ebook", &["azw3"]),
+    ("application/vnd.americandynamics.acc", &["acc"]),
+    ("application/vnd.amiga.ami", &["ami"]),
+    ("application/vnd.android.ota", &["ota"]),
+    ("application/vnd.android.package-archive", &["apk"]),
+    ("application/vnd.anki", &["apkg"]),
+    ("application/vnd.anser-web-certificate-issue-initiation", &["cii"]),
+    ("application/vnd.anser-web-funds-transfer-initiation", &["fti"]),
+    ("application/vnd.apache.arrow.file", &["arrow"]),
+    ("application/vnd.apache.arrow.stream", &["arrows"]),
+    ("application/vnd.apexlang", &["apexlang", "apex"]),
+    ("application/vnd.apple.installer+xml", &["dist", "distz", "pkg", "mpkg"]),
+    ("application/vnd.apple.keynote", &["keynote"]),
+    ("application/vnd.apple.mpegurl", &["m3u8"]),
+    ("application/vnd.apple.numbers", &["numbers"]),
+    ("application/vnd.apple.pages", &["pages"]),
+    ("application/vnd.aristanetworks.swi", &["swi"]),
+    ("application/vnd.artisan+json", &["artisan"]),
+    ("application/vnd.astraea-software.iota", &["iota"]),
+    ("application/vnd.audiograph", &["aep"]),
+    ("application/vnd.autopackage", &["package"]),
+    ("application/vnd.balsamiq.bmml+xml", &["bmml"]),
+    ("application/vnd.balsamiq.bmpr", &["bmpr"]),
+    ("application/vnd.banana-accounting", &["ac2"]),
+    ("application/vnd.belightsoft.lhzd+zip", &["lhzd"]),
+    ("application/vnd.belightsoft.lhzl+zip", &["lhzl"]),
+    ("application/vnd.blueice.multipass", &["mpm"]),
+    ("application/vnd.bluetooth.ep.oob", &["ep"]),
+    ("application/vnd.bluetooth.le.oob", &["le"]),
+    ("application/vnd.bmi", &["bmi"]),
+    ("application/vnd.businessobjects", &["rep"]),
+    ("application/vnd.cendio.thinlinc.clientconf", &["tlclient"]),
+    ("application/vnd.chemdraw+xml", &["cdxml"]),
+    ("application/vnd.chess-pgn", &["pgn"]),
+    ("application/vnd.chipnuts.karaoke-mmd", &["mmd"]),
+    ("application/vnd.cinderella", &["cdy"]),
+    ("application/vnd.citationstyles.style+xml", &["csl"]),
+    ("application/vnd.claymore", &["cla"]),
+    ("application/vnd.cloanto.rp9", &["rp9"]),
+    ("application/vnd.clonk.c4group", &["c4g", "c4d", "c4f", "c4p", "c4u"]),
+    ("application/vnd.cluetrust.cartomobile-config", &["c11amc"]),
+    ("application/vnd.cluetrust.cartomobile-config-pkg", &["c11amz"]),
+    ("application/vnd.coffeescript", &["coffee"]),
+    ("application/vnd.collabio.xodocuments.document", &["xodt"]),
+    ("application/vnd.collabio.xodocuments.document-template", &["xott"]),
+    ("application/vnd.collabio.xodocuments.presentation", &["xodp"]),
+    ("application/vnd.collabio.xodocuments.presentation-template", &["xotp"]),
+    ("application/vnd.collabio.xodocuments.spreadsheet", &["xods"]),
+    ("application/vnd.collabio.xodocuments.spreadsheet-template", &["xots"]),
+    ("application/vnd.comicbook+zip", &["cbz"]),
+    ("application/vnd.comicbook-rar", &["cbr"]),
+    ("application/vnd.commerce-battelle", &["icf", "icd", "ic0", "ic1", "ic2", "ic3", "ic4", "ic5", "ic6", "ic7", "ic8"]),
+    ("application/vnd.commonspace", &["csp", "cst"]),
+    ("application/vnd.contact.cmsg", &["cdbcmsg"]),
+    ("application/vnd.coreos.ignition+json", &["ign", "ignition"]),
+    ("application/vnd.cosmocaller", &["cmc"]),
+    ("application/vnd.crick.clicker", &["clkx"]),
+    ("application/vnd.crick.clicker.keyboard", &["clkk"]),
+    ("application/vnd.crick.clicker.palette", &["clkp"]),
+    ("application/vnd.crick.clicker.template", &["clkt"]),
+    ("application/vnd.crick.clicker.wordbank", &["clkw"]),
+    ("application/vnd.criticaltools.wbs+xml", &["wbs"]),
+    ("application/vnd.crypto-shade-file", &["ssvc"]),
+    ("application/vnd.cryptomator.encrypted", &["c9r", "c9s"]),
+    ("application/vnd.cryptomator.vault", &["cryptomator"]),
+    ("application/vnd.ctc-posml", &["pml"]),
+    ("application/vnd.cups-ppd", &["ppd"]),
+    ("application/vnd.dart", &["dart"]),
+    ("application/vnd.data-vision.rdz", &["rdz"]),
+    ("application/vnd.datalog", &["dl"]),
+    ("application/vnd.dbf", &["dbf"]),
+    ("application/vnd.debian.binary-package", &["deb", "ddeb", "udeb"]),
+    ("application/vnd.dece.data", &["uvf", "uvvf", "uvd", "uvvd"]),
+    ("application/vnd.dece.ttml+xml", &["uvt", "uvvt"]),
+    ("application/vnd.dece.unspecified", &["uvx", "uvvx"]),
+    ("application/vnd.dece.zip", &["uvz", "uvvz"]),
+    ("application/vnd.denovo.fcselayout-link", &["fe_launch"]),
+    ("application/vnd.desmume.movie", &["dsm"]),
+    ("application/vnd.dna", &["dna"]),
+    ("application/vnd.document+json", &["docjson"]),
+    ("application/vnd.doremir.scorecloud-binary-document", &["scld"]),
+    ("application/vnd.dpgraph", &["dpg", "mwc", "dpgraph"]),
+    ("application/vnd.dreamfactory", &["dfac"]),
+    ("application/vnd.dtg.local.flash", &["fla"]),
+    ("application/vnd.dvb.ait", &["ait"]),
+    ("application/vnd.dvb.service", &["svc"]),
+    ("application/vnd.dynageo", &["geo"]),
+    ("application/vnd.dzr", &["dzr"]),
+    ("application/vnd.ecowin.chart", &["mag"]),
+    ("application/vnd.eln+zip", &["eln"]),
+    ("application/vnd.enliven", &["nml"]),
+    ("application/vnd.epson.esf", &["esf"]),
+    ("application/vnd.epson.msf", &["msf"]),
+    ("application/vnd.epson.quickanime", &["qam"]),
+    ("application/vnd.epson.salt", &["slt"]),
+    ("application/vnd.epson.ssf", &["ssf"]),
+    ("application/vnd.ericsson.quickcall", &["qcall", "qca"]),
+    ("application/vnd.espass-espass+zip", &["espass"]),
+    ("application/vnd.eszigno3+xml", &["es3", "et3"]),
+    ("application/vnd.etsi.asic-e+zip", &["asice", "sce"]),
+    ("application/vnd.etsi.asic-s+zip", &["asics"]),
+    ("application/vnd.etsi.timestamp-token", &["tst"]),
+    ("application/vnd.eu.kasparian.car+json", &["carjson"]),
+    ("application/vnd.evolv.ecig.profile", &["ecigprofile"]),
+    ("application/vnd.evolv.ecig.settings", &["ecig"]),
+    ("application/vnd.evolv.ecig.theme", &["ecigtheme"]),
+    ("application/vnd.exstream-empower+zip", &["mpw"]),
+    ("application/vnd.exstream-package", &["pub"]),
+    ("application/vnd.ezpix-album", &["ez2"]),
+    ("application/vnd.ezpix-package", &["ez3"]),
+    ("application/vnd.familysearch.gedcom+zip", &["gdz"]),
+    ("application/vnd.fastcopy-disk-image", &["dim"]),
+    ("application/vnd.fdsn.mseed", &["msd", "mseed"]),
+    ("application/vnd.fdsn.seed", &["seed", "dataless"]),
+    ("application/vnd.ficlab.flb+zip", &["flb"]),
+    ("application/vnd.filmit.zfc", &["zfc"]),
+    ("application/vnd.flographit", &["gph"]),
+    ("application/vnd.fluxtime.clip", &["ftc"]),
+    ("application/vnd.font-fontforge-sfd", &["sfd"]),
+    ("application/vnd.framemaker", &["fm"]),
+    ("application/vnd.fsc.weblaunch", &["fsc"]),
+    ("application/vnd.fujitsu.oasys", &["oas"]),
+    ("application/vnd.fujitsu.oasys2", &["oa2"]),
+    ("application/vnd.fujitsu.oasys3", &["oa3"]),
+    ("application/vnd.fujitsu.oasysgp", &["fg5"]),
+    ("application/vnd.fujitsu.oasysprs", &["bh2"]),
+    ("application/vnd.fujixerox.ddd", &["ddd"]),
+    ("application/vnd.fujixerox.docuworks", &["xdw"]),
+    ("application/vnd.fujixerox.docuworks.binder", &["xbd"]),
+    ("application/vnd.fujixerox.docuworks.container", &["xct"]),
+    ("application/vnd.fuzzysheet", &["fzs"]),
+    ("application/vnd.genomatix.tuxedo", &["txd"]),
+    ("application/vnd.genozip", &["genozip"]),
+    ("application/vnd.gentics.grd+json", &["grd"]),
+    ("application/vnd.gentoo.ebuild", &["ebuild"]),
+    ("application/vnd.gentoo.eclass", &["eclass"]),
+    ("application/vnd.gentoo.gpkg", &["gpkg.tar"]),
+    ("application/vnd.gentoo.xpak", &["xpak"]),
+    ("application/vnd.geogebra.file", &["ggb"]),
+    ("application/vnd.geogebra.slides", &["ggs"]),
+    ("application/vnd.geogebra.tool", &["ggt"]),
+    ("application/vnd.geometry-explorer", &["gex", "gre"]),
+    ("application/vnd.geonext", &["gxt"]),
+    ("application/vnd.geoplan", &["g2w"]),
+    ("application/vnd.geospace", &["g3w"]),
+    ("application/vnd.google-earth.kml+xml", &["kml"]),
+    ("application/vnd.google-earth.kmz", &["kmz"]),
+    ("application/vnd.grafeq", &["gqf", "gqs"]),
+    ("application/vnd.groove-account", &["gac"]),
+    ("application/vnd.groove-help", &["ghf"]),
+    ("application/vnd.groove-identity-message", &["gim"]),
+    ("application/vnd.groove-injector", &["grv"]),
+    ("application/vnd.groove-tool-message", &["gtm"]),
+    ("application/vnd.groove-tool-template", &["tpl"]),
+    ("application/vnd.groove-vcard", &["vcg"]),
+    ("application/vnd.hal+xml", &["hal"]),
+    ("application/vnd.handheld-entertainment+xml", &["zmm"]),
+    ("application/vnd.hbci", &["hbci", "hbc", "kom", "upa", "pkd", "bpd"]),
+    ("application/vnd.hdt", &["hdt"]),
+    ("application/vnd.hhe.lesson-player", &["les"]),
+    ("application/vnd.hp-hpgl", &["hpgl"]),
+    ("application/vnd.hp-hpid", &["hpi", "hpid"]),
+    ("application/vnd.hp-hps", &["hps"]),
+    ("application/vnd.hp-jlyt", &["jlt"]),
+    ("application/vnd.hp-pcl", &["pcl"]),
+    ("application/vnd.hydrostatix.sof-data", &["sfd-hdstx"]),
+    ("application/vnd.ibm.electronic-media", &["emm"]),
+    ("application/vnd.ibm.minipay", &["mpy"]),
+    ("application/vnd.ibm.rights-management", &["irm"]),
+    ("application/vnd.ibm.secure-container", &["sc"]),
+    ("application/vnd.iccprofile", &["icc", "icm"]),
+    ("application/vnd.ieee.1905", &["1905.1"]),
+    ("application/vnd.igloader", &["igl"]),
+    ("application/vnd.imagemeter.folder+zip", &["imf"]),
+    ("application/vnd.imagemeter.image+zip", &["imi"]),
+    ("application/vnd.immervision-ivp", &["ivp"]),
+    ("application/vnd.immervision-ivu", &["ivu"]),
+    ("application/vnd.ims.imsccv1p1", &["imscc"]),
+    ("application/vnd.insors.igm", &["igm"]),
+    ("application/vnd.intercon.formnet", &["xpw", "xpx"]),
+    ("application/vnd.intergeo", &["i2g"]),
+    ("application/vnd.intu.qbo", &["qbo"]),
+    ("application/vnd.intu.qfx", &["qfx"]),
+    ("application/vnd.ipld.car", &["car"]),
+    ("application/vnd.ipunplugged.rcprofile", &["rcprofile"]),
+    ("application/vnd.irepository.package+xml", &["irp"]),
+    ("application/vnd.is-xpr", &["xpr"]),
+    ("application/vnd.isac.fcs", &["fcs"]),
+    ("application/vnd.jam", &["jam"]),
+    ("application/vnd.jcp.javame.midlet-rms", &["rms"]),
+    ("application/vnd.jisp", &["jisp"]),
+    ("application/vnd.joost.joda-archive", &["joda"]),
+    ("application/vnd.kahootz", &["ktz", "ktr"]),
+    ("application/vnd.kde.karbon", &["karbon"]),
+    ("application/vnd.kde.kchart", &["chrt"]),
+    ("application/vnd.kde.kformula", &["kfo"]),
+    ("application/vnd.kde.kivio", &["flw"]),
+    ("application/vnd.kde.kontour", &["kon"]),
+    ("application/vnd.kde.kpresenter", &["kpr", "kpt"]),
+    ("application/vnd.kde.kspread", &["ksp"]),
+    ("application/vnd.kde.kword", &["kwd", "kwt"]),
+    ("application/vnd.kenameaapp", &["htke"]),
+    ("application/vnd.kidspiration", &["kia"]),
+    ("application/vnd.kinar", &["kne", "knp", "sdf"]),
+    ("application/vnd.koan", &["skp", "skd", "skm", "skt"]),
+    ("application/vnd.kodak-descriptor", &["sse"]),
+    ("application/vnd.las", &["las"]),
+    ("application/vnd.las.las+json", &["lasjson"]),
+    ("application/vnd.las.las+xml", &["lasxml"]),
+    ("application/vnd.llamagraphics.life-balance.desktop", &["lbd"]),
+    ("application/vnd.llamagraphics.life-balance.exchange+xml", &["lbe"]),
+    ("application/vnd.logipipe.circuit+zip", &["lcs", "lca"]),
+    ("application/vnd.loom", &["loom"]),
+    ("application/vnd.lotus-1-2-3", &["123", "wk4", "wk3", "wk1"]),
+    ("application/vnd.lotus-approach", &["apr", "vew"]),
+    ("application/vnd.lotus-freelance", &["prz", "pre"]),
+    ("application/vnd.lotus-notes", &["nsf", "ntf", "ndl", "ns4", "ns3", "ns2", "nsh", "nsg"]),
+    ("application/vnd.lotus-organizer", &["or3", "or2", "org"]),
+    ("application/vnd.lotus-screencam", &["scm"]),
+    ("application/vnd.lotus-wordpro", &["lwp", "sam"]),
+    ("application/vnd.macports.portpkg", &["portpkg"]),
+    ("application/vnd.mapbox-vector-tile", &["mvt"]),
+    ("application/vnd.marlin.drm.mdcf", &["mdc"]),
+    ("application/vnd.maxar.archive.3tz+zip", &["3tz"]),
+    ("application/vnd.maxmind.maxmind-db", &["mmdb"]),
+    ("application/vnd.mcd", &["mcd"]),
+    ("application/vnd.medcalcdata", &["mc1"]),
+    ("application/vnd.mediastation.cdkey", &["cdkey"]),
+    ("application/vnd.medicalholodeck.recordxr", &["rxt"]),
+    ("application/vnd.mfer", &["mwf"]),
+    ("application/vnd.mfmp", &["mfm"]),
+    ("application/vnd.micrografx.flo", &["flo"]),
+    ("application/vnd.micrografx.igx", &["igx"]),
+    ("application/vnd.mif", &["mif"]),
+    ("application/vnd.mobius.daf", &["daf"]),
+    ("application/vnd.mobius.dis", &["dis"]),
+    ("application/vnd.mobius.mbk", &["mbk"]),
+    ("application/vnd.mobius.mqy", &["mqy"]),
+    ("application/vnd.mobius.msl", &["msl"]),
+    ("application/vnd.mobius.plc", &["plc"]),
+    ("application/vnd.mobius.txf", &["txf"]),
+    ("application/vnd.mophun.application", &["mpn"]),
+    ("application/vnd.mophun.certificate", &["mpc"]),
+    ("application/vnd.mozilla.xul+xml", &["xul"]),
+    ("application/vnd.ms-3mfdocument", &["3mf"]),
+    ("application/vnd.ms-artgalry", &["cil"]),
+    ("application/vnd.ms-asf", &["asf"]),
+    ("application/vnd.ms-cab-compressed", &["cab"]),
+    ("application/vnd.ms-excel", &["xls", "xlm", "xla", "xlc", "xlt", "xlw"]),
+    ("application/vnd.ms-excel.addin.macroenabled.12", &["xlam"]),
+    ("application/vnd.ms-excel.sheet.binary.macroenabled.12", &["xlsb"]),
+    ("application/vnd.ms-excel.sheet.macroenabled.12", &["xlsm"]),
+    ("application/vnd.ms-excel.template.macroenabled.12", &["xltm"]),
+    ("application/vnd.ms-fontobject", &["eot"]),
+    ("application/vnd.ms-htmlhelp", &["chm"]),
+    ("application/vnd.ms-ims", &["ims"]),
+    ("application/vnd.ms-lrm", &["lrm"]),
+    ("application/vnd.ms-officetheme", &["thmx"]),
+    ("application/vnd.ms-pki.seccat", &["cat"]),
+    ("application/vnd.ms-powerpoint", &["ppt", "pps"]),
+    ("application/vnd.ms-powerpoint.addin.macroenabled.12", &["ppam"]),
+    ("application/vnd.ms-powerpoint.presentation.macroenabled.12", &["pptm"]),
+    ("application/vnd.ms-powerpoint.slide.macroenabled.12", &["sldm"]),
+    ("application/vnd.ms-powerpoint.slideshow.macroenabled.12", &["ppsm"]),
+    ("application/vnd.ms-powerpoint.template.macroenabled.12", &["potm"]),
+    ("application/vnd.ms-project", &["mpp", "mpt"]),
+    ("application/vnd.ms-tnef", &["tnef", "tnf"]),
+    ("application/vnd.ms-word.document.macroenabled.12", &["docm"]),
+    ("application/vnd.ms-word.template.macroenabled.12", &["dotm"]),
+    ("application/vnd.ms-works", &["wcm", "wdb", "wks", "wps"]),
+    ("application/vnd.ms-wpl", &["wpl"]),
+    ("application/vnd.ms-xpsdocument", &["xps"]),
+    ("application/vnd.msa-disk-image", &["msa"]),
+    ("application/vnd.mseq", &["mseq"]),
+    ("application/vnd.multiad.creator", &["crtr"]),
+    ("application/vnd.multiad.creator.cif", &["cif"]),
+    ("application/vnd.musician", &["mus"]),
+    ("application/vnd.muvee.style", &["msty"]),
+    ("application/vnd.mynfc", &["taglet"]),
+    ("application/vnd.nebumind.line", &["nebul", "line"]),
+    ("application/vnd.nervana", &["entity", "request", "bkm", "kcm"]),
+    ("application/vnd.neurolanguage.nlu", &["nlu"]),
+    ("application/vnd.nimn", &["nimn"]),
+    ("application/vnd.nintendo.nitro.rom", &["nds"]),
+    ("application/vnd.nintendo.snes.rom", &["sfc", "smc"]),
+    ("application/vnd.nitf", &["nitf"]),
+    ("application/vnd.noblenet-directory", &["nnd"]),
+    ("application/vnd.noblenet-sealer", &["nns"]),
+    ("application/vnd.noblenet-web", &["nnw"]),
+    ("application/vnd.nokia.n-gage.data", &["ngdat"]),
+    ("application/vnd.nokia.radio-preset", &["rpst"]),
+    ("application/vnd.nokia.radio-presets", &["rpss"]),
+    ("application/vnd.novadigm.edm", &["edm"]),
+    ("application/vnd.novadigm.edx", &["edx"]),
+    ("application/vnd.novadigm.ext", &["ext"]),
+    ("application/vnd.oasis.opendocument.base", &["odb"]),
+    ("application/vnd.oasis.opendocument.chart", &["odc"]),
+    ("application/vnd.oasis.opendocument.chart-template", &["otc"]),
+    ("application/vnd.oasis.opendocument.formula", &["odf"]),
+    ("application/vnd.oasis.opendocument.graphics", &["odg"]),
+    ("application/vnd.oasis.opendocument.graphics-template", &["otg"]),
+    ("application/vnd.oasis.opendocument.image", &["odi"]),
+    ("application/vnd.oasis.opendocument.image-template", &["oti"]),
+    ("application/vnd.oasis.opendocument.presentation", &["odp"]),
+    ("application/vnd.oasis.opendocument.presentation-template", &["otp"]),
+    ("application/vnd.oasis.opendocument.spreadsheet", &["ods"]),
+    ("application/vnd.oasis.opendocument.spreadsheet-template", &["ots"]),
+    ("application/vnd.oasis.opendocument.text", &["odt"]),
+    ("application/vnd.oasis.opendocument.text-master", &["odm"]),
+    ("application/vnd.oasis.opendocument.text-template", &["ott"]),
+    ("application/vnd.oasis.opendocument.text-web", &["oth"]),
+    ("application/vnd.olpc-sugar", &["xo"]),
+    ("application/vnd.oma.dd2+xml", &["dd2"]),
+    ("application/vnd.onepager", &["tam"]),
+    ("application/vnd.onepagertamp", &["tamp"]),
+    ("application/vnd.onepagertamx", &["tamx"]),
+    ("application/vnd.onepagertat", &["tat"]),
+    ("application/vnd.onepagertatp", &["tatp"]),
+    ("application/vnd.onepagertatx", &["tatx"]),
+    ("application/vnd.openblox.game+xml", &["obgx"]),
+    ("application/vnd.openblox.game-binary", &["obg"]),
+    ("application/vnd.openeye.oeb", &["oeb"]),
+    ("application/vnd.openofficeorg.extension", &["oxt"]),
+    ("application/vnd.openstreetmap.data+xml", &["osm"]),
+    ("application/vnd.openxmlformats-officedocument.presentationml.presentation", &["pptx"]),
+    ("application/vnd.openxmlformats-officedocument.presentationml.slide", &["sldx"]),
+    ("application/vnd.openxmlformats-officedocument.presentationml.slideshow", &["ppsx"]),
+    ("application/vnd.openxmlformats-officedocument.presentationml.template", &["potx"]),
+    ("application/vnd.openxmlformats-officedocument.spreadsheetml.sheet", &["xlsx"]),
+    ("application/vnd.openxmlformats-officedocument.spreadsheetml.template", &["xltx"]),
+    ("application/vnd.openxmlformats-officedocument.wordprocessingml.document", &["docx"]),
+    ("application/vnd.openxmlformats-officedocument.wordprocessingml.template", &["dotx"]),
+    ("application/vnd.osa.netdeploy", &["ndc"]),
+    ("application/vnd.osgeo.mapguide.package", &["mgp"]),
+    ("application/vnd.osgi.dp", &["dp"]),
+    ("application/vnd.osgi.subsystem", &["esa"]),
+    ("application/vnd.oxli.countgraph", &["oxlicg"]),
+    ("application/vnd.palm", &["pdb", "pqa", "oprc"]),
+    ("application/vnd.panoply", &["plp"]),
+    ("application/vnd.patentdive", &["dive"]),
+    ("application/vnd.pawaafile", &["paw"]),
+    ("application/vnd.pg.format", &["str"]),
+    ("application/vnd.pg.osasli", &["ei6"]),
+    ("application/vnd.piaccess.application-licence", &["pil"]),
+    ("application/vnd.picsel", &["efif"]),
+    ("application/vnd.pmi.widget", &["wg"]),
+    ("application/vnd.pocketlearn", &["plf"]),
+    ("application/vnd.powerbuilder6", &["pbd"]),
+    ("application/vnd.preminet", &["preminet"]),
+    ("application/vnd.previewsystems.box", &["box", "vbox"]),
+    ("application/vnd.proteus.magazine", &["mgz"]),
+    ("application/vnd.psfs", &["psfs"]),
+    ("application/vnd.publishare-delta-tree", &["qps"]),
+    ("application/vnd.pvi.ptid1", &["ptid"]),
+    ("application/vnd.qualcomm.brew-app-res", &["bar"]),
+    ("application/vnd.quark.quarkxpress", &["qxd", "qxt", "qwd", "qwt", "qxl", "qxb"]),
+    ("application/vnd.quobject-quoxdocument", &["quox", "quiz"]),
+    ("application/vnd.rainstor.data", &["tree"]),
+    ("application/vnd.rar", &["rar"]),
+    ("application/vnd.realvnc.bed", &["bed"]),
+    ("application/vnd.recordare.musicxml", &["mxl"]),
+    ("application/vnd.resilient.logic", &["rlm", "reload"]),
+    ("application/vnd.rig.cryptonote", &["cryptonote"]),
+    ("application/vnd.rim.cod", &["cod"]),
+    ("application/vnd.route66.link66+xml", &["link66"]),
+    ("application/vnd.sailingtracker.track", &["st"]),
+    ("application/vnd.sar", &["sar"]),
+    ("application/vnd.scribus", &["scd", "sla", "slaz"]),
+    ("application/vnd.sealed.3df", &["s3df"]),
+    ("application/vnd.sealed.csf", &["scsf"]),
+    ("application/vnd.sealed.doc", &["sdoc", "sdo", "s1w"]),
+    ("application/vnd.sealed.eml", &["seml", "sem"]),
+    ("application/vnd.sealed.mht", &["smht", "smh"]),
+    ("application/vnd.sealed.ppt", &["sppt", "s1p"]),
+    ("application/vnd.sealed.tiff", &["stif"]),
+    ("application/vnd.sealed.xls", &["sxls", "sxl", "s1e"]),
+    ("application/vnd.sealedmedia.softseal.html", &["stml", "s1h"]),
+    ("application/vnd.sealedmedia.softseal.pdf", &["spdf", "spd", "s1a"]),
+    ("application/vnd.seemail", &["see"]),
+    ("application/vnd.sema", &["sema"]),
+    ("application/vnd.semd", &["semd"]),
+    ("application/vnd.semf", &["semf"]),
+    ("application/vnd.shade-save-file", &["ssv"]),
+    ("application/vnd.shana.informed.formdata", &["ifm"]),
+    ("application/vnd.shana.informed.formtemplate", &["itp"]),
+    ("application/vnd.shana.informed.interchange", &["iif"]),
+    ("application/vnd.shana.informed.package", &["ipk"]),
+    ("application/vnd.shp", &["shp"]),
+    ("application/vnd.shx", &["shx"]),
+    ("application/vnd.sigrok.session", &["sr"]),
+    ("application/vnd.simtech-mindmapper", &["twd", "twds"]),
+    ("application/vnd.smaf", &["mmf"]),
+    ("application/vnd.smart.notebook", &["notebook"]),
+    ("application/vnd.smart.teacher", &["teacher"]),
+    ("application/vnd.snesdev-page-table", &["ptrom", "pt"]),
+    ("application/vnd.software602.filler.form+xml", &["fo"]),
+    ("application/vnd.software602.filler.form-xml-zip", &["zfo"]),
+    ("application/vnd.solent.sdkm+xml", &["sdkm", "sdkd"]),
+    ("application/vnd.spotfire.dxp", &["dxp"]),
+    ("application/vnd.spotfire.sfs", &["sfs"]),
+    ("application/vnd.sqlite3", &["sqlite", "sqlite3"]),
+    ("application/vnd.stardivision.calc", &["sdc"]),
+    ("application/vnd.stardivision.chart", &["sds"]),
+    ("application/vnd.stardivision.draw", &["sda"]),
+    ("application/vnd.stardivision.impress", &["sdd"]),
+    ("application/vnd.stardivision.math", &["smf"]),
+    ("application/vnd.stardivision.writer", &["sdw"]),
+    ("application/vnd.stardivision.writer-global", &["sgl"]),
+    ("application/vnd.stepmania.package", &["smzip"]),
+    ("application/vnd.stepmania.stepchart", &["sm"]),
+    ("application/vnd.sun.wadl+xml", &["wadl"]),
+    ("application/vnd.sun.xml.calc", &["sxc"]),
+    ("application/vnd.sun.xml.calc.template", &["stc"]),
+    ("application/vnd.sun.xml.draw", &["sxd"]),
+    ("application/vnd.sun.xml.draw.template", &["std"]),
+    ("application/vnd.sun.xml.impress", &["sxi"]),
+    ("application/vnd.sun.xml.impress.template", &["sti"]),
+    ("application/vnd.sun.xml.math", &["sxm"]),
+    ("application/vnd.sun.xml.writer", &["sxw"]),
+    ("application/vnd.sun.xml.writer.global", &["sxg"]),
+    ("application/vnd.sun.xml.writer.template", &["stw"]),
+    ("application/vnd.sus-calendar", &["sus", "susp"]),
+    ("application/vnd.sybyl.mol2", &["ml2", "mol2", "sy2"]),
+    ("application/vnd.sycle+xml", &["scl"]),
+    ("application/vnd.syft+json", &["syft.json"]),
+    ("application/vnd.symbian.install", &["sis"]),
+    ("application/vnd.syncml+xml", &["xsm"]),
+    ("application/vnd.syncml.dm+wbxml", &["bdm"]),
+    ("application/vnd.syncml.dm+xml", &["xdm"]),
+    ("application/vnd.syncml.dmddf+xml", &["ddf"]),
+    ("application/vnd.tao.intent-module-archive", &["tao"]),
+    ("application/vnd.tcpdump.pcap", &["pcap", "cap", "dmp"]),
+    ("application/vnd.theqvd", &["qvd"]),
+    ("application/vnd.think-cell.ppttc+json", &["ppttc"]),
+    ("application/vnd.tml", &["vfr", "viaframe"]),
+    ("application/vnd.tmobile-livetv", &["tmo"]),
+    ("application/vnd.trid.tpt", &["tpt"]),
+    ("application/vnd.triscape.mxs", &["mxs"]),
+    ("application/vnd.trueapp", &["tra"]),
+    ("application/vnd.ufdl", &["ufdl", "ufd", "frm"]),
+    ("application/vnd.uiq.theme", &["utz"]),
+    ("application/vnd.umajin", &["umj"]),
+    ("application/vnd.unity", &["unityweb"]),
+    ("application/vnd.uoml+xml", &["uoml", "uo"]),
+    ("application/vnd.uri-map", &["urim", "urimap"]),
+    ("application/vnd.valve.source.material", &["vmt"]),
+    ("application/vnd.vcx", &["vcx"]),
+    ("application/vnd.vd-study", &["mxi", "study-inter", "model-inter"]),
+    ("application/vnd.vectorworks", &["vwx"]),
+    ("application/vnd.veritone.aion+json", &["aion", "vtnstd"]),
+    ("application/vnd.veryant.thin", &["istc", "isws"]),
+    ("application/vnd.ves.encrypted", &["ves"]),
+    ("application/vnd.vidsoft.vidconference", &["vsc"]),
+    ("application/vnd.visio", &["vsd", "vst", "vsw", "vss"]),
+    ("application/vnd.visionary", &["vis"]),
+    ("application/vnd.vsf", &["vsf"]),
+    ("application/vnd.wap.sic", &["sic"]),
+    ("application/vnd.wap.slc", &["slc"]),
+    ("application/vnd.wap.wbxml", &["wbxml"]),
+    ("application/vnd.wap.wmlc", &["wmlc"]),
+    ("application/vnd.wap.wmlscriptc", &["wmlsc"]),
+    ("application/vnd.wasmflow.wafl", &["wafl"]),
+    ("application/vnd.webturbo", &["wtb"]),
+    ("application/vnd.wfa.p2p", &["p2p"]),
+    ("application/vnd.wfa.wsc", &["wsc"]),
+    ("application/vnd.wmc", &["wmc"]),
+    ("application/vnd.wolfram.mathematica", &["nb"]),
+    ("application/vnd.wolfram.mathematica.package", &["m"]),
+    ("application/vnd.wolfram.player", &["nbp"]),
+    ("application/vnd.wordperfect", &["wpd"]),
+    ("application/vnd.wqd", &["wqd"]),
+    ("application/vnd.wt.stf", &["stf"]),
+    ("application/vnd.wv.csp+wbxml", &["wv"]),
+    ("application/vnd.xara", &["xar"]),
+    ("application/vnd.xfdl", &["xfdl", "xfd"]),
+    ("application/vnd.xmpie.cpkg", &["cpkg"]),
+    ("application/vnd.xmpie.dpkg", &["dpkg"]),
+    ("application/vnd.xmpie.ppkg", &["ppkg"]),
+    ("application/vnd.xmpie.xlim", &["xlim"]),
+    ("application/vnd.yamaha.hv-dic", &["hvd"]),
+    ("application/vnd.yamaha.hv-script", &["hvs"]),
+    ("application/vnd.yamaha.hv-voice", &["hvp"]),
+    ("application/vnd.yamaha.openscoreformat", &["osf"]),
+    ("application/vnd.yamaha.smaf-audio", &["saf"]),
+    ("application/vnd.yamaha.smaf-phrase", &["spf"]),
+    ("application/vnd.yaoweme", &["yme"]),
+    ("application/vnd.yellowriver-custom-menu", &["cmp"]),
+    ("application/vnd.zul", &["zir", "zirz"]),
+    ("application/vnd.zzazz.deck+xml", &["zaz"]),
+    ("application/voicexml+xml", &["vxml"]),
+    ("application/voucher-cms+json", &["vcj"]),
+    ("application/wasm", &["wasm"]),
+    ("application/watcherinfo+xml", &["wif"]),
+    ("application/widget", &["wgt"]),
+    ("application/wsdl+xml", &["wsdl"]),
+    ("application/wspolicy+xml", &["wspolicy"]),
+    ("application/x-123", &["wk"]),
+    ("application/x-7z-compressed", &["7z"]),
+    ("application/x-abiword", &["abw"]),
+    ("application/x-apple-diskimage", &["dmg"]),
+    ("application/x-bcpio", &["bcpio"]),
+    ("application/x-bittorrent", &["torrent"]),
+    ("application/x-cdf", &["cdf", "cda"]),
+    ("application/x-cdlink", &["vcd"]),
+    ("application/x-cocoa", &["cco"]),
+    ("application/x-comsol", &["mph"]),
+    ("application/x-cpio", &["cpio"]),
+    ("application/x-csh", &["csh"]),
+    ("application/x-director", &["dcr", "dir", "dxr"]),
+    ("application/x-doom", &["wad"]),
+    ("application/x-dvi", &["dvi"]),
+    ("application/x-font", &["pfa", "pfb", "gsf"]),
+    ("application/x-font-pcf", &["pcf", "pcf.z"]),
+    ("application/x-freemind", &["mm"]),
+    ("application/x-ganttproject", &["gan"]),
+    ("application/x-gnumeric", &["gnumeric"]),
+    ("application/x-go-sgf", &["sgf"]),
+    ("application/x-graphing-calculator", &["gcf"]),
+    ("application/x-gtar", &["gtar"]),
+    ("application/x-gtar-compressed", &["tgz", "taz"]),
+    ("application/x-hdf", &["hdf"]),
+    ("application/x-hwp", &["hwp"]),
+    ("application/x-ica", &["ica"]),
+    ("application/x-info", &["info"]),
+    ("application/x-internet-signup", &["ins", "isp"]),
+    ("application/x-iphone", &["iii"]),
+    ("application/x-iso9660-image", &["iso"]),
+    ("application/x-java-archive-diff", &["jardiff"]),
+    ("application/x-java-jnlp-file", &["jnlp"]),
+    ("application/x-jmol", &["jmz"]),
+    ("application/x-killustrator", &["kil"]),
+    ("application/x-latex", &["latex"]),
+    ("application/x-lha", &["lha"]),
+    ("application/x-lyx", &["lyx"]),
+    ("application/x-lzh", &["lzh"]),
+    ("application/x-lzx", &["lzx"]),
+    ("application/x-maker", &["frm", "maker", "frame", "fm", "fb", "book", "fbdoc"]),
+    ("application/x-makeself", &["run"]),
+    ("application/x-ms-wmd", &["wmd"]),
+    ("application/x-ms-wmz", &["wmz"]),
+    ("application/x-msdos-program", &["com", "exe", "bat", "dll"]),
+    ("application/x-msi", &["msi"]),
+    ("application/x-netcdf", &["nc"]),
+    ("application/x-ns-proxy-autoconfig", &["pac"]),
+    ("application/x-nwc", &["nwc"]),
+    ("application/x-object", &["o"]),
+    ("application/x-oz-application", &["oza"]),
+    ("application/x-perl", &["pl", "pm"]),
+    ("application/x-pilot", &["prc", "pdb"]),
+    ("application/x-pkcs7-certreqresp", &["p7r"]),
+    ("application/x-python-code", &["pyc", "pyo"]),
+    ("application/x-qgis", &["qgs", "shp", "shx"]),
+    ("application/x-quicktimeplayer", &["qtl"]),
+    ("application/x-rar-compressed", &["rar"]),
+    ("application/x-rdp", &["rdp"]),
+    ("application/x-redhat-package-manager", &["rpm"]),
+    ("application/x-rss+xml", &["rss"]),
+    ("application/x-ruby", &["rb"]),
+    ("application/x-scilab", &["sci", "sce"]),
+    ("application/x-scilab-xcos", &["xcos"]),
+    ("application/x-sea", &["sea"]),
+    ("application/x-sh", &["sh"]),
+    ("application/x-shar", &["shar"]),
+    ("application/x-shockwave-flash", &["swf"]),
+    ("application/x-silverlight", &["scr"]),
+    ("application/x-stuffit", &["sit", "sitx"]),
+    ("application/x-sv4cpio", &["sv4cpio"]),
+    ("application/x-sv4crc", &["sv4crc"]),
+    ("application/x-tar", &["tar"]),
+    ("application/x-tcl", &["tcl", "tk"]),
+    ("application/x-tex-gf", &["gf"]),
+    ("application/x-tex-pk", &["pk"]),
+    ("application/x-texinfo", &["texinfo", "texi"]),
+    ("application/x-trash", &["~", "%", "bak", "old", "sik"]),
+    ("application/x-troff-man", &["man"]),
+    ("application/x-troff-me", &["me"]),
+    ("application/x-troff-ms", &["ms"]),
+    ("application/x-ustar", &["ustar"]),
+    ("application/x-wais-source", &["src"]),
+    ("application/x-wingz", &["wz"]),
+    ("application/x-x509-ca-cert", &["crt", "der", "pem"]),
+    ("application/x-xfig", &["fig"]),
+    ("application/x-xpinstall", &["xpi"]),
+    ("application/x-xz", &["xz"]),
+    ("application/xcap-att+xml", &["xav"]),
+    ("application/xcap-caps+xml", &["xca"]),
+    ("application/xcap-diff+xml", &["xdf"]),
+    ("application/xcap-el+xml", &["xel"]),
+    ("application/xcap-error+xml", &["xer"]),
+    ("application/xcap-ns+xml", &["xns"]),
+    ("application/xfdf", &["xfdf"]),
+    ("application/xhtml+xml", &["xhtml", "xhtm", "xht"]),
+    ("application/xliff+xml", &["xlf"]),
+    ("application/xml", &["xml"]),
+    ("application/xml-dtd", &["dtd", "mod"]),
+    ("application/xml-external-parsed-entity", &["ent"]),
+    ("application/xop+xml", &["xop"]),
+    ("application/xslt+xml", &["xsl", "xslt"]),
+    ("application/xspf+xml", &["xspf"]),
+    ("application/xv+xml", &["mxml", "xhvml", "xvml", "xvm"]),
+    ("application/yang", &["yang"]),
+    ("application/yin+xml", &["yin"]),
+    ("application/zip", &["zip"]),
+    ("application/zstd", &["zst"]),
+    ("audio/32kadpcm", &["726"]),
+    ("audio/aac", &["adts", "aac", "ass"]),
+    ("audio/ac3", &["ac3"]),
+    ("audio/amr", &["amr"]),
+    ("audio/amr-wb", &["awb"]),
+    ("audio/annodex", &["axa"]),
+    ("audio/asc", &["acn"]),
+    ("audio/atrac-advanced-lossless", &["aal"]),
+    ("audio/atrac-x", &["atx"]),
+    ("audio/atrac3", &["at3", "aa3", "omg"]),
+    ("audio/basic", &["au", "snd"]),
+    ("audio/csound", &["csd", "orc", "sco"]),
+    ("audio/dls", &["dls"]),
+    ("audio/evrc", &["evc"]),
+    ("audio/evrc-qcp", &["qcp"]),
+    ("audio/evrcb", &["evb"]),
+    ("audio/evrcnw", &["enw"]),
+    ("audio/evrcwb", &["evw"]),
+    ("audio/flac", &["flac"]),
+    ("audio/ilbc", &["lbc"]),
+    ("audio/l16", &["l16"]),
+    ("audio/mhas", &["mhas"]),
+    ("audio/midi", &["mid", "midi", "kar"]),
+    ("audio/mobile-xmf", &["mxmf"]),
+    ("audio/mp4", &["m4a"]),
+    ("audio/mpeg", &["mpga", "mpega", "mp1", "mp2", "mp3"]),
+    ("audio/mpegurl", &["m3u"]),
+    ("audio/ogg", &["oga", "ogg", "opus", "spx"]),
+    ("audio/prs.sid", &["sid", "psid"]),
+    ("audio/smv", &["smv"]),
+    ("audio/sofa", &["sofa"]),
+    ("audio/sp-midi", &["mid"]),
+    ("audio/usac", &["loas", "xhe"]),
+    ("audio/vnd.audiokoz", &["koz"]),
+    ("audio/vnd.dece.audio", &["uva", "uvva"]),
+    ("audio/vnd.digital-winds", &["eol"]),
+    ("audio/vnd.dolby.mlp", &["mlp"]),
+    ("audio/vnd.dts", &["dts"]),
+    ("audio/vnd.dts.hd", &["dtshd"]),
+    ("audio/vnd.everad.plj", &["plj"]),
+    ("audio/vnd.lucent.voice", &["lvp"]),
+    ("audio/vnd.ms-playready.media.pya", &["pya"]),
+    ("audio/vnd.nortel.vbk", &["vbk"]),
+    ("audio/vnd.nuera.ecelp4800", &["ecelp4800"]),
+    ("audio/vnd.nuera.ecelp7470", &["ecelp7470"]),
+    ("audio/vnd.nuera.ecelp9600", &["ecelp9600"]),
+    ("audio/vnd.presonus.multitrack", &["multitrack"]),
+    ("audio/vnd.rip", &["rip"]),
+    ("audio/vnd.sealedmedia.softseal.mpeg", &["smp3", "smp", "s1m"]),
+    ("audio/x-aiff", &["aif", "aiff", "aifc"]),
+    ("audio/x-gsm", &["gsm"]),
+    ("audio/x-m4a", &["m4a"]),
+    ("audio/x-ms-wax", &["wax"]),
+    ("audio/x-ms-wma", &["wma"]),
+    ("audio/x-pn-realaudio", &["ra", "rm", "ram"]),
+    ("audio/x-realaudio", &["ra"]),
+    ("audio/x-scpls", &["pls"]),
+    ("audio/x-sd2", &["sd2"]),
+    ("audio/x-wav", &["wav"]),
+    ("chemical/x-alchemy", &["alc"]),
+    ("chemical/x-cache", &["cac", "cache"]),
+    ("chemical/x-cache-csf", &["csf"]),
+    ("chemical/x-cactvs-binary", &["cbin", "cascii", "ctab"]),
+    ("chemical/x-cdx", &["cdx"]),
+    ("chemical/x-chem3d", &["c3d"]),
+    ("chemical/x-chemdraw", &["chm"]),
+    ("chemical/x-cif", &["cif"]),
+    ("chemical/x-cmdf", &["cmdf"]),
+    ("chemical/x-cml", &["cml"]),
+    ("chemical/x-compass", &["cpa"]),
+    ("chemical/x-crossfire", &["bsd"]),
+    ("chemical/x-csml", &["csml", "csm"]),
+    ("chemical/x-ctx", &["ctx"]),
+    ("chemical/x-cxf", &["cxf", "cef"]),
+    ("chemical/x-embl-dl-nucleotide", &["emb", "embl"]),
+    ("chemical/x-galactic-spc", &["spc"]),
+    ("chemical/x-gamess-input", &["inp", "gam", "gamin"]),
+    ("chemical/x-gaussian-checkpoint", &["fch", "fchk"]),
+    ("chemical/x-gaussian-cube", &["cub"]),
+    ("chemical/x-gaussian-input", &["gau", "gjc", "gjf"]),
+    ("chemical/x-gaussian-log", &["gal"]),
+    ("chemical/x-gcg8-sequence", &["gcg"]),
+    ("chemical/x-genbank", &["gen"]),
+    ("chemical/x-hin", &["hin"]),
+    ("chemical/x-isostar", &["istr", "ist"]),
+    ("chemical/x-jcamp-dx", &["jdx", "dx"]),
+    ("chemical/x-kinemage", &["kin"]),
+    ("chemical/x-macmolecule", &["mcm"]),
+    ("chemical/x-macromodel-input", &["mmod"]),
+    ("chemical/x-mdl-molfile", &["mol"]),
+    ("chemical/x-mdl-rdfile", &["rd"]),
+    ("chemical/x-mdl-rxnfile", &["rxn"]),
+    ("chemical/x-mdl-sdfile", &["sd", "sdf"]),
+    ("chemical/x-mdl-tgf", &["tgf"]),
+    ("chemical/x-mmcif", &["mcif"]),
+    ("chemical/x-molconn-z", &["b"]),
+    ("chemical/x-mopac-graph", &["gpt"]),
+    ("chemical/x-mopac-input", &["mop", "mopcrt", "mpc", "zmt"]),
+    ("chemical/x-mopac-out", &["moo"]),
+    ("chemical/x-mopac-vib", &["mvb"]),
+    ("chemical/x-ncbi-asn1", &["asn"]),
+    ("chemical/x-ncbi-asn1-ascii", &["prt"]),
+    ("chemical/x-ncbi-asn1-binary", &["val", "aso"]),
+    ("chemical/x-ncbi-asn1-spec", &["asn"]),
+    ("chemical/x-pdb", &["pdb"]),
+    ("chemical/x-rosdal", &["ros"]),
+    ("chemical/x-swissprot", &["sw"]),
+    ("chemical/x-vamas-iso14976", &["vms"]),
+    ("chemical/x-vmd", &["vmd"]),
+    ("chemical/x-xtel", &["xtel"]),
+    ("chemical/x-xyz", &["xyz"]),
+    ("font/collection", &["ttc"]),
+    ("font/otf", &["otf"]),
+    ("font/ttf", &["ttf"]),
+    ("font/woff", &["woff"]),
+    ("font/woff2", &["woff2"]),
+    ("image/aces", &["exr"]),
+    ("image/apng", &["apng"]),
+    ("image/avci", &["avci"]),
+    ("image/avcs", &["avcs"]),
+    ("image/avif", &["avif", "hif"]),
+    ("image/bmp", &["bmp"]),
+    ("image/cgm", &["cgm"]),
+    ("image/dicom-rle", &["drle"]),
+    ("image/dpx", &["dpx"]),
+    ("image/emf", &["emf"]),
+    ("image/fits", &["fits", "fit", "fts"]),
+    ("image/gif", &["gif"]),
+    ("image/heic", &["heic"]),
+    ("image/heic-sequence", &["heics"]),
+    ("image/heif", &["heif"]),
+    ("image/heif-sequence", &["heifs"]),
+    ("image/hej2k", &["hej2"]),
+    ("image/hsj2", &["hsj2"]),
+    ("image/ief", &["ief"]),
+    ("image/jls", &["jls"]),
+    ("image/jp2", &["jp2", "jpg2"]),
+    ("image/jpeg", &["jpeg", "jpg", "jpe", "jfif"]),
+    ("image/jph", &["jph"]),
+    ("image/jphc", &["jhc", "jphc"]),
+    ("image/jpm", &["jpm", "jpgm"]),
+    ("image/jpx", &["jpx", "jpf"]),
+    ("image/jxl", &["jxl"]),
+    ("image/jxr", &["jxr"]),
+    ("image/jxra", &["jxra"]),
+    ("image/jxrs", &["jxrs"]),
+    ("image/jxs", &["jxs"]),
+    ("image/jxsc", &["jxsc"]),
+    ("image/jxsi", &["jxsi"]),
+    ("image/jxss", &["jxss"]),
+    ("image/ktx", &["ktx"]),
+    ("image/ktx2", &["ktx2"]),
+    ("image/png", &["png"]),
+    ("image/prs.btif", &["btif", "btf"]),
+    ("image/prs.pti", &["pti"]),
+    ("image/svg+xml", &["svg", "svgz"]),
+    ("image/tiff", &["tiff", "tif"]),
+    ("image/tiff-fx", &["tfx"]),
+    ("image/vnd.adobe.photoshop", &["psd"]),
+    ("image/vnd.airzip.accelerator.azv", &["azv"]),
+    ("image/vnd.dece.graphic", &["uvi", "uvvi", "uvg", "uvvg"]),
+    ("image/vnd.djvu", &["djvu", "djv"]),
+    ("image/vnd.dwg", &["dwg"]),
+    ("image/vnd.dxf", &["dxf"]),
+    ("image/vnd.fastbidsheet", &["fbs"]),
+    ("image/vnd.fpx", &["fpx"]),
+    ("image/vnd.fst", &["fst"]),
+    ("image/vnd.fujixerox.edmics-mmr", &["mmr"]),
+    ("image/vnd.fujixerox.edmics-rlc", &["rlc"]),
+    ("image/vnd.globalgraphics.pgb", &["pgb"]),
+    ("image/vnd.microsoft.icon", &["ico"]),
+    ("image/vnd.ms-modi", &["mdi"]),
+    ("image/vnd.pco.b16", &["b16"]),
+    ("image/vnd.radiance", &["hdr", "rgbe", "xyze"]),
+    ("image/vnd.sealed.png", &["spng", "spn", "s1n"]),
+    ("image/vnd.sealedmedia.softseal.gif", &["sgif", "sgi", "s1g"]),
+    ("image/vnd.sealedmedia.softseal.jpg", &["sjpg", "sjp", "s1j"]),
+    ("image/vnd.tencent.tap", &["tap"]),
+    ("image/vnd.valve.source.texture", &["vtf"]),
+    ("image/vnd.wap.wbmp", &["wbmp"]),
+    ("image/vnd.xiff", &["xif"]),
+    ("image/vnd.zbrush.pcx", &["pcx"]),
+    ("image/webp", &["webp"]),
+    ("image/wmf", &["wmf"]),
+    ("image/x-canon-cr2", &["cr2"]),
+    ("image/x-canon-crw", &["crw"]),
+    ("image/x-cmu-raster", &["ras"]),
+    ("image/x-coreldraw", &["cdr"]),
+    ("image/x-coreldrawpattern", &["pat"]),
+    ("image/x-coreldrawtemplate", &["cdt"]),
+    ("image/x-corelphotopaint", &["cpt"]),
+    ("image/x-epson-erf", &["erf"]),
+    ("image/x-icon", &["ico"]),
+    ("image/x-jg", &["art"]),
+    ("image/x-jng", &["jng"]),
+    ("image/x-ms-bmp", &["bmp"]),
+    ("image/x-nikon-nef", &["nef"]),
+    ("image/x-olympus-orf", &["orf"]),
+    ("image/x-portable-anymap", &["pnm"]),
+    ("image/x-portable-bitmap", &["pbm"]),
+    ("image/x-portable-graymap", &["pgm"]),
+    ("image/x-portable-pixmap", &["ppm"]),
+    ("image/x-rgb", &["rgb"]),
+    ("image/x-xbitmap", &["xbm"]),
+    ("image/x-xcf", &["xcf"]),
+    ("image/x-xpixmap", &["xpm"]),
+    ("image/x-xwindowdump", &["xwd"]),
+    ("message/global", &["u8msg"]),
+    ("message/global-delivery-status", &["u8dsn"]),
+    ("message/global-disposition-notification", &["u8mdn"]),
+    ("message/global-headers", &["u8hdr"]),
+    ("message/rfc822", &["eml", "mail", "art"]),
+    ("model/gltf+json", &["gltf"]),
+    ("model/gltf-binary", &["glb"]),
+    ("model/iges", &["igs", "iges"]),
+    ("model/jt", &["jt"]),
+    ("model/mesh", &["msh", "mesh", "silo"]),
+    ("model/mtl", &["mtl"]),
+    ("model/obj", &["obj"]),
+    ("model/prc", &["prc"]),
+    ("model/step", &["stp", "step"]),
+    ("model/step+xml", &["stpx"]),
+    ("model/step+zip", &["stpz"]),
+    ("model/step-xml+zip", &["stpxz"]),
+    ("model/stl", &["stl"]),
+    ("model/u3d", &["u3d"]),
+    ("model/vnd.cld", &["cld"]),
+    ("model/vnd.collada+xml", &["dae"]),
+    ("model/vnd.dwf", &["dwf"]),
+    ("model/vnd.gdl", &["gdl", "gsm", "win", "dor", "lmp", "rsm", "msm", "ism"]),
+    ("model/vnd.gtw", &["gtw"]),
+    ("model/vnd.moml+xml", &["moml"]),
+    ("model/vnd.mts", &["mts"]),
+    ("model/vnd.opengex", &["ogex"]),
+    ("model/vnd.parasolid.transmit.binary", &["x_b", "xmt_bin"]),
+    ("model/vnd.parasolid.transmit.text", &["x_t", "xmt_txt"]),
+    ("model/vnd.pytha.pyox", &["pyox"]),
+    ("model/vnd.sap.vds", &["vds"]),
+    ("model/vnd.usda", &["usda"]),
+    ("model/vnd.usdz+zip", &["usdz"]),
+    ("model/vnd.valve.source.compiled-map", &["bsp"]),
+    ("model/vnd.vtu", &["vtu"]),
+    ("model/vrml", &["wrl", "vrm", "vrml"]),
+    ("model/x3d+fastinfoset", &["x3db"]),
+    ("model/x3d+xml", &["x3d", "x3dz"]),
+    ("model/x3d-vrml", &["x3dv", "x3dvz"]),
+    ("multipart/vnd.bint.med-plus", &["bmed"]),
+    ("multipart/voice-message", &["vpm"]),
+    ("text/cache-manifest", &["appcache", "manifest"]),
+    ("text/calendar", &["ics", "ifb"]),
+    ("text/cql", &["cql"]),
+    ("text/css", &["css"]),
+    ("text/csv", &["csv"]),
+    ("text/csv-schema", &["csvs"]),
+    ("text/dns", &["soa", "zone"]),
+    ("text/gff3", &["gff3"]),
+    ("text/html", &["html", "htm", "shtml"]),
+    ("text/javascript", &["es", "js", "mjs"]),
+    ("text/jcr-cnd", &["cnd"]),
+    ("text/markdown", &["md", "markdown"]),
+    ("text/mathml", &["mml"]),
+    ("text/mizar", &["miz"]),
+    ("text/n3", &["n3"]),
+    ("text/plain", &["txt", "text", "pot", "brf", "srt"]),
+    ("text/provenance-notation", &["provn"]),
+    ("text/prs.fallenstein.rst", &["rst"]),
+    ("text/prs.lines.tag", &["tag", "dsc"]),
+    ("text/sgml", &["sgml", "sgm"]),
+    ("text/shaclc", &["shaclc", "shc"]),
+    ("text/shex", &["shex"]),
+    ("text/spdx", &["spdx"]),
+    ("text/tab-separated-values", &["tsv"]),
+    ("text/texmacs", &["tm"]),
+    ("text/troff", &["t", "tr", "roff"]),
+    ("text/turtle", &["ttl"]),
+    ("text/uri-list", &["uris", "uri"]),
+    ("text/vcard", &["vcf", "vcard"]),
+    ("text/vnd.a", &["a"]),
+    ("text/vnd.abc", &["abc"]),
+    ("text/vnd.ascii-art", &["ascii"]),
+    ("text/vnd.curl", &["curl"]),
+    ("text/vnd.debian.copyright", &["copyright"]),
+    ("text/vnd.dmclientscript", &["dms"]),
+    ("text/vnd.esmertec.theme-descriptor", &["jtd"]),
+    ("text/vnd.exchangeable", &["vfk"]),
+    ("text/vnd.familysearch.gedcom", &["ged"]),
+    ("text/vnd.ficlab.flt", &["flt"]),
+    ("text/vnd.fly", &["fly"]),
+    ("text/vnd.fmi.flexstor", &["flx"]),
+    ("text/vnd.graphviz", &["gv", "dot"]),
+    ("text/vnd.hans", &["hans"]),
+    ("text/vnd.hgl", &["hgl"]),
+    ("text/vnd.in3d.3dml", &["3dml", "3dm"]),
+    ("text/vnd.in3d.spot", &["spot", "spo"]),
+    ("text/vnd.ms-mediapackage", &["mpf"]),
+    ("text/vnd.net2phone.commcenter.command", &["ccc"]),
+    ("text/vnd.senx.warpscript", &["mc2"]),
+    ("text/vnd.sosi", &["sos"]),
+    ("text/vnd.sun.j2me.app-descriptor", &["jad"]),
+    ("text/vnd.trolltech.linguist", &["ts"]),
+    ("text/vnd.wap.si", &["si"]),
+    ("text/vnd.wap.sl", &["sl"]),
+    ("text/vnd.wap.wml", &["wml"]),
+    ("text/vnd.wap.wmlscript", &["wmls"]),
+    ("text/vtt", &["vtt"]),
+    ("text/wgsl", &["wgsl"]),
+    ("text/x-bibtex", &["bib"]),
+    ("text/x-boo", &["boo"]),
+    ("text/x-c++hdr", &["h++", "hpp", "hxx", "hh"]),
+    ("text/x-c++src", &["c++", "cpp", "cxx", "cc"]),
+    ("text/x-chdr", &["h"]),
+    ("text/x-component", &["htc"]),
+    ("text/x-csh", &["csh"]),
+    ("text/x-csrc", &["c"]),
+    ("text/x-diff", &["diff", "patch"]),
+    ("text/x-dsrc", &["d"]),
+    ("text/x-haskell", &["hs"]),
+    ("text/x-java", &["java"]),
+    ("text/x-lilypond", &["ly"]),
+    ("text/x-literate-haskell", &["lhs"]),
+    ("text/x-moc", &["moc"]),
+    ("text/x-pascal", &["p", "pas"]),
+    ("text/x-pcs-gcd", &["gcd"]),
+    ("text/x-perl", &["pl", "pm"]),
+    ("text/x-python", &["py"]),
+    ("text/x-scala", &["scala"]),
+    ("text/x-setext", &["etx"]),
+    ("text/x-sfv", &["sfv"]),
+    ("text/x-sh", &["sh"]),
+    ("text/x-tcl", &["tcl", "tk"]),
+    ("text/x-tex", &["tex", "ltx", "sty", "cls"]),
+    ("text/x-vcalendar", &["vcs"]),
+    ("text/xml", &["xml"]),
+    ("video/3gpp", &["3gpp", "3gp"]),
+    ("video/annodex", &["axv"]),
+    ("video/dv", &["dif", "dv"]),
+    ("video/fli", &["fli"]),
+    ("video/gl", &["gl"]),
+    ("video/iso.segment", &["m4s"]),
+    ("video/mj2", &["mj2", "mjp2"]),
+    ("video/mp2t", &["ts"]),
+    ("video/mp4", &["mp4", "mpg4", "m4v"]),
+    ("video/mpeg", &["mpeg", "mpg", "mpe", "m1v", "m2v"]),
+    ("video/ogg", &["ogv"]),
+    ("video/quicktime", &["qt", "mov"]),
+    ("video/vnd.dece.hd", &["uvh", "uvvh"]),
+    ("video/vnd.dece.mobile", &["uvm", "uvvm"]),
+    ("video/vnd.dece.mp4", &["uvu", "uvvu"]),
+    ("video/vnd.dece.pd", &["uvp", "uvvp"]),
+    ("video/vnd.dece.sd", &["uvs", "uvvs"]),
+    ("video/vnd.dece.video", &["uvv", "uvvv"]),
+    ("video/vnd.dvb.file", &["dvb"]),
+    ("video/vnd.fvt", &["fvt"]),
+    ("video/vnd.mpegurl", &["mxu", "m4u"]),
+    ("video/vnd.ms-playready.media.pyv", &["pyv"]),
+    ("video/vnd.nokia.interleaved-multimedia", &["nim"]),
+    ("video/vnd.radgamettools.bink", &["bik", "bk2"]),
+    ("video/vnd.radgamettools.smacker", &["smk"]),
+    ("video/vnd.sealed.mpeg1", &["smpg", "s11"]),
+    ("video/vnd.sealed.mpeg4", &["s14"]),
+    ("video/vnd.sealed.swf", &["sswf", "ssw"]),
+    ("video/vnd.sealedmedia.softseal.mov", &["smov", "smo", "s1q"]),
+    ("video/vnd.vivo", &["viv"]),
+    ("video/vnd.youtube.yt", &["yt"]),
+    ("video/webm", &["webm"]),
+    ("video/x-flv", &["flv"]),
+    ("video/x-la-asf", &["lsf", "lsx"]),
+    ("video/x-m4v", &["m4v"]),
+    ("video/x-matroska", &["mpv", "mkv"]),
+    ("video/x-mng", &["mng"]),
+    ("video/x-ms-asf", &["asx", "asf"]),
+    ("video/x-ms-wm", &["wm"]),
+    ("video/x-ms-wmv", &["wmv"]),
+    ("video/x-ms-wmx", &["wmx"]),
+    ("video/x-ms-wvx", &["wvx"]),
+    ("video/x-msvideo", &["avi"]),
+    ("video/x-sgi-movie", &["movie"]),
+];
@@ -0,0 +1,296 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{MediaRange, MediaType, APPLICATION_OCTET_STREAM, TEXT, TEXT_PLAIN};
+
+// Types that can be treated as another type, besides the implicit rules
+// in `Hierarchy::parents`. Most are from shared-mime-info: archives that
+// are really ZIP files, and text formats that are readable as plain text.
+//
+// Sorted by child, so it's easy to check a type isn't listed twice.
+static PARENTS: &[(&str, &str)] = &[
+    ("application/java-archive", "application/zip"),
+    ("application/json", "text/plain"),
+    ("application/sql", "text/plain"),
+    ("application/toml", "text/plain"),
+    ("application/vnd.android.package-archive", "application/java-archive"),
+    ("application/vnd.oasis.opendocument.presentation", "application/zip"),
+    ("application/vnd.oasis.opendocument.spreadsheet", "application/zip"),
+    ("application/vnd.oasis.opendocument.text", "application/zip"),
+    ("application/vnd.openxmlformats-officedocument.presentationml.presentation", "application/zip"),
+    ("application/vnd.openxmlformats-officedocument.spreadsheetml.sheet", "application/zip"),
+    ("application/vnd.openxmlformats-officedocument.wordprocessingml.document", "application/zip"),
+    ("application/xml", "text/plain"),
+    ("application/yaml", "text/plain"),
+    ("audio/ogg", "application/ogg"),
+    ("image/apng", "image/png"),
+    ("message/rfc822", "text/plain"),
+    ("video/ogg", "application/ogg"),
+];
+
+/// A graph of which media types can be treated as which other types.
+///
+/// A type is a subclass of its parents, and of their parents in turn.
+/// Besides the built-in relationships, and any added with
+/// [`add_parent`](Hierarchy::add_parent), a few rules always apply:
+///
+/// - A type with a known +suffix is a subclass of the format it names,
+///   so `image/svg+xml` is an `application/xml`.
+/// - Every `text/*` type is a subclass of `text/plain`.
+/// - Every type is a subclass of `application/octet-stream`, except
+///   `inode/*` types, which aren't file contents.
+///
+/// Legacy aliases are [canonicalized](MediaType::canonicalize) first.
+///
+/// # Example
+///
+/// ```
+/// use mime::{Hierarchy, MediaType};
+///
+/// let csv = MediaType::parse("text/csv").unwrap();
+/// let mut hierarchy = Hierarchy::new();
+///
+/// let tsv = MediaType::parse("text/tab-separated-values").unwrap();
+/// assert!(!hierarchy.is_a(&tsv, &csv));
+///
+/// hierarchy.add_parent(&tsv, csv.clone());
+/// assert!(hierarchy.is_a(&tsv, &csv));
+/// assert!(hierarchy.is_a(&tsv, &mime::TEXT_PLAIN));
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Hierarchy {
+    // essence -> direct parents, added by the user
+    parents: HashMap<String, Vec<MediaType>>,
+}
+
+impl Hierarchy {
+    /// Create a `Hierarchy` with only the built-in relationships.
+    pub fn new() -> Hierarchy {
+        Hierarchy::default()
+    }
+
+    /// Add a parent of a type.
+    ///
+    /// The parameters of both types are ignored.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mime::{Hierarchy, MediaType};
+    ///
+    /// let geojson_seq = MediaType::parse("application/geo+json-seq").unwrap();
+    /// let mut hierarchy = Hierarchy::new();
+    /// hierarchy.add_parent(&geojson_seq, MediaType::parse("application/geo+json").unwrap());
+    ///
+    /// assert!(hierarchy.is_a(&geojson_seq, &mime::APPLICATION_JSON));
+    /// ```
+    pub fn add_parent(&mut self, mt: &MediaType, parent: MediaType) {
+        let parent = parent.canonicalize().without_params();
+        let parents = self
+            .parents
+            .entry(mt.canonicalize().mime.essence().to_owned())
+            .or_default();
+        if !parents.contains(&parent) {
+            parents.push(parent);
+        }
+    }
+
+    /// Get the types a type is directly a subclass of.
+    ///
+    /// Parents added with [`add_parent`](Hierarchy::add_parent) come first,
+    /// then the built-in ones, then those from the implicit rules.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let hierarchy = mime::Hierarchy::new();
+    ///
+    /// let svg = hierarchy.parents(&mime::IMAGE_SVG);
+    /// assert_eq!(svg, ["application/xml", "application/octet-stream"]);
+    ///
+    /// let xml = hierarchy.parents(&svg[0]);
+    /// assert_eq!(xml, ["text/plain", "application/octet-stream"]);
+    /// ```
+    pub fn parents(&self, mt: &MediaType) -> Vec<MediaType> {
+        let mt = mt.canonicalize().without_params();
+        let essence = mt.mime.essence();
+        let mut parents = self.parents.get(essence).cloned().unwrap_or_default();
+        let mut push = |parent: MediaType| {
+            if !parents.contains(&parent) {
+                parents.push(parent);
+            }
+        };
+
+        for &(_, parent) in PARENTS.iter().filter(|&&(child, _)| child == essence) {
+            push(MediaType::parse(parent).expect("built-in parents are valid"));
+        }
+        if let Some(format) = mt.underlying_format() {
+            push(format);
+        }
+        if mt.type_() == TEXT && mt != TEXT_PLAIN {
+            push(TEXT_PLAIN);
+        }
+        if mt.type_() != "inode" && mt != APPLICATION_OCTET_STREAM {
+            push(APPLICATION_OCTET_STREAM);
+        }
+        parents
+    }
+
+    /// Returns true if a type is the same as, or a subclass of, another.
+    ///
+    /// Any parameters of `other` must also be in `mt`, as with
+    /// [`MediaRange::matches`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mime::MediaType;
+    ///
+    /// let hierarchy = mime::Hierarchy::new();
+    /// let jsonld = MediaType::parse("application/ld+json; charset=utf-8").unwrap();
+    ///
+    /// assert!(hierarchy.is_a(&jsonld, &mime::APPLICATION_JSON));
+    /// assert!(hierarchy.is_a(&jsonld, &mime::TEXT_PLAIN_UTF_8));
+    /// assert!(!hierarchy.is_a(&mime::APPLICATION_JSON, &jsonld));
+    /// ```
+    pub fn is_a(&self, mt: &MediaType, other: &MediaType) -> bool {
+        self.matches(&MediaRange::from(other.canonicalize()), mt)
+    }
+
+    /// Returns true if a range matches a type, or any type it's a subclass of.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mime::MediaRange;
+    ///
+    /// let hierarchy = mime::Hierarchy::new();
+    /// let xml = MediaRange::parse("application/xml").unwrap();
+    ///
+    /// // SVG is both an image and XML
+    /// assert!(hierarchy.matches(&mime::IMAGE_STAR, &mime::IMAGE_SVG));
+    /// assert!(hierarchy.matches(&xml, &mime::IMAGE_SVG));
+    /// assert!(!hierarchy.matches(&mime::IMAGE_STAR, &mime::APPLICATION_JSON));
+    /// ```
+    pub fn matches(&self, range: &MediaRange, mt: &MediaType) -> bool {
+        // parents don't have parameters, so check the range's against
+        // the original type
+        if range.matches_params(mt, crate::MatchKind::Exact).is_none() {
+            return false;
+        }
+
+        let mut seen = HashSet::new();
+        let mut stack = vec![mt.canonicalize().without_params(), mt.clone().without_params()];
+        while let Some(mt) = stack.pop() {
+            if range.match_kind(&mt).is_some() {
+                return true;
+            }
+            if seen.insert(mt.mime.essence().to_owned()) {
+                stack.extend(self.parents(&mt));
+            }
+        }
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn parse(s: &str) -> MediaType {
+        MediaType::parse(s).unwrap()
+    }
+
+    #[test]
+    fn test_builtin_parents() {
+        for pair in super::PARENTS.windows(2) {
+            assert!(pair[0].0 <= pair[1].0, "{:?} sorted before {:?}", pair[0].0, pair[1].0);
+        }
+        for &(child, parent) in super::PARENTS {
+            let child = parse(child);
+            // not an alias, or it would never be looked up
+            assert_eq!(child.canonicalize(), child);
+            assert!(child.is_a(&parse(parent)));
+            assert!(!parse(parent).is_a(&child));
+        }
+    }
+
+    #[test]
+    fn test_is_a() {
+        let cases = [
+            ("text/csv", "text/plain", true),
+            ("text/csv", "text/csv", true),
+            ("TEXT/CSV", "text/csv", true),
+            ("text/plain", "text/csv", false),
+            ("image/svg+xml", "application/xml", true),
+            ("image/svg+xml", "text/plain", true),
+            ("image/svg+xml", "application/octet-stream", true),
+            ("image/svg+xml", "image/png", false),
+            ("application/vnd.api+json", "application/json", true),
+            ("application/vnd.foo+yaml", "application/yaml", false),
+            ("application/vnd.android.package-archive", "application/zip", true),
+            ("application/epub+zip", "application/zip", true),
+            ("image/apng", "image/png", true),
+            ("image/png", "image/apng", false),
+            ("audio/ogg", "application/ogg", true),
+            ("inode/directory", "application/octet-stream", false),
+            // aliases are canonicalized
+            ("image/jpg", "image/jpeg", true),
+            ("image/jpeg", "image/jpg", true),
+            ("application/x-javascript", "text/plain", true),
+            // parameters
+            ("text/csv; charset=utf-8", "text/plain; charset=utf-8", true),
+            ("text/csv; charset=utf-8", "text/plain", true),
+            ("text/csv", "text/plain; charset=utf-8", false),
+            ("text/csv; charset=latin1", "text/plain; charset=utf-8", false),
+        ];
+        for &(mt, other, expected) in &cases {
+            assert_eq!(parse(mt).is_a(&parse(other)), expected, "{} is a {}", mt, other);
+        }
+    }
+
+    #[test]
+    fn test_matches() {
+        let hierarchy = Hierarchy::new();
+        let cases = [
+            ("image/*", "image/svg+xml", true),
+            ("text/*", "image/svg+xml", true),
+            ("text/*", "application/json", true),
+            ("application/*+xml", "image/svg+xml", false),
+            ("application/*+json", "application/geo+json", true),
+            ("*/*", "inode/directory", true),
+            ("video/*", "audio/ogg", false),
+            ("text/plain; q=0.5", "text/html", true),
+            ("text/plain; charset=utf-8", "text/html", false),
+            ("image/jpg", "image/jpg", true),
+        ];
+        for &(range, mt, expected) in &cases {
+            let range = MediaRange::parse_with_suffix_wildcard(range).unwrap();
+            assert_eq!(hierarchy.matches(&range, &parse(mt)), expected, "{} matches {}", range, mt);
+        }
+    }
+
+    #[test]
+    fn test_add_parent() {
+        let mut hierarchy = Hierarchy::new();
+        let a = parse("application/vnd.a");
+        let b = parse("application/vnd.b");
+        let c = parse("application/vnd.c");
+
+        hierarchy.add_parent(&a, b.clone());
+        hierarchy.add_parent(&a, b.clone());
+        hierarchy.add_parent(&b, parse("Application/Vnd.C; x=y"));
+        assert_eq!(hierarchy.parents(&a), [b.clone(), APPLICATION_OCTET_STREAM]);
+        assert!(hierarchy.is_a(&a, &c));
+        assert!(!hierarchy.is_a(&c, &a));
+        assert!(!a.is_a(&c));
+
+        // cycles don't loop forever
+        hierarchy.add_parent(&c, a.clone());
+        assert!(hierarchy.is_a(&c, &b));
+        assert!(!hierarchy.is_a(&c, &TEXT_PLAIN));
+
+        // added parents are found through aliases
+        hierarchy.add_parent(&parse("image/x-png"), parse("image/vnd.example"));
+        assert!(hierarchy.is_a(&IMAGE_PNG, &parse("image/vnd.example")));
+    }
+}
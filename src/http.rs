@@ -0,0 +1,134 @@
+use std::convert::TryFrom;
+
+use ::http::header::{HeaderMap, HeaderValue, CONTENT_TYPE};
+use mime_parse::{Mime, Source};
+
+use crate::{InvalidMime, MediaRange, MediaType};
+
+impl MediaType {
+    /// Get the `MediaType` of the `Content-Type` header in a `HeaderMap`.
+    ///
+    /// Returns `None` if there is no `Content-Type` header. If the header
+    /// was sent more than once, the first value is used.
+    ///
+    /// This requires the `http` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use http::header::{HeaderMap, HeaderValue, CONTENT_TYPE};
+    ///
+    /// let mut headers = HeaderMap::new();
+    /// assert!(mime::MediaType::from_content_type(&headers).is_none());
+    ///
+    /// headers.insert(CONTENT_TYPE, HeaderValue::from_static("text/html; charset=utf-8"));
+    /// let mt = mime::MediaType::from_content_type(&headers).unwrap().unwrap();
+    /// assert_eq!(mt, mime::TEXT_HTML_UTF_8);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the header is not a valid media type.
+    pub fn from_content_type(headers: &HeaderMap) -> Option<Result<MediaType, InvalidMime>> {
+        headers.get(CONTENT_TYPE).map(MediaType::try_from)
+    }
+}
+
+impl TryFrom<&HeaderValue> for MediaType {
+    type Error = InvalidMime;
+
+    #[inline]
+    fn try_from(value: &HeaderValue) -> Result<MediaType, InvalidMime> {
+        MediaType::parse(value.as_bytes())
+    }
+}
+
+impl TryFrom<&HeaderValue> for MediaRange {
+    type Error = InvalidMime;
+
+    #[inline]
+    fn try_from(value: &HeaderValue) -> Result<MediaRange, InvalidMime> {
+        MediaRange::parse(value.as_bytes())
+    }
+}
+
+impl From<MediaType> for HeaderValue {
+    #[inline]
+    fn from(mt: MediaType) -> HeaderValue {
+        header_value(mt.mime)
+    }
+}
+
+impl From<MediaRange> for HeaderValue {
+    #[inline]
+    fn from(range: MediaRange) -> HeaderValue {
+        header_value(range.mime)
+    }
+}
+
+// Constants are already `&'static str`s, and anything that was parsed is a
+// valid header value, so nothing needs to be checked again.
+fn header_value(mime: Mime) -> HeaderValue {
+    match mime.into_source() {
+        // a quoted-string could contain non-ASCII, which `from_static`
+        // doesn't allow
+        Source::Atom(_, s) if s.is_ascii() => HeaderValue::from_static(s),
+        Source::Atom(_, s) => HeaderValue::from_bytes(s.as_bytes())
+            .expect("MediaType is a valid HeaderValue"),
+        Source::Dynamic(s) => HeaderValue::try_from(s)
+            .expect("MediaType is a valid HeaderValue"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+
+    use ::http::header::{HeaderMap, HeaderValue, CONTENT_TYPE};
+
+    use crate::*;
+
+    #[test]
+    fn test_try_from_header_value() {
+        let value = HeaderValue::from_static("Text/Plain; Charset=\"UTF-8\"");
+        assert_eq!(MediaType::try_from(&value).unwrap(), TEXT_PLAIN_UTF_8);
+
+        let value = HeaderValue::from_static("text/*; q=0.5");
+        assert_eq!(MediaRange::try_from(&value).unwrap(), "text/*; q=0.5");
+        MediaType::try_from(&value).unwrap_err();
+
+        let value = HeaderValue::from_bytes(b"text/plain; name=\"caf\xc3\xa9\"").unwrap();
+        assert_eq!(MediaType::try_from(&value).unwrap().param("name").unwrap(), "caf\u{e9}");
+
+        let value = HeaderValue::from_bytes(b"text/pl\xe9in").unwrap();
+        MediaType::try_from(&value).unwrap_err();
+    }
+
+    #[test]
+    fn test_into_header_value() {
+        assert_eq!(HeaderValue::from(TEXT_PLAIN_UTF_8), "text/plain; charset=utf-8");
+        assert_eq!(HeaderValue::from(STAR_STAR), "*/*");
+
+        let mt = MediaType::parse("Application/JSON; A=\"b c\"").unwrap();
+        assert_eq!(HeaderValue::from(mt), "application/json; a=\"b c\"");
+
+        let range = MediaRange::parse("image/*; q=0.8").unwrap();
+        assert_eq!(HeaderValue::from(range), "image/*; q=0.8");
+
+        let mt = MediaType::parse("text/plain; name=\"caf\u{e9}\"").unwrap();
+        assert_eq!(HeaderValue::from(mt).as_bytes(), "text/plain; name=\"caf\u{e9}\"".as_bytes());
+    }
+
+    #[test]
+    fn test_from_content_type() {
+        let mut headers = HeaderMap::new();
+        assert!(MediaType::from_content_type(&headers).is_none());
+
+        headers.insert(CONTENT_TYPE, HeaderValue::from(APPLICATION_JSON));
+        headers.append(CONTENT_TYPE, HeaderValue::from(TEXT_PLAIN));
+        assert_eq!(MediaType::from_content_type(&headers).unwrap().unwrap(), APPLICATION_JSON);
+
+        headers.insert(CONTENT_TYPE, HeaderValue::from_static("nope"));
+        MediaType::from_content_type(&headers).unwrap().unwrap_err();
+    }
+}
@@ -71,15 +71,26 @@
 //!         assert_eq!(range, mime::TEXT_STAR);
 //!
 //!         // "text/plain" is a match
-//!         assert!(range.matches(&mime::TEXT_PLAIN));
+//!         assert!(range.matches(&mime::TEXT_PLAIN).is_some());
 //!
 //!         // "application/json" is NOT
-//!         assert!(!range.matches(&mime::APPLICATION_JSON));
+//!         assert!(range.matches(&mime::APPLICATION_JSON).is_none());
 //!
 //!     },
 //!     Err(err) => panic!("that's a bad range: {}", err),
 //! }
 //! ```
+//!
+//! A whole `Accept` header can be parsed as a [`MediaRangeList`], which
+//! also exposes the quality weight of each range:
+//!
+//! ```
+//! let accept = mime::MediaRangeList::parse("text/html, text/*;q=0.5").unwrap();
+//!
+//! for range in &accept {
+//!     println!("{} has weight {}", range, range.quality());
+//! }
+//! ```
 #[cfg(feature = "macro")]
 use proc_macro_hack::proc_macro_hack;
 
@@ -97,6 +108,9 @@ use proc_macro_hack::proc_macro_hack;
 /// mime = { version = "0.4", features = ["macro"] }
 /// ```
 ///
+/// [`MediaType::from_static`] does the same without the feature, for a
+/// source that is already lowercase.
+///
 /// # Example
 ///
 /// ```
@@ -106,31 +120,85 @@ use proc_macro_hack::proc_macro_hack;
 #[proc_macro_hack]
 pub use mime_macro::media_type;
 
+/// Compile-time `MediaRange`s.
+///
+/// The same as [`media_type!`], but the type and subtype can be `*`, and
+/// a `q` parameter must be a valid [`Quality`].
+///
+/// This also requires the `macro` feature.
+///
+/// # Example
+///
+/// ```
+/// const ANY_TEXT: mime::MediaRange = mime::media_range!("text/*; q=0.5");
+/// static ANY: mime::MediaRange = mime::media_range!("*/*");
+///
+/// assert_eq!(ANY_TEXT.quality().thousandths(), 500);
+/// assert!(ANY.matches(&mime::IMAGE_PNG).is_some());
+/// ```
+#[cfg(feature = "macro")]
+#[proc_macro_hack]
+pub use mime_macro::media_range;
+
 pub use mime_parse::constants::names::*;
+pub use self::builder::Builder;
+pub use self::charset::Charset;
 pub use self::constants::mimes::*;
 pub use self::error::InvalidMime;
+pub use self::hierarchy::Hierarchy;
+pub use self::list::MediaRangeList;
+pub use self::quality::Quality;
 pub use self::range::MediaRange;
+pub use self::specificity::{MatchKind, Specificity};
+pub use self::suffix::StructuredSuffix;
+pub use self::syntax::Syntax;
+pub use self::tree::RegistrationTree;
 pub use self::type_::MediaType;
+pub use self::type_ref::MediaTypeRef;
 pub use self::value::{Value, UTF_8};
 
+mod builder;
+mod charset;
 mod cmp;
+pub mod codecs;
 mod constants;
 mod error;
+#[cfg(feature = "extensions")]
+mod extensions;
+mod hierarchy;
+#[cfg(feature = "http")]
+mod http;
+mod list;
 #[cfg(feature = "macro")]
 mod macros;
+mod multipart;
+mod quality;
 mod range;
+#[cfg(feature = "registry")]
+pub mod registry;
 #[cfg(feature = "serde1")]
 mod serde;
+#[cfg(feature = "shared-mime-info")]
+pub mod shared_mime_info;
+pub mod sniff;
+mod specificity;
+mod suffix;
+mod syntax;
+mod tree;
 mod type_;
+mod type_ref;
 mod value;
 
 
 fn _assert_traits() {
     fn assert_send_sync<T: Send + Sync>() {}
 
+    assert_send_sync::<Hierarchy>();
     assert_send_sync::<InvalidMime>();
     assert_send_sync::<MediaRange>();
+    assert_send_sync::<MediaRangeList>();
     assert_send_sync::<MediaType>();
+    assert_send_sync::<MediaTypeRef>();
     assert_send_sync::<Value>();
 }
 
@@ -0,0 +1,331 @@
+use std::fmt;
+use std::slice;
+use std::str::FromStr;
+use std::vec;
+
+use mime_parse::Parse;
+
+use crate::{InvalidMime, MediaRange, MediaType, Quality};
+
+/// A list of media ranges, such as the value of an HTTP `Accept` header.
+///
+/// # Example
+///
+/// ```
+/// use mime::{MediaRangeList, Quality};
+///
+/// let accept = MediaRangeList::parse("text/html, text/*;q=0.5, */*;q=0.1").unwrap();
+///
+/// let mut ranges = accept.iter();
+///
+/// let html = ranges.next().unwrap();
+/// assert_eq!(html, "text/html");
+/// assert_eq!(html.quality(), Quality::ONE);
+///
+/// let text = ranges.next().unwrap();
+/// assert_eq!(text.type_(), mime::TEXT);
+/// assert_eq!(text.subtype(), mime::STAR);
+/// assert_eq!(text.quality().thousandths(), 500);
+///
+/// let any = ranges.next().unwrap();
+/// assert_eq!(any.type_(), mime::STAR);
+/// assert_eq!(any.quality().thousandths(), 100);
+///
+/// assert!(ranges.next().is_none());
+/// ```
+#[derive(Clone, Default, PartialEq)]
+pub struct MediaRangeList {
+    ranges: Vec<MediaRange>,
+}
+
+impl MediaRangeList {
+    /// Parse a comma-separated string as a `MediaRangeList`.
+    ///
+    /// Commas inside quoted parameter values do not separate ranges, and
+    /// empty list elements are ignored.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let accept = mime::MediaRangeList::parse(
+    ///     r#"text/plain; title="a, b", image/*"#
+    /// ).unwrap();
+    ///
+    /// assert_eq!(accept.len(), 2);
+    /// assert_eq!(accept.iter().next().unwrap().param("title").unwrap(), "a, b");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any element is not a valid media range, or
+    /// contains an invalid `q` parameter.
+    pub fn parse(source: impl Parse) -> Result<Self, InvalidMime> {
+        let ranges = mime_parse::Parser::can_range()
+            .parse_list(source)
+            .map_err(|e| InvalidMime { inner: e })?
+            .into_iter()
+            .map(MediaRange::from_mime)
+            .collect::<Result<_, _>>()?;
+
+        Ok(MediaRangeList {
+            ranges,
+        })
+    }
+
+    /// Pick the best of the `available` media types for this list.
+    ///
+    /// Each type is weighted by the most specific range that matches it,
+    /// as described in [RFC 9110](https://www.rfc-editor.org/rfc/rfc9110#section-12.5.1).
+    /// The type with the highest weight wins, and ties are resolved in
+    /// favor of whichever comes first in `available`, so it should be
+    /// sorted by the server's preference.
+    ///
+    /// Returns `None` if none of the types are acceptable, such as when
+    /// they only match ranges with `q=0`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let accept = mime::MediaRangeList::parse(
+    ///     "text/*;q=0.5, text/html, application/json;q=0.9, image/png;q=0"
+    /// ).unwrap();
+    ///
+    /// let available = [mime::IMAGE_PNG, mime::TEXT_PLAIN, mime::APPLICATION_JSON];
+    /// assert_eq!(accept.negotiate(&available), Some(&mime::APPLICATION_JSON));
+    ///
+    /// let available = [mime::IMAGE_PNG, mime::TEXT_PLAIN, mime::TEXT_CSS];
+    /// assert_eq!(accept.negotiate(&available), Some(&mime::TEXT_PLAIN));
+    ///
+    /// let available = [mime::IMAGE_PNG];
+    /// assert_eq!(accept.negotiate(&available), None);
+    /// ```
+    pub fn negotiate<'a, I>(&self, available: I) -> Option<&'a MediaType>
+    where
+        I: IntoIterator<Item = &'a MediaType>,
+    {
+        let mut best: Option<(&'a MediaType, Quality)> = None;
+
+        for mt in available {
+            let quality = self.quality_of(mt);
+            if quality == Quality::ZERO {
+                continue;
+            }
+
+            match best {
+                Some((_, best_quality)) if best_quality >= quality => (),
+                _ => best = Some((mt, quality)),
+            }
+        }
+
+        best.map(|(mt, _)| mt)
+    }
+
+    /// Get the weight this list gives to a `MediaType`.
+    ///
+    /// This is the quality of the most specific range that matches the
+    /// type, or `Quality::ZERO` if none do.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mime::Quality;
+    ///
+    /// let accept = mime::MediaRangeList::parse("text/*;q=0.5, text/html").unwrap();
+    ///
+    /// assert_eq!(accept.quality_of(&mime::TEXT_HTML), Quality::ONE);
+    /// assert_eq!(accept.quality_of(&mime::TEXT_PLAIN).thousandths(), 500);
+    /// assert_eq!(accept.quality_of(&mime::IMAGE_PNG), Quality::ZERO);
+    /// ```
+    pub fn quality_of(&self, mt: &MediaType) -> Quality {
+        let mut best = None;
+
+        for range in &self.ranges {
+            if let Some(specificity) = range.matches(mt) {
+                match best {
+                    // on equal specificity, the first listed range wins
+                    Some((best_specificity, _)) if best_specificity >= specificity => (),
+                    _ => best = Some((specificity, range)),
+                }
+            }
+        }
+
+        best.map(|(_, range)| range.quality()).unwrap_or(Quality::ZERO)
+    }
+
+    /// Returns an iterator over the ranges, in the order they were listed.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = &MediaRange> {
+        self.ranges.iter()
+    }
+
+    /// Returns the number of ranges in the list.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.ranges.len()
+    }
+
+    /// Returns true if the list contains no ranges.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+}
+
+impl<'a> IntoIterator for &'a MediaRangeList {
+    type Item = &'a MediaRange;
+    type IntoIter = slice::Iter<'a, MediaRange>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.ranges.iter()
+    }
+}
+
+impl IntoIterator for MediaRangeList {
+    type Item = MediaRange;
+    type IntoIter = vec::IntoIter<MediaRange>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.ranges.into_iter()
+    }
+}
+
+impl From<Vec<MediaRange>> for MediaRangeList {
+    fn from(ranges: Vec<MediaRange>) -> MediaRangeList {
+        MediaRangeList {
+            ranges,
+        }
+    }
+}
+
+impl FromStr for MediaRangeList {
+    type Err = InvalidMime;
+
+    fn from_str(s: &str) -> Result<MediaRangeList, Self::Err> {
+        MediaRangeList::parse(s)
+    }
+}
+
+impl fmt::Debug for MediaRangeList {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(&self.ranges).finish()
+    }
+}
+
+impl fmt::Display for MediaRangeList {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut ranges = self.ranges.iter();
+        if let Some(first) = ranges.next() {
+            fmt::Display::fmt(first, f)?;
+            for range in ranges {
+                f.write_str(", ")?;
+                fmt::Display::fmt(range, f)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn media_range_list_parse() {
+        let list = MediaRangeList::parse("text/html,application/xhtml+xml,application/xml;q=0.9,*/*;q=0.8").unwrap();
+        assert_eq!(list.len(), 4);
+
+        let qualities = list.iter().map(|r| r.quality().thousandths()).collect::<Vec<_>>();
+        assert_eq!(qualities, [1000, 1000, 900, 800]);
+
+        assert!(list.iter().nth(3).unwrap().matches(&IMAGE_PNG).is_some());
+    }
+
+    #[test]
+    fn media_range_list_empty() {
+        let list = MediaRangeList::parse("").unwrap();
+        assert!(list.is_empty());
+
+        let list = MediaRangeList::parse(" , ").unwrap();
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn media_range_list_invalid_quality() {
+        MediaRangeList::parse("text/html, text/*;q=2").unwrap_err();
+        MediaRangeList::parse("text/html;q=0.1234").unwrap_err();
+        MediaRangeList::parse("text/html;q=").unwrap_err();
+        MediaRangeList::parse("text/html;q=\"0.5\"").unwrap_err();
+    }
+
+    #[test]
+    fn media_range_list_invalid_range() {
+        MediaRangeList::parse("text/html, text").unwrap_err();
+        MediaRangeList::parse("text/html; p=\"a, b").unwrap_err();
+    }
+
+    #[test]
+    fn negotiate_specificity() {
+        let accept = MediaRangeList::parse("*/*;q=0.1, text/*;q=0.3, text/plain;q=0.7, text/plain;format=flowed").unwrap();
+
+        let flowed = MediaType::parse("text/plain; format=flowed").unwrap();
+        assert_eq!(accept.quality_of(&flowed), Quality::ONE);
+        assert_eq!(accept.quality_of(&TEXT_PLAIN).thousandths(), 700);
+        assert_eq!(accept.quality_of(&TEXT_HTML).thousandths(), 300);
+        assert_eq!(accept.quality_of(&IMAGE_PNG).thousandths(), 100);
+
+        let available = [IMAGE_PNG, TEXT_HTML, TEXT_PLAIN, flowed.clone()];
+        assert_eq!(accept.negotiate(&available), Some(&flowed));
+        assert_eq!(accept.negotiate(&available[..3]), Some(&TEXT_PLAIN));
+        assert_eq!(accept.negotiate(&available[..2]), Some(&TEXT_HTML));
+        assert_eq!(accept.negotiate(&available[..1]), Some(&IMAGE_PNG));
+        assert_eq!(accept.negotiate(&[]), None);
+    }
+
+    #[test]
+    fn negotiate_not_acceptable() {
+        let accept = MediaRangeList::parse("text/*, text/plain;q=0, */*;q=0").unwrap();
+
+        assert_eq!(accept.quality_of(&TEXT_PLAIN), Quality::ZERO);
+        assert_eq!(accept.negotiate(&[TEXT_PLAIN, IMAGE_PNG]), None);
+        assert_eq!(accept.negotiate(&[TEXT_PLAIN, TEXT_CSS]), Some(&TEXT_CSS));
+    }
+
+    #[test]
+    fn negotiate_server_preference() {
+        let accept = MediaRangeList::parse("application/json, text/html").unwrap();
+
+        assert_eq!(accept.negotiate(&[TEXT_HTML, APPLICATION_JSON]), Some(&TEXT_HTML));
+        assert_eq!(accept.negotiate(&[APPLICATION_JSON, TEXT_HTML]), Some(&APPLICATION_JSON));
+    }
+
+    #[test]
+    fn negotiate_params() {
+        let accept = MediaRangeList::parse("text/*;charset=utf-8, text/*;q=0.2").unwrap();
+
+        assert_eq!(accept.quality_of(&TEXT_HTML_UTF_8), Quality::ONE);
+        assert_eq!(accept.quality_of(&TEXT_HTML).thousandths(), 200);
+        assert_eq!(accept.negotiate(&[TEXT_HTML, TEXT_PLAIN_UTF_8]), Some(&TEXT_PLAIN_UTF_8));
+    }
+
+    #[test]
+    fn negotiate_empty_list() {
+        let accept = MediaRangeList::parse("").unwrap();
+        assert_eq!(accept.negotiate(&[TEXT_HTML]), None);
+    }
+
+    #[test]
+    fn media_range_list_fmt() {
+        let list = MediaRangeList::parse("text/html ,, image/*; q=0.5").unwrap();
+        assert_eq!(list.to_string(), "text/html, image/*; q=0.5");
+        assert_eq!(format!("{:?}", list), r#"["text/html", "image/*; q=0.5"]"#);
+    }
+
+    #[test]
+    fn media_range_list_into_iter() {
+        let list: MediaRangeList = "text/html, image/*".parse().unwrap();
+        let ranges = list.into_iter().collect::<Vec<_>>();
+        assert_eq!(ranges, [MediaRange::from(TEXT_HTML), IMAGE_STAR]);
+    }
+}
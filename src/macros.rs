@@ -1,4 +1,4 @@
-use super::MediaType;
+use super::{MediaRange, MediaType};
 
 impl MediaType {
     /// **DO NOT CALL THIS FUNCTION.**
@@ -25,8 +25,16 @@ impl MediaType {
     ///
     /// # Parameters
     ///
+    /// ```
+    /// let two = mime::media_type!("multipart/form-data; boundary=abcd; two=2");
+    /// assert_eq!(two.param("two").unwrap(), "2");
+    ///
+    /// const THREE: mime::MediaType = mime::media_type!("text/plain; a=1; b=2; c=3");
+    /// assert_eq!(THREE.params().count(), 3);
+    /// ```
+    ///
     /// ```compile_fail
-    /// mime::media_type!("multipart/form-data; boundary=abcd; two=2");
+    /// mime::media_type!("text/plain; title*=utf-8''%E2%82%AC");
     /// ```
     ///
     /// # Ranges
@@ -61,6 +69,48 @@ impl MediaType {
     }
 }
 
+impl MediaRange {
+    /// **DO NOT CALL THIS FUNCTION.**
+    ///
+    /// This function has no backwards-compatibility guarantees. It can and
+    /// *will* change, and your code *will* break.
+    /// Kittens **will** die.
+    ///
+    /// # Tests
+    ///
+    /// ```
+    /// let range = mime::media_range!("text/*");
+    /// assert_eq!(range, mime::TEXT_STAR);
+    /// assert!(range.matches(&mime::TEXT_PLAIN).is_some());
+    /// ```
+    ///
+    /// # Quality
+    ///
+    /// ```
+    /// const RANGE: mime::MediaRange = mime::media_range!("*/*; q=0.1");
+    /// assert_eq!(RANGE.quality().thousandths(), 100);
+    /// ```
+    ///
+    /// ```compile_fail
+    /// mime::media_range!("*/*; q=2");
+    /// ```
+    ///
+    /// # Not a range
+    ///
+    /// ```compile_fail
+    /// mime::media_range!("*/plain");
+    /// ```
+    #[doc(hidden)]
+    #[cfg(feature = "macro")]
+    pub const unsafe fn private_from_proc_macro(
+        mime: crate::private::Mime,
+    ) -> Self {
+        MediaRange {
+            mime,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::*;
@@ -121,6 +171,61 @@ mod tests {
         assert_eq!(mt.param("boundary").unwrap(), "AbCd");
     }
 
+    #[test]
+    fn media_type_from_static() {
+        macro_rules! same {
+            ($s:expr) => ({
+                let a = media_type!($s);
+                let b = MediaType::from_static($s);
+                assert_eq!(a.mime.private_atom(), b.mime.private_atom(), "{:?}", $s);
+                assert_eq!(a.as_ref(), b.as_ref());
+                assert_eq!(a.mime.private_subtype_offset(), b.mime.private_subtype_offset());
+                assert_eq!(a.suffix(), b.suffix());
+                assert_eq!(a.params().collect::<Vec<_>>(), b.params().collect::<Vec<_>>());
+                assert_eq!(a, b);
+            })
+        }
+
+        same!("text/plain");
+        same!("text/plain; charset=utf-8");
+        same!("text/foo; charset=utf-8");
+        same!("image/svg+xml");
+        same!("multipart/form-data; boundary=AbCd");
+        same!("text/plain; charset=\"utf-8\"");
+    }
+
+    #[test]
+    fn media_type_two_params() {
+        let mt = media_type!("text/plain; charset=utf-8; format=flowed");
+        assert_eq!(mt.param(CHARSET), Some(UTF_8));
+        assert_eq!(mt.param("format").unwrap(), "flowed");
+        assert_eq!(mt, MediaType::parse("text/plain; charset=utf-8; format=flowed").unwrap());
+        assert_eq!(mt.clone().without_params(), TEXT_PLAIN);
+    }
+
+    #[test]
+    fn media_type_many_params() {
+        static MT: MediaType = media_type!("Text/HTML; Level=1; a=\"b; c\"; Charset=ISO-8859-1");
+        assert_eq!(MT.to_string(), "text/html; level=1; a=\"b; c\"; charset=iso-8859-1");
+        assert_eq!(MT.params().count(), 3);
+        assert_eq!(MT.param("a").unwrap(), "b; c");
+        assert_eq!(MT, MediaType::parse("text/html; charset=iso-8859-1; a=\"b; c\"; level=1").unwrap());
+        assert_eq!(MT.clone().without_params(), TEXT_HTML);
+    }
+
+    #[test]
+    fn media_range() {
+        const RANGE: MediaRange = media_range!("application/*; a=1; q=0.5");
+        assert_eq!(RANGE.type_(), APPLICATION);
+        assert_eq!(RANGE.subtype(), STAR);
+        assert_eq!(RANGE.quality().thousandths(), 500);
+        assert!(RANGE.matches(&media_type!("application/json; a=1")).is_some());
+        assert!(RANGE.matches(&APPLICATION_JSON).is_none());
+
+        assert_eq!(media_range!("*/*"), STAR_STAR);
+        assert_eq!(media_range!("image/png"), MediaRange::from(IMAGE_PNG));
+    }
+
     #[test]
     fn media_type_lowercase() {
         let mt = media_type!("MULTIPART/FORM-DATA; BOUNDARY=AbCd");
@@ -0,0 +1,135 @@
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::{InvalidMime, MediaType, Value, BOUNDARY, MULTIPART};
+
+// The `bchars` of RFC 2046, without the space, which can't be last anyway.
+const BCHARS: &[u8] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz'()+_,-./:=?";
+
+const MAX_LEN: usize = 70;
+const GENERATED_LEN: usize = 40;
+
+pub(crate) fn multipart(subtype: &str) -> Result<MediaType, InvalidMime> {
+    MediaType::builder(MULTIPART, subtype)
+        .param(BOUNDARY, &generate())
+        .build()
+}
+
+pub(crate) fn boundary(mt: &MediaType) -> Option<Value<'_>> {
+    if mt.type_() != MULTIPART {
+        return None;
+    }
+    let value = mt.param(BOUNDARY)?;
+    if is_valid(&value.to_content()) {
+        Some(value)
+    } else {
+        None
+    }
+}
+
+fn is_valid(boundary: &str) -> bool {
+    (1..=MAX_LEN).contains(&boundary.len())
+        && !boundary.ends_with(' ')
+        && boundary.bytes().all(|b| b == b' ' || BCHARS.contains(&b))
+}
+
+// The boundary only needs to not appear in any of the parts, so it doesn't
+// need a CSPRNG. `RandomState` has random keys, and a new state is only an
+// increment away from the last one, so the counter and time are mixed in
+// to differ between threads and processes too.
+fn generate() -> String {
+    static COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+    let state = RandomState::new();
+    let count = COUNTER.fetch_add(1, Ordering::Relaxed);
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_nanos())
+        .unwrap_or(0);
+
+    let mut boundary = String::with_capacity(GENERATED_LEN);
+    let mut block = 0u64;
+    while boundary.len() < GENERATED_LEN {
+        let mut hasher = state.build_hasher();
+        hasher.write_usize(count);
+        hasher.write_u128(nanos);
+        hasher.write_u64(block);
+        let mut n = hasher.finish();
+        block += 1;
+
+        // 74^8 fits well within a u64
+        for _ in 0..8 {
+            boundary.push(BCHARS[(n % BCHARS.len() as u64) as usize] as char);
+            n /= BCHARS.len() as u64;
+        }
+    }
+    boundary.truncate(GENERATED_LEN);
+    boundary
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_constants() {
+        let cases = [
+            (MULTIPART_FORM_DATA, FORM_DATA),
+            (MULTIPART_MIXED, MIXED),
+            (MULTIPART_ALTERNATIVE, ALTERNATIVE),
+            (MULTIPART_RELATED, RELATED),
+            (MULTIPART_BYTERANGES, BYTERANGES),
+        ];
+        for (constant, subtype) in &cases {
+            assert_eq!(constant.type_(), MULTIPART);
+            assert_eq!(constant.subtype(), *subtype);
+            assert_eq!(MediaType::parse(constant.as_ref()).unwrap(), *constant);
+        }
+    }
+
+    #[test]
+    fn test_multipart() {
+        let mut seen = std::collections::HashSet::new();
+        for _ in 0..100 {
+            let mt = MediaType::multipart(FORM_DATA).unwrap();
+            assert_eq!(mt.clone().without_params(), MULTIPART_FORM_DATA);
+
+            let boundary = mt.boundary().unwrap().to_content().into_owned();
+            assert_eq!(boundary.len(), super::GENERATED_LEN);
+            assert!(super::is_valid(&boundary));
+            assert!(seen.insert(boundary));
+
+            // reparsing keeps the same boundary
+            let reparsed = MediaType::parse(mt.to_string()).unwrap();
+            assert_eq!(reparsed.boundary(), mt.boundary());
+        }
+
+        assert!(MediaType::multipart("not a subtype").is_err());
+    }
+
+    #[test]
+    fn test_boundary() {
+        let long = format!("multipart/mixed; boundary={}", "a".repeat(70));
+        let too_long = format!("multipart/mixed; boundary={}", "a".repeat(71));
+        let cases = [
+            ("multipart/mixed; boundary=simple", Some("simple")),
+            ("multipart/mixed; boundary=\"gc0pJq0M:08jU534c0p\"", Some("gc0pJq0M:08jU534c0p")),
+            ("multipart/mixed; boundary=\"simple boundary\"", Some("simple boundary")),
+            ("MULTIPART/MIXED; BOUNDARY=\"---=_Part_1\"", Some("---=_Part_1")),
+            (&long, Some(&long[26..])),
+            (&too_long, None),
+            ("multipart/mixed; boundary=\"\"", None),
+            ("multipart/mixed; boundary=\"trailing \"", None),
+            ("multipart/mixed; boundary=\"semi;colon\"", None),
+            ("multipart/mixed; boundary=a&b", None),
+            ("multipart/mixed", None),
+            ("text/plain; boundary=simple", None),
+        ];
+        for &(src, expected) in &cases {
+            let mt = MediaType::parse(src).unwrap();
+            assert_eq!(mt.boundary().map(|b| b.to_content().into_owned()).as_deref(), expected, "{}", src);
+        }
+    }
+}
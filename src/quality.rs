@@ -0,0 +1,151 @@
+use std::fmt;
+
+use mime_parse::ParseError;
+
+/// A quality weight, from the `q` parameter of a `MediaRange`.
+///
+/// Weights range from `0` to `1`, with up to three decimal digits of
+/// precision. A weight of `0` means "not acceptable".
+///
+/// # Example
+///
+/// ```
+/// use mime::{MediaRange, Quality};
+///
+/// let range = MediaRange::parse("text/*; q=0.5").unwrap();
+/// assert_eq!(range.quality(), Quality::from_thousandths(500).unwrap());
+///
+/// // no `q` means the highest weight
+/// assert_eq!(mime::TEXT_STAR.quality(), Quality::ONE);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Quality(u16);
+
+impl Quality {
+    /// The highest weight, `q=1`.
+    pub const ONE: Quality = Quality(1000);
+
+    /// The weight `q=0`, meaning "not acceptable".
+    pub const ZERO: Quality = Quality(0);
+
+    /// Create a `Quality` from an amount of thousandths.
+    ///
+    /// Returns `None` if `thousandths` is greater than `1000`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mime::Quality;
+    ///
+    /// assert_eq!(Quality::from_thousandths(1000), Some(Quality::ONE));
+    /// assert_eq!(Quality::from_thousandths(1001), None);
+    /// ```
+    #[inline]
+    pub fn from_thousandths(thousandths: u16) -> Option<Quality> {
+        if thousandths <= 1000 {
+            Some(Quality(thousandths))
+        } else {
+            None
+        }
+    }
+
+    /// Get this weight as an amount of thousandths.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let range = mime::MediaRange::parse("text/*; q=0.25").unwrap();
+    /// assert_eq!(range.quality().thousandths(), 250);
+    /// ```
+    #[inline]
+    pub fn thousandths(self) -> u16 {
+        self.0
+    }
+}
+
+impl Default for Quality {
+    #[inline]
+    fn default() -> Quality {
+        Quality::ONE
+    }
+}
+
+impl fmt::Debug for Quality {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Quality({})", self)
+    }
+}
+
+impl fmt::Display for Quality {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.0 {
+            1000 => f.write_str("1"),
+            0 => f.write_str("0"),
+            mut n => {
+                // trim trailing zeros, 500 is written as `0.5`
+                let mut digits = 3;
+                while n % 10 == 0 {
+                    n /= 10;
+                    digits -= 1;
+                }
+                write!(f, "0.{:0width$}", n, width = digits)
+            }
+        }
+    }
+}
+
+// From [RFC7231](https://tools.ietf.org/html/rfc7231#section-5.3.1):
+//
+// >     weight = OWS ";" OWS "q=" qvalue
+// >     qvalue = ( "0" [ "." 0*3DIGIT ] )
+// >            / ( "1" [ "." 0*3("0") ] )
+pub(crate) fn parse(s: &str) -> Result<Quality, ParseError> {
+    mime_parse::parse_quality(s).map(Quality)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, Quality};
+
+    #[test]
+    fn parse_valid() {
+        assert_eq!(parse("1").unwrap(), Quality::ONE);
+        assert_eq!(parse("1.").unwrap(), Quality::ONE);
+        assert_eq!(parse("1.000").unwrap(), Quality::ONE);
+        assert_eq!(parse("0").unwrap(), Quality::ZERO);
+        assert_eq!(parse("0.").unwrap(), Quality::ZERO);
+        assert_eq!(parse("0.5").unwrap().thousandths(), 500);
+        assert_eq!(parse("0.05").unwrap().thousandths(), 50);
+        assert_eq!(parse("0.123").unwrap().thousandths(), 123);
+    }
+
+    #[test]
+    fn parse_invalid() {
+        parse("").unwrap_err();
+        parse("2").unwrap_err();
+        parse("1.5").unwrap_err();
+        parse("1.0001").unwrap_err();
+        parse("0.1234").unwrap_err();
+        parse("0,5").unwrap_err();
+        parse(".5").unwrap_err();
+        parse("0.a").unwrap_err();
+        parse("-0").unwrap_err();
+        parse("\"0.5\"").unwrap_err();
+    }
+
+    #[test]
+    fn fmt() {
+        assert_eq!(Quality::ONE.to_string(), "1");
+        assert_eq!(Quality::ZERO.to_string(), "0");
+        assert_eq!(Quality(500).to_string(), "0.5");
+        assert_eq!(Quality(50).to_string(), "0.05");
+        assert_eq!(Quality(123).to_string(), "0.123");
+        assert_eq!(format!("{:?}", Quality(1)), "Quality(0.001)");
+    }
+
+    #[test]
+    fn ord() {
+        assert!(Quality::ZERO < Quality(1));
+        assert!(Quality(999) < Quality::ONE);
+    }
+}
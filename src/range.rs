@@ -1,9 +1,11 @@
+use std::cmp::Ordering;
 use std::fmt;
+use std::hash::{Hash, Hasher};
 use std::str::FromStr;
 
 use mime_parse::{Mime, Parse};
 
-use crate::{InvalidMime, MediaType, Value};
+use crate::{InvalidMime, MatchKind, MediaType, Quality, Specificity, Value, Q};
 
 /// A parsed media range used to match media types.
 ///
@@ -26,7 +28,7 @@ use crate::{InvalidMime, MediaType, Value};
 /// ];
 ///
 /// for format in &formats {
-///     if range.matches(format) {
+///     if range.matches(format).is_some() {
 ///         // This should print for the plain and HTML text...
 ///         println!("We could send in {:?} format!", format);
 ///     }
@@ -49,13 +51,57 @@ impl MediaRange {
     ///
     /// # Errors
     ///
-    /// Returns an error if the source is not a valid media range.
+    /// Returns an error if the source is not a valid media range, or has
+    /// an invalid `q` parameter.
     #[inline]
     pub fn parse(source: impl Parse) -> Result<Self, InvalidMime> {
         mime_parse::Parser::can_range()
             .parse(source)
-            .map(|mime| MediaRange { mime })
             .map_err(|e| InvalidMime { inner: e })
+            .and_then(MediaRange::from_mime)
+    }
+
+    /// Parse a string as a `MediaRange`, also allowing a wildcard subtype
+    /// with a structured syntax suffix, such as `application/*+json`.
+    ///
+    /// This is not part of the HTTP grammar, so it is not allowed by
+    /// [`MediaRange::parse`], but it is useful for configuration that wants
+    /// to match any type built on some format.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mime::{MatchKind, MediaRange, MediaType};
+    ///
+    /// let json = MediaRange::parse_with_suffix_wildcard("application/*+json").unwrap();
+    ///
+    /// let problem = MediaType::parse("application/problem+json").unwrap();
+    /// let how = json.matches(&problem).unwrap();
+    /// assert_eq!(how.kind(), MatchKind::SuffixWildcard);
+    ///
+    /// let ld = MediaType::parse("application/ld+json").unwrap();
+    /// assert!(json.matches(&ld).is_some());
+    ///
+    /// assert!(json.matches(&mime::APPLICATION_JSON).is_none());
+    /// assert!(MediaRange::parse("application/*+json").is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the source is not a valid media range, or has
+    /// an invalid `q` parameter.
+    pub fn parse_with_suffix_wildcard(source: impl Parse) -> Result<Self, InvalidMime> {
+        mime_parse::Parser::can_suffix_range()
+            .parse(source)
+            .map_err(|e| InvalidMime { inner: e })
+            .and_then(MediaRange::from_mime)
+    }
+
+    pub(crate) fn from_mime(mime: Mime) -> Result<Self, InvalidMime> {
+        if let Some(q) = mime.param(Q) {
+            crate::quality::parse(q).map_err(|e| InvalidMime { inner: e })?;
+        }
+        Ok(MediaRange { mime })
     }
 
     /// Get the top level media type for this `MediaRange`.
@@ -113,53 +159,164 @@ impl MediaRange {
 
     /// Checks if this `MediaRange` matches a specific `MediaType`.
     ///
+    /// If it does, returns the [`Specificity`] of the match, describing
+    /// whether it was exact or through a wildcard, and how many parameters
+    /// were matched.
+    ///
     /// # Example
     ///
     /// ```
+    /// use mime::MatchKind;
+    ///
     /// let images = mime::IMAGE_STAR;
     ///
-    /// assert!(images.matches(&mime::IMAGE_JPEG));
-    /// assert!(images.matches(&mime::IMAGE_PNG));
+    /// assert!(images.matches(&mime::IMAGE_JPEG).is_some());
+    /// assert!(images.matches(&mime::IMAGE_PNG).is_some());
     ///
-    /// assert!(!images.matches(&mime::TEXT_PLAIN));
+    /// assert!(images.matches(&mime::TEXT_PLAIN).is_none());
+    ///
+    /// let how = images.matches(&mime::IMAGE_PNG).unwrap();
+    /// assert_eq!(how.kind(), MatchKind::SubtypeWildcard);
+    /// assert_eq!(how.params(), 0);
     /// ```
-    pub fn matches(&self, mt: &MediaType) -> bool {
+    pub fn matches(&self, mt: &MediaType) -> Option<Specificity> {
+        let kind = self.match_kind(mt)?;
+        // type and subtype match, last thing to do is check that the
+        // MediaType contains all this range's parameters...
+        self.matches_params(mt, kind)
+    }
+
+    /// Matches the type and subtype, ignoring parameters.
+    pub(crate) fn match_kind(&self, mt: &MediaType) -> Option<MatchKind> {
         let type_ = self.type_();
 
         if type_ == crate::STAR {
             // sanity check there's no `*/plain` or whatever
             debug_assert_eq!(self.subtype(), crate::STAR);
 
-            return self.matches_params(mt);
+            return Some(MatchKind::FullWildcard);
         }
 
         if type_ != mt.type_() {
-            return false;
+            return None;
         }
 
         let subtype = self.subtype();
 
         if subtype == crate::STAR {
-            return self.matches_params(mt);
+            return Some(MatchKind::SubtypeWildcard);
+        }
+
+        if self.is_suffix_wildcard() {
+            if self.suffix() != mt.suffix() {
+                return None;
+            }
+            return Some(MatchKind::SuffixWildcard);
         }
 
         if subtype != mt.subtype() {
-            return false;
+            return None;
         }
 
-        // type and subtype are the same, last thing to do is check
-        // that the MediaType contains all this range's parameters...
-        self.matches_params(mt)
+        Some(MatchKind::Exact)
     }
 
-    fn matches_params(&self, mt: &MediaType) -> bool {
-        for (name, value) in self.params() {
-            if name != "q" && mt.param(name) != Some(value) {
-                return false;
+    pub(crate) fn matches_params(&self, mt: &MediaType, kind: MatchKind) -> Option<Specificity> {
+        let mut count = 0;
+        for (name, value) in self.range_params() {
+            if mt.param(name) != Some(value) {
+                return None;
             }
+            count += 1;
         }
 
-        true
+        Some(Specificity::new(kind, count))
+    }
+
+    // `type/*+suffix`, from `parse_with_suffix_wildcard`
+    fn is_suffix_wildcard(&self) -> bool {
+        self.subtype().starts_with("*+")
+    }
+
+    /// The parameters before `q`, the rest are accept-extensions and not
+    /// part of the range.
+    fn range_params(&self) -> impl Iterator<Item = (&str, Value<'_>)> {
+        self.params().take_while(|&(name, _)| name != Q)
+    }
+
+    /// Get how specific this `MediaRange` is.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mime::MatchKind;
+    ///
+    /// let range = mime::MediaRange::parse("text/*; charset=utf-8; q=0.5").unwrap();
+    ///
+    /// assert_eq!(range.specificity().kind(), MatchKind::SubtypeWildcard);
+    /// assert_eq!(range.specificity().params(), 1);
+    /// ```
+    pub fn specificity(&self) -> Specificity {
+        let kind = if self.type_() == crate::STAR {
+            MatchKind::FullWildcard
+        } else if self.subtype() == crate::STAR {
+            MatchKind::SubtypeWildcard
+        } else if self.is_suffix_wildcard() {
+            MatchKind::SuffixWildcard
+        } else {
+            MatchKind::Exact
+        };
+
+        Specificity::new(kind, self.range_params().count())
+    }
+
+    /// Compares the precedence of two `MediaRange`s.
+    ///
+    /// A range with a greater [`Specificity`] has higher precedence. Sorting
+    /// with this comparator puts the least specific ranges first.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mime::MediaRange;
+    ///
+    /// let mut ranges = vec![
+    ///     mime::STAR_STAR,
+    ///     MediaRange::parse("text/plain; format=flowed").unwrap(),
+    ///     mime::TEXT_STAR,
+    ///     MediaRange::from(mime::TEXT_PLAIN),
+    /// ];
+    ///
+    /// // most specific first
+    /// ranges.sort_by(|a, b| b.cmp_precedence(a));
+    ///
+    /// assert_eq!(ranges[0], "text/plain; format=flowed");
+    /// assert_eq!(ranges[1], "text/plain");
+    /// assert_eq!(ranges[2], "text/*");
+    /// assert_eq!(ranges[3], "*/*");
+    /// ```
+    pub fn cmp_precedence(&self, other: &MediaRange) -> Ordering {
+        self.specificity().cmp(&other.specificity())
+    }
+
+    /// Get the quality weight of this `MediaRange`.
+    ///
+    /// This is the value of the `q` parameter, or `Quality::ONE` if there
+    /// is none.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let range = mime::MediaRange::parse("image/*; q=0.8").unwrap();
+    /// assert_eq!(range.quality().thousandths(), 800);
+    ///
+    /// assert_eq!(mime::IMAGE_STAR.quality(), mime::Quality::ONE);
+    /// ```
+    pub fn quality(&self) -> Quality {
+        self.mime
+            .param(Q)
+            .and_then(|q| crate::quality::parse(q).ok())
+            .unwrap_or_default()
     }
 
     /// Look up a parameter by name.
@@ -199,7 +356,7 @@ impl MediaRange {
     /// assert!(params.next().is_none());
     /// ```
     #[inline]
-    pub fn params(&self) -> impl Iterator<Item = (&str, Value)> {
+    pub fn params(&self) -> impl Iterator<Item = (&str, Value<'_>)> {
         crate::value::params(&self.mime)
     }
 
@@ -247,6 +404,27 @@ impl PartialEq for MediaRange {
     }
 }
 
+impl Eq for MediaRange {}
+
+impl Hash for MediaRange {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        crate::cmp::mime_hash(&self.mime, state);
+    }
+}
+
+impl PartialOrd for MediaRange {
+    #[inline]
+    fn partial_cmp(&self, other: &MediaRange) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for MediaRange {
+    fn cmp(&self, other: &MediaRange) -> Ordering {
+        crate::cmp::mime_cmp(&self.mime, &other.mime)
+    }
+}
+
 impl PartialEq<str> for MediaRange {
     fn eq(&self, s: &str) -> bool {
         crate::cmp::str_eq(&self.mime, s)
@@ -324,43 +502,122 @@ mod tests {
 
     #[test]
     fn media_range_matches() {
-        assert!(STAR_STAR.matches(&TEXT_PLAIN), "*/* matches everything");
+        assert!(STAR_STAR.matches(&TEXT_PLAIN).is_some(), "*/* matches everything");
 
-        assert!(TEXT_STAR.matches(&TEXT_PLAIN), "text/* matches text/plain");
-        assert!(TEXT_STAR.matches(&TEXT_HTML), "text/* matches text/html");
-        assert!(TEXT_STAR.matches(&TEXT_HTML_UTF_8), "text/* matches text/html; charset=utf-8");
+        assert!(TEXT_STAR.matches(&TEXT_PLAIN).is_some(), "text/* matches text/plain");
+        assert!(TEXT_STAR.matches(&TEXT_HTML).is_some(), "text/* matches text/html");
+        assert!(TEXT_STAR.matches(&TEXT_HTML_UTF_8).is_some(), "text/* matches text/html; charset=utf-8");
 
-        assert!(!TEXT_STAR.matches(&IMAGE_GIF), "text/* doesn't match image/gif");
+        assert!(TEXT_STAR.matches(&IMAGE_GIF).is_none(), "text/* doesn't match image/gif");
     }
 
     #[test]
     fn media_range_matches_params() {
         let text_any_utf8 = MediaRange::parse("text/*; charset=utf-8").unwrap();
 
-        assert!(text_any_utf8.matches(&TEXT_PLAIN_UTF_8));
-        assert!(text_any_utf8.matches(&TEXT_HTML_UTF_8));
+        assert!(text_any_utf8.matches(&TEXT_PLAIN_UTF_8).is_some());
+        assert!(text_any_utf8.matches(&TEXT_HTML_UTF_8).is_some());
 
-        assert!(!text_any_utf8.matches(&TEXT_HTML));
+        assert!(text_any_utf8.matches(&TEXT_HTML).is_none());
 
         let many_params = MediaType::parse("text/plain; charset=utf-8; foo=bar").unwrap();
-        assert!(text_any_utf8.matches(&many_params));
+        assert!(text_any_utf8.matches(&many_params).is_some());
 
         let text_plain = MediaRange::parse("text/plain").unwrap();
-        assert!(text_plain.matches(&many_params));
+        assert!(text_plain.matches(&many_params).is_some());
     }
 
     #[test]
     fn media_range_matches_skips_q() {
         let range = MediaRange::parse("text/*; q=0.8").unwrap();
 
-        assert!(range.matches(&TEXT_PLAIN_UTF_8));
-        assert!(range.matches(&TEXT_HTML_UTF_8));
+        assert!(range.matches(&TEXT_PLAIN_UTF_8).is_some());
+        assert!(range.matches(&TEXT_HTML_UTF_8).is_some());
 
         let range = MediaRange::parse("text/*; charset=utf-8; q=0.8").unwrap();
 
-        assert!(range.matches(&TEXT_PLAIN_UTF_8));
-        assert!(range.matches(&TEXT_HTML_UTF_8));
-        assert!(!range.matches(&TEXT_HTML));
+        assert!(range.matches(&TEXT_PLAIN_UTF_8).is_some());
+        assert!(range.matches(&TEXT_HTML_UTF_8).is_some());
+        assert!(range.matches(&TEXT_HTML).is_none());
+    }
+
+    #[test]
+    fn media_range_matches_skips_accept_ext() {
+        let range = MediaRange::parse("text/*; q=0.8; foo=bar").unwrap();
+
+        assert!(range.matches(&TEXT_PLAIN).is_some());
+        assert!(range.matches(&TEXT_HTML_UTF_8).is_some());
+    }
+
+    #[test]
+    fn media_range_matches_specificity() {
+        let any = STAR_STAR.matches(&TEXT_PLAIN_UTF_8).unwrap();
+        assert_eq!(any.kind(), MatchKind::FullWildcard);
+        assert_eq!(any.params(), 0);
+
+        let text = TEXT_STAR.matches(&TEXT_PLAIN_UTF_8).unwrap();
+        assert_eq!(text.kind(), MatchKind::SubtypeWildcard);
+
+        let utf8 = MediaRange::from(TEXT_PLAIN_UTF_8).matches(&TEXT_PLAIN_UTF_8).unwrap();
+        assert_eq!(utf8.kind(), MatchKind::Exact);
+        assert_eq!(utf8.params(), 1);
+
+        assert!(any < text);
+        assert!(text < utf8);
+    }
+
+    #[test]
+    fn media_range_specificity_order() {
+        let flowed = MediaRange::parse("text/plain; format=flowed; q=0.1").unwrap();
+        let plain = MediaRange::parse("text/plain; q=1; ext=1; ext2=2").unwrap();
+
+        assert_eq!(flowed.specificity().params(), 1);
+        assert_eq!(plain.specificity().params(), 0);
+
+        assert_eq!(flowed.cmp_precedence(&plain), std::cmp::Ordering::Greater);
+        assert_eq!(plain.cmp_precedence(&TEXT_STAR), std::cmp::Ordering::Greater);
+        assert_eq!(TEXT_STAR.cmp_precedence(&IMAGE_STAR), std::cmp::Ordering::Equal);
+        assert_eq!(STAR_STAR.cmp_precedence(&IMAGE_STAR), std::cmp::Ordering::Less);
     }
-}
 
+    #[test]
+    fn media_range_quality() {
+        assert_eq!(STAR_STAR.quality(), Quality::ONE);
+        assert_eq!(MediaRange::parse("*/*; q=0").unwrap().quality(), Quality::ZERO);
+        assert_eq!(MediaRange::parse("text/*; Q=0.3").unwrap().quality().thousandths(), 300);
+
+        MediaRange::parse("text/*; q=1.1").unwrap_err();
+        MediaRange::parse("text/*; q=high").unwrap_err();
+    }
+
+    #[test]
+    fn media_range_suffix_wildcard() {
+        let json = MediaRange::parse_with_suffix_wildcard("application/*+json; q=0.9").unwrap();
+        assert_eq!(json.subtype(), "*+json");
+        assert_eq!(json.suffix(), Some(JSON));
+        assert_eq!(json.quality().thousandths(), 900);
+
+        for &src in &["application/problem+json", "application/ld+json; profile=x", "APPLICATION/VND.API+JSON"] {
+            let mt = MediaType::parse(src).unwrap();
+            assert_eq!(json.matches(&mt).unwrap().kind(), MatchKind::SuffixWildcard, "{}", src);
+        }
+        assert!(json.matches(&APPLICATION_JSON).is_none());
+        assert!(json.matches(&IMAGE_SVG).is_none());
+        assert!(json.matches(&MediaType::parse("text/foo+json").unwrap()).is_none());
+
+        let with_params = MediaRange::parse_with_suffix_wildcard("application/*+json; profile=x").unwrap();
+        assert!(with_params.matches(&MediaType::parse("application/ld+json; profile=x").unwrap()).is_some());
+        assert!(with_params.matches(&MediaType::parse("application/ld+json").unwrap()).is_none());
+
+        // ordered between `type/*` and exact types
+        assert!(json.specificity() > MediaRange::parse("application/*").unwrap().specificity());
+        assert!(json.specificity() < MediaRange::parse("application/ld+json").unwrap().specificity());
+
+        // the usual ranges still work
+        assert_eq!(MediaRange::parse_with_suffix_wildcard("*/*").unwrap(), STAR_STAR);
+        assert_eq!(MediaRange::parse_with_suffix_wildcard("text/*").unwrap(), TEXT_STAR);
+
+        MediaRange::parse("application/*+json").unwrap_err();
+        MediaRange::parse_with_suffix_wildcard("application/*+json; q=2").unwrap_err();
+    }
+}
@@ -0,0 +1,316 @@
+//! The IANA media type registry.
+//!
+//! Every type in the [IANA registry](https://www.iana.org/assignments/media-types/)
+//! has an [`Entry`], with what is known about its registration. The table
+//! is generated by `mime-gen` from a vendored snapshot of the registry.
+//!
+//! This requires the `registry` feature enabled on the mime crate.
+//!
+//! # Example
+//!
+//! ```
+//! use mime::registry::{Registry, Status};
+//!
+//! let entry = Registry::lookup(&mime::APPLICATION_JAVASCRIPT).unwrap();
+//! assert_eq!(entry.status(), Status::Obsolete);
+//! assert_eq!(entry.replacement(), Some(mime::TEXT_JAVASCRIPT));
+//!
+//! let entry = Registry::lookup(&mime::TEXT_PLAIN_UTF_8).unwrap();
+//! assert_eq!(entry.rfc(), Some(2046));
+//! assert!(entry.optional_params().contains(&"charset"));
+//!
+//! let unregistered = mime::MediaType::parse("application/x-unknown").unwrap();
+//! assert!(Registry::lookup(&unregistered).is_none());
+//! ```
+
+use std::cmp::Ordering;
+
+use crate::MediaType;
+
+mod table;
+
+/// Lookups into the IANA media type registry.
+///
+/// See the [module docs](self) for more.
+#[derive(Debug)]
+pub struct Registry {
+    _priv: (),
+}
+
+impl Registry {
+    /// Get the registry entry for a `MediaType`.
+    ///
+    /// Parameters are ignored. Returns `None` if the type isn't registered.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mime::registry::Registry;
+    ///
+    /// let entry = Registry::lookup(&mime::APPLICATION_JSON).unwrap();
+    /// assert_eq!(entry.template(), "application/json");
+    /// assert_eq!(entry.reference(), Some("[RFC8259]"));
+    /// ```
+    pub fn lookup(mt: &MediaType) -> Option<&'static Entry> {
+        let essence = mt.mime.essence();
+        table::ENTRIES
+            .binary_search_by(|entry| cmp_lowercase(entry.template, essence))
+            .ok()
+            .map(|i| &table::ENTRIES[i])
+    }
+
+    /// Returns an iterator over every entry in the registry, sorted by
+    /// template.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mime::registry::Registry;
+    ///
+    /// let fonts = Registry::iter()
+    ///     .filter(|entry| entry.media_type().type_() == mime::FONT)
+    ///     .count();
+    /// assert_eq!(fonts, 6);
+    /// ```
+    pub fn iter() -> impl Iterator<Item = &'static Entry> {
+        table::ENTRIES.iter()
+    }
+}
+
+/// The registration of a media type.
+#[derive(Clone, Debug)]
+pub struct Entry {
+    template: &'static str,
+    reference: Option<&'static str>,
+    rfc: Option<u16>,
+    status: Status,
+    replacement: Option<&'static str>,
+    required: &'static [&'static str],
+    optional: &'static [&'static str],
+}
+
+/// Whether a registration is still in use.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Status {
+    /// The type is current.
+    Current,
+    /// The type is deprecated, and shouldn't be used for new content.
+    Deprecated,
+    /// The type is obsolete, and shouldn't be used at all.
+    Obsolete,
+}
+
+impl Entry {
+    /// Get the type as it was registered.
+    ///
+    /// Some registrations use uppercase letters, which a parsed
+    /// `MediaType` won't have.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let h264 = mime::MediaType::parse("video/h264").unwrap();
+    /// let entry = mime::registry::Registry::lookup(&h264).unwrap();
+    /// assert_eq!(entry.template(), "video/H264");
+    /// ```
+    pub fn template(&self) -> &'static str {
+        self.template
+    }
+
+    /// Get the registered type as a `MediaType`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let entry = mime::registry::Registry::lookup(&mime::TEXT_CSV_UTF_8).unwrap();
+    /// assert_eq!(entry.media_type(), mime::TEXT_CSV);
+    /// ```
+    pub fn media_type(&self) -> MediaType {
+        MediaType::parse(self.template).expect("registry has valid media types")
+    }
+
+    /// Get the references of the registration, as they are written in the
+    /// registry, such as `"[RFC2045][RFC2046]"`.
+    ///
+    /// Returns `None` if the snapshot doesn't have a reference for it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let entry = mime::registry::Registry::lookup(&mime::TEXT_CSS).unwrap();
+    /// assert_eq!(entry.reference(), Some("[RFC2318]"));
+    /// ```
+    pub fn reference(&self) -> Option<&'static str> {
+        self.reference
+    }
+
+    /// Get the number of the first RFC in the references.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let entry = mime::registry::Registry::lookup(&mime::APPLICATION_OCTET_STREAM).unwrap();
+    /// assert_eq!(entry.rfc(), Some(2045));
+    ///
+    /// let entry = mime::registry::Registry::lookup(&mime::TEXT_HTML).unwrap();
+    /// assert_eq!(entry.reference(), Some("[W3C][Robin_Berjon]"));
+    /// assert_eq!(entry.rfc(), None);
+    /// ```
+    pub fn rfc(&self) -> Option<u16> {
+        self.rfc
+    }
+
+    /// Get the status of the registration.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mime::registry::{Registry, Status};
+    ///
+    /// assert_eq!(Registry::lookup(&mime::TEXT_JAVASCRIPT).unwrap().status(), Status::Current);
+    /// assert_eq!(Registry::lookup(&mime::APPLICATION_JAVASCRIPT).unwrap().status(), Status::Obsolete);
+    /// ```
+    pub fn status(&self) -> Status {
+        self.status
+    }
+
+    /// Get the type that should be used instead of an obsolete or
+    /// deprecated one.
+    ///
+    /// Not every obsolete type names a replacement.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let woff = mime::MediaType::parse("application/font-woff").unwrap();
+    /// let entry = mime::registry::Registry::lookup(&woff).unwrap();
+    /// assert_eq!(entry.replacement(), Some(mime::FONT_WOFF));
+    /// ```
+    pub fn replacement(&self) -> Option<MediaType> {
+        self.replacement
+            .map(|s| MediaType::parse(s).expect("registry has valid replacements"))
+    }
+
+    /// Get the names of the parameters that the registration requires.
+    ///
+    /// Parameters are only known for some common types, so an empty slice
+    /// doesn't promise the registration has none.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mixed = mime::MediaType::parse("multipart/mixed").unwrap();
+    /// let entry = mime::registry::Registry::lookup(&mixed).unwrap();
+    /// assert_eq!(entry.required_params(), &["boundary"]);
+    /// ```
+    pub fn required_params(&self) -> &'static [&'static str] {
+        self.required
+    }
+
+    /// Get the names of the optional parameters of the registration.
+    ///
+    /// Like [`required_params`](Entry::required_params), these are only
+    /// known for some common types.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let entry = mime::registry::Registry::lookup(&mime::TEXT_PLAIN).unwrap();
+    /// assert_eq!(entry.optional_params(), &["charset", "format", "delsp"]);
+    /// ```
+    pub fn optional_params(&self) -> &'static [&'static str] {
+        self.optional
+    }
+}
+
+// Compares a registered template to a lowercase essence.
+fn cmp_lowercase(template: &str, essence: &str) -> Ordering {
+    template
+        .bytes()
+        .map(|b| b.to_ascii_lowercase())
+        .cmp(essence.bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use super::{table, Registry, Status};
+
+    #[test]
+    fn table_is_sorted() {
+        for pair in table::ENTRIES.windows(2) {
+            let (a, b) = (pair[0].template, pair[1].template);
+            assert!(a.to_ascii_lowercase() < b.to_ascii_lowercase(), "{:?} sorted before {:?}", a, b);
+        }
+    }
+
+    #[test]
+    fn table_parses() {
+        for entry in Registry::iter() {
+            let mt = entry.media_type();
+            assert!(mt.mime.essence().eq_ignore_ascii_case(entry.template), "{:?}", entry);
+            assert!(Registry::lookup(&mt).is_some(), "{:?}", entry);
+            if let Some(replacement) = entry.replacement() {
+                assert_ne!(entry.status(), Status::Current, "{:?}", entry);
+                assert!(Registry::lookup(&replacement).is_some(), "{:?}", entry);
+            }
+        }
+    }
+
+    #[test]
+    fn lookup_constants() {
+        let registered = [
+            APPLICATION_JSON,
+            APPLICATION_OCTET_STREAM,
+            APPLICATION_PDF,
+            APPLICATION_WWW_FORM_URLENCODED,
+            IMAGE_PNG,
+            IMAGE_SVG,
+            FONT_WOFF2,
+            TEXT_HTML_UTF_8,
+            TEXT_VCARD,
+            TEXT_XML,
+        ];
+        for mt in &registered {
+            let entry = Registry::lookup(mt).unwrap_or_else(|| panic!("{} is registered", mt));
+            assert_eq!(entry.status(), Status::Current, "{}", mt);
+            assert!(entry.reference().is_some(), "{}", mt);
+        }
+
+        assert!(Registry::lookup(&APPLICATION_MSGPACK).is_none());
+    }
+
+    #[test]
+    fn lookup_ignores_case_and_params() {
+        let mt = MediaType::parse("Multipart/Related; Type=\"text/html\"").unwrap();
+        let entry = Registry::lookup(&mt).unwrap();
+        assert_eq!(entry.rfc(), Some(2387));
+        assert_eq!(entry.required_params(), &["boundary", "type"]);
+        assert_eq!(entry.optional_params(), &["start", "start-info"]);
+    }
+
+    #[test]
+    fn obsolete_and_deprecated() {
+        let geo = MediaType::parse("application/vnd.geo+json").unwrap();
+        let entry = Registry::lookup(&geo).unwrap();
+        assert_eq!(entry.status(), Status::Obsolete);
+        assert_eq!(entry.replacement().unwrap(), "application/geo+json");
+        assert_eq!(entry.rfc(), None);
+
+        let afp = MediaType::parse("application/vnd.ibm.afplinedata").unwrap();
+        let entry = Registry::lookup(&afp).unwrap();
+        assert_eq!(entry.replacement().unwrap(), "application/vnd.afpc.afplinedata");
+
+        let news = MediaType::parse("message/news").unwrap();
+        let entry = Registry::lookup(&news).unwrap();
+        assert_eq!(entry.status(), Status::Obsolete);
+        assert!(entry.replacement().is_none());
+        assert_eq!(entry.rfc(), Some(5537));
+
+        let directory = MediaType::parse("text/directory").unwrap();
+        let entry = Registry::lookup(&directory).unwrap();
+        assert_eq!(entry.status(), Status::Deprecated);
+        assert!(entry.replacement().is_none());
+        assert_eq!(entry.rfc(), Some(2425));
+    }
+}
@@ -0,0 +1,2145 @@
+// This file is @generated by `cargo run -p mime-gen`, do not edit it by hand.
+
+use super::{Entry, Status};
+
+pub(super) static ENTRIES: &[Entry] = &[
+    Entry { template: "application/1d-interleaved-parityfec", reference: Some("[RFC6015]"), rfc: Some(6015), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/3gpdash-qoe-report+xml", reference: Some("[_3GPP][Ozgur_Oyman]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/3gpp-ims+xml", reference: Some("[_3GPP][John_M_Meredith]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/3gppHal+json", reference: Some("[_3GPP][Ulrich_Wiehe]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/3gppHalForms+json", reference: Some("[_3GPP][Ulrich_Wiehe]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/A2L", reference: Some("[ASAM][Thomas_Thomsen]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/ace+cbor", reference: Some("[RFC9200]"), rfc: Some(9200), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/ace+json", reference: Some("[RFC9431]"), rfc: Some(9431), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/ace-groupcomm+cbor", reference: Some("[RFC9594]"), rfc: Some(9594), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/activemessage", reference: Some("[Ehud_Shapiro]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/activity+json", reference: Some("[W3C][Benjamin_Goering]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/aif+cbor", reference: Some("[RFC9237]"), rfc: Some(9237), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/aif+json", reference: Some("[RFC9237]"), rfc: Some(9237), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/alto-cdni+json", reference: Some("[RFC9241]"), rfc: Some(9241), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/alto-cdnifilter+json", reference: Some("[RFC9241]"), rfc: Some(9241), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/alto-costmap+json", reference: Some("[RFC7285]"), rfc: Some(7285), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/alto-costmapfilter+json", reference: Some("[RFC7285]"), rfc: Some(7285), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/alto-directory+json", reference: Some("[RFC7285]"), rfc: Some(7285), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/alto-endpointcost+json", reference: Some("[RFC7285]"), rfc: Some(7285), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/alto-endpointcostparams+json", reference: Some("[RFC7285]"), rfc: Some(7285), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/alto-endpointprop+json", reference: Some("[RFC7285]"), rfc: Some(7285), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/alto-endpointpropparams+json", reference: Some("[RFC7285]"), rfc: Some(7285), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/alto-error+json", reference: Some("[RFC7285]"), rfc: Some(7285), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/alto-networkmap+json", reference: Some("[RFC7285]"), rfc: Some(7285), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/alto-networkmapfilter+json", reference: Some("[RFC7285]"), rfc: Some(7285), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/alto-propmap+json", reference: Some("[RFC9240]"), rfc: Some(9240), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/alto-propmapparams+json", reference: Some("[RFC9240]"), rfc: Some(9240), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/alto-tips+json", reference: Some("[RFC9569]"), rfc: Some(9569), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/alto-tipsparams+json", reference: Some("[RFC9569]"), rfc: Some(9569), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/alto-updatestreamcontrol+json", reference: Some("[RFC8895]"), rfc: Some(8895), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/alto-updatestreamparams+json", reference: Some("[RFC8895]"), rfc: Some(8895), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/AML", reference: Some("[ASAM][Thomas_Thomsen]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/andrew-inset", reference: Some("[Nathaniel_Borenstein]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/applefile", reference: Some("[Patrik_Faltstrom]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/at+jwt", reference: Some("[RFC9068]"), rfc: Some(9068), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/ATF", reference: Some("[ASAM][Thomas_Thomsen]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/ATFX", reference: Some("[ASAM][Thomas_Thomsen]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/atom+xml", reference: Some("[RFC4287][RFC5023]"), rfc: Some(4287), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/atomcat+xml", reference: Some("[RFC5023]"), rfc: Some(5023), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/atomdeleted+xml", reference: Some("[RFC6721]"), rfc: Some(6721), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/atomicmail", reference: Some("[Nathaniel_Borenstein]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/atomsvc+xml", reference: Some("[RFC5023]"), rfc: Some(5023), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/atsc-dwd+xml", reference: Some("[ATSC]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/atsc-dynamic-event-message", reference: Some("[ATSC]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/atsc-held+xml", reference: Some("[ATSC]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/atsc-rdt+json", reference: Some("[ATSC]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/atsc-rsat+xml", reference: Some("[ATSC]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/ATXML", reference: Some("[ASAM][Thomas_Thomsen]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/auth-policy+xml", reference: Some("[RFC4745]"), rfc: Some(4745), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/automationml-aml+xml", reference: Some("[AutomationML_e.V.]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/automationml-amlx+zip", reference: Some("[AutomationML_e.V.]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/bacnet-xdd+zip", reference: Some("[ASHRAE][Dave_Robin]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/batch-SMTP", reference: Some("[RFC2442]"), rfc: Some(2442), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/beep+xml", reference: Some("[RFC3080]"), rfc: Some(3080), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/bufr", reference: Some("[World_Meterological_Organization][Anna_Milan]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/c2pa", reference: Some("[C2PA][Leonard_Rosenthol]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/calendar+json", reference: Some("[RFC7265]"), rfc: Some(7265), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/calendar+xml", reference: Some("[RFC6321]"), rfc: Some(6321), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/call-completion", reference: Some("[RFC6910]"), rfc: Some(6910), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/CALS-1840", reference: Some("[RFC1895]"), rfc: Some(1895), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/captive+json", reference: Some("[RFC8908]"), rfc: Some(8908), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/cbor", reference: Some("[RFC8949]"), rfc: Some(8949), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/cbor-seq", reference: Some("[RFC8742]"), rfc: Some(8742), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/cccex", reference: Some("[_3GPP]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/ccmp+xml", reference: Some("[RFC6503]"), rfc: Some(6503), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/ccxml+xml", reference: Some("[RFC4267]"), rfc: Some(4267), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/cda+xml", reference: Some("[HL7][Marc_Duteau]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/CDFX+XML", reference: Some("[ASAM][Thomas_Thomsen]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/cdmi-capability", reference: Some("[RFC6208]"), rfc: Some(6208), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/cdmi-container", reference: Some("[RFC6208]"), rfc: Some(6208), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/cdmi-domain", reference: Some("[RFC6208]"), rfc: Some(6208), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/cdmi-object", reference: Some("[RFC6208]"), rfc: Some(6208), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/cdmi-queue", reference: Some("[RFC6208]"), rfc: Some(6208), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/cdni", reference: Some("[RFC7736]"), rfc: Some(7736), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/CEA", reference: Some("[ASAM][Thomas_Thomsen]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/cea-2018+xml", reference: Some("[Gottfried_Zimmermann]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/cellml+xml", reference: Some("[RFC4708]"), rfc: Some(4708), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/cfw", reference: Some("[RFC6230]"), rfc: Some(6230), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/cid-edhoc+cbor-seq", reference: Some("[RFC9528]"), rfc: Some(9528), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/city+json", reference: Some("[OGC][Scott_Simmons]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/clr", reference: Some("[IMS_Global][Andy_Miller]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/clue+xml", reference: Some("[RFC8847]"), rfc: Some(8847), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/clue_info+xml", reference: Some("[RFC8846]"), rfc: Some(8846), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/cms", reference: Some("[RFC7193]"), rfc: Some(7193), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/cnrp+xml", reference: Some("[RFC3367]"), rfc: Some(3367), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/coap-group+json", reference: Some("[RFC7390]"), rfc: Some(7390), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/coap-payload", reference: Some("[RFC8075]"), rfc: Some(8075), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/commonground", reference: Some("[David_Glazer]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/concise-problem-details+cbor", reference: Some("[RFC9290, Section 6.3]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/conference-info+xml", reference: Some("[RFC4575]"), rfc: Some(4575), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/cose", reference: Some("[RFC9052]"), rfc: Some(9052), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/cose-key", reference: Some("[RFC9052]"), rfc: Some(9052), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/cose-key-set", reference: Some("[RFC9052]"), rfc: Some(9052), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/cose-x509", reference: Some("[RFC9360]"), rfc: Some(9360), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/cpl+xml", reference: Some("[RFC3880]"), rfc: Some(3880), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/csrattrs", reference: Some("[RFC7030]"), rfc: Some(7030), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/csta+xml", reference: Some("[Ecma_International_Helpdesk]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/CSTAdata+xml", reference: Some("[Ecma_International_Helpdesk]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/csvm+json", reference: Some("[W3C][Ivan_Herman]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/cwl", reference: Some("[CWL_Project][Michael_R._Crusoe]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/cwl+json", reference: Some("[CWL_Project][Michael_R._Crusoe]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/cwl+yaml", reference: Some("[CWL_Project][Michael_R._Crusoe]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/cwt", reference: Some("[RFC8392]"), rfc: Some(8392), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/cybercash", reference: Some("[Donald_E._Eastlake_3rd]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/dash+xml", reference: Some("[ISO-IEC_JTC_1][Thomas_Stockhammer]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/dash-patch+xml", reference: Some("[ISO-IEC_JTC_1]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/dashdelta", reference: Some("[David_Furbeck]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/davmount+xml", reference: Some("[RFC4709]"), rfc: Some(4709), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/dca-rft", reference: Some("[Larry_Campbell]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/DCD", reference: Some("[ASAM][Thomas_Thomsen]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/dec-dx", reference: Some("[Larry_Campbell]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/dialog-info+xml", reference: Some("[RFC4235]"), rfc: Some(4235), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/dicom", reference: Some("[RFC3240]"), rfc: Some(3240), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/dicom+json", reference: Some("[DICOM_Standard_Committee][David_Clunie]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/dicom+xml", reference: Some("[DICOM_Standard_Committee][David_Clunie]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/DII", reference: Some("[ASAM][Thomas_Thomsen]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/DIT", reference: Some("[ASAM][Thomas_Thomsen]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/dns", reference: Some("[RFC4027]"), rfc: Some(4027), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/dns+json", reference: Some("[RFC8427]"), rfc: Some(8427), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/dns-message", reference: Some("[RFC8484]"), rfc: Some(8484), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/dots+cbor", reference: Some("[RFC9132]"), rfc: Some(9132), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/dpop+jwt", reference: Some("[RFC9449]"), rfc: Some(9449), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/dskpp+xml", reference: Some("[RFC6063]"), rfc: Some(6063), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/dssc+der", reference: Some("[RFC5698]"), rfc: Some(5698), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/dssc+xml", reference: Some("[RFC5698]"), rfc: Some(5698), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/dvcs", reference: Some("[RFC3029]"), rfc: Some(3029), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/ecmascript", reference: Some("[RFC4329][RFC9239]"), rfc: Some(4329), status: Status::Obsolete, replacement: Some("text/javascript"), required: &[], optional: &[] },
+    Entry { template: "application/edhoc+cbor-seq", reference: Some("[RFC9528]"), rfc: Some(9528), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/EDI-consent", reference: Some("[RFC1767]"), rfc: Some(1767), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/EDI-X12", reference: Some("[RFC1767]"), rfc: Some(1767), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/EDIFACT", reference: Some("[RFC1767]"), rfc: Some(1767), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/efi", reference: Some("[UEFI_Forum][Samer_El-Haj-Mahmoud]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/elm+json", reference: Some("[HL7][Bryn_Rhodes]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/elm+xml", reference: Some("[HL7][Bryn_Rhodes]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/EmergencyCallData.cap+xml", reference: Some("[RFC8876]"), rfc: Some(8876), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/EmergencyCallData.Comment+xml", reference: Some("[RFC7852]"), rfc: Some(7852), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/EmergencyCallData.Control+xml", reference: Some("[RFC8147]"), rfc: Some(8147), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/EmergencyCallData.DeviceInfo+xml", reference: Some("[RFC7852]"), rfc: Some(7852), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/EmergencyCallData.eCall.MSD", reference: Some("[RFC8147]"), rfc: Some(8147), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/EmergencyCallData.LegacyESN+json", reference: Some("[NENA][Randall_Gellens]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/EmergencyCallData.ProviderInfo+xml", reference: Some("[RFC7852]"), rfc: Some(7852), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/EmergencyCallData.ServiceInfo+xml", reference: Some("[RFC7852]"), rfc: Some(7852), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/EmergencyCallData.SubscriberInfo+xml", reference: Some("[RFC7852]"), rfc: Some(7852), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/EmergencyCallData.VEDS+xml", reference: Some("[RFC8148][RFC Errata 6500]"), rfc: Some(8148), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/emma+xml", reference: Some("[W3C][http://www.w3.org/TR/2007/CR-emma-20071211/#media-type-registration][ISO-IEC_JTC_1]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/emotionml+xml", reference: Some("[W3C][Kazuyuki_Ashimura]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/encaprtp", reference: Some("[RFC6849]"), rfc: Some(6849), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/entity-statement+jwt", reference: Some("[OpenID_Foundation_Artifact_Binding_WG]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/epp+xml", reference: Some("[RFC5730]"), rfc: Some(5730), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/epub+zip", reference: Some("[W3C][EPUB_3_WG]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/eshop", reference: Some("[Steve_Katz]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/example", reference: Some("[RFC4735]"), rfc: Some(4735), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/exi", reference: Some("[W3C][http://www.w3.org/TR/2009/CR-exi-20091208/#mediaTypeRegistration]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/expect-ct-report+json", reference: Some("[RFC9163]"), rfc: Some(9163), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/express", reference: Some("[ISO-TC_184-SC_4][Dana_Tripp]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/fastinfoset", reference: Some("[ITU-T_ASN.1_Rapporteur][ISO-IEC_JTC_1_SC_6_ASN.1_Rapporteur]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/fastsoap", reference: Some("[ITU-T_ASN.1_Rapporteur][ISO-IEC_JTC_1_SC_6_ASN.1_Rapporteur]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/fdf", reference: Some("[ISO-TC_171-SC_2][Betsy_Fanning]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/fdt+xml", reference: Some("[RFC6726]"), rfc: Some(6726), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/fhir+json", reference: Some("[HL7][Grahame_Grieve]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/fhir+xml", reference: Some("[HL7][Grahame_Grieve]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/fits", reference: Some("[RFC4047]"), rfc: Some(4047), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/flexfec", reference: Some("[RFC8627]"), rfc: Some(8627), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/font-sfnt", reference: Some("[Levantovsky][ISO-IEC_JTC_1][RFC8081]"), rfc: Some(8081), status: Status::Deprecated, replacement: Some("font/sfnt"), required: &[], optional: &[] },
+    Entry { template: "application/font-tdpfr", reference: Some("[RFC3073]"), rfc: Some(3073), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/font-woff", reference: Some("[W3C][RFC8081]"), rfc: Some(8081), status: Status::Deprecated, replacement: Some("font/woff"), required: &[], optional: &[] },
+    Entry { template: "application/framework-attributes+xml", reference: Some("[RFC6230]"), rfc: Some(6230), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/geo+json", reference: Some("[RFC7946]"), rfc: Some(7946), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/geo+json-seq", reference: Some("[RFC8142]"), rfc: Some(8142), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/geopackage+sqlite3", reference: Some("[OGC][Scott_Simmons]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/geopose+json", reference: Some("[OGC][Scott_Simmons]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/geoxacml+json", reference: Some("[OGC][Scott_Simmons]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/geoxacml+xml", reference: Some("[OGC][Scott_Simmons]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/gltf-buffer", reference: Some("[Khronos][Saurabh_Bhatia]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/gml+xml", reference: Some("[OGC][Clemens_Portele]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/gnap-binding-jws", reference: Some("[RFC-ietf-gnap-core-protocol-20]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/gnap-binding-jwsd", reference: Some("[RFC-ietf-gnap-core-protocol-20]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/gnap-binding-rotation-jws", reference: Some("[RFC-ietf-gnap-core-protocol-20]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/gnap-binding-rotation-jwsd", reference: Some("[RFC-ietf-gnap-core-protocol-20]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/grib", reference: Some("[World_Meterological_Organization][Anna_Milan]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/gzip", reference: Some("[RFC6713]"), rfc: Some(6713), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/H224", reference: Some("[RFC4573]"), rfc: Some(4573), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/held+xml", reference: Some("[RFC5985]"), rfc: Some(5985), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/hl7v2+xml", reference: Some("[HL7][Marc_Duteau]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/http", reference: Some("[RFC9112]"), rfc: Some(9112), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/hyperstudio", reference: Some("[Michael_Domino]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/ibe-key-request+xml", reference: Some("[RFC5408]"), rfc: Some(5408), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/ibe-pkg-reply+xml", reference: Some("[RFC5408]"), rfc: Some(5408), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/ibe-pp-data", reference: Some("[RFC5408]"), rfc: Some(5408), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/iges", reference: Some("[Curtis_Parks]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/im-iscomposing+xml", reference: Some("[RFC3994]"), rfc: Some(3994), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/index", reference: Some("[RFC2652]"), rfc: Some(2652), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/index.cmd", reference: Some("[RFC2652]"), rfc: Some(2652), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/index.obj", reference: Some("[RFC2652]"), rfc: Some(2652), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/index.response", reference: Some("[RFC2652]"), rfc: Some(2652), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/index.vnd", reference: Some("[RFC2652]"), rfc: Some(2652), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/inkml+xml", reference: Some("[Kazuyuki_Ashimura]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/IOTP", reference: Some("[RFC2935]"), rfc: Some(2935), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/ipfix", reference: Some("[RFC5655]"), rfc: Some(5655), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/ipp", reference: Some("[RFC8010]"), rfc: Some(8010), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/ISUP", reference: Some("[RFC3204]"), rfc: Some(3204), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/its+xml", reference: Some("[W3C][ITS-IG-W3C]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/java-archive", reference: Some("[JCP][Iris_Clark]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/javascript", reference: Some("[RFC4329][RFC9239]"), rfc: Some(4329), status: Status::Obsolete, replacement: Some("text/javascript"), required: &[], optional: &[] },
+    Entry { template: "application/jf2feed+json", reference: Some("[W3C][Ivan_Herman]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/jose", reference: Some("[RFC7515]"), rfc: Some(7515), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/jose+json", reference: Some("[RFC7515]"), rfc: Some(7515), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/jrd+json", reference: Some("[RFC7033]"), rfc: Some(7033), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/jscalendar+json", reference: Some("[RFC8984]"), rfc: Some(8984), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/jscontact+json", reference: Some("[RFC9553]"), rfc: Some(9553), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/json", reference: Some("[RFC8259]"), rfc: Some(8259), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/json-patch+json", reference: Some("[RFC6902]"), rfc: Some(6902), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/json-seq", reference: Some("[RFC7464]"), rfc: Some(7464), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/jsonpath", reference: Some("[RFC9535]"), rfc: Some(9535), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/jwk+json", reference: Some("[RFC7517]"), rfc: Some(7517), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/jwk-set+json", reference: Some("[RFC7517]"), rfc: Some(7517), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/jwk-set+jwt", reference: Some("[OpenID_Foundation_Artifact_Binding_WG]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/jwt", reference: Some("[RFC7519]"), rfc: Some(7519), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/kpml-request+xml", reference: Some("[RFC4730]"), rfc: Some(4730), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/kpml-response+xml", reference: Some("[RFC4730]"), rfc: Some(4730), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/ld+json", reference: Some("[W3C][Ivan_Herman]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/lgr+xml", reference: Some("[RFC7940]"), rfc: Some(7940), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/link-format", reference: Some("[RFC6690]"), rfc: Some(6690), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/linkset", reference: Some("[RFC9264]"), rfc: Some(9264), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/linkset+json", reference: Some("[RFC9264]"), rfc: Some(9264), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/load-control+xml", reference: Some("[RFC7200]"), rfc: Some(7200), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/logout+jwt", reference: Some("[OpenID_Foundation_Artifact_Binding_WG]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/lost+xml", reference: Some("[RFC5222]"), rfc: Some(5222), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/lostsync+xml", reference: Some("[RFC6739]"), rfc: Some(6739), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/lpf+zip", reference: Some("[W3C][Ivan_Herman]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/LXF", reference: Some("[ASAM][Thomas_Thomsen]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/mac-binhex40", reference: Some("[Patrik_Faltstrom]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/macwriteii", reference: Some("[Paul_Lindner]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/mads+xml", reference: Some("[RFC6207]"), rfc: Some(6207), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/manifest+json", reference: Some("[W3C][Marcos_Caceres]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/marc", reference: Some("[RFC2220]"), rfc: Some(2220), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/marcxml+xml", reference: Some("[RFC6207]"), rfc: Some(6207), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/mathematica", reference: Some("[Wolfram]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/mathml+xml", reference: Some("[W3C][http://www.w3.org/TR/MathML3/appendixb.html]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/mathml-content+xml", reference: Some("[W3C][http://www.w3.org/TR/MathML3/appendixb.html]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/mathml-presentation+xml", reference: Some("[W3C][http://www.w3.org/TR/MathML3/appendixb.html]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/mbms-associated-procedure-description+xml", reference: Some("[_3GPP]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/mbms-deregister+xml", reference: Some("[_3GPP]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/mbms-envelope+xml", reference: Some("[_3GPP]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/mbms-msk+xml", reference: Some("[_3GPP]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/mbms-msk-response+xml", reference: Some("[_3GPP]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/mbms-protection-description+xml", reference: Some("[_3GPP]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/mbms-reception-report+xml", reference: Some("[_3GPP]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/mbms-register+xml", reference: Some("[_3GPP]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/mbms-register-response+xml", reference: Some("[_3GPP]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/mbms-schedule+xml", reference: Some("[_3GPP][Eric_Turcotte]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/mbms-user-service-description+xml", reference: Some("[_3GPP]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/mbox", reference: Some("[RFC4155]"), rfc: Some(4155), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/media-policy-dataset+xml", reference: Some("[RFC6796]"), rfc: Some(6796), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/media_control+xml", reference: Some("[RFC5168]"), rfc: Some(5168), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/mediaservercontrol+xml", reference: Some("[RFC5022]"), rfc: Some(5022), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/merge-patch+json", reference: Some("[RFC7396]"), rfc: Some(7396), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/metalink4+xml", reference: Some("[RFC5854]"), rfc: Some(5854), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/mets+xml", reference: Some("[RFC6207]"), rfc: Some(6207), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/MF4", reference: Some("[ASAM][Thomas_Thomsen]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/mikey", reference: Some("[RFC3830]"), rfc: Some(3830), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/mipc", reference: Some("[NCGIS][Bryan_Blank]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/missing-blocks+cbor-seq", reference: Some("[RFC9177]"), rfc: Some(9177), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/mmt-aei+xml", reference: Some("[ATSC]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/mmt-usd+xml", reference: Some("[ATSC]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/mods+xml", reference: Some("[RFC6207]"), rfc: Some(6207), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/moss-keys", reference: Some("[RFC1848]"), rfc: Some(1848), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/moss-signature", reference: Some("[RFC1848]"), rfc: Some(1848), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/mosskey-data", reference: Some("[RFC1848]"), rfc: Some(1848), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/mosskey-request", reference: Some("[RFC1848]"), rfc: Some(1848), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/mp21", reference: Some("[RFC6381][David_Singer]"), rfc: Some(6381), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/mp4", reference: Some("[RFC4337][RFC6381]"), rfc: Some(4337), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/mpeg4-generic", reference: Some("[RFC3640]"), rfc: Some(3640), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/mpeg4-iod", reference: Some("[RFC4337]"), rfc: Some(4337), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/mpeg4-iod-xmt", reference: Some("[RFC4337]"), rfc: Some(4337), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/mrb-consumer+xml", reference: Some("[RFC6917]"), rfc: Some(6917), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/mrb-publish+xml", reference: Some("[RFC6917]"), rfc: Some(6917), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/msc-ivr+xml", reference: Some("[RFC6231]"), rfc: Some(6231), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/msc-mixer+xml", reference: Some("[RFC6505]"), rfc: Some(6505), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/msword", reference: Some("[Paul_Lindner]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/mud+json", reference: Some("[RFC8520]"), rfc: Some(8520), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/multipart-core", reference: Some("[RFC8710]"), rfc: Some(8710), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/mxf", reference: Some("[RFC4539]"), rfc: Some(4539), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/n-quads", reference: Some("[W3C][Eric_Prudhommeaux]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/n-triples", reference: Some("[W3C][Eric_Prudhommeaux]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/nasdata", reference: Some("[RFC4707]"), rfc: Some(4707), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/news-checkgroups", reference: Some("[RFC5537]"), rfc: Some(5537), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/news-groupinfo", reference: Some("[RFC5537]"), rfc: Some(5537), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/news-transmission", reference: Some("[RFC5537]"), rfc: Some(5537), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/nlsml+xml", reference: Some("[RFC6787]"), rfc: Some(6787), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/node", reference: Some("[Node.js_TSC]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/nss", reference: Some("[Michael_Hammer]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/oauth-authz-req+jwt", reference: Some("[RFC9101]"), rfc: Some(9101), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/oblivious-dns-message", reference: Some("[RFC9230]"), rfc: Some(9230), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/ocsp-request", reference: Some("[RFC6960]"), rfc: Some(6960), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/ocsp-response", reference: Some("[RFC6960]"), rfc: Some(6960), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/octet-stream", reference: Some("[RFC2045][RFC2046]"), rfc: Some(2045), status: Status::Current, replacement: None, required: &[], optional: &["type", "padding"] },
+    Entry { template: "application/ODA", reference: Some("[RFC1494]"), rfc: Some(1494), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/odm+xml", reference: Some("[CDISC][Sam_Hume]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/ODX", reference: Some("[ASAM][Thomas_Thomsen]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/oebps-package+xml", reference: Some("[W3C][EPUB_3_WG]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/ogg", reference: Some("[RFC5334][RFC7845]"), rfc: Some(5334), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/ohttp-keys", reference: Some("[RFC9458]"), rfc: Some(9458), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/opc-nodeset+xml", reference: Some("[OPC_Foundation]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/oscore", reference: Some("[RFC8613]"), rfc: Some(8613), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/oxps", reference: Some("[Ecma_International_Helpdesk]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/p21", reference: Some("[ISO-TC_184-SC_4][Dana_Tripp]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/p21+zip", reference: Some("[ISO-TC_184-SC_4][Dana_Tripp]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/p2p-overlay+xml", reference: Some("[RFC6940]"), rfc: Some(6940), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/parityfec", reference: Some("[RFC3009]"), rfc: Some(3009), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/passport", reference: Some("[RFC8225]"), rfc: Some(8225), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/patch-ops-error+xml", reference: Some("[RFC5261]"), rfc: Some(5261), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/pdf", reference: Some("[RFC8118]"), rfc: Some(8118), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/PDX", reference: Some("[ASAM][Thomas_Thomsen]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/pem-certificate-chain", reference: Some("[RFC8555]"), rfc: Some(8555), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/pgp-encrypted", reference: Some("[RFC3156]"), rfc: Some(3156), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/pgp-keys", reference: Some("[RFC3156]"), rfc: Some(3156), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/pgp-signature", reference: Some("[RFC3156]"), rfc: Some(3156), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/pidf+xml", reference: Some("[RFC3863]"), rfc: Some(3863), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/pidf-diff+xml", reference: Some("[RFC5262]"), rfc: Some(5262), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/pkcs10", reference: Some("[RFC5967]"), rfc: Some(5967), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/pkcs12", reference: Some("[IETF]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/pkcs7-mime", reference: Some("[RFC8551][RFC7114]"), rfc: Some(8551), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/pkcs7-signature", reference: Some("[RFC8551]"), rfc: Some(8551), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/pkcs8", reference: Some("[RFC5958]"), rfc: Some(5958), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/pkcs8-encrypted", reference: Some("[RFC8351]"), rfc: Some(8351), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/pkix-attr-cert", reference: Some("[RFC5877]"), rfc: Some(5877), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/pkix-cert", reference: Some("[RFC2585]"), rfc: Some(2585), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/pkix-crl", reference: Some("[RFC2585]"), rfc: Some(2585), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/pkix-pkipath", reference: Some("[RFC6066]"), rfc: Some(6066), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/pkixcmp", reference: Some("[RFC2510]"), rfc: Some(2510), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/pls+xml", reference: Some("[RFC4267]"), rfc: Some(4267), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/poc-settings+xml", reference: Some("[RFC4354]"), rfc: Some(4354), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/postscript", reference: Some("[RFC2045][RFC2046]"), rfc: Some(2045), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/ppsp-tracker+json", reference: Some("[RFC7846]"), rfc: Some(7846), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/private-token-issuer-directory", reference: Some("[RFC9578]"), rfc: Some(9578), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/private-token-request", reference: Some("[RFC9578]"), rfc: Some(9578), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/private-token-response", reference: Some("[RFC9578]"), rfc: Some(9578), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/problem+json", reference: Some("[RFC9457]"), rfc: Some(9457), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/problem+xml", reference: Some("[RFC9457]"), rfc: Some(9457), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/provenance+xml", reference: Some("[W3C][Ivan_Herman]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/prs.alvestrand.titrax-sheet", reference: Some("[Harald_T._Alvestrand]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/prs.cww", reference: Some("[Khemchart_Rungchavalnont]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/prs.cyn", reference: Some("[Cynthia_Revström]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/prs.hpub+zip", reference: Some("[Giulio_Zambon]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/prs.implied-document+xml", reference: Some("[Marek_Čermák]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/prs.implied-executable", reference: Some("[Marek_Čermák]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/prs.implied-object+json", reference: Some("[Marek_Čermák]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/prs.implied-object+json-seq", reference: Some("[Marek_Čermák]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/prs.implied-object+yaml", reference: Some("[Marek_Čermák]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/prs.implied-structure", reference: Some("[Marek_Čermák]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/prs.mayfile", reference: Some("[Azalea_Gardenia]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/prs.nprend", reference: Some("[Jay_Doggett]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/prs.plucker", reference: Some("[Bill_Janssen]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/prs.rdf-xml-crypt", reference: Some("[Toby_Inkster]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/prs.vcfbzip2", reference: Some("[Paolo_Marcheschi]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/prs.xsf+xml", reference: Some("[Maik_Stührenberg]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/pskc+xml", reference: Some("[RFC6030]"), rfc: Some(6030), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/pvd+json", reference: Some("[RFC8801]"), rfc: Some(8801), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/QSIG", reference: Some("[RFC3204]"), rfc: Some(3204), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/raptorfec", reference: Some("[RFC6682]"), rfc: Some(6682), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/rdap+json", reference: Some("[RFC9083]"), rfc: Some(9083), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/rdf+xml", reference: Some("[RFC3870]"), rfc: Some(3870), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/reginfo+xml", reference: Some("[RFC3680]"), rfc: Some(3680), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/relax-ng-compact-syntax", reference: Some("[http://www.JTC_1sc34.org/repository/0661.pdf]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/remote-printing", reference: Some("[RFC1486][Marshall_Rose][Moving TPC.INT and NSAP.INT infrastructure domains to historic]"), rfc: Some(1486), status: Status::Obsolete, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/reputon+json", reference: Some("[RFC7071]"), rfc: Some(7071), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/resolve-response+jwt", reference: Some("[OpenID_Foundation_Artifact_Binding_WG]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/resource-lists+xml", reference: Some("[RFC4826]"), rfc: Some(4826), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/resource-lists-diff+xml", reference: Some("[RFC5362]"), rfc: Some(5362), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/rfc+xml", reference: Some("[RFC7991]"), rfc: Some(7991), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/riscos", reference: Some("[Nick_Smith]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/rlmi+xml", reference: Some("[RFC4662]"), rfc: Some(4662), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/rls-services+xml", reference: Some("[RFC4826]"), rfc: Some(4826), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/route-apd+xml", reference: Some("[ATSC]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/route-s-tsid+xml", reference: Some("[ATSC]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/route-usd+xml", reference: Some("[ATSC]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/rpki-checklist", reference: Some("[RFC9323]"), rfc: Some(9323), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/rpki-ghostbusters", reference: Some("[RFC6493]"), rfc: Some(6493), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/rpki-manifest", reference: Some("[RFC6481]"), rfc: Some(6481), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/rpki-publication", reference: Some("[RFC8181]"), rfc: Some(8181), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/rpki-roa", reference: Some("[RFC9582]"), rfc: Some(9582), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/rpki-signed-tal", reference: Some("[RFC-ietf-sidrops-signed-tal-16]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/rpki-updown", reference: Some("[RFC6492]"), rfc: Some(6492), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/rtf", reference: Some("[Paul_Lindner]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/rtploopback", reference: Some("[RFC6849]"), rfc: Some(6849), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/rtx", reference: Some("[RFC4588]"), rfc: Some(4588), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/samlassertion+xml", reference: Some("[OASIS_Security_Services_Technical_Committee_SSTC]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/samlmetadata+xml", reference: Some("[OASIS_Security_Services_Technical_Committee_SSTC]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/sarif+json", reference: Some("[OASIS][Michael_C._Fanning][Laurence_J._Golding]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/sarif-external-properties+json", reference: Some("[OASIS][David_Keaton][Michael_C._Fanning]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/sbe", reference: Some("[FIX_Trading_Community][Donald_L._Mendelson]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/sbml+xml", reference: Some("[RFC3823]"), rfc: Some(3823), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/scaip+xml", reference: Some("[SIS][Oskar_Jonsson]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/scim+json", reference: Some("[RFC7644]"), rfc: Some(7644), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/scvp-cv-request", reference: Some("[RFC5055]"), rfc: Some(5055), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/scvp-cv-response", reference: Some("[RFC5055]"), rfc: Some(5055), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/scvp-vp-request", reference: Some("[RFC5055]"), rfc: Some(5055), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/scvp-vp-response", reference: Some("[RFC5055]"), rfc: Some(5055), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/sdp", reference: Some("[RFC8866]"), rfc: Some(8866), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/secevent+jwt", reference: Some("[RFC8417]"), rfc: Some(8417), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/senml+cbor", reference: Some("[RFC8428]"), rfc: Some(8428), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/senml+json", reference: Some("[RFC8428]"), rfc: Some(8428), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/senml+xml", reference: Some("[RFC8428]"), rfc: Some(8428), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/senml-etch+cbor", reference: Some("[RFC8790]"), rfc: Some(8790), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/senml-etch+json", reference: Some("[RFC8790]"), rfc: Some(8790), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/senml-exi", reference: Some("[RFC8428]"), rfc: Some(8428), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/sensml+cbor", reference: Some("[RFC8428]"), rfc: Some(8428), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/sensml+json", reference: Some("[RFC8428]"), rfc: Some(8428), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/sensml+xml", reference: Some("[RFC8428]"), rfc: Some(8428), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/sensml-exi", reference: Some("[RFC8428]"), rfc: Some(8428), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/sep+xml", reference: Some("[Robby_Simpson][IEEE]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/sep-exi", reference: Some("[Robby_Simpson][IEEE]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/session-info", reference: Some("[_3GPP][Frederic_Firmin]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/set-payment", reference: Some("[Brian_Korver]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/set-payment-initiation", reference: Some("[Brian_Korver]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/set-registration", reference: Some("[Brian_Korver]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/set-registration-initiation", reference: Some("[Brian_Korver]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/SGML", reference: Some("[RFC1874]"), rfc: Some(1874), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/sgml-open-catalog", reference: Some("[Paul_Grosso]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/shf+xml", reference: Some("[RFC4194]"), rfc: Some(4194), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/sieve", reference: Some("[RFC5228]"), rfc: Some(5228), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/simple-filter+xml", reference: Some("[RFC4661]"), rfc: Some(4661), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/simple-message-summary", reference: Some("[RFC3842]"), rfc: Some(3842), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/simpleSymbolContainer", reference: Some("[_3GPP]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/sipc", reference: Some("[NCGIS][Bryan_Blank]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/slate", reference: Some("[Terry_Crowley]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/smil", reference: Some("[RFC4536]"), rfc: Some(4536), status: Status::Obsolete, replacement: Some("application/smil+xml"), required: &[], optional: &[] },
+    Entry { template: "application/smil+xml", reference: Some("[RFC4536]"), rfc: Some(4536), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/smpte336m", reference: Some("[RFC6597]"), rfc: Some(6597), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/soap+fastinfoset", reference: Some("[ITU-T_ASN.1_Rapporteur][ISO-IEC_JTC_1_SC_6_ASN.1_Rapporteur]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/soap+xml", reference: Some("[RFC3902]"), rfc: Some(3902), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/sparql-query", reference: Some("[W3C][http://www.w3.org/TR/2007/CR-rdf-sparql-query-20070614/#mediaType]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/sparql-results+xml", reference: Some("[W3C][http://www.w3.org/TR/2007/CR-rdf-sparql-XMLres-20070925/#mime]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/spdx+json", reference: Some("[Linux_Foundation][Rose_Judge]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/spirits-event+xml", reference: Some("[RFC3910]"), rfc: Some(3910), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/sql", reference: Some("[RFC6922]"), rfc: Some(6922), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/srgs", reference: Some("[RFC4267]"), rfc: Some(4267), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/srgs+xml", reference: Some("[RFC4267]"), rfc: Some(4267), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/sru+xml", reference: Some("[RFC6207]"), rfc: Some(6207), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/sslkeylogfile", reference: Some("[RFC-ietf-tls-keylogfile-02]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/ssml+xml", reference: Some("[RFC4267]"), rfc: Some(4267), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/ST2110-41", reference: Some("[SMPTE][SMPTE_Director_of_Standards_Development]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/stix+json", reference: Some("[OASIS][Chet_Ensign]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/stratum", reference: Some("[Ben_van_Hartingsveldt][1]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/swid+cbor", reference: Some("[RFC9393]"), rfc: Some(9393), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/swid+xml", reference: Some("[ISO-IEC_JTC_1][David_Waltermire][Ron_Brill]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/tamp-apex-update", reference: Some("[RFC5934]"), rfc: Some(5934), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/tamp-apex-update-confirm", reference: Some("[RFC5934]"), rfc: Some(5934), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/tamp-community-update", reference: Some("[RFC5934]"), rfc: Some(5934), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/tamp-community-update-confirm", reference: Some("[RFC5934]"), rfc: Some(5934), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/tamp-error", reference: Some("[RFC5934]"), rfc: Some(5934), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/tamp-sequence-adjust", reference: Some("[RFC5934]"), rfc: Some(5934), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/tamp-sequence-adjust-confirm", reference: Some("[RFC5934]"), rfc: Some(5934), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/tamp-status-query", reference: Some("[RFC5934]"), rfc: Some(5934), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/tamp-status-response", reference: Some("[RFC5934]"), rfc: Some(5934), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/tamp-update", reference: Some("[RFC5934]"), rfc: Some(5934), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/tamp-update-confirm", reference: Some("[RFC5934]"), rfc: Some(5934), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/taxii+json", reference: Some("[OASIS][Chet_Ensign]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/td+json", reference: Some("[W3C][Matthias_Kovatsch]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/tei+xml", reference: Some("[RFC6129]"), rfc: Some(6129), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/TETRA_ISI", reference: Some("[ETSI][Miguel_Angel_Reina_Ortega]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/thraud+xml", reference: Some("[RFC5941]"), rfc: Some(5941), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/timestamp-query", reference: Some("[RFC3161]"), rfc: Some(3161), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/timestamp-reply", reference: Some("[RFC3161]"), rfc: Some(3161), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/timestamped-data", reference: Some("[RFC5955]"), rfc: Some(5955), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/tlsrpt+gzip", reference: Some("[RFC8460]"), rfc: Some(8460), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/tlsrpt+json", reference: Some("[RFC8460]"), rfc: Some(8460), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/tm+json", reference: Some("[W3C][Sebastian_Kaebisch]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/tnauthlist", reference: Some("[RFC8226]"), rfc: Some(8226), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/token-introspection+jwt", reference: Some("[RFC-oauth-jwt-introspection-response-12]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/trickle-ice-sdpfrag", reference: Some("[RFC8840]"), rfc: Some(8840), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/trig", reference: Some("[W3C][W3C_RDF_Working_Group]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/trust-chain+json", reference: Some("[OpenID_Foundation_Artifact_Binding_WG]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/trust-mark+jwt", reference: Some("[OpenID_Foundation_Artifact_Binding_WG]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/trust-mark-delegation+jwt", reference: Some("[OpenID_Foundation_Artifact_Binding_WG]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/ttml+xml", reference: Some("[W3C][W3C_Timed_Text_Working_Group]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/tve-trigger", reference: Some("[Linda_Welsh]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/tzif", reference: Some("[RFC-murchison-rfc8536bis-15]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/tzif-leap", reference: Some("[RFC-murchison-rfc8536bis-15]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/ulpfec", reference: Some("[RFC5109]"), rfc: Some(5109), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/urc-grpsheet+xml", reference: Some("[Gottfried_Zimmermann][ISO-IEC_JTC_1]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/urc-ressheet+xml", reference: Some("[Gottfried_Zimmermann][ISO-IEC_JTC_1]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/urc-targetdesc+xml", reference: Some("[Gottfried_Zimmermann][ISO-IEC_JTC_1]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/urc-uisocketdesc+xml", reference: Some("[Gottfried_Zimmermann]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vc", reference: Some("[W3C][Ivan_Herman]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vcard+json", reference: Some("[RFC7095]"), rfc: Some(7095), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vcard+xml", reference: Some("[RFC6351]"), rfc: Some(6351), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vemmi", reference: Some("[RFC2122]"), rfc: Some(2122), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.1000minds.decision-model+xml", reference: Some("[Franz_Ombler]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.1ob", reference: Some("[Rob_Coyle]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.3gpp-prose+xml", reference: Some("[Frederic_Firmin]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.3gpp-prose-pc3a+xml", reference: Some("[Haorui_Yang]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.3gpp-prose-pc3ach+xml", reference: Some("[Haorui_Yang]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.3gpp-prose-pc3ch+xml", reference: Some("[Frederic_Firmin]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.3gpp-prose-pc8+xml", reference: Some("[Haorui_Yang]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.3gpp-v2x-local-service-information", reference: Some("[Frederic_Firmin]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.3gpp.5gnas", reference: Some("[_3GPP][Jones_Lu_Yunjie]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.3gpp.5gsa2x", reference: Some("[_3GPP_CT1][Dongwook_Kim]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.3gpp.5gsa2x-local-service-information", reference: Some("[_3GPP_CT1][Dongwook_Kim]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.3gpp.access-transfer-events+xml", reference: Some("[Frederic_Firmin]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.3gpp.bsf+xml", reference: Some("[John_M_Meredith]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.3gpp.crs+xml", reference: Some("[Xu_Chen]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.3gpp.current-location-discovery+xml", reference: Some("[Peter_Leis]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.3gpp.GMOP+xml", reference: Some("[Frederic_Firmin]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.3gpp.gtpc", reference: Some("[_3GPP][Yang_Yong]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.3gpp.interworking-data", reference: Some("[Frederic_Firmin]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.3gpp.lpp", reference: Some("[_3GPP][Jones_Lu_Yunjie]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.3gpp.mc-signalling-ear", reference: Some("[Tim_Woodward]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.3gpp.mcdata-affiliation-command+xml", reference: Some("[Frederic_Firmin]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.3gpp.mcdata-info+xml", reference: Some("[Frederic_Firmin]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.3gpp.mcdata-msgstore-ctrl-request+xml", reference: Some("[Kiran_Kapale]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.3gpp.mcdata-payload", reference: Some("[Frederic_Firmin]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.3gpp.mcdata-regroup+xml", reference: Some("[Kiran_Kapale]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.3gpp.mcdata-service-config+xml", reference: Some("[Frederic_Firmin]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.3gpp.mcdata-signalling", reference: Some("[Frederic_Firmin]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.3gpp.mcdata-ue-config+xml", reference: Some("[Frederic_Firmin]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.3gpp.mcdata-user-profile+xml", reference: Some("[Frederic_Firmin]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.3gpp.mcptt-affiliation-command+xml", reference: Some("[Frederic_Firmin]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.3gpp.mcptt-floor-request+xml", reference: Some("[Frederic_Firmin]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.3gpp.mcptt-info+xml", reference: Some("[Frederic_Firmin]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.3gpp.mcptt-location-info+xml", reference: Some("[Frederic_Firmin]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.3gpp.mcptt-mbms-usage-info+xml", reference: Some("[Frederic_Firmin]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.3gpp.mcptt-regroup+xml", reference: Some("[Kiran_Kapale]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.3gpp.mcptt-service-config+xml", reference: Some("[Frederic_Firmin]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.3gpp.mcptt-signed+xml", reference: Some("[Frederic_Firmin]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.3gpp.mcptt-ue-config+xml", reference: Some("[Frederic_Firmin]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.3gpp.mcptt-ue-init-config+xml", reference: Some("[Frederic_Firmin]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.3gpp.mcptt-user-profile+xml", reference: Some("[Frederic_Firmin]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.3gpp.mcvideo-affiliation-command+xml", reference: Some("[Frederic_Firmin]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.3gpp.mcvideo-affiliation-info+xml", reference: Some("[Frederic_Firmin]"), rfc: None, status: Status::Obsolete, replacement: Some("application/vnd.3gpp.mcvideo-info+xml"), required: &[], optional: &[] },
+    Entry { template: "application/vnd.3gpp.mcvideo-info+xml", reference: Some("[Frederic_Firmin]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.3gpp.mcvideo-location-info+xml", reference: Some("[Frederic_Firmin]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.3gpp.mcvideo-mbms-usage-info+xml", reference: Some("[Frederic_Firmin]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.3gpp.mcvideo-regroup+xml", reference: Some("[Kiran_Kapale]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.3gpp.mcvideo-service-config+xml", reference: Some("[Frederic_Firmin]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.3gpp.mcvideo-transmission-request+xml", reference: Some("[Frederic_Firmin]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.3gpp.mcvideo-ue-config+xml", reference: Some("[Frederic_Firmin]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.3gpp.mcvideo-user-profile+xml", reference: Some("[Frederic_Firmin]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.3gpp.mid-call+xml", reference: Some("[Frederic_Firmin]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.3gpp.ngap", reference: Some("[_3GPP][Yang_Yong]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.3gpp.pfcp", reference: Some("[_3GPP][Bruno_Landais]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.3gpp.pic-bw-large", reference: Some("[John_M_Meredith]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.3gpp.pic-bw-small", reference: Some("[John_M_Meredith]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.3gpp.pic-bw-var", reference: Some("[John_M_Meredith]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.3gpp.pinapp-info+xml", reference: Some("[_3GPP_CT1][Dongwook_Kim]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.3gpp.s1ap", reference: Some("[_3GPP][Yang_Yong]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.3gpp.seal-group-doc+xml", reference: Some("[Sapan_Shah]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.3gpp.seal-info+xml", reference: Some("[_3GPP][Christian_Herrero-Veron]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.3gpp.seal-location-info+xml", reference: Some("[_3GPP][Christian_Herrero-Veron]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.3gpp.seal-mbms-usage-info+xml", reference: Some("[_3GPP][Christian_Herrero-Veron]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.3gpp.seal-network-QoS-management-info+xml", reference: Some("[_3GPP][Christian_Herrero-Veron]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.3gpp.seal-ue-config-info+xml", reference: Some("[Sapan_Shah]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.3gpp.seal-unicast-info+xml", reference: Some("[_3GPP][Christian_Herrero-Veron]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.3gpp.seal-user-profile-info+xml", reference: Some("[Sapan_Shah]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.3gpp.sms", reference: Some("[John_M_Meredith]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.3gpp.sms+xml", reference: Some("[Frederic_Firmin]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.3gpp.srvcc-ext+xml", reference: Some("[Frederic_Firmin]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.3gpp.SRVCC-info+xml", reference: Some("[Frederic_Firmin]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.3gpp.state-and-event-info+xml", reference: Some("[Frederic_Firmin]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.3gpp.ussd+xml", reference: Some("[Frederic_Firmin]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.3gpp.v2x", reference: Some("[Sang_Min_Park]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.3gpp.vae-info+xml", reference: Some("[_3GPP][Christian_Herrero-Veron]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.3gpp2.bcmcsinfo+xml", reference: Some("[AC_Mahendran]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.3gpp2.sms", reference: Some("[AC_Mahendran]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.3gpp2.tcap", reference: Some("[AC_Mahendran]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.3lightssoftware.imagescal", reference: Some("[Gus_Asadi]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.3M.Post-it-Notes", reference: Some("[Michael_OBrien]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.accpac.simply.aso", reference: Some("[Steve_Leow]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.accpac.simply.imp", reference: Some("[Steve_Leow]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.acm.addressxfer+json", reference: Some("[Sridhar_Ramakrishnan]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.acm.chatbot+json", reference: Some("[Sridhar_Ramakrishnan]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.acucobol", reference: Some("[Dovid_Lubin]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.acucorp", reference: Some("[Dovid_Lubin]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.adobe.flash.movie", reference: Some("[Henrik_Andersson]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.adobe.formscentral.fcdt", reference: Some("[Chris_Solc]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.adobe.fxp", reference: Some("[Steven_Heintz]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.adobe.partial-upload", reference: Some("[Tapani_Otala]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.adobe.xdp+xml", reference: Some("[John_Brinkman]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.aether.imp", reference: Some("[Jay_Moskowitz]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.afpc.afplinedata", reference: Some("[Jörg_Palmer]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.afpc.afplinedata-pagedef", reference: Some("[Jörg_Palmer]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.afpc.cmoca-cmresource", reference: Some("[Jörg_Palmer]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.afpc.foca-charset", reference: Some("[Jörg_Palmer]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.afpc.foca-codedfont", reference: Some("[Jörg_Palmer]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.afpc.foca-codepage", reference: Some("[Jörg_Palmer]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.afpc.modca", reference: Some("[Jörg_Palmer]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.afpc.modca-cmtable", reference: Some("[Jörg_Palmer]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.afpc.modca-formdef", reference: Some("[Jörg_Palmer]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.afpc.modca-mediummap", reference: Some("[Jörg_Palmer]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.afpc.modca-objectcontainer", reference: Some("[Jörg_Palmer]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.afpc.modca-overlay", reference: Some("[Jörg_Palmer]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.afpc.modca-pagesegment", reference: Some("[Jörg_Palmer]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.age", reference: Some("[Filippo_Valsorda]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.ah-barcode", reference: Some("[Katsuhiko_Ichinose]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.ahead.space", reference: Some("[Tor_Kristensen]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.airzip.filesecure.azf", reference: Some("[Daniel_Mould][Gary_Clueit]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.airzip.filesecure.azs", reference: Some("[Daniel_Mould][Gary_Clueit]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.amadeus+json", reference: Some("[Patrick_Brosse]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.amazon.mobi8-ebook", reference: Some("[Kim_Scarborough]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.americandynamics.acc", reference: Some("[Gary_Sands]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.amiga.ami", reference: Some("[Kevin_Blumberg]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.amundsen.maze+xml", reference: Some("[Mike_Amundsen]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.android.ota", reference: Some("[Greg_Kaiser]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.anki", reference: Some("[Kerrick_Staley]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.anser-web-certificate-issue-initiation", reference: Some("[Hiroyoshi_Mori]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.antix.game-component", reference: Some("[Daniel_Shelton]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.apache.arrow.file", reference: Some("[Apache_Arrow_Project]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.apache.arrow.stream", reference: Some("[Apache_Arrow_Project]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.apache.parquet", reference: Some("[Apache_Parquet_Project]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.apache.thrift.binary", reference: Some("[Roger_Meier]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.apache.thrift.compact", reference: Some("[Roger_Meier]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.apache.thrift.json", reference: Some("[Roger_Meier]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.apexlang", reference: Some("[Fawad_Shaikh]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.api+json", reference: Some("[Steve_Klabnik]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.aplextor.warrp+json", reference: Some("[Oleg_Uryutin]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.apothekende.reservation+json", reference: Some("[Adrian_Föder]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.apple.installer+xml", reference: Some("[Peter_Bierman]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.apple.keynote", reference: Some("[Manichandra_Sajjanapu]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.apple.mpegurl", reference: Some("[RFC8216]"), rfc: Some(8216), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.apple.numbers", reference: Some("[Manichandra_Sajjanapu]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.apple.pages", reference: Some("[Manichandra_Sajjanapu]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.arastra.swi", reference: Some("[Bill_Fenner]"), rfc: None, status: Status::Obsolete, replacement: Some("application/vnd.aristanetworks.swi"), required: &[], optional: &[] },
+    Entry { template: "application/vnd.aristanetworks.swi", reference: Some("[Bill_Fenner]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.artisan+json", reference: Some("[Brad_Turner]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.artsquare", reference: Some("[Christopher_Smith]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.astraea-software.iota", reference: Some("[Christopher_Snazell]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.audiograph", reference: Some("[Horia_Cristian_Slusanschi]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.autopackage", reference: Some("[Mike_Hearn]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.avalon+json", reference: Some("[Ben_Hinman]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.avistar+xml", reference: Some("[Vladimir_Vysotsky]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.balsamiq.bmml+xml", reference: Some("[Giacomo_Guilizzoni]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.balsamiq.bmpr", reference: Some("[Giacomo_Guilizzoni]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.banana-accounting", reference: Some("[José_Del_Romano]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.bbf.usp.error", reference: Some("[Broadband_Forum]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.bbf.usp.msg", reference: Some("[Broadband_Forum]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.bbf.usp.msg+json", reference: Some("[Broadband_Forum]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.bekitzur-stech+json", reference: Some("[Jegulsky]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.belightsoft.lhzd+zip", reference: Some("[Dmytro_Yunchyk]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.belightsoft.lhzl+zip", reference: Some("[Dmytro_Yunchyk]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.bint.med-content", reference: Some("[Heinz-Peter_Schütz]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.biopax.rdf+xml", reference: Some("[Pathway_Commons]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.blink-idb-value-wrapper", reference: Some("[Victor_Costan]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.blueice.multipass", reference: Some("[Thomas_Holmstrom]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.bluetooth.ep.oob", reference: Some("[Mike_Foley]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.bluetooth.le.oob", reference: Some("[Mark_Powell]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.bmi", reference: Some("[Tadashi_Gotoh]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.bpf", reference: Some("[NCGIS][Bryan_Blank]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.bpf3", reference: Some("[NCGIS][Bryan_Blank]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.businessobjects", reference: Some("[Philippe_Imoucha]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.byu.uapi+json", reference: Some("[Brent_Moore]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.bzip3", reference: Some("[Kamila_Szewczyk]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.c3voc.schedule+xml", reference: Some("[Andreas_Hubel]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.cab-jscript", reference: Some("[Joerg_Falkenberg]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.canon-cpdl", reference: Some("[Shin_Muto]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.canon-lips", reference: Some("[Shin_Muto]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.capasystems-pg+json", reference: Some("[Yüksel_Aydemir]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.cendio.thinlinc.clientconf", reference: Some("[Peter_Astrand]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.century-systems.tcp_stream", reference: Some("[Shuji_Fujii]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.chemdraw+xml", reference: Some("[Glenn_Howes]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.chess-pgn", reference: Some("[Kim_Scarborough]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.chipnuts.karaoke-mmd", reference: Some("[Chunyun_Xiong]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.ciedi", reference: Some("[Hidekazu_Enjo]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.cinderella", reference: Some("[Ulrich_Kortenkamp]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.cirpack.isdn-ext", reference: Some("[Pascal_Mayeux]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.citationstyles.style+xml", reference: Some("[Rintze_M._Zelle]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.claymore", reference: Some("[Ray_Simpson]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.cloanto.rp9", reference: Some("[Mike_Labatt]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.clonk.c4group", reference: Some("[Guenther_Brammer]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.cluetrust.cartomobile-config", reference: Some("[Gaige_Paulsen]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.cluetrust.cartomobile-config-pkg", reference: Some("[Gaige_Paulsen]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.cncf.helm.chart.content.v1.tar+gzip", reference: Some("[Andrew_Block]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.cncf.helm.chart.provenance.v1.prov", reference: Some("[Andrew_Block]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.cncf.helm.config.v1+json", reference: Some("[Andrew_Block]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.coffeescript", reference: Some("[Devyn_Collier_Johnson]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.collabio.xodocuments.document", reference: Some("[Alexey_Meandrov]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.collabio.xodocuments.document-template", reference: Some("[Alexey_Meandrov]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.collabio.xodocuments.presentation", reference: Some("[Alexey_Meandrov]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.collabio.xodocuments.presentation-template", reference: Some("[Alexey_Meandrov]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.collabio.xodocuments.spreadsheet", reference: Some("[Alexey_Meandrov]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.collabio.xodocuments.spreadsheet-template", reference: Some("[Alexey_Meandrov]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.collection+json", reference: Some("[Mike_Amundsen]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.collection.doc+json", reference: Some("[Irakli_Nadareishvili]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.collection.next+json", reference: Some("[Ioseb_Dzmanashvili]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.comicbook+zip", reference: Some("[Kim_Scarborough]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.comicbook-rar", reference: Some("[Kim_Scarborough]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.commerce-battelle", reference: Some("[David_Applebaum]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.commonspace", reference: Some("[Ravinder_Chandhok]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.contact.cmsg", reference: Some("[Frank_Patz]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.coreos.ignition+json", reference: Some("[Alex_Crawford]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.cosmocaller", reference: Some("[Steve_Dellutri]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.crick.clicker", reference: Some("[Andrew_Burt]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.crick.clicker.keyboard", reference: Some("[Andrew_Burt]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.crick.clicker.palette", reference: Some("[Andrew_Burt]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.crick.clicker.template", reference: Some("[Andrew_Burt]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.crick.clicker.wordbank", reference: Some("[Andrew_Burt]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.criticaltools.wbs+xml", reference: Some("[Jim_Spiller]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.cryptii.pipe+json", reference: Some("[Fränz_Friederes]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.crypto-shade-file", reference: Some("[Connor_Horman]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.cryptomator.encrypted", reference: Some("[Sebastian_Stenzel]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.cryptomator.vault", reference: Some("[Sebastian_Stenzel]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.ctc-posml", reference: Some("[Bayard_Kohlhepp]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.ctct.ws+xml", reference: Some("[Jim_Ancona]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.cups-pdf", reference: Some("[Michael_Sweet]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.cups-postscript", reference: Some("[Michael_Sweet]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.cups-ppd", reference: Some("[Michael_Sweet]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.cups-raster", reference: Some("[Michael_Sweet]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.cups-raw", reference: Some("[Michael_Sweet]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.curl", reference: Some("[Robert_Byrnes]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.cyan.dean.root+xml", reference: Some("[Matt_Kern]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.cybank", reference: Some("[Nor_Helmee]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.cyclonedx+json", reference: Some("[Patrick_Dwyer]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.cyclonedx+xml", reference: Some("[Patrick_Dwyer]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.d2l.coursepackage1p0+zip", reference: Some("[Viktor_Haag]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.d3m-dataset", reference: Some("[Mi_Tar]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.d3m-problem", reference: Some("[Mi_Tar]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.dart", reference: Some("[Anders_Sandholm]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.data-vision.rdz", reference: Some("[James_Fields]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.datalog", reference: Some("[Simon_Johnston]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.datapackage+json", reference: Some("[Paul_Walsh]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.dataresource+json", reference: Some("[Paul_Walsh]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.dbf", reference: Some("[Mi_Tar]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.debian.binary-package", reference: Some("[Debian_Policy_mailing_list]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.dece.data", reference: Some("[Michael_A_Dolan]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.dece.ttml+xml", reference: Some("[Michael_A_Dolan]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.dece.unspecified", reference: Some("[Michael_A_Dolan]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.dece.zip", reference: Some("[Michael_A_Dolan]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.denovo.fcselayout-link", reference: Some("[Michael_Dixon]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.desmume.movie", reference: Some("[Henrik_Andersson]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.dir-bi.plate-dl-nosuffix", reference: Some("[Yamanaka]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.dm.delegation+xml", reference: Some("[Axel_Ferrazzini]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.dna", reference: Some("[Meredith_Searcy]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.document+json", reference: Some("[Tom_Christie]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.dolby.mobile.1", reference: Some("[Steve_Hattersley]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.dolby.mobile.2", reference: Some("[Steve_Hattersley]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.doremir.scorecloud-binary-document", reference: Some("[Erik_Ronström]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.dpgraph", reference: Some("[David_Parker]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.dreamfactory", reference: Some("[William_C._Appleton]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.drive+json", reference: Some("[Keith_Kester]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.dtg.local", reference: Some("[Ali_Teffahi]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.dtg.local.flash", reference: Some("[Ali_Teffahi]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.dtg.local.html", reference: Some("[Ali_Teffahi]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.dvb.ait", reference: Some("[Peter_Siebert][Michael_Lagally]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.dvb.dvbisl+xml", reference: Some("[Emily_DUBS]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.dvb.dvbj", reference: Some("[Peter_Siebert][Michael_Lagally]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.dvb.esgcontainer", reference: Some("[Joerg_Heuer]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.dvb.ipdcdftnotifaccess", reference: Some("[Roy_Yue]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.dvb.ipdcesgaccess", reference: Some("[Joerg_Heuer]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.dvb.ipdcesgaccess2", reference: Some("[Jerome_Marcon]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.dvb.ipdcesgpdd", reference: Some("[Jerome_Marcon]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.dvb.ipdcroaming", reference: Some("[Yiling_Xu]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.dvb.iptv.alfec-base", reference: Some("[Jean-Baptiste_Henry]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.dvb.iptv.alfec-enhancement", reference: Some("[Jean-Baptiste_Henry]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.dvb.notif-aggregate-root+xml", reference: Some("[Roy_Yue]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.dvb.notif-container+xml", reference: Some("[Roy_Yue]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.dvb.notif-generic+xml", reference: Some("[Roy_Yue]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.dvb.notif-ia-msglist+xml", reference: Some("[Roy_Yue]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.dvb.notif-ia-registration-request+xml", reference: Some("[Roy_Yue]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.dvb.notif-ia-registration-response+xml", reference: Some("[Roy_Yue]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.dvb.notif-init+xml", reference: Some("[Roy_Yue]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.dvb.pfr", reference: Some("[Peter_Siebert][Michael_Lagally]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.dvb.service", reference: Some("[Peter_Siebert][Michael_Lagally]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.dxr", reference: Some("[Michael_Duffy]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.dynageo", reference: Some("[Roland_Mechling]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.dzr", reference: Some("[Carl_Anderson]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.easykaraoke.cdgdownload", reference: Some("[Iain_Downs]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.ecdis-update", reference: Some("[Gert_Buettgenbach]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.ecip.rlp", reference: Some("[Wei_Tang]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.eclipse.ditto+json", reference: Some("[Eclipse_Ditto_developers]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.ecowin.chart", reference: Some("[Thomas_Olsson]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.ecowin.filerequest", reference: Some("[Thomas_Olsson]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.ecowin.fileupdate", reference: Some("[Thomas_Olsson]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.ecowin.series", reference: Some("[Thomas_Olsson]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.ecowin.seriesrequest", reference: Some("[Thomas_Olsson]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.ecowin.seriesupdate", reference: Some("[Thomas_Olsson]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.efi.img", reference: Some("[UEFI_Forum][Fu_Siyuan]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.efi.iso", reference: Some("[UEFI_Forum][Fu_Siyuan]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.eln+zip", reference: Some("[Nicolas_CARPI]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.emclient.accessrequest+xml", reference: Some("[Filip_Navara]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.enliven", reference: Some("[Paul_Santinelli_Jr.]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.enphase.envoy", reference: Some("[Chris_Eich]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.eprints.data+xml", reference: Some("[Tim_Brody]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.epson.esf", reference: Some("[Shoji_Hoshina]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.epson.msf", reference: Some("[Shoji_Hoshina]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.epson.quickanime", reference: Some("[Yu_Gu]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.epson.salt", reference: Some("[Yasuhito_Nagatomo]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.epson.ssf", reference: Some("[Shoji_Hoshina]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.ericsson.quickcall", reference: Some("[Paul_Tidwell]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.erofs", reference: Some("[Xiang_Gao]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.espass-espass+zip", reference: Some("[Marcus_Ligi_Büschleb]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.eszigno3+xml", reference: Some("[Szilveszter_Tóth]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.etsi.aoc+xml", reference: Some("[Shicheng_Hu]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.etsi.asic-e+zip", reference: Some("[Miguel_Angel_Reina_Ortega]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.etsi.asic-s+zip", reference: Some("[Miguel_Angel_Reina_Ortega]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.etsi.cug+xml", reference: Some("[Shicheng_Hu]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.etsi.iptvcommand+xml", reference: Some("[Shicheng_Hu]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.etsi.iptvdiscovery+xml", reference: Some("[Shicheng_Hu]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.etsi.iptvprofile+xml", reference: Some("[Shicheng_Hu]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.etsi.iptvsad-bc+xml", reference: Some("[Shicheng_Hu]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.etsi.iptvsad-cod+xml", reference: Some("[Shicheng_Hu]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.etsi.iptvsad-npvr+xml", reference: Some("[Shicheng_Hu]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.etsi.iptvservice+xml", reference: Some("[Miguel_Angel_Reina_Ortega]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.etsi.iptvsync+xml", reference: Some("[Miguel_Angel_Reina_Ortega]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.etsi.iptvueprofile+xml", reference: Some("[Shicheng_Hu]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.etsi.mcid+xml", reference: Some("[Shicheng_Hu]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.etsi.mheg5", reference: Some("[Miguel_Angel_Reina_Ortega][Ian_Medland]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.etsi.overload-control-policy-dataset+xml", reference: Some("[Miguel_Angel_Reina_Ortega]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.etsi.pstn+xml", reference: Some("[Jiwan_Han][Thomas_Belling]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.etsi.sci+xml", reference: Some("[Shicheng_Hu]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.etsi.simservs+xml", reference: Some("[Shicheng_Hu]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.etsi.timestamp-token", reference: Some("[Miguel_Angel_Reina_Ortega]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.etsi.tsl+xml", reference: Some("[Shicheng_Hu]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.etsi.tsl.der", reference: Some("[Shicheng_Hu]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.eu.kasparian.car+json", reference: Some("[Hervé_Kasparian]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.eudora.data", reference: Some("[Pete_Resnick]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.evolv.ecig.profile", reference: Some("[James_Bellinger]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.evolv.ecig.settings", reference: Some("[James_Bellinger]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.evolv.ecig.theme", reference: Some("[James_Bellinger]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.exstream-empower+zip", reference: Some("[Bill_Kidwell]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.exstream-package", reference: Some("[Bill_Kidwell]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.ezpix-album", reference: Some("[ElectronicZombieCorp]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.ezpix-package", reference: Some("[ElectronicZombieCorp]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.f-secure.mobile", reference: Some("[Samu_Sarivaara]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.familysearch.gedcom+zip", reference: Some("[Gordon_Clarke]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.fastcopy-disk-image", reference: Some("[Thomas_Huth]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.fdsn.mseed", reference: Some("[Chad_Trabant]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.fdsn.seed", reference: Some("[Chad_Trabant]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.ffsns", reference: Some("[Holstage]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.ficlab.flb+zip", reference: Some("[Steve_Gilberd]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.filmit.zfc", reference: Some("[Harms_Moeller]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.fints", reference: Some("[Ingo_Hammann]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.firemonkeys.cloudcell", reference: Some("[Alex_Dubov]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.FloGraphIt", reference: Some("[Dick_Floersch]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.fluxtime.clip", reference: Some("[Marc_Winter]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.font-fontforge-sfd", reference: Some("[George_Williams]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.framemaker", reference: Some("[Mike_Wexler]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.freelog.comic", reference: Some("[Liu_Qiancen]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.frogans.fnc", reference: Some("[OP3FT][Alexis_Tamas]"), rfc: None, status: Status::Obsolete, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.frogans.ltf", reference: Some("[OP3FT][Alexis_Tamas]"), rfc: None, status: Status::Obsolete, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.fsc.weblaunch", reference: Some("[Derek_Smith]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.fujifilm.fb.docuworks", reference: Some("[Kazuya_Iimura]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.fujifilm.fb.docuworks.binder", reference: Some("[Kazuya_Iimura]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.fujifilm.fb.docuworks.container", reference: Some("[Kazuya_Iimura]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.fujifilm.fb.jfi+xml", reference: Some("[Keitaro_Ishida]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.fujitsu.oasys", reference: Some("[Nobukazu_Togashi]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.fujitsu.oasys2", reference: Some("[Nobukazu_Togashi]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.fujitsu.oasys3", reference: Some("[Seiji_Okudaira]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.fujitsu.oasysgp", reference: Some("[Masahiko_Sugimoto]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.fujitsu.oasysprs", reference: Some("[Masumi_Ogita]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.fujixerox.ART-EX", reference: Some("[Fumio_Tanabe]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.fujixerox.ART4", reference: Some("[Fumio_Tanabe]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.fujixerox.ddd", reference: Some("[Masanori_Onda]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.fujixerox.docuworks", reference: Some("[Takatomo_Wakibayashi]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.fujixerox.docuworks.binder", reference: Some("[Takashi_Matsumoto]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.fujixerox.docuworks.container", reference: Some("[Kiyoshi_Tashiro]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.fujixerox.HBPL", reference: Some("[Fumio_Tanabe]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.fut-misnet", reference: Some("[Jann_Pruulman]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.futoin+cbor", reference: Some("[Andrey_Galkin]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.futoin+json", reference: Some("[Andrey_Galkin]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.fuzzysheet", reference: Some("[Simon_Birtwistle]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.ga4gh.passport+jwt", reference: Some("[GA4GH_Secretariat]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.genomatix.tuxedo", reference: Some("[Torben_Frey]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.genozip", reference: Some("[Divon_Lan]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.gentics.grd+json", reference: Some("[Philipp_Gortan]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.gentoo.catmetadata+xml", reference: Some("[Michał_Górny]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.gentoo.ebuild", reference: Some("[Michał_Górny]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.gentoo.eclass", reference: Some("[Michał_Górny]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.gentoo.gpkg", reference: Some("[Michał_Górny]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.gentoo.manifest", reference: Some("[Michał_Górny]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.gentoo.pkgmetadata+xml", reference: Some("[Michał_Górny]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.gentoo.xpak", reference: Some("[Gentoo_Portage_Project]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.geo+json", reference: Some("[Sean_Gillies]"), rfc: None, status: Status::Obsolete, replacement: Some("application/geo+json"), required: &[], optional: &[] },
+    Entry { template: "application/vnd.geocube+xml", reference: Some("[Francois_Pirsch]"), rfc: None, status: Status::Obsolete, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.geogebra.file", reference: Some("[GeoGebra][Yves_Kreis]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.geogebra.slides", reference: Some("[GeoGebra][Michael_Borcherds][Markus_Hohenwarter]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.geogebra.tool", reference: Some("[GeoGebra][Yves_Kreis]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.geometry-explorer", reference: Some("[Michael_Hvidsten]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.geonext", reference: Some("[Matthias_Ehmann]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.geoplan", reference: Some("[Christian_Mercat]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.geospace", reference: Some("[Christian_Mercat]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.gerber", reference: Some("[Thomas_Weyn]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.globalplatform.card-content-mgt", reference: Some("[Gil_Bernabeu]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.globalplatform.card-content-mgt-response", reference: Some("[Gil_Bernabeu]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.gmx", reference: Some("[Christian_V._Sciberras]"), rfc: None, status: Status::Deprecated, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.gnu.taler.exchange+json", reference: Some("[Christian_Grothoff]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.gnu.taler.merchant+json", reference: Some("[Christian_Grothoff]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.google-earth.kml+xml", reference: Some("[Michael_Ashbridge]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.google-earth.kmz", reference: Some("[Michael_Ashbridge]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.gov.sk.e-form+xml", reference: Some("[Stefan_Szilva]"), rfc: None, status: Status::Obsolete, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.gov.sk.e-form+zip", reference: Some("[Stefan_Szilva]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.gov.sk.xmldatacontainer+xml", reference: Some("[Stefan_Szilva]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.gpxsee.map+xml", reference: Some("[Martin_Tůma]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.grafeq", reference: Some("[Jeff_Tupper]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.gridmp", reference: Some("[Jeff_Lawson]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.groove-account", reference: Some("[Todd_Joseph]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.groove-help", reference: Some("[Todd_Joseph]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.groove-identity-message", reference: Some("[Todd_Joseph]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.groove-injector", reference: Some("[Todd_Joseph]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.groove-tool-message", reference: Some("[Todd_Joseph]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.groove-tool-template", reference: Some("[Todd_Joseph]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.groove-vcard", reference: Some("[Todd_Joseph]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.hal+json", reference: Some("[Mike_Kelly]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.hal+xml", reference: Some("[Mike_Kelly]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.HandHeld-Entertainment+xml", reference: Some("[Eric_Hamilton]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.hbci", reference: Some("[Ingo_Hammann]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.hc+json", reference: Some("[Jan_Schütze]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.hcl-bireports", reference: Some("[Doug_R._Serres]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.hdt", reference: Some("[Javier_D._Fernández]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.heroku+json", reference: Some("[Wesley_Beary]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.hhe.lesson-player", reference: Some("[Randy_Jones]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.hp-HPGL", reference: Some("[Bob_Pentecost]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.hp-hpid", reference: Some("[Aloke_Gupta]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.hp-hps", reference: Some("[Steve_Aubrey]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.hp-jlyt", reference: Some("[Amir_Gaash]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.hp-PCL", reference: Some("[Bob_Pentecost]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.hp-PCLXL", reference: Some("[Bob_Pentecost]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.hsl", reference: Some("[Heungsub_Lee]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.httphone", reference: Some("[Franck_Lefevre]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.hydrostatix.sof-data", reference: Some("[Allen_Gillam]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.hyper+json", reference: Some("[Irakli_Nadareishvili]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.hyper-item+json", reference: Some("[Mario_Demuth]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.hyperdrive+json", reference: Some("[Daniel_Sims]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.hzn-3d-crossword", reference: Some("[James_Minnis]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.ibm.afplinedata", reference: Some("[Roger_Buis]"), rfc: None, status: Status::Obsolete, replacement: Some("application/vnd.afpc.afplinedata"), required: &[], optional: &[] },
+    Entry { template: "application/vnd.ibm.electronic-media", reference: Some("[Bruce_Tantlinger]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.ibm.MiniPay", reference: Some("[Amir_Herzberg]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.ibm.modcap", reference: Some("[Reinhard_Hohensee]"), rfc: None, status: Status::Obsolete, replacement: Some("application/vnd.afpc.modca"), required: &[], optional: &[] },
+    Entry { template: "application/vnd.ibm.rights-management", reference: Some("[Bruce_Tantlinger]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.ibm.secure-container", reference: Some("[Bruce_Tantlinger]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.iccprofile", reference: Some("[Phil_Green]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.ieee.1905", reference: Some("[Purva_R_Rajkotia]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.igloader", reference: Some("[Tim_Fisher]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.imagemeter.folder+zip", reference: Some("[Dirk_Farin]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.imagemeter.image+zip", reference: Some("[Dirk_Farin]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.immervision-ivp", reference: Some("[Mathieu_Villegas]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.immervision-ivu", reference: Some("[Mathieu_Villegas]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.ims.imsccv1p1", reference: Some("[Lisa_Mattson]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.ims.imsccv1p2", reference: Some("[Lisa_Mattson]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.ims.imsccv1p3", reference: Some("[Lisa_Mattson]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.ims.lis.v2.result+json", reference: Some("[Lisa_Mattson]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.ims.lti.v2.toolconsumerprofile+json", reference: Some("[Lisa_Mattson]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.ims.lti.v2.toolproxy+json", reference: Some("[Lisa_Mattson]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.ims.lti.v2.toolproxy.id+json", reference: Some("[Lisa_Mattson]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.ims.lti.v2.toolsettings+json", reference: Some("[Lisa_Mattson]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.ims.lti.v2.toolsettings.simple+json", reference: Some("[Lisa_Mattson]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.informedcontrol.rms+xml", reference: Some("[Mark_Wahl]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.informix-visionary", reference: Some("[Christopher_Gales]"), rfc: None, status: Status::Obsolete, replacement: Some("application/vnd.visionary"), required: &[], optional: &[] },
+    Entry { template: "application/vnd.infotech.project", reference: Some("[Charles_Engelke]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.infotech.project+xml", reference: Some("[Charles_Engelke]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.innopath.wamp.notification", reference: Some("[Takanori_Sudo]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.insors.igm", reference: Some("[Jon_Swanson]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.intercon.formnet", reference: Some("[Tom_Gurak]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.intergeo", reference: Some("[Yves_Kreis_2]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.intertrust.digibox", reference: Some("[Luke_Tomasello]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.intertrust.nncp", reference: Some("[Luke_Tomasello]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.intu.qbo", reference: Some("[Greg_Scratchley]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.intu.qfx", reference: Some("[Greg_Scratchley]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.ipfs.ipns-record", reference: Some("[Marcin_Rataj]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.ipld.car", reference: Some("[Marcin_Rataj]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.ipld.dag-cbor", reference: Some("[Marcin_Rataj]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.ipld.dag-json", reference: Some("[Marcin_Rataj]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.ipld.raw", reference: Some("[Marcin_Rataj]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.iptc.g2.catalogitem+xml", reference: Some("[Michael_Steidl]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.iptc.g2.conceptitem+xml", reference: Some("[Michael_Steidl]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.iptc.g2.knowledgeitem+xml", reference: Some("[Michael_Steidl]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.iptc.g2.newsitem+xml", reference: Some("[Michael_Steidl]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.iptc.g2.newsmessage+xml", reference: Some("[Michael_Steidl]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.iptc.g2.packageitem+xml", reference: Some("[Michael_Steidl]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.iptc.g2.planningitem+xml", reference: Some("[Michael_Steidl]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.ipunplugged.rcprofile", reference: Some("[Per_Ersson]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.irepository.package+xml", reference: Some("[Martin_Knowles]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.is-xpr", reference: Some("[Satish_Navarajan]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.isac.fcs", reference: Some("[Ryan_Brinkman]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.iso11783-10+zip", reference: Some("[Frank_Wiebeler]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.jam", reference: Some("[Brijesh_Kumar]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.japannet-directory-service", reference: Some("[Kiyofusa_Fujii]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.japannet-jpnstore-wakeup", reference: Some("[Jun_Yoshitake]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.japannet-payment-wakeup", reference: Some("[Kiyofusa_Fujii]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.japannet-registration", reference: Some("[Jun_Yoshitake]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.japannet-registration-wakeup", reference: Some("[Kiyofusa_Fujii]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.japannet-setstore-wakeup", reference: Some("[Jun_Yoshitake]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.japannet-verification", reference: Some("[Jun_Yoshitake]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.japannet-verification-wakeup", reference: Some("[Kiyofusa_Fujii]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.jcp.javame.midlet-rms", reference: Some("[Mikhail_Gorshenev]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.jisp", reference: Some("[Sebastiaan_Deckers]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.joost.joda-archive", reference: Some("[Joost]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.jsk.isdn-ngn", reference: Some("[Yokoyama_Kiyonobu]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.kahootz", reference: Some("[Tim_Macdonald]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.kde.karbon", reference: Some("[David_Faure]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.kde.kchart", reference: Some("[David_Faure]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.kde.kformula", reference: Some("[David_Faure]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.kde.kivio", reference: Some("[David_Faure]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.kde.kontour", reference: Some("[David_Faure]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.kde.kpresenter", reference: Some("[David_Faure]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.kde.kspread", reference: Some("[David_Faure]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.kde.kword", reference: Some("[David_Faure]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.kenameaapp", reference: Some("[Dirk_DiGiorgio-Haag]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.keyman.kmp+zip", reference: Some("[Marc_Durdin]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.keyman.kmx", reference: Some("[Marc_Durdin]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.kidspiration", reference: Some("[Jack_Bennett]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.Kinar", reference: Some("[Hemant_Thakkar]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.koan", reference: Some("[Pete_Cole]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.kodak-descriptor", reference: Some("[Michael_J._Donahue]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.las", reference: Some("[NCGIS][Bryan_Blank]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.las.las+json", reference: Some("[Rob_Bailey]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.las.las+xml", reference: Some("[Rob_Bailey]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.laszip", reference: Some("[NCGIS][Bryan_Blank]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.ldev.productlicensing", reference: Some("[L.development_Polska]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.leap+json", reference: Some("[Mark_C_Fralick]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.liberty-request+xml", reference: Some("[Brett_McDowell]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.llamagraphics.life-balance.desktop", reference: Some("[Catherine_E._White]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.llamagraphics.life-balance.exchange+xml", reference: Some("[Catherine_E._White]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.logipipe.circuit+zip", reference: Some("[Victor_Kuchynsky]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.loom", reference: Some("[Sten_Linnarsson]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.lotus-1-2-3", reference: Some("[Paul_Wattenberger]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.lotus-approach", reference: Some("[Paul_Wattenberger]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.lotus-freelance", reference: Some("[Paul_Wattenberger]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.lotus-notes", reference: Some("[Michael_Laramie]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.lotus-organizer", reference: Some("[Paul_Wattenberger]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.lotus-screencam", reference: Some("[Paul_Wattenberger]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.lotus-wordpro", reference: Some("[Paul_Wattenberger]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.macports.portpkg", reference: Some("[James_Berry]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.mapbox-vector-tile", reference: Some("[Blake_Thompson]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.marlin.drm.actiontoken+xml", reference: Some("[Gary_Ellison]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.marlin.drm.conftoken+xml", reference: Some("[Gary_Ellison]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.marlin.drm.license+xml", reference: Some("[Gary_Ellison]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.marlin.drm.mdcf", reference: Some("[Gary_Ellison]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.mason+json", reference: Some("[Jorn_Wildt]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.maxar.archive.3tz+zip", reference: Some("[Erik_Dahlström]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.maxmind.maxmind-db", reference: Some("[William_Stevenson]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.mcd", reference: Some("[Tadashi_Gotoh]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.mdl", reference: Some("[Lutz_Kettner]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.mdl-mbsdf", reference: Some("[Lutz_Kettner]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.medcalcdata", reference: Some("[Frank_Schoonjans]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.mediastation.cdkey", reference: Some("[Henry_Flurry]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.medicalholodeck.recordxr", reference: Some("[Dominique_Sandoz]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.meridian-slingshot", reference: Some("[Eric_Wedel]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.mermaid", reference: Some("[Sidharth_Vinod]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.MFER", reference: Some("[Masaaki_Hirai]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.mfmp", reference: Some("[Yukari_Ikeda]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.micro+json", reference: Some("[Dali_Zheng]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.micrografx.flo", reference: Some("[Joe_Prevo]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.micrografx.igx", reference: Some("[Joe_Prevo]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.microsoft.portable-executable", reference: Some("[Henrik_Andersson]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.microsoft.windows.thumbnail-cache", reference: Some("[Henrik_Andersson]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.miele+json", reference: Some("[Nils_Langhammer]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.mif", reference: Some("[Mike_Wexler]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.minisoft-hp3000-save", reference: Some("[Chris_Bartram]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.mitsubishi.misty-guard.trustweb", reference: Some("[Tanaka]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.Mobius.DAF", reference: Some("[Allen_K._Kabayama]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.Mobius.DIS", reference: Some("[Allen_K._Kabayama]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.Mobius.MBK", reference: Some("[Alex_Devasia]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.Mobius.MQY", reference: Some("[Alex_Devasia]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.Mobius.MSL", reference: Some("[Allen_K._Kabayama]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.Mobius.PLC", reference: Some("[Allen_K._Kabayama]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.Mobius.TXF", reference: Some("[Allen_K._Kabayama]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.modl", reference: Some("[Elliott_Brown]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.mophun.application", reference: Some("[Bjorn_Wennerstrom]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.mophun.certificate", reference: Some("[Bjorn_Wennerstrom]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.motorola.flexsuite", reference: Some("[Mark_Patton]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.motorola.flexsuite.adsi", reference: Some("[Mark_Patton]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.motorola.flexsuite.fis", reference: Some("[Mark_Patton]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.motorola.flexsuite.gotap", reference: Some("[Mark_Patton]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.motorola.flexsuite.kmr", reference: Some("[Mark_Patton]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.motorola.flexsuite.ttc", reference: Some("[Mark_Patton]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.motorola.flexsuite.wem", reference: Some("[Mark_Patton]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.motorola.iprm", reference: Some("[Rafie_Shamsaasef]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.mozilla.xul+xml", reference: Some("[Braden_N_McDaniel]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.ms-3mfdocument", reference: Some("[Shawn_Maloney]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.ms-artgalry", reference: Some("[Dean_Slawson]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.ms-asf", reference: Some("[Eric_Fleischman]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.ms-cab-compressed", reference: Some("[Kim_Scarborough]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.ms-excel", reference: Some("[Sukvinder_S._Gill]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.ms-excel.addin.macroEnabled.12", reference: Some("[Chris_Rae]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.ms-excel.sheet.binary.macroEnabled.12", reference: Some("[Chris_Rae]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.ms-excel.sheet.macroEnabled.12", reference: Some("[Chris_Rae]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.ms-excel.template.macroEnabled.12", reference: Some("[Chris_Rae]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.ms-fontobject", reference: Some("[Kim_Scarborough]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.ms-htmlhelp", reference: Some("[Anatoly_Techtonik]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.ms-ims", reference: Some("[Eric_Ledoux]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.ms-lrm", reference: Some("[Eric_Ledoux]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.ms-office.activeX+xml", reference: Some("[Chris_Rae]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.ms-officetheme", reference: Some("[Chris_Rae]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.ms-playready.initiator+xml", reference: Some("[Daniel_Schneider]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.ms-powerpoint", reference: Some("[Sukvinder_S._Gill]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.ms-powerpoint.addin.macroEnabled.12", reference: Some("[Chris_Rae]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.ms-powerpoint.presentation.macroEnabled.12", reference: Some("[Chris_Rae]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.ms-powerpoint.slide.macroEnabled.12", reference: Some("[Chris_Rae]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.ms-powerpoint.slideshow.macroEnabled.12", reference: Some("[Chris_Rae]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.ms-powerpoint.template.macroEnabled.12", reference: Some("[Chris_Rae]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.ms-PrintDeviceCapabilities+xml", reference: Some("[Justin_Hutchings]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.ms-PrintSchemaTicket+xml", reference: Some("[Justin_Hutchings]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.ms-project", reference: Some("[Sukvinder_S._Gill]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.ms-tnef", reference: Some("[Sukvinder_S._Gill]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.ms-windows.devicepairing", reference: Some("[Justin_Hutchings]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.ms-windows.nwprinting.oob", reference: Some("[Justin_Hutchings]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.ms-windows.printerpairing", reference: Some("[Justin_Hutchings]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.ms-windows.wsd.oob", reference: Some("[Justin_Hutchings]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.ms-wmdrm.lic-chlg-req", reference: Some("[Kevin_Lau]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.ms-wmdrm.lic-resp", reference: Some("[Kevin_Lau]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.ms-wmdrm.meter-chlg-req", reference: Some("[Kevin_Lau]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.ms-wmdrm.meter-resp", reference: Some("[Kevin_Lau]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.ms-word.document.macroEnabled.12", reference: Some("[Chris_Rae]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.ms-word.template.macroEnabled.12", reference: Some("[Chris_Rae]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.ms-works", reference: Some("[Sukvinder_S._Gill]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.ms-wpl", reference: Some("[Dan_Plastina]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.ms-xpsdocument", reference: Some("[Jesse_McGatha]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.msa-disk-image", reference: Some("[Thomas_Huth]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.mseq", reference: Some("[Gwenael_Le_Bodic]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.msgpack", reference: Some("[Alexander_Ivanov]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.msign", reference: Some("[Malte_Borcherding]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.multiad.creator", reference: Some("[Steve_Mills]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.multiad.creator.cif", reference: Some("[Steve_Mills]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.music-niff", reference: Some("[Tim_Butler]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.musician", reference: Some("[Greg_Adams]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.muvee.style", reference: Some("[Chandrashekhara_Anantharamu]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.mynfc", reference: Some("[Franck_Lefevre]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.nacamar.ybrid+json", reference: Some("[Sebastian_A._Weiss]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.nato.bindingdataobject+cbor", reference: Some("[Aidan_Murdock]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.nato.bindingdataobject+json", reference: Some("[Aidan_Murdock]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.nato.bindingdataobject+xml", reference: Some("[Aidan_Murdock]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.nato.openxmlformats-package.iepd+zip", reference: Some("[Aidan_Murdock]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.ncd.control", reference: Some("[Lauri_Tarkkala]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.ncd.reference", reference: Some("[Lauri_Tarkkala]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.nearst.inv+json", reference: Some("[Thomas_Schoffelen]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.nebumind.line", reference: Some("[Andreas_Molzer]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.nervana", reference: Some("[Steve_Judkins]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.netfpx", reference: Some("[Andy_Mutz]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.neurolanguage.nlu", reference: Some("[Dan_DuFeu]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.nimn", reference: Some("[Amit_Kumar_Gupta]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.nintendo.nitro.rom", reference: Some("[Henrik_Andersson]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.nintendo.snes.rom", reference: Some("[Henrik_Andersson]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.nitf", reference: Some("[Steve_Rogan]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.noblenet-directory", reference: Some("[Monty_Solomon]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.noblenet-sealer", reference: Some("[Monty_Solomon]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.noblenet-web", reference: Some("[Monty_Solomon]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.nokia.catalogs", reference: Some("[Nokia]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.nokia.conml+wbxml", reference: Some("[Nokia]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.nokia.conml+xml", reference: Some("[Nokia]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.nokia.iptv.config+xml", reference: Some("[Nokia]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.nokia.iSDS-radio-presets", reference: Some("[Nokia]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.nokia.landmark+wbxml", reference: Some("[Nokia]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.nokia.landmark+xml", reference: Some("[Nokia]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.nokia.landmarkcollection+xml", reference: Some("[Nokia]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.nokia.n-gage.ac+xml", reference: Some("[Nokia]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.nokia.n-gage.data", reference: Some("[Nokia]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.nokia.n-gage.symbian.install", reference: Some("[Nokia]"), rfc: None, status: Status::Obsolete, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.nokia.ncd", reference: Some("[Nokia]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.nokia.pcd+wbxml", reference: Some("[Nokia]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.nokia.pcd+xml", reference: Some("[Nokia]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.nokia.radio-preset", reference: Some("[Nokia]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.nokia.radio-presets", reference: Some("[Nokia]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.novadigm.EDM", reference: Some("[Janine_Swenson]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.novadigm.EDX", reference: Some("[Janine_Swenson]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.novadigm.EXT", reference: Some("[Janine_Swenson]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.ntt-local.content-share", reference: Some("[Akinori_Taya]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.ntt-local.file-transfer", reference: Some("[NTT-local]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.ntt-local.ogw_remote-access", reference: Some("[NTT-local]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.ntt-local.sip-ta_remote", reference: Some("[NTT-local]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.ntt-local.sip-ta_tcp_stream", reference: Some("[NTT-local]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.oai.workflows", reference: Some("[Frank_Kilcommins]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.oai.workflows+json", reference: Some("[Frank_Kilcommins]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.oai.workflows+yaml", reference: Some("[Frank_Kilcommins]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.oasis.opendocument.base", reference: Some("[OASIS_TC_Admin][OASIS]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.oasis.opendocument.chart", reference: Some("[OASIS_TC_Admin][OASIS]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.oasis.opendocument.chart-template", reference: Some("[OASIS_TC_Admin][OASIS]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.oasis.opendocument.database", reference: Some("[OASIS_TC_Admin][OASIS]"), rfc: None, status: Status::Obsolete, replacement: Some("application/vnd.oasis.opendocument.base"), required: &[], optional: &[] },
+    Entry { template: "application/vnd.oasis.opendocument.formula", reference: Some("[OASIS_TC_Admin][OASIS]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.oasis.opendocument.formula-template", reference: Some("[OASIS_TC_Admin][OASIS]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.oasis.opendocument.graphics", reference: Some("[OASIS_TC_Admin][OASIS]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.oasis.opendocument.graphics-template", reference: Some("[OASIS_TC_Admin][OASIS]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.oasis.opendocument.image", reference: Some("[OASIS_TC_Admin][OASIS]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.oasis.opendocument.image-template", reference: Some("[OASIS_TC_Admin][OASIS]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.oasis.opendocument.presentation", reference: Some("[OASIS_TC_Admin][OASIS]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.oasis.opendocument.presentation-template", reference: Some("[OASIS_TC_Admin][OASIS]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.oasis.opendocument.spreadsheet", reference: Some("[OASIS_TC_Admin][OASIS]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.oasis.opendocument.spreadsheet-template", reference: Some("[OASIS_TC_Admin][OASIS]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.oasis.opendocument.text", reference: Some("[OASIS_TC_Admin][OASIS]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.oasis.opendocument.text-master", reference: Some("[OASIS_TC_Admin][OASIS]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.oasis.opendocument.text-master-template", reference: Some("[OASIS_TC_Admin][OASIS]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.oasis.opendocument.text-template", reference: Some("[OASIS_TC_Admin][OASIS]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.oasis.opendocument.text-web", reference: Some("[OASIS_TC_Admin][OASIS]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.obn", reference: Some("[Matthias_Hessling]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.ocf+cbor", reference: Some("[Michael_Koster]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.oci.image.manifest.v1+json", reference: Some("[Steven_Lasker]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.oftn.l10n+json", reference: Some("[Eli_Grey]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.oipf.contentaccessdownload+xml", reference: Some("[Claire_DEsclercs]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.oipf.contentaccessstreaming+xml", reference: Some("[Claire_DEsclercs]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.oipf.cspg-hexbinary", reference: Some("[Claire_DEsclercs]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.oipf.dae.svg+xml", reference: Some("[Claire_DEsclercs]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.oipf.dae.xhtml+xml", reference: Some("[Claire_DEsclercs]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.oipf.mippvcontrolmessage+xml", reference: Some("[Claire_DEsclercs]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.oipf.pae.gem", reference: Some("[Claire_DEsclercs]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.oipf.spdiscovery+xml", reference: Some("[Claire_DEsclercs]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.oipf.spdlist+xml", reference: Some("[Claire_DEsclercs]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.oipf.ueprofile+xml", reference: Some("[Claire_DEsclercs]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.oipf.userprofile+xml", reference: Some("[Claire_DEsclercs]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.olpc-sugar", reference: Some("[John_Palmieri]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.oma-scws-config", reference: Some("[Ilan_Mahalal]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.oma-scws-http-request", reference: Some("[Ilan_Mahalal]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.oma-scws-http-response", reference: Some("[Ilan_Mahalal]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.oma.bcast.associated-procedure-parameter+xml", reference: Some("[Uwe_Rauschenbach][Open_Mobile_Naming_Authority]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.oma.bcast.drm-trigger+xml", reference: Some("[Uwe_Rauschenbach][Open_Mobile_Naming_Authority]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.oma.bcast.imd+xml", reference: Some("[Uwe_Rauschenbach][Open_Mobile_Naming_Authority]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.oma.bcast.ltkm", reference: Some("[Uwe_Rauschenbach][Open_Mobile_Naming_Authority]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.oma.bcast.notification+xml", reference: Some("[Uwe_Rauschenbach][Open_Mobile_Naming_Authority]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.oma.bcast.provisioningtrigger", reference: Some("[Uwe_Rauschenbach][Open_Mobile_Naming_Authority]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.oma.bcast.sgboot", reference: Some("[Uwe_Rauschenbach][Open_Mobile_Naming_Authority]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.oma.bcast.sgdd+xml", reference: Some("[Uwe_Rauschenbach][Open_Mobile_Naming_Authority]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.oma.bcast.sgdu", reference: Some("[Uwe_Rauschenbach][Open_Mobile_Naming_Authority]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.oma.bcast.simple-symbol-container", reference: Some("[Uwe_Rauschenbach][Open_Mobile_Naming_Authority]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.oma.bcast.smartcard-trigger+xml", reference: Some("[Uwe_Rauschenbach][Open_Mobile_Naming_Authority]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.oma.bcast.sprov+xml", reference: Some("[Uwe_Rauschenbach][Open_Mobile_Naming_Authority]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.oma.bcast.stkm", reference: Some("[Uwe_Rauschenbach][Open_Mobile_Naming_Authority]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.oma.cab-address-book+xml", reference: Some("[Hao_Wang][OMA]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.oma.cab-feature-handler+xml", reference: Some("[Hao_Wang][OMA]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.oma.cab-pcc+xml", reference: Some("[Hao_Wang][OMA]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.oma.cab-subs-invite+xml", reference: Some("[Hao_Wang][OMA]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.oma.cab-user-prefs+xml", reference: Some("[Hao_Wang][OMA]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.oma.dcd", reference: Some("[Avi_Primo][Open_Mobile_Naming_Authority]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.oma.dcdc", reference: Some("[Avi_Primo][Open_Mobile_Naming_Authority]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.oma.dd2+xml", reference: Some("[Jun_Sato][Open_Mobile_Alliance_BAC_DLDRM_Working_Group]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.oma.drm.risd+xml", reference: Some("[Uwe_Rauschenbach][Open_Mobile_Naming_Authority]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.oma.group-usage-list+xml", reference: Some("[Sean_Kelley][OMA_Presence_and_Availability_PAG_Working_Group]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.oma.lwm2m+cbor", reference: Some("[Open_Mobile_Naming_Authority][John_Mudge]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.oma.lwm2m+json", reference: Some("[Open_Mobile_Naming_Authority][John_Mudge]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.oma.lwm2m+tlv", reference: Some("[Open_Mobile_Naming_Authority][John_Mudge]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.oma.pal+xml", reference: Some("[Brian_McColgan][Open_Mobile_Naming_Authority]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.oma.poc.detailed-progress-report+xml", reference: Some("[OMA_Push_to_Talk_over_Cellular_POC_Working_Group]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.oma.poc.final-report+xml", reference: Some("[OMA_Push_to_Talk_over_Cellular_POC_Working_Group]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.oma.poc.groups+xml", reference: Some("[Sean_Kelley][OMA_Push_to_Talk_over_Cellular_POC_Working_Group]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.oma.poc.invocation-descriptor+xml", reference: Some("[OMA_Push_to_Talk_over_Cellular_POC_Working_Group]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.oma.poc.optimized-progress-report+xml", reference: Some("[OMA_Push_to_Talk_over_Cellular_POC_Working_Group]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.oma.push", reference: Some("[Bryan_Sullivan][OMA]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.oma.scidm.messages+xml", reference: Some("[Wenjun_Zeng][Open_Mobile_Naming_Authority]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.oma.xcap-directory+xml", reference: Some("[Sean_Kelley][OMA_Presence_and_Availability_PAG_Working_Group]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.omads-email+xml", reference: Some("[OMA_Data_Synchronization_Working_Group]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.omads-file+xml", reference: Some("[OMA_Data_Synchronization_Working_Group]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.omads-folder+xml", reference: Some("[OMA_Data_Synchronization_Working_Group]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.omaloc-supl-init", reference: Some("[Julien_Grange]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.onepager", reference: Some("[Nathan_Black]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.onepagertamp", reference: Some("[Nathan_Black]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.onepagertamx", reference: Some("[Nathan_Black]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.onepagertat", reference: Some("[Nathan_Black]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.onepagertatp", reference: Some("[Nathan_Black]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.onepagertatx", reference: Some("[Nathan_Black]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.onvif.metadata", reference: Some("[Hans_Busch]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.openblox.game+xml", reference: Some("[Mark_Otaris]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.openblox.game-binary", reference: Some("[Mark_Otaris]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.openeye.oeb", reference: Some("[Craig_Bruce]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.openstreetmap.data+xml", reference: Some("[Paul_Norman]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.opentimestamps.ots", reference: Some("[Peter_Todd]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.openxmlformats-officedocument.custom-properties+xml", reference: Some("[Makoto_Murata]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.openxmlformats-officedocument.customXmlProperties+xml", reference: Some("[Makoto_Murata]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.openxmlformats-officedocument.drawing+xml", reference: Some("[Makoto_Murata]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.openxmlformats-officedocument.drawingml.chart+xml", reference: Some("[Makoto_Murata]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.openxmlformats-officedocument.drawingml.chartshapes+xml", reference: Some("[Makoto_Murata]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.openxmlformats-officedocument.drawingml.diagramColors+xml", reference: Some("[Makoto_Murata]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.openxmlformats-officedocument.drawingml.diagramData+xml", reference: Some("[Makoto_Murata]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.openxmlformats-officedocument.drawingml.diagramLayout+xml", reference: Some("[Makoto_Murata]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.openxmlformats-officedocument.drawingml.diagramStyle+xml", reference: Some("[Makoto_Murata]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.openxmlformats-officedocument.extended-properties+xml", reference: Some("[Makoto_Murata]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.openxmlformats-officedocument.presentationml.commentAuthors+xml", reference: Some("[Makoto_Murata]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.openxmlformats-officedocument.presentationml.comments+xml", reference: Some("[Makoto_Murata]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.openxmlformats-officedocument.presentationml.handoutMaster+xml", reference: Some("[Makoto_Murata]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.openxmlformats-officedocument.presentationml.notesMaster+xml", reference: Some("[Makoto_Murata]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.openxmlformats-officedocument.presentationml.notesSlide+xml", reference: Some("[Makoto_Murata]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.openxmlformats-officedocument.presentationml.presentation", reference: Some("[Makoto_Murata]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.openxmlformats-officedocument.presentationml.presentation.main+xml", reference: Some("[Makoto_Murata]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.openxmlformats-officedocument.presentationml.presProps+xml", reference: Some("[Makoto_Murata]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.openxmlformats-officedocument.presentationml.slide", reference: Some("[Makoto_Murata]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.openxmlformats-officedocument.presentationml.slide+xml", reference: Some("[Makoto_Murata]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.openxmlformats-officedocument.presentationml.slideLayout+xml", reference: Some("[Makoto_Murata]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.openxmlformats-officedocument.presentationml.slideMaster+xml", reference: Some("[Makoto_Murata]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.openxmlformats-officedocument.presentationml.slideshow", reference: Some("[Makoto_Murata]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.openxmlformats-officedocument.presentationml.slideshow.main+xml", reference: Some("[Makoto_Murata]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.openxmlformats-officedocument.presentationml.slideUpdateInfo+xml", reference: Some("[Makoto_Murata]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.openxmlformats-officedocument.presentationml.tableStyles+xml", reference: Some("[Makoto_Murata]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.openxmlformats-officedocument.presentationml.tags+xml", reference: Some("[Makoto_Murata]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.openxmlformats-officedocument.presentationml.template", reference: Some("[Makoto_Murata]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.openxmlformats-officedocument.presentationml.template.main+xml", reference: Some("[Makoto_Murata]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.openxmlformats-officedocument.presentationml.viewProps+xml", reference: Some("[Makoto_Murata]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.openxmlformats-officedocument.spreadsheetml.calcChain+xml", reference: Some("[Makoto_Murata]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.openxmlformats-officedocument.spreadsheetml.chartsheet+xml", reference: Some("[Makoto_Murata]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.openxmlformats-officedocument.spreadsheetml.comments+xml", reference: Some("[Makoto_Murata]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.openxmlformats-officedocument.spreadsheetml.connections+xml", reference: Some("[Makoto_Murata]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.openxmlformats-officedocument.spreadsheetml.dialogsheet+xml", reference: Some("[Makoto_Murata]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.openxmlformats-officedocument.spreadsheetml.externalLink+xml", reference: Some("[Makoto_Murata]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.openxmlformats-officedocument.spreadsheetml.pivotCacheDefinition+xml", reference: Some("[Makoto_Murata]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.openxmlformats-officedocument.spreadsheetml.pivotCacheRecords+xml", reference: Some("[Makoto_Murata]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.openxmlformats-officedocument.spreadsheetml.pivotTable+xml", reference: Some("[Makoto_Murata]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.openxmlformats-officedocument.spreadsheetml.queryTable+xml", reference: Some("[Makoto_Murata]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.openxmlformats-officedocument.spreadsheetml.revisionHeaders+xml", reference: Some("[Makoto_Murata]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.openxmlformats-officedocument.spreadsheetml.revisionLog+xml", reference: Some("[Makoto_Murata]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.openxmlformats-officedocument.spreadsheetml.sharedStrings+xml", reference: Some("[Makoto_Murata]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet", reference: Some("[Makoto_Murata]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet.main+xml", reference: Some("[Makoto_Murata]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.openxmlformats-officedocument.spreadsheetml.sheetMetadata+xml", reference: Some("[Makoto_Murata]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.openxmlformats-officedocument.spreadsheetml.styles+xml", reference: Some("[Makoto_Murata]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.openxmlformats-officedocument.spreadsheetml.table+xml", reference: Some("[Makoto_Murata]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.openxmlformats-officedocument.spreadsheetml.tableSingleCells+xml", reference: Some("[Makoto_Murata]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.openxmlformats-officedocument.spreadsheetml.template", reference: Some("[Makoto_Murata]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.openxmlformats-officedocument.spreadsheetml.template.main+xml", reference: Some("[Makoto_Murata]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.openxmlformats-officedocument.spreadsheetml.userNames+xml", reference: Some("[Makoto_Murata]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.openxmlformats-officedocument.spreadsheetml.volatileDependencies+xml", reference: Some("[Makoto_Murata]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.openxmlformats-officedocument.spreadsheetml.worksheet+xml", reference: Some("[Makoto_Murata]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.openxmlformats-officedocument.theme+xml", reference: Some("[Makoto_Murata]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.openxmlformats-officedocument.themeOverride+xml", reference: Some("[Makoto_Murata]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.openxmlformats-officedocument.vmlDrawing", reference: Some("[Makoto_Murata]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.openxmlformats-officedocument.wordprocessingml.comments+xml", reference: Some("[Makoto_Murata]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.openxmlformats-officedocument.wordprocessingml.document", reference: Some("[Makoto_Murata]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.openxmlformats-officedocument.wordprocessingml.document.glossary+xml", reference: Some("[Makoto_Murata]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.openxmlformats-officedocument.wordprocessingml.document.main+xml", reference: Some("[Makoto_Murata]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.openxmlformats-officedocument.wordprocessingml.endnotes+xml", reference: Some("[Makoto_Murata]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.openxmlformats-officedocument.wordprocessingml.fontTable+xml", reference: Some("[Makoto_Murata]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.openxmlformats-officedocument.wordprocessingml.footer+xml", reference: Some("[Makoto_Murata]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.openxmlformats-officedocument.wordprocessingml.footnotes+xml", reference: Some("[Makoto_Murata]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.openxmlformats-officedocument.wordprocessingml.numbering+xml", reference: Some("[Makoto_Murata]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.openxmlformats-officedocument.wordprocessingml.settings+xml", reference: Some("[Makoto_Murata]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.openxmlformats-officedocument.wordprocessingml.styles+xml", reference: Some("[Makoto_Murata]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.openxmlformats-officedocument.wordprocessingml.template", reference: Some("[Makoto_Murata]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.openxmlformats-officedocument.wordprocessingml.template.main+xml", reference: Some("[Makoto_Murata]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.openxmlformats-officedocument.wordprocessingml.webSettings+xml", reference: Some("[Makoto_Murata]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.openxmlformats-package.core-properties+xml", reference: Some("[Makoto_Murata]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.openxmlformats-package.digital-signature-xmlsignature+xml", reference: Some("[Makoto_Murata]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.openxmlformats-package.relationships+xml", reference: Some("[Makoto_Murata]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.oracle.resource+json", reference: Some("[Ning_Dong]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.orange.indata", reference: Some("[CHATRAS_Bruno]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.osa.netdeploy", reference: Some("[Steven_Klos]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.osgeo.mapguide.package", reference: Some("[Jason_Birch]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.osgi.bundle", reference: Some("[Peter_Kriens]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.osgi.dp", reference: Some("[Peter_Kriens]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.osgi.subsystem", reference: Some("[Peter_Kriens]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.otps.ct-kip+xml", reference: Some("[Magnus_Nystrom]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.oxli.countgraph", reference: Some("[C._Titus_Brown]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.pagerduty+json", reference: Some("[Steve_Rice]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.palm", reference: Some("[Gavin_Peacock]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.panoply", reference: Some("[Natarajan_Balasundara]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.paos.xml", reference: Some("[John_Kemp]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.patentdive", reference: Some("[Christian_Trosclair]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.patientecommsdoc", reference: Some("[Andrew_David_Kendall]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.pawaafile", reference: Some("[Prakash_Baskaran]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.pcos", reference: Some("[Slawomir_Lisznianski]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.pg.format", reference: Some("[April_Gandert]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.pg.osasli", reference: Some("[April_Gandert]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.piaccess.application-licence", reference: Some("[Lucas_Maneos]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.picsel", reference: Some("[Giuseppe_Naccarato]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.pmi.widget", reference: Some("[Rhys_Lewis]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.poc.group-advertisement+xml", reference: Some("[Sean_Kelley][OMA_Push_to_Talk_over_Cellular_POC_Working_Group]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.pocketlearn", reference: Some("[Jorge_Pando]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.powerbuilder6", reference: Some("[David_Guy]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.powerbuilder6-s", reference: Some("[David_Guy]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.powerbuilder7", reference: Some("[Reed_Shilts]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.powerbuilder7-s", reference: Some("[Reed_Shilts]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.powerbuilder75", reference: Some("[Reed_Shilts]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.powerbuilder75-s", reference: Some("[Reed_Shilts]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.preminet", reference: Some("[Juoko_Tenhunen]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.previewsystems.box", reference: Some("[Roman_Smolgovsky]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.proteus.magazine", reference: Some("[Pete_Hoch]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.psfs", reference: Some("[Kristopher_Durski]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.pt.mundusmundi", reference: Some("[Igor_Lima_Bolacha_Severino]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.publishare-delta-tree", reference: Some("[Oren_Ben-Kiki]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.pvi.ptid1", reference: Some("[Charles_P._Lamb]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.pwg-multiplexed", reference: Some("[RFC3391]"), rfc: Some(3391), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.pwg-xhtml-print+xml", reference: Some("[Don_Wright]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.qualcomm.brew-app-res", reference: Some("[Glenn_Forrester]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.quarantainenet", reference: Some("[Casper_Joost_Eyckelhof]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.Quark.QuarkXPress", reference: Some("[Hannes_Scheidler]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.quobject-quoxdocument", reference: Some("[Matthias_Ludwig]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.radisys.moml+xml", reference: Some("[RFC5707]"), rfc: Some(5707), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.radisys.msml+xml", reference: Some("[RFC5707]"), rfc: Some(5707), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.radisys.msml-audit+xml", reference: Some("[RFC5707]"), rfc: Some(5707), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.radisys.msml-audit-conf+xml", reference: Some("[RFC5707]"), rfc: Some(5707), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.radisys.msml-audit-conn+xml", reference: Some("[RFC5707]"), rfc: Some(5707), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.radisys.msml-audit-dialog+xml", reference: Some("[RFC5707]"), rfc: Some(5707), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.radisys.msml-audit-stream+xml", reference: Some("[RFC5707]"), rfc: Some(5707), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.radisys.msml-conf+xml", reference: Some("[RFC5707]"), rfc: Some(5707), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.radisys.msml-dialog+xml", reference: Some("[RFC5707]"), rfc: Some(5707), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.radisys.msml-dialog-base+xml", reference: Some("[RFC5707]"), rfc: Some(5707), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.radisys.msml-dialog-fax-detect+xml", reference: Some("[RFC5707]"), rfc: Some(5707), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.radisys.msml-dialog-fax-sendrecv+xml", reference: Some("[RFC5707]"), rfc: Some(5707), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.radisys.msml-dialog-group+xml", reference: Some("[RFC5707]"), rfc: Some(5707), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.radisys.msml-dialog-speech+xml", reference: Some("[RFC5707]"), rfc: Some(5707), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.radisys.msml-dialog-transform+xml", reference: Some("[RFC5707]"), rfc: Some(5707), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.rainstor.data", reference: Some("[Kevin_Crook]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.rapid", reference: Some("[Etay_Szekely]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.rar", reference: Some("[Kim_Scarborough]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.realvnc.bed", reference: Some("[Nick_Reeves]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.recordare.musicxml", reference: Some("[W3C_Music_Notation_Community_Group]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.recordare.musicxml+xml", reference: Some("[W3C_Music_Notation_Community_Group]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.relpipe", reference: Some("[Frantisek_Kucera]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.RenLearn.rlprint", reference: Some("[James_Wick]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.resilient.logic", reference: Some("[Benedikt_Muessig]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.restful+json", reference: Some("[Stephen_Mizell]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.rig.cryptonote", reference: Some("[Ken_Jibiki]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.route66.link66+xml", reference: Some("[Sybren_Kikstra]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.rs-274x", reference: Some("[Lee_Harding]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.ruckus.download", reference: Some("[Jerry_Harris]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.s3sms", reference: Some("[Lauri_Tarkkala]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.sailingtracker.track", reference: Some("[Heikki_Vesalainen]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.sar", reference: Some("[Markus_Strehle]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.sbm.cid", reference: Some("[Shinji_Kusakari]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.sbm.mid2", reference: Some("[Masanori_Murai]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.scribus", reference: Some("[Craig_Bradney]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.sealed.3df", reference: Some("[John_Kwan]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.sealed.csf", reference: Some("[John_Kwan]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.sealed.doc", reference: Some("[David_Petersen]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.sealed.eml", reference: Some("[David_Petersen]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.sealed.mht", reference: Some("[David_Petersen]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.sealed.net", reference: Some("[Martin_Lambert]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.sealed.ppt", reference: Some("[David_Petersen]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.sealed.tiff", reference: Some("[John_Kwan][Martin_Lambert]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.sealed.xls", reference: Some("[David_Petersen]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.sealedmedia.softseal.html", reference: Some("[David_Petersen]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.sealedmedia.softseal.pdf", reference: Some("[David_Petersen]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.seemail", reference: Some("[Steve_Webb]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.seis+json", reference: Some("[ICT_Manager]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.sema", reference: Some("[Anders_Hansson]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.semd", reference: Some("[Anders_Hansson]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.semf", reference: Some("[Anders_Hansson]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.shade-save-file", reference: Some("[Connor_Horman]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.shana.informed.formdata", reference: Some("[Guy_Selzler]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.shana.informed.formtemplate", reference: Some("[Guy_Selzler]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.shana.informed.interchange", reference: Some("[Guy_Selzler]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.shana.informed.package", reference: Some("[Guy_Selzler]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.shootproof+json", reference: Some("[Ben_Ramsey]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.shopkick+json", reference: Some("[Ronald_Jacobs]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.shp", reference: Some("[Mi_Tar]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.shx", reference: Some("[Mi_Tar]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.sigrok.session", reference: Some("[Uwe_Hermann]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.SimTech-MindMapper", reference: Some("[Patrick_Koh]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.siren+json", reference: Some("[Kevin_Swiber]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.smaf", reference: Some("[Hiroaki_Takahashi]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.smart.notebook", reference: Some("[Jonathan_Neitz]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.smart.teacher", reference: Some("[Michael_Boyle]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.smintio.portals.archive", reference: Some("[Reinhard_Holzner]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.snesdev-page-table", reference: Some("[Connor_Horman]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.software602.filler.form+xml", reference: Some("[Jakub_Hytka][Martin_Vondrous]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.software602.filler.form-xml-zip", reference: Some("[Jakub_Hytka][Martin_Vondrous]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.solent.sdkm+xml", reference: Some("[Cliff_Gauntlett]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.spotfire.dxp", reference: Some("[Stefan_Jernberg]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.spotfire.sfs", reference: Some("[Stefan_Jernberg]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.sqlite3", reference: Some("[Clemens_Ladisch]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.sss-cod", reference: Some("[Asang_Dani]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.sss-dtf", reference: Some("[Eric_Bruno]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.sss-ntf", reference: Some("[Eric_Bruno]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.stepmania.package", reference: Some("[Henrik_Andersson]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.stepmania.stepchart", reference: Some("[Henrik_Andersson]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.street-stream", reference: Some("[Glenn_Levitt]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.sun.wadl+xml", reference: Some("[Marc_Hadley]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.sus-calendar", reference: Some("[Jonathan_Niedfeldt]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.svd", reference: Some("[Scott_Becker]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.swiftview-ics", reference: Some("[Glenn_Widener]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.sybyl.mol2", reference: Some("[Finn_Rayk_Gärtner]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.sycle+xml", reference: Some("[Johann_Terblanche]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.syft+json", reference: Some("[Dan_Luhring]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.syncml+xml", reference: Some("[OMA_Data_Synchronization_Working_Group]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.syncml.dm+wbxml", reference: Some("[OMA-DM_Work_Group]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.syncml.dm+xml", reference: Some("[Bindu_Rama_Rao][OMA-DM_Work_Group]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.syncml.dm.notification", reference: Some("[Peter_Thompson][OMA-DM_Work_Group]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.syncml.dmddf+wbxml", reference: Some("[OMA-DM_Work_Group]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.syncml.dmddf+xml", reference: Some("[OMA-DM_Work_Group]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.syncml.dmtnds+wbxml", reference: Some("[OMA-DM_Work_Group]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.syncml.dmtnds+xml", reference: Some("[OMA-DM_Work_Group]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.syncml.ds.notification", reference: Some("[OMA_Data_Synchronization_Working_Group]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.tableschema+json", reference: Some("[Paul_Walsh]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.tao.intent-module-archive", reference: Some("[Daniel_Shelton]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.tcpdump.pcap", reference: Some("[Guy_Harris][Glen_Turner]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.think-cell.ppttc+json", reference: Some("[Arno_Schoedl]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.tmd.mediaflex.api+xml", reference: Some("[Alex_Sibilev]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.tml", reference: Some("[Joey_Smith]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.tmobile-livetv", reference: Some("[Nicolas_Helin]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.tri.onesource", reference: Some("[Rick_Rupp]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.trid.tpt", reference: Some("[Frank_Cusack]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.triscape.mxs", reference: Some("[Steven_Simonoff]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.trueapp", reference: Some("[J._Scott_Hepler]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.truedoc", reference: Some("[Brad_Chase]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.ubisoft.webplayer", reference: Some("[Martin_Talbot]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.ufdl", reference: Some("[Dave_Manning]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.uic.osdm+json", reference: Some("[David_Sarfatti]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.uiq.theme", reference: Some("[Tim_Ocock]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.umajin", reference: Some("[Jamie_Riden]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.unity", reference: Some("[Unity3d]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.uoml+xml", reference: Some("[Arne_Gerdes]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.uplanet.alert", reference: Some("[Bruce_Martin]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.uplanet.alert-wbxml", reference: Some("[Bruce_Martin]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.uplanet.bearer-choice", reference: Some("[Bruce_Martin]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.uplanet.bearer-choice-wbxml", reference: Some("[Bruce_Martin]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.uplanet.cacheop", reference: Some("[Bruce_Martin]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.uplanet.cacheop-wbxml", reference: Some("[Bruce_Martin]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.uplanet.channel", reference: Some("[Bruce_Martin]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.uplanet.channel-wbxml", reference: Some("[Bruce_Martin]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.uplanet.list", reference: Some("[Bruce_Martin]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.uplanet.list-wbxml", reference: Some("[Bruce_Martin]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.uplanet.listcmd", reference: Some("[Bruce_Martin]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.uplanet.listcmd-wbxml", reference: Some("[Bruce_Martin]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.uplanet.signal", reference: Some("[Bruce_Martin]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.uri-map", reference: Some("[Sebastian_Baer]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.valve.source.material", reference: Some("[Henrik_Andersson]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.vcx", reference: Some("[Taisuke_Sugimoto]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.vd-study", reference: Some("[Luc_Rogge]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.vectorworks", reference: Some("[Lyndsey_Ferguson][Biplab_Sarkar]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.vel+json", reference: Some("[James_Wigger]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.verimatrix.vcas", reference: Some("[Petr_Peterka]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.veritone.aion+json", reference: Some("[Al_Brown]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.veryant.thin", reference: Some("[Massimo_Bertoli]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.ves.encrypted", reference: Some("[Jim_Zubov]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.vidsoft.vidconference", reference: Some("[Robert_Hess]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.visio", reference: Some("[Troy_Sandal]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.visionary", reference: Some("[Gayatri_Aravindakumar]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.vividence.scriptfile", reference: Some("[Mark_Risher]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.vsf", reference: Some("[Delton_Rowe]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.wap.sic", reference: Some("[WAP-Forum]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.wap.slc", reference: Some("[WAP-Forum]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.wap.wbxml", reference: Some("[Peter_Stark]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.wap.wmlc", reference: Some("[Peter_Stark]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.wap.wmlscriptc", reference: Some("[Peter_Stark]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.wasmflow.wafl", reference: Some("[Fawad_Shaikh]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.webturbo", reference: Some("[Yaser_Rehem]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.wfa.dpp", reference: Some("[Wi-Fi_Alliance][Dr._Jun_Tian]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.wfa.p2p", reference: Some("[Mick_Conley]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.wfa.wsc", reference: Some("[Wi-Fi_Alliance]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.windows.devicepairing", reference: Some("[Priya_Dandawate]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.wmc", reference: Some("[Thomas_Kjornes]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.wmf.bootstrap", reference: Some("[Thinh_Nguyenphu][Prakash_Iyer]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.wolfram.mathematica", reference: Some("[Wolfram]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.wolfram.mathematica.package", reference: Some("[Wolfram]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.wolfram.player", reference: Some("[Wolfram]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.wordlift", reference: Some("[David_Riccitelli]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.wordperfect", reference: Some("[Kim_Scarborough]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.wqd", reference: Some("[Jan_Bostrom]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.wrq-hp3000-labelled", reference: Some("[Chris_Bartram]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.wt.stf", reference: Some("[Bill_Wohler]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.wv.csp+wbxml", reference: Some("[Matti_Salmi]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.wv.csp+xml", reference: Some("[John_Ingi_Ingimundarson]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.wv.ssp+xml", reference: Some("[John_Ingi_Ingimundarson]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.xacml+json", reference: Some("[David_Brossard]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.xara", reference: Some("[David_Matthewman]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.xarin.cpj", reference: Some("[Ben_Brown]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.xecrets-encrypted", reference: Some("[Svante_Seleborg]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.xfdl", reference: Some("[Dave_Manning]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.xfdl.webform", reference: Some("[Michael_Mansell]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.xmi+xml", reference: Some("[Fred_Waskiewicz]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.xmpie.cpkg", reference: Some("[Reuven_Sherwin]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.xmpie.dpkg", reference: Some("[Reuven_Sherwin]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.xmpie.plan", reference: Some("[Reuven_Sherwin]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.xmpie.ppkg", reference: Some("[Reuven_Sherwin]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.xmpie.xlim", reference: Some("[Reuven_Sherwin]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.yamaha.hv-dic", reference: Some("[Tomohiro_Yamamoto]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.yamaha.hv-script", reference: Some("[Tomohiro_Yamamoto]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.yamaha.hv-voice", reference: Some("[Tomohiro_Yamamoto]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.yamaha.openscoreformat", reference: Some("[Mark_Olleson]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.yamaha.openscoreformat.osfpvg+xml", reference: Some("[Mark_Olleson]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.yamaha.remote-setup", reference: Some("[Takehiro_Sukizaki]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.yamaha.smaf-audio", reference: Some("[Keiichi_Shinoda]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.yamaha.smaf-phrase", reference: Some("[Keiichi_Shinoda]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.yamaha.through-ngn", reference: Some("[Takehiro_Sukizaki]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.yamaha.tunnel-udpencap", reference: Some("[Takehiro_Sukizaki]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.yaoweme", reference: Some("[Jens_Jorgensen]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.yellowriver-custom-menu", reference: Some("[Mr._Yellow]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.youtube.yt", reference: Some("[Laura_Wood]"), rfc: None, status: Status::Obsolete, replacement: Some("video/vnd.youtube.yt"), required: &[], optional: &[] },
+    Entry { template: "application/vnd.zul", reference: Some("[Rene_Grothmann]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vnd.zzazz.deck+xml", reference: Some("[Micheal_Hewett]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/voicexml+xml", reference: Some("[RFC4267]"), rfc: Some(4267), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/voucher-cms+json", reference: Some("[RFC8366]"), rfc: Some(8366), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vp", reference: Some("[W3C][Ivan_Herman]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/vq-rtcpxr", reference: Some("[RFC6035]"), rfc: Some(6035), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/wasm", reference: Some("[W3C][Eric_Prudhommeaux]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/watcherinfo+xml", reference: Some("[RFC3858]"), rfc: Some(3858), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/webpush-options+json", reference: Some("[RFC8292]"), rfc: Some(8292), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/whoispp-query", reference: Some("[RFC2957]"), rfc: Some(2957), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/whoispp-response", reference: Some("[RFC2958]"), rfc: Some(2958), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/widget", reference: Some("[W3C][Steven_Pemberton][W3C-Widgets-2012]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/wita", reference: Some("[Larry_Campbell]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/wordperfect5.1", reference: Some("[Paul_Lindner]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/wsdl+xml", reference: Some("[W3C]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/wspolicy+xml", reference: Some("[W3C]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/x-pki-message", reference: Some("[RFC8894]"), rfc: Some(8894), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/x-www-form-urlencoded", reference: Some("[WHATWG][Anne_van_Kesteren]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/x-x509-ca-cert", reference: Some("[RFC8894]"), rfc: Some(8894), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/x-x509-ca-ra-cert", reference: Some("[RFC8894]"), rfc: Some(8894), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/x-x509-next-ca-cert", reference: Some("[RFC8894]"), rfc: Some(8894), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/x400-bp", reference: Some("[RFC1494]"), rfc: Some(1494), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/xacml+xml", reference: Some("[RFC7061]"), rfc: Some(7061), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/xcap-att+xml", reference: Some("[RFC4825]"), rfc: Some(4825), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/xcap-caps+xml", reference: Some("[RFC4825]"), rfc: Some(4825), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/xcap-diff+xml", reference: Some("[RFC5874]"), rfc: Some(5874), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/xcap-el+xml", reference: Some("[RFC4825]"), rfc: Some(4825), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/xcap-error+xml", reference: Some("[RFC4825]"), rfc: Some(4825), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/xcap-ns+xml", reference: Some("[RFC4825]"), rfc: Some(4825), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/xcon-conference-info+xml", reference: Some("[RFC6502]"), rfc: Some(6502), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/xcon-conference-info-diff+xml", reference: Some("[RFC6502]"), rfc: Some(6502), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/xenc+xml", reference: Some("[Joseph_Reagle][XENC_Working_Group]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/xfdf", reference: Some("[ISO-TC_171-SC_2][Betsy_Fanning]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/xhtml+xml", reference: Some("[W3C][Robin_Berjon]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/xliff+xml", reference: Some("[OASIS][Chet_Ensign]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/xml", reference: Some("[RFC7303]"), rfc: Some(7303), status: Status::Current, replacement: None, required: &[], optional: &["charset"] },
+    Entry { template: "application/xml-dtd", reference: Some("[RFC7303]"), rfc: Some(7303), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/xml-external-parsed-entity", reference: Some("[RFC7303]"), rfc: Some(7303), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/xml-patch+xml", reference: Some("[RFC7351]"), rfc: Some(7351), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/xmpp+xml", reference: Some("[RFC3923]"), rfc: Some(3923), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/xop+xml", reference: Some("[Mark_Nottingham]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/xslt+xml", reference: Some("[W3C][http://www.w3.org/TR/2007/REC-xslt20-20070123/#media-type-registration]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/xv+xml", reference: Some("[RFC4374]"), rfc: Some(4374), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/yaml", reference: Some("[YAML][RFC9512]"), rfc: Some(9512), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/yang", reference: Some("[RFC6020]"), rfc: Some(6020), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/yang-data+cbor", reference: Some("[RFC9254]"), rfc: Some(9254), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/yang-data+json", reference: Some("[RFC8040]"), rfc: Some(8040), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/yang-data+xml", reference: Some("[RFC8040]"), rfc: Some(8040), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/yang-patch+json", reference: Some("[RFC8072]"), rfc: Some(8072), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/yang-patch+xml", reference: Some("[RFC8072]"), rfc: Some(8072), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/yang-sid+json", reference: Some("[RFC9595]"), rfc: Some(9595), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/yin+xml", reference: Some("[RFC6020]"), rfc: Some(6020), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/zip", reference: Some("[Paul_Lindner]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/zlib", reference: Some("[RFC6713]"), rfc: Some(6713), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "application/zstd", reference: Some("[RFC8878]"), rfc: Some(8878), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/1d-interleaved-parityfec", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/32kadpcm", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/3gpp", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/3gpp2", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/aac", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/ac3", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/AMR", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/AMR-WB", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/amr-wb+", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/annodex", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/aptx", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/asc", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/ATRAC-ADVANCED-LOSSLESS", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/ATRAC-X", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/ATRAC3", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/basic", reference: Some("[RFC2045][RFC2046]"), rfc: Some(2045), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/BV16", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/BV32", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/clearmode", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/CN", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/csound", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/DAT12", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/dls", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/dsr-es201108", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/dsr-es202050", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/dsr-es202211", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/dsr-es202212", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/DV", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/DVI4", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/eac3", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/encaprtp", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/EVRC", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/EVRC-QCP", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/EVRC0", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/EVRC1", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/EVRCB", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/EVRCB0", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/EVRCB1", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/EVRCNW", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/EVRCNW0", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/EVRCNW1", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/EVRCWB", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/EVRCWB0", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/EVRCWB1", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/EVS", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/example", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/flac", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/flexfec", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/fwdred", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/G711-0", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/G719", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/G722", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/G7221", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/G723", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/G726-16", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/G726-24", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/G726-32", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/G726-40", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/G728", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/G729", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/G7291", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/G729D", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/G729E", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/GSM", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/GSM-EFR", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/GSM-HR-08", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/iLBC", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/ip-mr_v2.5", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/L16", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/L20", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/L24", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/L8", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/LPC", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/MELP", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/MELP1200", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/MELP2400", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/MELP600", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/mhas", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/mobile-xmf", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/mp4", reference: Some("[RFC4337][RFC6381]"), rfc: Some(4337), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/MP4A-LATM", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/MPA", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/mpa-robust", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/mpeg", reference: Some("[RFC3003]"), rfc: Some(3003), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/mpeg4-generic", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/mpegurl", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/ogg", reference: Some("[RFC5334][RFC7845]"), rfc: Some(5334), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/opus", reference: Some("[RFC7587]"), rfc: Some(7587), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/parityfec", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/PCMA", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/PCMA-WB", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/PCMU", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/PCMU-WB", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/prs.sid", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/QCELP", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/raptorfec", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/RED", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/rtp-enc-aescm128", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/rtp-midi", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/rtploopback", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/rtx", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/scip", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/SMV", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/SMV-QCP", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/SMV0", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/sofa", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/sp-midi", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/speex", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/t140c", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/t38", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/telephone-event", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/TETRA_ACELP", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/TETRA_ACELP_BB", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/tone", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/TSVCIS", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/UEMCLIP", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/ulpfec", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/usac", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/VDVI", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/VMR-WB", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/vnd.3gpp.iufp", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/vnd.4SB", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/vnd.audiokoz", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/vnd.CELP", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/vnd.cisco.nse", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/vnd.cmles.radio-events", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/vnd.cns.anp1", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/vnd.cns.inf1", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/vnd.dece.audio", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/vnd.digital-winds", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/vnd.dlna.adts", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/vnd.dolby.heaac.1", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/vnd.dolby.heaac.2", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/vnd.dolby.mlp", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/vnd.dolby.mps", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/vnd.dolby.pl2", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/vnd.dolby.pl2x", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/vnd.dolby.pl2z", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/vnd.dolby.pulse.1", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/vnd.dra", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/vnd.dts", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/vnd.dts.hd", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/vnd.dts.uhd", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/vnd.dvb.file", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/vnd.everad.plj", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/vnd.hns.audio", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/vnd.lucent.voice", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/vnd.ms-playready.media.pya", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/vnd.nokia.mobile-xmf", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/vnd.nortel.vbk", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/vnd.nuera.ecelp4800", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/vnd.nuera.ecelp7470", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/vnd.nuera.ecelp9600", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/vnd.octel.sbc", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/vnd.presonus.multitrack", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/vnd.rhetorex.32kadpcm", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/vnd.rip", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/vnd.sealedmedia.softseal.mpeg", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/vnd.vmx.cvsd", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/vorbis", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "audio/vorbis-config", reference: None, rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "font/collection", reference: Some("[RFC8081]"), rfc: Some(8081), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "font/otf", reference: Some("[RFC8081]"), rfc: Some(8081), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "font/sfnt", reference: Some("[RFC8081]"), rfc: Some(8081), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "font/ttf", reference: Some("[RFC8081]"), rfc: Some(8081), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "font/woff", reference: Some("[RFC8081]"), rfc: Some(8081), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "font/woff2", reference: Some("[RFC8081]"), rfc: Some(8081), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "haptics/hjif", reference: Some("[RFC-ietf-mediaman-haptics-05]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "haptics/hmpg", reference: Some("[RFC-ietf-mediaman-haptics-05]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "haptics/ivs", reference: Some("[RFC-ietf-mediaman-haptics-05]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "image/aces", reference: Some("[SMPTE][Howard_Lukk]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "image/apng", reference: Some("[W3C][W3C_PNG_Working_Group]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "image/avci", reference: Some("[ISO-IEC_JTC_1][David_Singer]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "image/avcs", reference: Some("[ISO-IEC_JTC_1][David_Singer]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "image/avif", reference: Some("[Alliance_for_Open_Media][Cyril_Concolato]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "image/bmp", reference: Some("[RFC7903]"), rfc: Some(7903), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "image/cgm", reference: Some("[Alan_Francis]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "image/dicom-rle", reference: Some("[DICOM_Standard_Committee][David_Clunie]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "image/dpx", reference: Some("[SMPTE][SMPTE_Director_of_Standards_Development]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "image/emf", reference: Some("[RFC7903]"), rfc: Some(7903), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "image/example", reference: Some("[RFC4735]"), rfc: Some(4735), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "image/fits", reference: Some("[RFC4047]"), rfc: Some(4047), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "image/g3fax", reference: Some("[RFC1494]"), rfc: Some(1494), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "image/gif", reference: Some("[RFC2045][RFC2046]"), rfc: Some(2045), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "image/heic", reference: Some("[ISO-IEC_JTC_1][David_Singer]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "image/heic-sequence", reference: Some("[ISO-IEC_JTC_1][David_Singer]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "image/heif", reference: Some("[ISO-IEC_JTC_1][David_Singer]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "image/heif-sequence", reference: Some("[ISO-IEC_JTC_1][David_Singer]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "image/hej2k", reference: Some("[ISO-IEC_JTC_1][ITU-T]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "image/hsj2", reference: Some("[ISO-IEC_JTC_1][ITU-T]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "image/ief", reference: Some("[RFC1314]"), rfc: Some(1314), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "image/j2c", reference: Some("[ISO-IEC_JTC_1_SC_29_WG_1][ISO-IEC_JTC_1][ITU-T]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "image/jls", reference: Some("[DICOM_Standard_Committee][David_Clunie]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "image/jp2", reference: Some("[RFC3745]"), rfc: Some(3745), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "image/jpeg", reference: Some("[RFC2045][RFC2046]"), rfc: Some(2045), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "image/jph", reference: Some("[ISO-IEC_JTC_1][ITU-T]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "image/jphc", reference: Some("[ISO-IEC_JTC_1][ITU-T]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "image/jpm", reference: Some("[RFC3745]"), rfc: Some(3745), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "image/jpx", reference: Some("[RFC3745][ISO-IEC_JTC_1_SC_29_WG_1]"), rfc: Some(3745), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "image/jxl", reference: Some("[ISO-IEC_JTC_1_SC_29_WG_1][ISO-IEC_JTC_1]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "image/jxr", reference: Some("[ISO-IEC_JTC_1][ITU-T]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "image/jxrA", reference: Some("[ISO-IEC_JTC_1][ITU-T]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "image/jxrS", reference: Some("[ISO-IEC_JTC_1][ITU-T]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "image/jxs", reference: Some("[ISO-IEC_JTC_1]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "image/jxsc", reference: Some("[ISO-IEC_JTC_1]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "image/jxsi", reference: Some("[ISO-IEC_JTC_1]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "image/jxss", reference: Some("[ISO-IEC_JTC_1]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "image/ktx", reference: Some("[Khronos][Mark_Callow]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "image/ktx2", reference: Some("[Khronos][Mark_Callow]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "image/naplps", reference: Some("[Ilya_Ferber]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "image/png", reference: Some("[W3C][PNG_Working_Group]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "image/prs.btif", reference: Some("[Ben_Simon]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "image/prs.pti", reference: Some("[Juern_Laun]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "image/pwg-raster", reference: Some("[Michael_Sweet]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "image/svg+xml", reference: Some("[W3C][http://www.w3.org/TR/SVG/mimereg.html]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "image/t38", reference: Some("[RFC3362]"), rfc: Some(3362), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "image/tiff", reference: Some("[RFC3302]"), rfc: Some(3302), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "image/tiff-fx", reference: Some("[RFC3950]"), rfc: Some(3950), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "image/vnd.adobe.photoshop", reference: Some("[Kim_Scarborough]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "image/vnd.airzip.accelerator.azv", reference: Some("[Gary_Clueit]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "image/vnd.cns.inf2", reference: Some("[Ann_McLaughlin]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "image/vnd.dece.graphic", reference: Some("[Michael_A_Dolan]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "image/vnd.djvu", reference: Some("[Leon_Bottou]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "image/vnd.dvb.subtitle", reference: Some("[Peter_Siebert][Michael_Lagally]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "image/vnd.dwg", reference: Some("[Jodi_Moline]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "image/vnd.dxf", reference: Some("[Jodi_Moline]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "image/vnd.fastbidsheet", reference: Some("[Scott_Becker]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "image/vnd.fpx", reference: Some("[Marc_Douglas_Spencer]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "image/vnd.fst", reference: Some("[Arild_Fuldseth]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "image/vnd.fujixerox.edmics-mmr", reference: Some("[Masanori_Onda]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "image/vnd.fujixerox.edmics-rlc", reference: Some("[Masanori_Onda]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "image/vnd.globalgraphics.pgb", reference: Some("[Martin_Bailey]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "image/vnd.microsoft.icon", reference: Some("[Simon_Butcher]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "image/vnd.mix", reference: Some("[Saveen_Reddy]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "image/vnd.mozilla.apng", reference: Some("[Stuart_Parmenter]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "image/vnd.ms-modi", reference: Some("[Gregory_Vaughan]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "image/vnd.net-fpx", reference: Some("[Marc_Douglas_Spencer]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "image/vnd.pco.b16", reference: Some("[PCO_AG][Jan_Zeman]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "image/vnd.radiance", reference: Some("[Randolph_Fritz][Greg_Ward]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "image/vnd.sealed.png", reference: Some("[David_Petersen]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "image/vnd.sealedmedia.softseal.gif", reference: Some("[David_Petersen]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "image/vnd.sealedmedia.softseal.jpg", reference: Some("[David_Petersen]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "image/vnd.svf", reference: Some("[Jodi_Moline]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "image/vnd.tencent.tap", reference: Some("[Ni_Hui]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "image/vnd.valve.source.texture", reference: Some("[Henrik_Andersson]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "image/vnd.wap.wbmp", reference: Some("[Peter_Stark]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "image/vnd.xiff", reference: Some("[Steven_Martin]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "image/vnd.zbrush.pcx", reference: Some("[Chris_Charabaruk]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "image/webp", reference: Some("[RFC-zern-webp-15]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "image/wmf", reference: Some("[RFC7903]"), rfc: Some(7903), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "image/x-emf", reference: Some("[RFC7903]"), rfc: Some(7903), status: Status::Deprecated, replacement: Some("image/emf"), required: &[], optional: &[] },
+    Entry { template: "image/x-wmf", reference: Some("[RFC7903]"), rfc: Some(7903), status: Status::Deprecated, replacement: Some("image/wmf"), required: &[], optional: &[] },
+    Entry { template: "message/bhttp", reference: Some("[RFC9292]"), rfc: Some(9292), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "message/CPIM", reference: Some("[RFC3862]"), rfc: Some(3862), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "message/delivery-status", reference: Some("[RFC1894]"), rfc: Some(1894), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "message/disposition-notification", reference: Some("[RFC8098]"), rfc: Some(8098), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "message/example", reference: Some("[RFC4735]"), rfc: Some(4735), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "message/external-body", reference: Some("[RFC2045][RFC2046]"), rfc: Some(2045), status: Status::Current, replacement: None, required: &["access-type"], optional: &[] },
+    Entry { template: "message/feedback-report", reference: Some("[RFC5965]"), rfc: Some(5965), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "message/global", reference: Some("[RFC6532]"), rfc: Some(6532), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "message/global-delivery-status", reference: Some("[RFC6533]"), rfc: Some(6533), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "message/global-disposition-notification", reference: Some("[RFC6533]"), rfc: Some(6533), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "message/global-headers", reference: Some("[RFC6533]"), rfc: Some(6533), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "message/http", reference: Some("[RFC9112]"), rfc: Some(9112), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "message/imdn+xml", reference: Some("[RFC5438]"), rfc: Some(5438), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "message/mls", reference: Some("[RFC9420]"), rfc: Some(9420), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "message/news", reference: Some("[RFC5537][Henry_Spencer]"), rfc: Some(5537), status: Status::Obsolete, replacement: None, required: &[], optional: &[] },
+    Entry { template: "message/ohttp-req", reference: Some("[RFC9458]"), rfc: Some(9458), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "message/ohttp-res", reference: Some("[RFC9458]"), rfc: Some(9458), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "message/partial", reference: Some("[RFC2045][RFC2046]"), rfc: Some(2045), status: Status::Current, replacement: None, required: &["id", "number"], optional: &["total"] },
+    Entry { template: "message/rfc822", reference: Some("[RFC2045][RFC2046]"), rfc: Some(2045), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "message/s-http", reference: Some("[RFC2660][Status change of HTTP experiments to Historic]"), rfc: Some(2660), status: Status::Obsolete, replacement: None, required: &[], optional: &[] },
+    Entry { template: "message/sip", reference: Some("[RFC3261]"), rfc: Some(3261), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "message/sipfrag", reference: Some("[RFC3420]"), rfc: Some(3420), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "message/tracking-status", reference: Some("[RFC3886]"), rfc: Some(3886), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "message/vnd.si.simp", reference: Some("[Nicholas_Parks_Young]"), rfc: None, status: Status::Obsolete, replacement: None, required: &[], optional: &[] },
+    Entry { template: "message/vnd.wfa.wsc", reference: Some("[Mick_Conley]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "model/3mf", reference: Some("[http://www.3mf.io/specification][_3MF][Michael_Sweet]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "model/e57", reference: Some("[ASTM]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "model/example", reference: Some("[RFC4735]"), rfc: Some(4735), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "model/gltf+json", reference: Some("[Khronos][Uli_Klumpp]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "model/gltf-binary", reference: Some("[Khronos][Saurabh_Bhatia]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "model/iges", reference: Some("[Curtis_Parks]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "model/JT", reference: Some("[ISO-TC_184-SC_4][Michael_Zink]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "model/mesh", reference: Some("[RFC2077]"), rfc: Some(2077), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "model/mtl", reference: Some("[DICOM_Standard_Committee][DICOM_WG_17][Carolyn_Hull]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "model/obj", reference: Some("[DICOM_Standard_Committee][DICOM_WG_17][Carolyn_Hull]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "model/prc", reference: Some("[ISO-TC_171-SC_2][Betsy_Fanning]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "model/step", reference: Some("[ISO-TC_184-SC_4][Dana_Tripp]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "model/step+xml", reference: Some("[ISO-TC_184-SC_4][Dana_Tripp]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "model/step+zip", reference: Some("[ISO-TC_184-SC_4][Dana_Tripp]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "model/step-xml+zip", reference: Some("[ISO-TC_184-SC_4][Dana_Tripp]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "model/stl", reference: Some("[DICOM_Standard_Committee][DICOM_WG_17][Carolyn_Hull]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "model/u3d", reference: Some("[PDF_Association][Peter_Wyatt]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "model/vnd.bary", reference: Some("[Displaced_Micro-Mesh_SDK_Support]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "model/vnd.cld", reference: Some("[Robert_Monaghan]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "model/vnd.collada+xml", reference: Some("[James_Riordon]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "model/vnd.dwf", reference: Some("[Jason_Pratt]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "model/vnd.flatland.3dml", reference: Some("[Michael_Powers]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "model/vnd.gdl", reference: Some("[Attila_Babits]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "model/vnd.gs-gdl", reference: Some("[Attila_Babits]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "model/vnd.gtw", reference: Some("[Yutaka_Ozaki]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "model/vnd.moml+xml", reference: Some("[Christopher_Brooks]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "model/vnd.mts", reference: Some("[Boris_Rabinovitch]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "model/vnd.opengex", reference: Some("[Eric_Lengyel]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "model/vnd.parasolid.transmit.binary", reference: Some("[Parasolid]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "model/vnd.parasolid.transmit.text", reference: Some("[Parasolid]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "model/vnd.pytha.pyox", reference: Some("[Daniel_Flassig]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "model/vnd.rosette.annotated-data-model", reference: Some("[Benson_Margulies]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "model/vnd.sap.vds", reference: Some("[SAP_SE][Igor_Afanasyev]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "model/vnd.usda", reference: Some("[Sebastian_Grassia]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "model/vnd.usdz+zip", reference: Some("[Sebastian_Grassia]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "model/vnd.valve.source.compiled-map", reference: Some("[Henrik_Andersson]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "model/vnd.vtu", reference: Some("[Boris_Rabinovitch]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "model/vrml", reference: Some("[RFC2077]"), rfc: Some(2077), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "model/x3d+fastinfoset", reference: Some("[Web3D_X3D]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "model/x3d+xml", reference: Some("[Web3D][Web3D_X3D]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "model/x3d-vrml", reference: Some("[Web3D][Web3D_X3D]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "multipart/alternative", reference: Some("[RFC2046][RFC2045]"), rfc: Some(2046), status: Status::Current, replacement: None, required: &["boundary"], optional: &[] },
+    Entry { template: "multipart/appledouble", reference: Some("[Patrik_Faltstrom]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "multipart/byteranges", reference: Some("[RFC9110]"), rfc: Some(9110), status: Status::Current, replacement: None, required: &["boundary"], optional: &[] },
+    Entry { template: "multipart/digest", reference: Some("[RFC2046][RFC2045]"), rfc: Some(2046), status: Status::Current, replacement: None, required: &["boundary"], optional: &[] },
+    Entry { template: "multipart/encrypted", reference: Some("[RFC1847]"), rfc: Some(1847), status: Status::Current, replacement: None, required: &["boundary", "protocol"], optional: &[] },
+    Entry { template: "multipart/example", reference: Some("[RFC4735]"), rfc: Some(4735), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "multipart/form-data", reference: Some("[RFC7578]"), rfc: Some(7578), status: Status::Current, replacement: None, required: &["boundary"], optional: &[] },
+    Entry { template: "multipart/header-set", reference: Some("[Dave_Crocker]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "multipart/mixed", reference: Some("[RFC2046][RFC2045]"), rfc: Some(2046), status: Status::Current, replacement: None, required: &["boundary"], optional: &[] },
+    Entry { template: "multipart/multilingual", reference: Some("[RFC8255]"), rfc: Some(8255), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "multipart/parallel", reference: Some("[RFC2046][RFC2045]"), rfc: Some(2046), status: Status::Current, replacement: None, required: &["boundary"], optional: &[] },
+    Entry { template: "multipart/related", reference: Some("[RFC2387]"), rfc: Some(2387), status: Status::Current, replacement: None, required: &["boundary", "type"], optional: &["start", "start-info"] },
+    Entry { template: "multipart/report", reference: Some("[RFC6522]"), rfc: Some(6522), status: Status::Current, replacement: None, required: &["boundary", "report-type"], optional: &[] },
+    Entry { template: "multipart/signed", reference: Some("[RFC1847]"), rfc: Some(1847), status: Status::Current, replacement: None, required: &["boundary", "protocol", "micalg"], optional: &[] },
+    Entry { template: "multipart/vnd.bint.med-plus", reference: Some("[Heinz-Peter_Schütz]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "multipart/voice-message", reference: Some("[RFC3801]"), rfc: Some(3801), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "multipart/x-mixed-replace", reference: Some("[W3C][Robin_Berjon]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "text/1d-interleaved-parityfec", reference: Some("[RFC6015]"), rfc: Some(6015), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "text/cache-manifest", reference: Some("[W3C][Robin_Berjon]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "text/calendar", reference: Some("[RFC5545]"), rfc: Some(5545), status: Status::Current, replacement: None, required: &[], optional: &["charset", "method", "component"] },
+    Entry { template: "text/cql", reference: Some("[HL7][Bryn_Rhodes]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "text/cql-expression", reference: Some("[HL7][Bryn_Rhodes]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "text/cql-identifier", reference: Some("[HL7][Bryn_Rhodes]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "text/css", reference: Some("[RFC2318]"), rfc: Some(2318), status: Status::Current, replacement: None, required: &[], optional: &["charset"] },
+    Entry { template: "text/csv", reference: Some("[RFC4180][RFC7111]"), rfc: Some(4180), status: Status::Current, replacement: None, required: &[], optional: &["charset", "header"] },
+    Entry { template: "text/csv-schema", reference: Some("[National_Archives_UK][David_Underdown]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "text/directory", reference: Some("[RFC2425][RFC6350]"), rfc: Some(2425), status: Status::Deprecated, replacement: None, required: &[], optional: &[] },
+    Entry { template: "text/dns", reference: Some("[RFC4027]"), rfc: Some(4027), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "text/ecmascript", reference: Some("[RFC9239]"), rfc: Some(9239), status: Status::Obsolete, replacement: Some("text/javascript"), required: &[], optional: &[] },
+    Entry { template: "text/encaprtp", reference: Some("[RFC6849]"), rfc: Some(6849), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "text/enriched", reference: Some("[RFC1896]"), rfc: Some(1896), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "text/example", reference: Some("[RFC4735]"), rfc: Some(4735), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "text/fhirpath", reference: Some("[HL7][Bryn_Rhodes]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "text/flexfec", reference: Some("[RFC8627]"), rfc: Some(8627), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "text/fwdred", reference: Some("[RFC6354]"), rfc: Some(6354), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "text/gff3", reference: Some("[Sequence_Ontology]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "text/grammar-ref-list", reference: Some("[RFC6787]"), rfc: Some(6787), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "text/hl7v2", reference: Some("[HL7][Marc_Duteau]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "text/html", reference: Some("[W3C][Robin_Berjon]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &["charset"] },
+    Entry { template: "text/javascript", reference: Some("[RFC9239]"), rfc: Some(9239), status: Status::Current, replacement: None, required: &[], optional: &["charset"] },
+    Entry { template: "text/jcr-cnd", reference: Some("[Peeter_Piegaze]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "text/markdown", reference: Some("[RFC7763]"), rfc: Some(7763), status: Status::Current, replacement: None, required: &["charset"], optional: &["variant"] },
+    Entry { template: "text/mizar", reference: Some("[Jesse_Alama]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "text/n3", reference: Some("[W3C][Eric_Prudhommeaux]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "text/parameters", reference: Some("[RFC7826]"), rfc: Some(7826), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "text/parityfec", reference: Some("[RFC3009]"), rfc: Some(3009), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "text/plain", reference: Some("[RFC2046][RFC3676][RFC5147]"), rfc: Some(2046), status: Status::Current, replacement: None, required: &[], optional: &["charset", "format", "delsp"] },
+    Entry { template: "text/provenance-notation", reference: Some("[W3C][Ivan_Herman]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "text/prs.fallenstein.rst", reference: Some("[Benja_Fallenstein]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "text/prs.lines.tag", reference: Some("[John_Lines]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "text/prs.prop.logic", reference: Some("[Hans-Dieter_A._Hiep]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "text/prs.texi", reference: Some("[Matin_Bavardi]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "text/raptorfec", reference: Some("[RFC6682]"), rfc: Some(6682), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "text/RED", reference: Some("[RFC4102]"), rfc: Some(4102), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "text/rfc822-headers", reference: Some("[RFC6522]"), rfc: Some(6522), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "text/richtext", reference: Some("[RFC2045][RFC2046]"), rfc: Some(2045), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "text/rtf", reference: Some("[Paul_Lindner]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "text/rtp-enc-aescm128", reference: Some("[_3GPP]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "text/rtploopback", reference: Some("[RFC6849]"), rfc: Some(6849), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "text/rtx", reference: Some("[RFC4588]"), rfc: Some(4588), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "text/SGML", reference: Some("[RFC1874]"), rfc: Some(1874), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "text/shaclc", reference: Some("[W3C_SHACL_Community_Group][Vladimir_Alexiev]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "text/shex", reference: Some("[W3C][Eric_Prudhommeaux]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "text/spdx", reference: Some("[Linux_Foundation][Rose_Judge]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "text/strings", reference: Some("[IEEE-ISTO-PWG-PPP]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "text/t140", reference: Some("[RFC4103]"), rfc: Some(4103), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "text/tab-separated-values", reference: Some("[Paul_Lindner]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "text/troff", reference: Some("[RFC4263]"), rfc: Some(4263), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "text/turtle", reference: Some("[W3C][Eric_Prudhommeaux]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "text/ulpfec", reference: Some("[RFC5109]"), rfc: Some(5109), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "text/uri-list", reference: Some("[RFC2483]"), rfc: Some(2483), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "text/vcard", reference: Some("[RFC6350]"), rfc: Some(6350), status: Status::Current, replacement: None, required: &[], optional: &["version"] },
+    Entry { template: "text/vnd.a", reference: Some("[Regis_Dehoux]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "text/vnd.abc", reference: Some("[Steve_Allen]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "text/vnd.ascii-art", reference: Some("[Kim_Scarborough]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "text/vnd.curl", reference: Some("[Robert_Byrnes]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "text/vnd.debian.copyright", reference: Some("[Charles_Plessy]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "text/vnd.DMClientScript", reference: Some("[Dan_Bradley]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "text/vnd.dvb.subtitle", reference: Some("[Peter_Siebert][Michael_Lagally]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "text/vnd.esmertec.theme-descriptor", reference: Some("[Stefan_Eilemann]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "text/vnd.exchangeable", reference: Some("[Martin_Cizek]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "text/vnd.familysearch.gedcom", reference: Some("[Gordon_Clarke]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "text/vnd.ficlab.flt", reference: Some("[Steve_Gilberd]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "text/vnd.fly", reference: Some("[John-Mark_Gurney]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "text/vnd.fmi.flexstor", reference: Some("[Kari_E._Hurtta]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "text/vnd.gml", reference: Some("[Mi_Tar]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "text/vnd.graphviz", reference: Some("[John_Ellson]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "text/vnd.hans", reference: Some("[Hill_Hanxv]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "text/vnd.hgl", reference: Some("[Heungsub_Lee]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "text/vnd.in3d.3dml", reference: Some("[Michael_Powers]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "text/vnd.in3d.spot", reference: Some("[Michael_Powers]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "text/vnd.IPTC.NewsML", reference: Some("[IPTC]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "text/vnd.IPTC.NITF", reference: Some("[IPTC]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "text/vnd.latex-z", reference: Some("[Mikusiak_Lubos]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "text/vnd.motorola.reflex", reference: Some("[Mark_Patton]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "text/vnd.ms-mediapackage", reference: Some("[Jan_Nelson]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "text/vnd.net2phone.commcenter.command", reference: Some("[Feiyu_Xie]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "text/vnd.radisys.msml-basic-layout", reference: Some("[RFC5707]"), rfc: Some(5707), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "text/vnd.senx.warpscript", reference: Some("[Pierre_Papin]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "text/vnd.si.uricatalogue", reference: Some("[Nicholas_Parks_Young]"), rfc: None, status: Status::Obsolete, replacement: None, required: &[], optional: &[] },
+    Entry { template: "text/vnd.sosi", reference: Some("[Petter_Reinholdtsen]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "text/vnd.sun.j2me.app-descriptor", reference: Some("[Gary_Adams]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "text/vnd.trolltech.linguist", reference: Some("[David_Lee_Lambert]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "text/vnd.vcf", reference: Some("[Anand_Jahagirdar]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "text/vnd.wap.si", reference: Some("[WAP-Forum]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "text/vnd.wap.sl", reference: Some("[WAP-Forum]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "text/vnd.wap.wml", reference: Some("[Peter_Stark]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "text/vnd.wap.wmlscript", reference: Some("[Peter_Stark]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "text/vnd.zoo.kcl", reference: Some("[Jessie_Frazelle]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "text/vtt", reference: Some("[W3C][Silvia_Pfeiffer]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "text/wgsl", reference: Some("[W3C][David_Neto]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "text/xml", reference: Some("[RFC7303]"), rfc: Some(7303), status: Status::Current, replacement: None, required: &[], optional: &["charset"] },
+    Entry { template: "text/xml-external-parsed-entity", reference: Some("[RFC7303]"), rfc: Some(7303), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "video/1d-interleaved-parityfec", reference: Some("[RFC6015]"), rfc: Some(6015), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "video/3gpp", reference: Some("[RFC3839][RFC6381]"), rfc: Some(3839), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "video/3gpp-tt", reference: Some("[RFC4396]"), rfc: Some(4396), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "video/3gpp2", reference: Some("[RFC4393][RFC6381]"), rfc: Some(4393), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "video/AV1", reference: Some("[Alliance_for_Open_Media]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "video/BMPEG", reference: Some("[RFC3555]"), rfc: Some(3555), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "video/BT656", reference: Some("[RFC3555]"), rfc: Some(3555), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "video/CelB", reference: Some("[RFC3555]"), rfc: Some(3555), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "video/DV", reference: Some("[RFC6469]"), rfc: Some(6469), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "video/encaprtp", reference: Some("[RFC6849]"), rfc: Some(6849), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "video/evc", reference: Some("[RFC9584]"), rfc: Some(9584), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "video/example", reference: Some("[RFC4735]"), rfc: Some(4735), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "video/FFV1", reference: Some("[RFC9043]"), rfc: Some(9043), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "video/flexfec", reference: Some("[RFC8627]"), rfc: Some(8627), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "video/H261", reference: Some("[RFC4587]"), rfc: Some(4587), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "video/H263", reference: Some("[RFC3555]"), rfc: Some(3555), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "video/H263-1998", reference: Some("[RFC4629]"), rfc: Some(4629), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "video/H263-2000", reference: Some("[RFC4629]"), rfc: Some(4629), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "video/H264", reference: Some("[RFC6184]"), rfc: Some(6184), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "video/H264-RCDO", reference: Some("[RFC6185]"), rfc: Some(6185), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "video/H264-SVC", reference: Some("[RFC6190]"), rfc: Some(6190), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "video/H265", reference: Some("[RFC7798]"), rfc: Some(7798), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "video/H266", reference: Some("[RFC9328]"), rfc: Some(9328), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "video/iso.segment", reference: Some("[David_Singer][ISO-IEC_JTC_1]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "video/JPEG", reference: Some("[RFC3555]"), rfc: Some(3555), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "video/jpeg2000", reference: Some("[RFC5371][RFC5372]"), rfc: Some(5371), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "video/jxsv", reference: Some("[RFC9134]"), rfc: Some(9134), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "video/matroska", reference: Some("[RFC-ietf-cellar-matroska-21]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "video/matroska-3d", reference: Some("[RFC-ietf-cellar-matroska-21]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "video/mj2", reference: Some("[RFC3745]"), rfc: Some(3745), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "video/MP1S", reference: Some("[RFC3555]"), rfc: Some(3555), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "video/MP2P", reference: Some("[RFC3555]"), rfc: Some(3555), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "video/MP2T", reference: Some("[RFC3555]"), rfc: Some(3555), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "video/mp4", reference: Some("[RFC4337][RFC6381]"), rfc: Some(4337), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "video/MP4V-ES", reference: Some("[RFC6416]"), rfc: Some(6416), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "video/mpeg", reference: Some("[RFC2045][RFC2046]"), rfc: Some(2045), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "video/mpeg4-generic", reference: Some("[RFC3640]"), rfc: Some(3640), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "video/MPV", reference: Some("[RFC3555]"), rfc: Some(3555), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "video/nv", reference: Some("[RFC4856]"), rfc: Some(4856), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "video/ogg", reference: Some("[RFC5334][RFC7845]"), rfc: Some(5334), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "video/parityfec", reference: Some("[RFC3009]"), rfc: Some(3009), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "video/pointer", reference: Some("[RFC2862]"), rfc: Some(2862), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "video/quicktime", reference: Some("[RFC6381][Paul_Lindner]"), rfc: Some(6381), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "video/raptorfec", reference: Some("[RFC6682]"), rfc: Some(6682), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "video/raw", reference: Some("[RFC4175]"), rfc: Some(4175), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "video/rtp-enc-aescm128", reference: Some("[_3GPP]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "video/rtploopback", reference: Some("[RFC6849]"), rfc: Some(6849), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "video/rtx", reference: Some("[RFC4588]"), rfc: Some(4588), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "video/scip", reference: Some("[RFC9607]"), rfc: Some(9607), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "video/smpte291", reference: Some("[RFC8331]"), rfc: Some(8331), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "video/SMPTE292M", reference: Some("[RFC3497]"), rfc: Some(3497), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "video/ulpfec", reference: Some("[RFC5109]"), rfc: Some(5109), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "video/vc1", reference: Some("[RFC4425]"), rfc: Some(4425), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "video/vc2", reference: Some("[RFC8450]"), rfc: Some(8450), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "video/vnd.CCTV", reference: Some("[Frank_Rottmann]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "video/vnd.dece.hd", reference: Some("[Michael_A_Dolan]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "video/vnd.dece.mobile", reference: Some("[Michael_A_Dolan]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "video/vnd.dece.mp4", reference: Some("[Michael_A_Dolan]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "video/vnd.dece.pd", reference: Some("[Michael_A_Dolan]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "video/vnd.dece.sd", reference: Some("[Michael_A_Dolan]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "video/vnd.dece.video", reference: Some("[Michael_A_Dolan]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "video/vnd.directv.mpeg", reference: Some("[Nathan_Zerbe]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "video/vnd.directv.mpeg-tts", reference: Some("[Nathan_Zerbe]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "video/vnd.dlna.mpeg-tts", reference: Some("[Edwin_Heredia]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "video/vnd.dvb.file", reference: Some("[Peter_Siebert][Kevin_Murray]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "video/vnd.fvt", reference: Some("[Arild_Fuldseth]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "video/vnd.hns.video", reference: Some("[Swaminathan]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "video/vnd.iptvforum.1dparityfec-1010", reference: Some("[Shuji_Nakamura]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "video/vnd.iptvforum.1dparityfec-2005", reference: Some("[Shuji_Nakamura]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "video/vnd.iptvforum.2dparityfec-1010", reference: Some("[Shuji_Nakamura]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "video/vnd.iptvforum.2dparityfec-2005", reference: Some("[Shuji_Nakamura]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "video/vnd.iptvforum.ttsavc", reference: Some("[Shuji_Nakamura]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "video/vnd.iptvforum.ttsmpeg2", reference: Some("[Shuji_Nakamura]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "video/vnd.motorola.video", reference: Some("[Tom_McGinty]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "video/vnd.motorola.videop", reference: Some("[Tom_McGinty]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "video/vnd.mpegurl", reference: Some("[Heiko_Recktenwald]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "video/vnd.ms-playready.media.pyv", reference: Some("[Steve_DiAcetis]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "video/vnd.nokia.interleaved-multimedia", reference: Some("[Petteri_Kangaslampi]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "video/vnd.nokia.mp4vr", reference: Some("[Miska_M._Hannuksela]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "video/vnd.nokia.videovoip", reference: Some("[Nokia]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "video/vnd.objectvideo", reference: Some("[John_Clark]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "video/vnd.radgamettools.bink", reference: Some("[Henrik_Andersson]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "video/vnd.radgamettools.smacker", reference: Some("[Henrik_Andersson]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "video/vnd.sealed.mpeg1", reference: Some("[David_Petersen]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "video/vnd.sealed.mpeg4", reference: Some("[David_Petersen]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "video/vnd.sealed.swf", reference: Some("[David_Petersen]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "video/vnd.sealedmedia.softseal.mov", reference: Some("[David_Petersen]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "video/vnd.uvvu.mp4", reference: Some("[Michael_A_Dolan]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "video/vnd.vivo", reference: Some("[John_Wolfe]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "video/vnd.youtube.yt", reference: Some("[Google]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "video/VP8", reference: Some("[RFC7741]"), rfc: Some(7741), status: Status::Current, replacement: None, required: &[], optional: &[] },
+    Entry { template: "video/VP9", reference: Some("[RFC-ietf-payload-vp9-16]"), rfc: None, status: Status::Current, replacement: None, required: &[], optional: &[] },
+];
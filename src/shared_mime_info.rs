@@ -0,0 +1,698 @@
+//! Load a freedesktop.org [shared-mime-info] database.
+//!
+//! Linux desktops keep their media type data in `/usr/share/mime`, as XML
+//! package files in `packages/`, compiled into a single `mime.cache`. A
+//! [`Database`] can be loaded from either, and then answers which types a
+//! file name or its content has, what a type is an alias of, and which
+//! types it is a subclass of.
+//!
+//! This requires the `shared-mime-info` feature enabled on the mime crate.
+//!
+//! [shared-mime-info]: https://specifications.freedesktop.org/shared-mime-info-spec/latest/
+//!
+//! # Example
+//!
+//! ```no_run
+//! use mime::shared_mime_info::Database;
+//!
+//! let db = Database::load("/usr/share/mime").unwrap();
+//!
+//! let c = db.glob("main.c");
+//! assert_eq!(c[0], "text/x-csrc");
+//! assert!(db.is_subclass_of(&c[0], &mime::TEXT_PLAIN));
+//! ```
+
+use std::collections::{HashMap, HashSet};
+use std::error::Error as StdError;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use crate::{InvalidMime, MediaType, APPLICATION_OCTET_STREAM, TEXT, TEXT_PLAIN};
+
+mod cache;
+mod xml;
+
+/// A loaded shared-mime-info database.
+#[derive(Clone, Debug, Default)]
+pub struct Database {
+    globs: Vec<Glob>,
+    // alias essence -> canonical type
+    aliases: HashMap<String, MediaType>,
+    // essence -> direct parents
+    parents: HashMap<String, Vec<MediaType>>,
+    // sorted by priority, highest first
+    magic: Vec<Magic>,
+}
+
+#[derive(Clone, Debug)]
+struct Glob {
+    // lowercased, unless case-sensitive
+    pattern: String,
+    media_type: MediaType,
+    weight: u32,
+    case_sensitive: bool,
+}
+
+#[derive(Clone, Debug)]
+struct Magic {
+    priority: u32,
+    media_type: MediaType,
+    matchlets: Vec<Matchlet>,
+}
+
+#[derive(Clone, Debug)]
+struct Matchlet {
+    start: usize,
+    // how many offsets after `start` the value may be found at, at least 1
+    range: usize,
+    // in the byte order of the data, with host types already swapped
+    value: Vec<u8>,
+    mask: Option<Vec<u8>>,
+    children: Vec<Matchlet>,
+}
+
+/// An error loading a shared-mime-info [`Database`].
+#[derive(Debug)]
+pub struct Error {
+    kind: ErrorKind,
+}
+
+#[derive(Debug)]
+enum ErrorKind {
+    Io(io::Error),
+    Xml(String),
+    Cache(&'static str),
+    InvalidMime(InvalidMime),
+}
+
+impl Database {
+    /// Load the database in a directory, such as `/usr/share/mime`.
+    ///
+    /// The compiled `mime.cache` is used if there is one, otherwise the
+    /// XML files in `packages/` are.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # let dir = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/shared-mime-info");
+    /// let db = mime::shared_mime_info::Database::load(dir).unwrap();
+    /// assert_eq!(db.glob("photo.png"), [mime::IMAGE_PNG]);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the files can't be read, or aren't valid.
+    pub fn load(dir: impl AsRef<Path>) -> Result<Database, Error> {
+        let dir = dir.as_ref();
+        let cache = dir.join("mime.cache");
+        if cache.is_file() {
+            Database::from_cache(cache)
+        } else {
+            Database::from_packages(dir)
+        }
+    }
+
+    /// Load the XML files in the `packages/` subdirectory of a directory.
+    ///
+    /// Files are read in order of their names, so a later file can
+    /// replace the globs and magic of an earlier one.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the files can't be read, or aren't valid.
+    pub fn from_packages(dir: impl AsRef<Path>) -> Result<Database, Error> {
+        let mut paths = fs::read_dir(dir.as_ref().join("packages"))?
+            .map(|entry| entry.map(|e| e.path()))
+            .collect::<Result<Vec<_>, _>>()?;
+        paths.retain(|path| path.extension() == Some("xml".as_ref()));
+        paths.sort();
+
+        let mut db = Database::default();
+        for path in paths {
+            xml::load(&mut db, &fs::read_to_string(path)?)?;
+        }
+        db.finish();
+        Ok(db)
+    }
+
+    /// Load a single shared-mime-info XML document.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let db = mime::shared_mime_info::Database::from_xml(r#"
+    ///     <mime-info xmlns="http://www.freedesktop.org/standards/shared-mime-info">
+    ///       <mime-type type="text/x-rust">
+    ///         <sub-class-of type="text/plain"/>
+    ///         <glob pattern="*.rs"/>
+    ///       </mime-type>
+    ///     </mime-info>
+    /// "#).unwrap();
+    ///
+    /// assert_eq!(db.glob("lib.rs")[0], "text/x-rust");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the document isn't valid.
+    pub fn from_xml(src: &str) -> Result<Database, Error> {
+        let mut db = Database::default();
+        xml::load(&mut db, src)?;
+        db.finish();
+        Ok(db)
+    }
+
+    /// Load a compiled `mime.cache` file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file can't be read, or isn't valid.
+    pub fn from_cache(path: impl AsRef<Path>) -> Result<Database, Error> {
+        let bytes = fs::read(path)?;
+        let mut db = Database::default();
+        cache::load(&mut db, &bytes)?;
+        db.finish();
+        Ok(db)
+    }
+
+    /// Get the types of a file name, from the globs of the database.
+    ///
+    /// The best match is first. Matches are ranked by the weight of their
+    /// glob, then by how long the glob is, so `*.tar.gz` beats `*.gz`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # let dir = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/shared-mime-info");
+    /// let db = mime::shared_mime_info::Database::load(dir).unwrap();
+    ///
+    /// assert_eq!(db.glob("backup.tar.gz"), ["application/x-compressed-tar", "application/gzip"]);
+    /// assert_eq!(db.glob("Makefile"), ["text/x-makefile"]);
+    /// assert!(db.glob("unknown").is_empty());
+    /// ```
+    pub fn glob(&self, file_name: &str) -> Vec<MediaType> {
+        let lower = file_name.to_lowercase();
+        let mut matches = self
+            .globs
+            .iter()
+            .filter(|glob| {
+                let name = if glob.case_sensitive { file_name } else { &lower };
+                fnmatch(&glob.pattern, name)
+            })
+            .collect::<Vec<_>>();
+        matches.sort_by(|a, b| {
+            b.weight
+                .cmp(&a.weight)
+                .then(b.pattern.len().cmp(&a.pattern.len()))
+                .then(b.case_sensitive.cmp(&a.case_sensitive))
+        });
+
+        let mut types = Vec::<MediaType>::new();
+        for glob in matches {
+            if !types.contains(&glob.media_type) {
+                types.push(glob.media_type.clone());
+            }
+        }
+        types
+    }
+
+    /// Get the type of some content, from the magic of the database.
+    ///
+    /// Returns the match with the highest priority, or `None` if nothing
+    /// matched.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # let dir = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/shared-mime-info");
+    /// let db = mime::shared_mime_info::Database::load(dir).unwrap();
+    ///
+    /// assert_eq!(db.magic(b"%PDF-1.7\n"), Some(mime::APPLICATION_PDF));
+    /// assert_eq!(db.magic(b"hello"), None);
+    /// ```
+    pub fn magic(&self, data: &[u8]) -> Option<MediaType> {
+        self.magic
+            .iter()
+            .find(|magic| magic.matchlets.iter().any(|m| m.matches(data)))
+            .map(|magic| magic.media_type.clone())
+    }
+
+    /// Get the canonical type of an alias, or the type itself if it isn't
+    /// one.
+    ///
+    /// Parameters are dropped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # let dir = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/shared-mime-info");
+    /// let db = mime::shared_mime_info::Database::load(dir).unwrap();
+    ///
+    /// let gzip = mime::MediaType::parse("application/x-gzip").unwrap();
+    /// assert_eq!(db.resolve_alias(&gzip), "application/gzip");
+    /// assert_eq!(db.resolve_alias(&mime::IMAGE_PNG), mime::IMAGE_PNG);
+    /// ```
+    pub fn resolve_alias(&self, mt: &MediaType) -> MediaType {
+        match self.aliases.get(mt.mime.essence()) {
+            Some(canonical) => canonical.clone(),
+            None => mt.clone().without_params(),
+        }
+    }
+
+    /// Get the types a type is directly a subclass of.
+    ///
+    /// Besides the ones in the database, every `text/*` type is a subclass
+    /// of `text/plain`, and every type other than `inode/*` is a subclass
+    /// of `application/octet-stream`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # let dir = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/shared-mime-info");
+    /// let db = mime::shared_mime_info::Database::load(dir).unwrap();
+    ///
+    /// let svg = db.parents(&mime::IMAGE_SVG);
+    /// assert_eq!(svg, ["application/xml", "application/octet-stream"]);
+    /// ```
+    pub fn parents(&self, mt: &MediaType) -> Vec<MediaType> {
+        let mt = self.resolve_alias(mt);
+        let mut parents = self
+            .parents
+            .get(mt.mime.essence())
+            .cloned()
+            .unwrap_or_default();
+
+        if mt.type_() == TEXT && mt != TEXT_PLAIN && !parents.contains(&TEXT_PLAIN) {
+            parents.push(TEXT_PLAIN);
+        }
+        if mt.type_() != "inode" && mt != APPLICATION_OCTET_STREAM && !parents.contains(&APPLICATION_OCTET_STREAM) {
+            parents.push(APPLICATION_OCTET_STREAM);
+        }
+        parents
+    }
+
+    /// Returns true if a type is the same as, or a subclass of, another.
+    ///
+    /// Aliases of either type are resolved first, and subclasses are
+    /// followed all the way up, so a C header is a subclass of C source,
+    /// which is a subclass of plain text.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # let dir = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/shared-mime-info");
+    /// let db = mime::shared_mime_info::Database::load(dir).unwrap();
+    ///
+    /// let csrc = mime::MediaType::parse("text/x-csrc").unwrap();
+    /// assert!(db.is_subclass_of(&csrc, &mime::TEXT_PLAIN));
+    /// assert!(!db.is_subclass_of(&mime::TEXT_PLAIN, &csrc));
+    /// ```
+    pub fn is_subclass_of(&self, mt: &MediaType, parent: &MediaType) -> bool {
+        let parent = self.resolve_alias(parent);
+        let mut seen = HashSet::new();
+        let mut stack = vec![self.resolve_alias(mt)];
+        while let Some(mt) = stack.pop() {
+            if mt == parent {
+                return true;
+            }
+            if seen.insert(mt.mime.essence().to_owned()) {
+                stack.extend(self.parents(&mt));
+            }
+        }
+        false
+    }
+
+    fn add_alias(&mut self, alias: &str, canonical: &str) -> Result<(), Error> {
+        let alias = MediaType::parse(alias)?;
+        let canonical = MediaType::parse(canonical)?;
+        self.aliases.insert(alias.mime.essence().to_owned(), canonical);
+        Ok(())
+    }
+
+    fn add_parent(&mut self, mt: &str, parent: &str) -> Result<(), Error> {
+        let mt = MediaType::parse(mt)?;
+        let parent = MediaType::parse(parent)?;
+        let parents = self.parents.entry(mt.mime.essence().to_owned()).or_default();
+        if !parents.contains(&parent) {
+            parents.push(parent);
+        }
+        Ok(())
+    }
+
+    fn add_glob(&mut self, pattern: &str, mt: &str, weight: u32, case_sensitive: bool) -> Result<(), Error> {
+        self.globs.push(Glob {
+            pattern: if case_sensitive { pattern.to_owned() } else { pattern.to_lowercase() },
+            media_type: MediaType::parse(mt)?,
+            weight,
+            case_sensitive,
+        });
+        Ok(())
+    }
+
+    fn finish(&mut self) {
+        // a stable sort, so equal priorities keep the order they were
+        // loaded in
+        self.magic.sort_by_key(|magic| std::cmp::Reverse(magic.priority));
+    }
+}
+
+impl Matchlet {
+    fn matches(&self, data: &[u8]) -> bool {
+        let end = self.start.saturating_add(self.range).min(data.len());
+        let found = (self.start..end).any(|offset| {
+            let window = match offset.checked_add(self.value.len()).and_then(|end| data.get(offset..end)) {
+                Some(window) => window,
+                None => return false,
+            };
+            match self.mask {
+                Some(ref mask) => window
+                    .iter()
+                    .zip(&self.value)
+                    .zip(mask)
+                    .all(|((d, v), m)| d & m == v & m),
+                None => window == &self.value[..],
+            }
+        });
+        found && (self.children.is_empty() || self.children.iter().any(|m| m.matches(data)))
+    }
+}
+
+// Matches a name against a glob, with `*`, `?`, and `[...]` character
+// classes, like `fnmatch(3)` without any flags.
+fn fnmatch(pattern: &str, name: &str) -> bool {
+    let pattern = pattern.chars().collect::<Vec<_>>();
+    let name = name.chars().collect::<Vec<_>>();
+
+    // backtracking positions after the last `*`
+    let (mut p, mut n) = (0, 0);
+    let mut star = None;
+    while n < name.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                star = Some((p + 1, n));
+                p += 1;
+                continue;
+            },
+            Some('?') => {
+                p += 1;
+                n += 1;
+                continue;
+            },
+            Some('[') => {
+                if let Some((matched, len)) = match_class(&pattern[p..], name[n]) {
+                    if matched {
+                        p += len;
+                        n += 1;
+                        continue;
+                    }
+                } else if name[n] == '[' {
+                    // an unclosed `[` is literal
+                    p += 1;
+                    n += 1;
+                    continue;
+                }
+            },
+            Some(&c) if c == name[n] => {
+                p += 1;
+                n += 1;
+                continue;
+            },
+            _ => (),
+        }
+        match star {
+            Some((sp, sn)) => {
+                p = sp;
+                n = sn + 1;
+                star = Some((sp, sn + 1));
+            },
+            None => return false,
+        }
+    }
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+// Returns whether `c` is in the class at the start of `pattern`, and the
+// length of the class, or `None` if it isn't closed.
+fn match_class(pattern: &[char], c: char) -> Option<(bool, usize)> {
+    let mut i = 1;
+    let negated = matches!(pattern.get(i), Some('!') | Some('^'));
+    if negated {
+        i += 1;
+    }
+    let mut matched = false;
+    let mut first = true;
+    loop {
+        let lo = *pattern.get(i)?;
+        if lo == ']' && !first {
+            return Some((matched != negated, i + 1));
+        }
+        first = false;
+        if pattern.get(i + 1) == Some(&'-') && matches!(pattern.get(i + 2), Some(&hi) if hi != ']') {
+            let hi = pattern[i + 2];
+            matched |= lo <= c && c <= hi;
+            i += 3;
+        } else {
+            matched |= lo == c;
+            i += 1;
+        }
+    }
+}
+
+impl Error {
+    fn xml(msg: impl fmt::Display) -> Error {
+        Error {
+            kind: ErrorKind::Xml(msg.to_string()),
+        }
+    }
+
+    fn cache(msg: &'static str) -> Error {
+        Error {
+            kind: ErrorKind::Cache(msg),
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(err: io::Error) -> Error {
+        Error {
+            kind: ErrorKind::Io(err),
+        }
+    }
+}
+
+impl From<InvalidMime> for Error {
+    fn from(err: InvalidMime) -> Error {
+        Error {
+            kind: ErrorKind::InvalidMime(err),
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.kind {
+            ErrorKind::Io(ref err) => write!(f, "failed to read shared-mime-info: {}", err),
+            ErrorKind::Xml(ref msg) => write!(f, "invalid shared-mime-info XML: {}", msg),
+            ErrorKind::Cache(msg) => write!(f, "invalid mime.cache: {}", msg),
+            ErrorKind::InvalidMime(ref err) => write!(f, "invalid shared-mime-info type: {}", err),
+        }
+    }
+}
+
+impl StdError for Error {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        match self.kind {
+            ErrorKind::Io(ref err) => Some(err),
+            ErrorKind::InvalidMime(ref err) => Some(err),
+            ErrorKind::Xml(_) | ErrorKind::Cache(_) => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use super::{fnmatch, Database};
+
+    const FIXTURE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/shared-mime-info");
+
+    fn databases() -> Vec<Database> {
+        vec![
+            Database::from_packages(FIXTURE).unwrap(),
+            Database::from_cache(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/shared-mime-info/mime.cache")).unwrap(),
+        ]
+    }
+
+    fn parse(s: &str) -> MediaType {
+        MediaType::parse(s).unwrap()
+    }
+
+    #[test]
+    fn test_fnmatch() {
+        assert!(fnmatch("*.c", "main.c"));
+        assert!(!fnmatch("*.c", "main.cc"));
+        assert!(fnmatch("*.tar.gz", "a.tar.gz"));
+        assert!(fnmatch("readme*", "readme.md"));
+        assert!(fnmatch("makefile", "makefile"));
+        assert!(!fnmatch("makefile", "makefile.am"));
+        assert!(fnmatch("[0-9][0-9][0-9].vdr", "001.vdr"));
+        assert!(!fnmatch("[0-9][0-9][0-9].vdr", "01a.vdr"));
+        assert!(fnmatch("[!a]?", "bc"));
+        assert!(!fnmatch("[!a]?", "ac"));
+        assert!(fnmatch("*a*b", "xaxxab"));
+        assert!(fnmatch("[]]", "]"));
+        assert!(fnmatch("[a", "[a"));
+        assert!(fnmatch("*", ""));
+    }
+
+    #[test]
+    fn test_glob() {
+        for db in databases() {
+            assert_eq!(db.glob("notes.TXT"), [TEXT_PLAIN]);
+            assert_eq!(db.glob("x.tgz"), ["application/x-compressed-tar"]);
+            assert_eq!(db.glob("a.tar.gz"), ["application/x-compressed-tar", "application/gzip"]);
+            assert_eq!(db.glob("GNUmakefile"), ["text/x-makefile"]);
+            assert_eq!(db.glob("042.vdr"), ["video/mpeg"]);
+
+            // weight beats length
+            assert_eq!(db.glob("README.txt"), ["text/plain", "text/x-readme"]);
+            assert_eq!(db.glob("README"), ["text/x-readme"]);
+
+            // case-sensitive globs
+            assert_eq!(db.glob("main.c")[0], "text/x-csrc");
+            assert_eq!(db.glob("main.C")[0], "text/x-c++src");
+
+            assert!(db.glob("image.jpeg").is_empty());
+        }
+    }
+
+    #[test]
+    fn test_magic() {
+        for db in databases() {
+            assert_eq!(db.magic(b"\x89PNG\r\n\x1a\n").unwrap(), IMAGE_PNG);
+            assert_eq!(db.magic(b"\x1f\x8b\x08\0").unwrap(), "application/gzip");
+            assert_eq!(db.magic(b"\xca\xfe\xba\xbe\0\0\0\x34").unwrap(), "application/x-java");
+
+            // a range of offsets
+            let mut pdf = vec![b' '; 100];
+            pdf.extend_from_slice(b"%PDF-1.4");
+            assert_eq!(db.magic(&pdf).unwrap(), APPLICATION_PDF);
+
+            // priority
+            assert_eq!(db.magic(b"<?xml version=\"1.0\"?>").unwrap(), "application/xml");
+            assert_eq!(db.magic(b"<svg xmlns=\"http://www.w3.org/2000/svg\">").unwrap(), IMAGE_SVG);
+
+            // nested matches
+            let mut bmp = b"BM\0\0\0\0\x01\x02\0\0\0\0\0\0".to_vec();
+            bmp.push(40);
+            assert_eq!(db.magic(&bmp).unwrap(), "image/bmp");
+            bmp[14] = 41;
+            assert!(db.magic(&bmp).is_none());
+
+            // masks
+            assert_eq!(db.magic(b"BMabcd\0\0").unwrap(), "image/bmp");
+            assert_eq!(db.magic(b"\0\0\x01\xb3").unwrap(), "video/mpeg");
+            assert!(db.magic(b"\0\0\x01\xc3").is_none());
+
+            let mut sqlite = vec![0; 20];
+            sqlite.extend_from_slice(b"\x0d\x0a\xff\xff");
+            assert_eq!(db.magic(&sqlite).unwrap(), "application/x-sqlite3");
+
+            // host byte order
+            assert_eq!(db.magic(&0o070707u16.to_ne_bytes()).unwrap(), "application/x-cpio");
+            assert_eq!(db.magic(b"070701").unwrap(), "application/x-cpio");
+
+            assert!(db.magic(b"").is_none());
+            assert!(db.magic(b"plain text").is_none());
+        }
+    }
+
+    #[test]
+    fn test_aliases() {
+        for db in databases() {
+            assert_eq!(db.resolve_alias(&parse("text/x-c")), "text/x-csrc");
+            assert_eq!(db.resolve_alias(&parse("image/x-ms-bmp")), "image/bmp");
+            assert_eq!(db.resolve_alias(&parse("text/xml; charset=utf-8")), "application/xml");
+            assert_eq!(db.resolve_alias(&TEXT_PLAIN_UTF_8), TEXT_PLAIN);
+        }
+    }
+
+    #[test]
+    fn test_subclasses() {
+        for db in databases() {
+            let chdr = parse("text/x-chdr");
+            assert_eq!(db.parents(&chdr), ["text/x-csrc", "text/plain", "application/octet-stream"]);
+            assert!(db.is_subclass_of(&chdr, &parse("text/x-csrc")));
+            assert!(db.is_subclass_of(&chdr, &TEXT_PLAIN));
+            assert!(db.is_subclass_of(&chdr, &APPLICATION_OCTET_STREAM));
+            assert!(db.is_subclass_of(&chdr, &chdr));
+            assert!(!db.is_subclass_of(&parse("text/x-csrc"), &chdr));
+
+            // through aliases
+            assert!(db.is_subclass_of(&parse("text/x-c"), &TEXT_PLAIN));
+            assert!(db.is_subclass_of(&IMAGE_SVG, &parse("text/xml")));
+            assert!(db.is_subclass_of(&parse("application/x-compressed-tar"), &parse("application/x-gzip")));
+
+            // implicit
+            assert!(db.is_subclass_of(&TEXT_CSS, &TEXT_PLAIN));
+            assert!(db.is_subclass_of(&IMAGE_PNG, &APPLICATION_OCTET_STREAM));
+            assert!(!db.is_subclass_of(&parse("inode/directory"), &APPLICATION_OCTET_STREAM));
+            assert!(!db.is_subclass_of(&IMAGE_PNG, &TEXT_PLAIN));
+            assert!(db.parents(&APPLICATION_OCTET_STREAM).is_empty());
+        }
+    }
+
+    #[test]
+    fn test_load() {
+        let db = Database::load(FIXTURE).unwrap();
+        assert_eq!(db.glob("a.png"), [IMAGE_PNG]);
+
+        // without a mime.cache, the packages are loaded
+        let db = Database::load(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/shared-mime-info-packages")).unwrap();
+        assert_eq!(db.glob("a.png"), [IMAGE_PNG]);
+        assert_eq!(db.magic(b"\x89PNG\r\n\x1a\n").unwrap(), IMAGE_PNG);
+
+        let err = Database::load(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures")).unwrap_err();
+        assert!(err.to_string().starts_with("failed to read"), "{}", err);
+    }
+
+    #[test]
+    fn test_xml_deleteall() {
+        let db = Database::from_xml(r#"
+            <mime-info xmlns="http://www.freedesktop.org/standards/shared-mime-info">
+              <mime-type type="text/x-a">
+                <glob pattern="*.a"/>
+                <magic><match type="string" value="a" offset="0"/></magic>
+              </mime-type>
+              <mime-type type="text/x-a">
+                <glob-deleteall/>
+                <magic-deleteall/>
+                <glob pattern="*.aa"/>
+              </mime-type>
+            </mime-info>
+        "#).unwrap();
+        assert!(db.glob("x.a").is_empty());
+        assert_eq!(db.glob("x.aa"), ["text/x-a"]);
+        assert!(db.magic(b"a").is_none());
+    }
+
+    #[test]
+    fn test_errors() {
+        Database::from_xml("<mime-info>").unwrap_err();
+        Database::from_xml(r#"<mime-info><mime-type type="nope"/></mime-info>"#).unwrap_err();
+        Database::from_xml(r#"<mime-info><mime-type type="a/b"><glob/></mime-type></mime-info>"#).unwrap_err();
+        Database::from_xml(r#"<mime-info><mime-type type="a/b"><magic><match type="big16" value="x" offset="0"/></magic></mime-type></mime-info>"#).unwrap_err();
+        Database::from_xml(r#"<mime-info><mime-type type="a/b"><magic><match type="string" value="ab" mask="0xaé0" offset="0"/></magic></mime-type></mime-info>"#).unwrap_err();
+        Database::from_xml(r#"<mime-info><mime-type type="a/b"><magic><match type="string" value="a" offset="0:18446744073709551615"/></magic></mime-type></mime-info>"#).unwrap_err();
+        Database::from_xml(r#"<mime-info><mime-type type="a/b"><magic><match type="string" value="a" offset="18446744073709551615"/></magic></mime-type></mime-info>"#).unwrap_err();
+        Database::from_packages("/nonexistent").unwrap_err();
+
+        let db = Database::from_xml(r#"<mime-info><mime-type type="a/b"><magic><match type="string" value="a" offset="4294967295"/></magic></mime-type></mime-info>"#).unwrap();
+        assert!(db.magic(b"a").is_none());
+
+        let err = Database::from_cache(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/shared-mime-info/packages/fixture.xml")).unwrap_err();
+        assert!(err.to_string().starts_with("invalid mime.cache"), "{}", err);
+    }
+}
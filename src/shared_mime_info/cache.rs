@@ -0,0 +1,161 @@
+use super::{Database, Error, Magic, Matchlet};
+use crate::MediaType;
+
+// From the [spec](https://specifications.freedesktop.org/shared-mime-info-spec/latest/ar01s03.html#id-1.4.5),
+// every number is a big-endian `CARD32`, except the version in the header.
+// Strings are nul-terminated, and referred to by their offset.
+//
+// The header has the offsets of each list:
+const ALIAS_LIST: usize = 4;
+const PARENT_LIST: usize = 8;
+const LITERAL_LIST: usize = 12;
+const REVERSE_SUFFIX_TREE: usize = 16;
+const GLOB_LIST: usize = 20;
+const MAGIC_LIST: usize = 24;
+
+const CASE_SENSITIVE: u32 = 0x100;
+
+// Matchlets can be nested, but a broken cache could point back at itself.
+const MAX_DEPTH: usize = 32;
+
+pub(super) fn load(db: &mut Database, bytes: &[u8]) -> Result<(), Error> {
+    let cache = Cache { bytes };
+    if bytes.len() < 4 || bytes[..2] != [0, 1] {
+        return Err(Error::cache("unsupported version"));
+    }
+
+    let list = cache.u32(ALIAS_LIST)?;
+    for i in 0..cache.u32(list)? {
+        let entry = list + 4 + i * 8;
+        db.add_alias(cache.str_at(entry)?, cache.str_at(entry + 4)?)?;
+    }
+
+    let list = cache.u32(PARENT_LIST)?;
+    for i in 0..cache.u32(list)? {
+        let entry = list + 4 + i * 8;
+        let mt = cache.str_at(entry)?;
+        let parents = cache.u32(entry + 4)?;
+        for j in 0..cache.u32(parents)? {
+            db.add_parent(mt, cache.str_at(parents + 4 + j * 4)?)?;
+        }
+    }
+
+    for &list in &[LITERAL_LIST, GLOB_LIST] {
+        let list = cache.u32(list)?;
+        for i in 0..cache.u32(list)? {
+            let entry = list + 4 + i * 12;
+            let flags = cache.u32(entry + 8)?;
+            db.add_glob(
+                cache.str_at(entry)?,
+                cache.str_at(entry + 4)?,
+                (flags & 0xff) as u32,
+                flags as u32 & CASE_SENSITIVE != 0,
+            )?;
+        }
+    }
+
+    // Each path from a root to a leaf is the suffix of a `*suffix` glob,
+    // from its last character to its first.
+    let tree = cache.u32(REVERSE_SUFFIX_TREE)?;
+    let mut suffix = Vec::new();
+    suffix_nodes(&cache, db, cache.u32(tree)?, cache.u32(tree + 4)?, &mut suffix)?;
+
+    let list = cache.u32(MAGIC_LIST)?;
+    let first = cache.u32(list + 8)?;
+    for i in 0..cache.u32(list)? {
+        let entry = first + i * 16;
+        db.magic.push(Magic {
+            priority: cache.u32(entry)? as u32,
+            media_type: MediaType::parse(cache.str_at(entry + 4)?)?,
+            matchlets: matchlets(&cache, cache.u32(entry + 8)?, cache.u32(entry + 12)?, 0)?,
+        });
+    }
+
+    Ok(())
+}
+
+fn suffix_nodes(cache: &Cache, db: &mut Database, n: usize, first: usize, suffix: &mut Vec<char>) -> Result<(), Error> {
+    if suffix.len() > 255 {
+        return Err(Error::cache("suffix tree is too deep"));
+    }
+    for i in 0..n {
+        let node = first + i * 12;
+        let c = cache.u32(node)?;
+        if c == 0 {
+            let flags = cache.u32(node + 8)?;
+            let pattern = std::iter::once('*').chain(suffix.iter().rev().copied()).collect::<String>();
+            db.add_glob(&pattern, cache.str_at(node + 4)?, (flags & 0xff) as u32, flags as u32 & CASE_SENSITIVE != 0)?;
+        } else {
+            let c = std::char::from_u32(c as u32).ok_or_else(|| Error::cache("invalid suffix character"))?;
+            suffix.push(c);
+            suffix_nodes(cache, db, cache.u32(node + 4)?, cache.u32(node + 8)?, suffix)?;
+            suffix.pop();
+        }
+    }
+    Ok(())
+}
+
+fn matchlets(cache: &Cache, n: usize, first: usize, depth: usize) -> Result<Vec<Matchlet>, Error> {
+    if depth > MAX_DEPTH {
+        return Err(Error::cache("magic is nested too deep"));
+    }
+    (0..n)
+        .map(|i| {
+            let entry = first + i * 32;
+            let word_size = cache.u32(entry + 8)?;
+            let len = cache.u32(entry + 12)?;
+            let mut value = cache.bytes(cache.u32(entry + 16)?, len)?.to_vec();
+            let mut mask = match cache.u32(entry + 20)? {
+                0 => None,
+                offset => Some(cache.bytes(offset, len)?.to_vec()),
+            };
+
+            // host16 and host32 values are stored big-endian
+            if cfg!(target_endian = "little") && word_size > 1 {
+                if len % word_size != 0 {
+                    return Err(Error::cache("magic value isn't a whole number of words"));
+                }
+                for bytes in std::iter::once(&mut value).chain(mask.as_mut()) {
+                    bytes.chunks_mut(word_size).for_each(<[u8]>::reverse);
+                }
+            }
+
+            Ok(Matchlet {
+                start: cache.u32(entry)?,
+                range: cache.u32(entry + 4)?.max(1),
+                value,
+                mask,
+                children: matchlets(cache, cache.u32(entry + 24)?, cache.u32(entry + 28)?, depth + 1)?,
+            })
+        })
+        .collect()
+}
+
+struct Cache<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Cache<'a> {
+    fn bytes(&self, offset: usize, len: usize) -> Result<&'a [u8], Error> {
+        offset
+            .checked_add(len)
+            .and_then(|end| self.bytes.get(offset..end))
+            .ok_or_else(|| Error::cache("offset out of bounds"))
+    }
+
+    fn u32(&self, offset: usize) -> Result<usize, Error> {
+        let b = self.bytes(offset, 4)?;
+        Ok(u32::from_be_bytes([b[0], b[1], b[2], b[3]]) as usize)
+    }
+
+    // The string at the offset stored at `offset`.
+    fn str_at(&self, offset: usize) -> Result<&'a str, Error> {
+        let start = self.u32(offset)?;
+        let rest = self.bytes.get(start..).ok_or_else(|| Error::cache("offset out of bounds"))?;
+        let len = rest
+            .iter()
+            .position(|&b| b == 0)
+            .ok_or_else(|| Error::cache("unterminated string"))?;
+        std::str::from_utf8(&rest[..len]).map_err(|_| Error::cache("string is not utf-8"))
+    }
+}
@@ -0,0 +1,211 @@
+use roxmltree::{Document, Node, ParsingOptions};
+
+use super::{Database, Error, Magic, Matchlet};
+use crate::MediaType;
+
+pub(super) fn load(db: &mut Database, src: &str) -> Result<(), Error> {
+    // the package files have a `<!DOCTYPE mime-info [...]>` with the DTD
+    let options = ParsingOptions {
+        allow_dtd: true,
+        ..ParsingOptions::default()
+    };
+    let doc = Document::parse_with_options(src, options).map_err(Error::xml)?;
+    let root = doc.root_element();
+    if root.tag_name().name() != "mime-info" {
+        return Err(Error::xml("root element is not <mime-info>"));
+    }
+
+    for mime_type in elements(root, "mime-type") {
+        let mt = attr(mime_type, "type")?;
+        let parsed = MediaType::parse(mt)?;
+
+        for child in mime_type.children().filter(Node::is_element) {
+            match child.tag_name().name() {
+                "glob" => {
+                    let weight = match child.attribute("weight") {
+                        Some(weight) => weight.parse().map_err(|_| Error::xml("invalid glob weight"))?,
+                        None => 50,
+                    };
+                    let case_sensitive = child.attribute("case-sensitive") == Some("true");
+                    db.add_glob(attr(child, "pattern")?, mt, weight, case_sensitive)?;
+                },
+                "glob-deleteall" => db.globs.retain(|glob| glob.media_type != parsed),
+                "alias" => db.add_alias(attr(child, "type")?, mt)?,
+                "sub-class-of" => db.add_parent(mt, attr(child, "type")?)?,
+                "magic" => {
+                    let priority = match child.attribute("priority") {
+                        Some(priority) => priority.parse().map_err(|_| Error::xml("invalid magic priority"))?,
+                        None => 50,
+                    };
+                    db.magic.push(Magic {
+                        priority,
+                        media_type: parsed.clone(),
+                        matchlets: matchlets(child)?,
+                    });
+                },
+                "magic-deleteall" => db.magic.retain(|magic| magic.media_type != parsed),
+                // comments, icons, treemagic, and root-XML aren't used
+                _ => (),
+            }
+        }
+    }
+    Ok(())
+}
+
+fn elements<'a, 'input: 'a>(node: Node<'a, 'input>, name: &'static str) -> impl Iterator<Item = Node<'a, 'input>> {
+    node.children().filter(move |n| n.is_element() && n.tag_name().name() == name)
+}
+
+fn attr<'a>(node: Node<'a, '_>, name: &str) -> Result<&'a str, Error> {
+    node.attribute(name)
+        .ok_or_else(|| Error::xml(format!("<{}> is missing the {:?} attribute", node.tag_name().name(), name)))
+}
+
+fn matchlets(node: Node) -> Result<Vec<Matchlet>, Error> {
+    elements(node, "match").map(matchlet).collect()
+}
+
+fn matchlet(node: Node) -> Result<Matchlet, Error> {
+    let offset = attr(node, "offset")?;
+    let (start, end) = match offset.find(':') {
+        Some(i) => (number(&offset[..i]), number(&offset[i + 1..])),
+        None => (number(offset), number(offset)),
+    };
+    let (start, end) = match (start, end) {
+        (Some(start), Some(end)) if start <= end && end <= u64::from(u32::MAX) => (start as usize, end as usize),
+        _ => return Err(Error::xml(format!("invalid match offset {:?}", offset))),
+    };
+
+    let kind = attr(node, "type")?;
+    let value = attr(node, "value")?;
+    let (value, mask) = match kind {
+        "string" => {
+            let mask = node.attribute("mask").map(hex_mask).transpose()?;
+            (unescape(value), mask)
+        },
+        _ => {
+            let to_bytes = |s: &str| -> Result<Vec<u8>, Error> {
+                let n = number(s).ok_or_else(|| Error::xml(format!("invalid {} {:?}", kind, s)))?;
+                Ok(match kind {
+                    "byte" => vec![n as u8],
+                    "host16" => (n as u16).to_ne_bytes().to_vec(),
+                    "host32" => (n as u32).to_ne_bytes().to_vec(),
+                    "big16" => (n as u16).to_be_bytes().to_vec(),
+                    "big32" => (n as u32).to_be_bytes().to_vec(),
+                    "little16" => (n as u16).to_le_bytes().to_vec(),
+                    "little32" => (n as u32).to_le_bytes().to_vec(),
+                    _ => return Err(Error::xml(format!("unknown match type {:?}", kind))),
+                })
+            };
+            (to_bytes(value)?, node.attribute("mask").map(to_bytes).transpose()?)
+        },
+    };
+    if matches!(&mask, Some(mask) if mask.len() != value.len()) {
+        return Err(Error::xml(format!("mask of {:?} is the wrong length", value)));
+    }
+
+    Ok(Matchlet {
+        start,
+        range: (end - start).saturating_add(1),
+        value,
+        mask,
+        children: matchlets(node)?,
+    })
+}
+
+// Like `strtoul` with a base of 0: `0x` is hex, a leading `0` is octal.
+fn number(s: &str) -> Option<u64> {
+    if s.starts_with("0x") || s.starts_with("0X") {
+        u64::from_str_radix(&s[2..], 16).ok()
+    } else if s.len() > 1 && s.starts_with('0') {
+        u64::from_str_radix(&s[1..], 8).ok()
+    } else {
+        s.parse().ok()
+    }
+}
+
+// A string mask is written as hex, such as `0xffff0000`.
+fn hex_mask(s: &str) -> Result<Vec<u8>, Error> {
+    let invalid = || Error::xml(format!("invalid mask {:?}", s));
+    let hex = s.strip_prefix("0x").ok_or_else(invalid)?;
+    if !hex.is_ascii() || hex.len() % 2 != 0 {
+        return Err(invalid());
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).map_err(|_| invalid()))
+        .collect()
+}
+
+// String values can have C escapes, like `\x89PNG` or `\037\213`.
+fn unescape(s: &str) -> Vec<u8> {
+    let bytes = s.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] != b'\\' || i + 1 == bytes.len() {
+            out.push(bytes[i]);
+            i += 1;
+            continue;
+        }
+        i += 1;
+        let digits = |i: usize, max: usize, radix: u32| {
+            bytes[i..]
+                .iter()
+                .take(max)
+                .take_while(|b| (**b as char).is_digit(radix))
+                .count()
+        };
+        match bytes[i] {
+            b'x' if digits(i + 1, 2, 16) > 0 => {
+                let len = digits(i + 1, 2, 16);
+                let hex = std::str::from_utf8(&bytes[i + 1..i + 1 + len]).expect("ascii");
+                out.push(u8::from_str_radix(hex, 16).expect("hex digits"));
+                i += 1 + len;
+            },
+            b'0'..=b'7' => {
+                let len = digits(i, 3, 8);
+                let oct = std::str::from_utf8(&bytes[i..i + len]).expect("ascii");
+                out.push(u16::from_str_radix(oct, 8).expect("octal digits") as u8);
+                i += len;
+            },
+            c => {
+                out.push(match c {
+                    b'n' => b'\n',
+                    b'r' => b'\r',
+                    b't' => b'\t',
+                    b'b' => 8,
+                    b'f' => 12,
+                    b'v' => 11,
+                    c => c,
+                });
+                i += 1;
+            },
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{number, unescape};
+
+    #[test]
+    fn test_unescape() {
+        assert_eq!(unescape("\\x89PNG"), b"\x89PNG");
+        assert_eq!(unescape("\\037\\213"), b"\x1f\x8b");
+        assert_eq!(unescape("BMxxxx\\000\\000"), b"BMxxxx\0\0");
+        assert_eq!(unescape("a\\\\b\\n\\ "), b"a\\b\n ");
+        assert_eq!(unescape("\\xg\\"), b"xg\\");
+    }
+
+    #[test]
+    fn test_number() {
+        assert_eq!(number("0xcafebabe"), Some(0xcafe_babe));
+        assert_eq!(number("070707"), Some(0o70707));
+        assert_eq!(number("40"), Some(40));
+        assert_eq!(number("0"), Some(0));
+        assert_eq!(number("08"), None);
+        assert_eq!(number(""), None);
+    }
+}
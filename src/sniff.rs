@@ -0,0 +1,683 @@
+//! Determine the `MediaType` of a resource from its content.
+//!
+//! This implements the byte pattern tables and rules of the
+//! [WHATWG MIME Sniffing Standard](https://mimesniff.spec.whatwg.org/).
+//! Only the first 1445 bytes of a resource, the "resource header", are ever
+//! inspected, so there is no need to pass in more than that.
+//!
+//! # Example
+//!
+//! ```
+//! use mime::sniff;
+//!
+//! let png = b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR";
+//!
+//! // an unhelpful Content-Type
+//! assert_eq!(sniff::compute(png, Some(&mime::APPLICATION_OCTET_STREAM)), mime::APPLICATION_OCTET_STREAM);
+//! // no Content-Type at all
+//! assert_eq!(sniff::compute(png, None), mime::IMAGE_PNG);
+//! // a lying image Content-Type
+//! assert_eq!(sniff::compute(png, Some(&mime::IMAGE_GIF)), mime::IMAGE_PNG);
+//! ```
+
+use crate::{MediaType, APPLICATION_OCTET_STREAM, TEXT_PLAIN};
+
+const RESOURCE_HEADER_LEN: usize = 1445;
+
+/// Compute the `MediaType` of a resource.
+///
+/// `supplied` is the type the resource was labeled with, such as from a
+/// `Content-Type` header, or `None` if it wasn't labeled.
+///
+/// Unlabeled resources, or those labeled `unknown/unknown` or
+/// `application/unknown`, are sniffed against every table, including
+/// HTML. Otherwise, only a few labels are second-guessed:
+///
+/// - `text/plain` is checked for binary content, to work around servers
+///   that send it for everything.
+/// - `text/html` is checked for RSS and Atom feeds.
+/// - `image/*`, `audio/*` and `video/*` are replaced by the matching
+///   type of the same kind, if there is one.
+///
+/// # Example
+///
+/// ```
+/// use mime::sniff;
+///
+/// assert_eq!(sniff::compute(b"  <!DOCTYPE html><html>", None), mime::TEXT_HTML);
+/// assert_eq!(sniff::compute(b"%PDF-1.7", None), mime::APPLICATION_PDF);
+/// assert_eq!(sniff::compute(b"hello world", None), mime::TEXT_PLAIN);
+/// assert_eq!(sniff::compute(b"\0\x01\x02", None), mime::APPLICATION_OCTET_STREAM);
+///
+/// // HTML is never sniffed from a resource labeled as plain text
+/// assert_eq!(sniff::compute(b"<html>", Some(&mime::TEXT_PLAIN)), mime::TEXT_PLAIN);
+/// ```
+pub fn compute(resource: &[u8], supplied: Option<&MediaType>) -> MediaType {
+    let header = resource_header(resource);
+
+    let supplied = match supplied {
+        Some(mt) if !is_unknown(mt) => mt,
+        _ => return unknown(header, true),
+    };
+
+    if is_apache_bug(supplied) {
+        return text_or_binary(header, supplied);
+    }
+
+    if is_xml(supplied) {
+        return supplied.clone();
+    }
+
+    if supplied.mime.essence() == "text/html" {
+        return feed_or_html(header).unwrap_or_else(|| supplied.clone());
+    }
+
+    let sniffed = match supplied.type_() {
+        "image" => image(header),
+        "audio" | "video" => audio_video(header),
+        _ => None,
+    };
+
+    sniffed.unwrap_or_else(|| supplied.clone())
+}
+
+/// Match a resource against the image type patterns.
+///
+/// # Example
+///
+/// ```
+/// assert_eq!(mime::sniff::image(b"GIF89a\x01\0\x01\0"), Some(mime::IMAGE_GIF));
+/// assert_eq!(mime::sniff::image(b"GIF"), None);
+/// ```
+pub fn image(resource: &[u8]) -> Option<MediaType> {
+    match_table(IMAGE, resource_header(resource))
+}
+
+/// Match a resource against the audio and video type patterns.
+///
+/// # Example
+///
+/// ```
+/// let ogg = mime::sniff::audio_video(b"OggS\0\x02").unwrap();
+/// assert_eq!(ogg, "application/ogg");
+/// ```
+pub fn audio_video(resource: &[u8]) -> Option<MediaType> {
+    let header = resource_header(resource);
+    if let Some(mt) = match_table(AUDIO_VIDEO, header) {
+        Some(mt)
+    } else if is_mp4(header) {
+        Some(from_static("video/mp4"))
+    } else if is_webm(header) {
+        Some(from_static("video/webm"))
+    } else if is_mp3_without_id3(header) {
+        Some(from_static("audio/mpeg"))
+    } else {
+        None
+    }
+}
+
+/// Match a resource against the font type patterns.
+///
+/// Fonts are not sniffed by [`compute`], only when a font is expected,
+/// such as by a CSS `@font-face` rule.
+///
+/// # Example
+///
+/// ```
+/// assert_eq!(mime::sniff::font(b"wOF2\0\x01\0\0"), Some(mime::FONT_WOFF2));
+/// ```
+pub fn font(resource: &[u8]) -> Option<MediaType> {
+    match_table(FONT, resource_header(resource))
+}
+
+/// Match a resource against the archive type patterns.
+///
+/// # Example
+///
+/// ```
+/// let zip = mime::sniff::archive(b"PK\x03\x04\x14\0").unwrap();
+/// assert_eq!(zip, "application/zip");
+/// ```
+pub fn archive(resource: &[u8]) -> Option<MediaType> {
+    match_table(ARCHIVE, resource_header(resource))
+}
+
+fn resource_header(resource: &[u8]) -> &[u8] {
+    &resource[..resource.len().min(RESOURCE_HEADER_LEN)]
+}
+
+fn from_static(s: &'static str) -> MediaType {
+    MediaType::parse(s).expect("sniff tables have valid media types")
+}
+
+fn is_unknown(mt: &MediaType) -> bool {
+    matches!(mt.mime.essence(), "unknown/unknown" | "application/unknown")
+}
+
+// Servers such as Apache used to label every unknown file with one of
+// these, so they're checked for binary content.
+fn is_apache_bug(mt: &MediaType) -> bool {
+    let s = mt.as_ref();
+    s == "text/plain"
+        || s.eq_ignore_ascii_case("text/plain; charset=iso-8859-1")
+        || s.eq_ignore_ascii_case("text/plain; charset=utf-8")
+}
+
+fn is_xml(mt: &MediaType) -> bool {
+    mt.suffix() == Some("xml") || matches!(mt.mime.essence(), "text/xml" | "application/xml")
+}
+
+// https://mimesniff.spec.whatwg.org/#rules-for-text-or-binary
+fn text_or_binary(header: &[u8], supplied: &MediaType) -> MediaType {
+    if header.starts_with(b"\xFE\xFF") || header.starts_with(b"\xFF\xFE") || header.starts_with(b"\xEF\xBB\xBF") {
+        supplied.clone()
+    } else if !header.iter().copied().any(is_binary_data) {
+        TEXT_PLAIN
+    } else {
+        unknown(header, false)
+    }
+}
+
+// https://mimesniff.spec.whatwg.org/#rules-for-identifying-an-unknown-mime-type
+fn unknown(header: &[u8], sniff_scriptable: bool) -> MediaType {
+    if sniff_scriptable {
+        if let Some(mt) = match_table(SCRIPTABLE, header) {
+            return mt;
+        }
+    }
+
+    match_table(UNKNOWN, header)
+        .or_else(|| image(header))
+        .or_else(|| audio_video(header))
+        .or_else(|| archive(header))
+        .unwrap_or_else(|| {
+            if header.iter().copied().any(is_binary_data) {
+                APPLICATION_OCTET_STREAM
+            } else {
+                TEXT_PLAIN
+            }
+        })
+}
+
+fn is_binary_data(b: u8) -> bool {
+    matches!(b, 0x00..=0x08 | 0x0B | 0x0E..=0x1A | 0x1C..=0x1F)
+}
+
+fn is_whitespace(b: u8) -> bool {
+    matches!(b, b'\t' | b'\n' | 0x0C | b'\r' | b' ')
+}
+
+fn is_tag_terminating(b: u8) -> bool {
+    b == b' ' || b == b'>'
+}
+
+// https://mimesniff.spec.whatwg.org/#rules-for-distinguishing-if-a-resource-is-a-feed-or-html
+fn feed_or_html(header: &[u8]) -> Option<MediaType> {
+    const RSS_NS: &[u8] = b"http://purl.org/rss/1.0/";
+    const RDF_NS: &[u8] = b"http://www.w3.org/1999/02/22-rdf-syntax-ns#";
+
+    let mut s = if header.starts_with(b"\xEF\xBB\xBF") { 3 } else { 0 };
+
+    loop {
+        // skip whitespace up to the next tag
+        loop {
+            match *header.get(s)? {
+                b'<' => break,
+                b if is_whitespace(b) => s += 1,
+                _ => return None,
+            }
+        }
+        s += 1;
+
+        let rest = &header[s..];
+        if rest.starts_with(b"!--") {
+            s += 3 + find(&header[s + 3..], b"-->")? + 3;
+        } else if rest.starts_with(b"!") {
+            s += 1 + find(&header[s + 1..], b">")? + 1;
+        } else if rest.starts_with(b"?") {
+            s += 1 + find(&header[s + 1..], b"?>")? + 2;
+        } else if rest.starts_with(b"rss") {
+            return Some(from_static("application/rss+xml"));
+        } else if rest.starts_with(b"feed") {
+            return Some(from_static("application/atom+xml"));
+        } else if rest.starts_with(b"rdf:RDF") {
+            let rest = &rest[7..];
+            return if find(rest, RSS_NS).is_some() && find(rest, RDF_NS).is_some() {
+                Some(from_static("application/rss+xml"))
+            } else {
+                None
+            };
+        } else {
+            return None;
+        }
+    }
+}
+
+fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack.windows(needle.len()).position(|w| w == needle)
+}
+
+// https://mimesniff.spec.whatwg.org/#signature-for-mp4
+fn is_mp4(header: &[u8]) -> bool {
+    if header.len() < 12 {
+        return false;
+    }
+
+    let box_size = u32::from_be_bytes([header[0], header[1], header[2], header[3]]) as usize;
+    if header.len() < box_size || box_size & 0b11 != 0 || &header[4..8] != b"ftyp" {
+        return false;
+    }
+
+    if &header[8..11] == b"mp4" {
+        return true;
+    }
+
+    // skip the minor version, then check the compatible brands
+    (16..box_size)
+        .step_by(4)
+        .any(|i| header.get(i..i + 3) == Some(&b"mp4"[..]))
+}
+
+// https://mimesniff.spec.whatwg.org/#signature-for-webm
+fn is_webm(header: &[u8]) -> bool {
+    if !header.starts_with(b"\x1A\x45\xDF\xA3") {
+        return false;
+    }
+
+    let mut iter = 4;
+    while iter + 1 < header.len() && iter < 38 {
+        if header[iter] == 0x42 && header[iter + 1] == 0x82 {
+            iter += 2;
+            if iter >= header.len() {
+                return false;
+            }
+
+            iter += vint_len(&header[iter..]);
+            if iter >= header.len() {
+                return false;
+            }
+
+            // the DocType may be padded with leading zeros
+            let padded = &header[iter..];
+            let doc_type = match padded.iter().position(|&b| b != 0) {
+                Some(i) => &padded[i..],
+                None => return false,
+            };
+            if doc_type.starts_with(b"webm") {
+                return true;
+            }
+        }
+        iter += 1;
+    }
+    false
+}
+
+// The length of an EBML variable size integer is the number of leading
+// zero bits in its first byte, plus one.
+fn vint_len(bytes: &[u8]) -> usize {
+    let mut mask = 0x80;
+    let mut len = 1;
+    while len < 8 && len < bytes.len() {
+        if bytes[0] & mask != 0 {
+            break;
+        }
+        mask >>= 1;
+        len += 1;
+    }
+    len
+}
+
+// https://mimesniff.spec.whatwg.org/#signature-for-mp3-without-id3
+fn is_mp3_without_id3(header: &[u8]) -> bool {
+    match mp3_frame_len(header) {
+        Some(len) if len >= 4 && len <= header.len() => mp3_frame_len(&header[len..]).is_some(),
+        _ => false,
+    }
+}
+
+// Parses an MPEG audio frame header, returning the length of the frame.
+fn mp3_frame_len(frame: &[u8]) -> Option<usize> {
+    const MPEG1_BITRATES: [u32; 15] = [0, 32, 40, 48, 56, 64, 80, 96, 112, 128, 160, 192, 224, 256, 320];
+    const MPEG2_BITRATES: [u32; 15] = [0, 8, 16, 24, 32, 40, 48, 56, 64, 80, 96, 112, 128, 144, 160];
+    const SAMPLE_RATES: [u32; 3] = [44100, 48000, 32000];
+
+    if frame.len() < 4 || frame[0] != 0xFF || frame[1] & 0xE0 != 0xE0 {
+        return None;
+    }
+
+    let version = (frame[1] & 0x18) >> 3;
+    let layer = (frame[1] & 0x06) >> 1;
+    let bitrate_index = usize::from((frame[2] & 0xF0) >> 4);
+    let sample_rate_index = usize::from((frame[2] & 0x0C) >> 2);
+    let padding = usize::from((frame[2] & 0x02) >> 1);
+
+    if version == 0b01 || layer == 0 {
+        return None;
+    }
+
+    // `SAMPLE_RATES` is for MPEG-1, MPEG-2 halves it, MPEG-2.5 quarters it.
+    let (bitrates, scale, sample_rate_shift) = match version {
+        0b11 => (&MPEG1_BITRATES, 144, 0),
+        0b10 => (&MPEG2_BITRATES, 72, 1),
+        _ => (&MPEG2_BITRATES, 72, 2),
+    };
+    let bitrate = *bitrates.get(bitrate_index)? * 1000;
+    let sample_rate = *SAMPLE_RATES.get(sample_rate_index)? >> sample_rate_shift;
+
+    Some((scale * bitrate / sample_rate) as usize + padding)
+}
+
+/// A byte pattern from the WHATWG tables.
+///
+/// A resource matches if, after skipping any leading bytes in `ignored`,
+/// its bytes masked by `mask` equal `pattern`.
+struct Pattern {
+    pattern: &'static [u8],
+    mask: &'static [u8],
+    ignored: &'static [u8],
+    // Whether the pattern must be followed by a tag-terminating byte.
+    tag: bool,
+    mime: &'static str,
+}
+
+const WHITESPACE: &[u8] = b"\t\n\x0C\r ";
+
+macro_rules! patterns {
+    ($($pattern:expr, $mask:expr, $ignored:expr, $tag:expr => $mime:expr;)+) => (
+        &[$(Pattern {
+            pattern: $pattern,
+            mask: $mask,
+            ignored: $ignored,
+            tag: $tag,
+            mime: $mime,
+        },)+]
+    );
+}
+
+fn match_table(table: &[Pattern], header: &[u8]) -> Option<MediaType> {
+    table
+        .iter()
+        .find(|p| p.matches(header))
+        .map(|p| from_static(p.mime))
+}
+
+impl Pattern {
+    fn matches(&self, header: &[u8]) -> bool {
+        debug_assert_eq!(self.pattern.len(), self.mask.len());
+
+        let start = header
+            .iter()
+            .position(|b| !self.ignored.contains(b))
+            .unwrap_or(header.len());
+        let input = &header[start..];
+
+        if input.len() < self.pattern.len() {
+            return false;
+        }
+
+        let matched = self
+            .pattern
+            .iter()
+            .zip(self.mask)
+            .zip(input)
+            .all(|((&p, &m), &b)| b & m == p);
+
+        matched && (!self.tag || matches!(input.get(self.pattern.len()), Some(&b) if is_tag_terminating(b)))
+    }
+}
+
+// https://mimesniff.spec.whatwg.org/#identifying-a-resource-with-an-unknown-mime-type
+//
+// The mask 0xDF upper cases ASCII letters, so the HTML patterns are
+// matched case-insensitively.
+static SCRIPTABLE: &[Pattern] = patterns! {
+    b"<!DOCTYPE HTML", b"\xFF\xFF\xDF\xDF\xDF\xDF\xDF\xDF\xDF\xFF\xDF\xDF\xDF\xDF", WHITESPACE, true => "text/html";
+    b"<HTML", b"\xFF\xDF\xDF\xDF\xDF", WHITESPACE, true => "text/html";
+    b"<HEAD", b"\xFF\xDF\xDF\xDF\xDF", WHITESPACE, true => "text/html";
+    b"<SCRIPT", b"\xFF\xDF\xDF\xDF\xDF\xDF\xDF", WHITESPACE, true => "text/html";
+    b"<IFRAME", b"\xFF\xDF\xDF\xDF\xDF\xDF\xDF", WHITESPACE, true => "text/html";
+    b"<H1", b"\xFF\xDF\xFF", WHITESPACE, true => "text/html";
+    b"<DIV", b"\xFF\xDF\xDF\xDF", WHITESPACE, true => "text/html";
+    b"<FONT", b"\xFF\xDF\xDF\xDF\xDF", WHITESPACE, true => "text/html";
+    b"<TABLE", b"\xFF\xDF\xDF\xDF\xDF\xDF", WHITESPACE, true => "text/html";
+    b"<A", b"\xFF\xDF", WHITESPACE, true => "text/html";
+    b"<STYLE", b"\xFF\xDF\xDF\xDF\xDF\xDF", WHITESPACE, true => "text/html";
+    b"<TITLE", b"\xFF\xDF\xDF\xDF\xDF\xDF", WHITESPACE, true => "text/html";
+    b"<B", b"\xFF\xDF", WHITESPACE, true => "text/html";
+    b"<BODY", b"\xFF\xDF\xDF\xDF\xDF", WHITESPACE, true => "text/html";
+    b"<BR", b"\xFF\xDF\xDF", WHITESPACE, true => "text/html";
+    b"<P", b"\xFF\xDF", WHITESPACE, true => "text/html";
+    b"<!--", b"\xFF\xFF\xFF\xFF", WHITESPACE, true => "text/html";
+    b"<?xml", b"\xFF\xFF\xFF\xFF\xFF", WHITESPACE, false => "text/xml";
+    b"%PDF-", b"\xFF\xFF\xFF\xFF\xFF", b"", false => "application/pdf";
+};
+
+static UNKNOWN: &[Pattern] = patterns! {
+    b"%!PS-Adobe-", b"\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF", b"", false => "application/postscript";
+    b"\xFE\xFF\0\0", b"\xFF\xFF\0\0", b"", false => "text/plain";
+    b"\xFF\xFE\0\0", b"\xFF\xFF\0\0", b"", false => "text/plain";
+    b"\xEF\xBB\xBF\0", b"\xFF\xFF\xFF\0", b"", false => "text/plain";
+};
+
+// https://mimesniff.spec.whatwg.org/#matching-an-image-type-pattern
+static IMAGE: &[Pattern] = patterns! {
+    b"\0\0\x01\0", b"\xFF\xFF\xFF\xFF", b"", false => "image/x-icon";
+    b"\0\0\x02\0", b"\xFF\xFF\xFF\xFF", b"", false => "image/x-icon";
+    b"BM", b"\xFF\xFF", b"", false => "image/bmp";
+    b"GIF87a", b"\xFF\xFF\xFF\xFF\xFF\xFF", b"", false => "image/gif";
+    b"GIF89a", b"\xFF\xFF\xFF\xFF\xFF\xFF", b"", false => "image/gif";
+    b"RIFF\0\0\0\0WEBPVP", b"\xFF\xFF\xFF\xFF\0\0\0\0\xFF\xFF\xFF\xFF\xFF\xFF", b"", false => "image/webp";
+    b"\x89PNG\r\n\x1A\n", b"\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF", b"", false => "image/png";
+    b"\xFF\xD8\xFF", b"\xFF\xFF\xFF", b"", false => "image/jpeg";
+};
+
+// https://mimesniff.spec.whatwg.org/#matching-an-audio-or-video-type-pattern
+static AUDIO_VIDEO: &[Pattern] = patterns! {
+    b"FORM\0\0\0\0AIFF", b"\xFF\xFF\xFF\xFF\0\0\0\0\xFF\xFF\xFF\xFF", b"", false => "audio/aiff";
+    b"ID3", b"\xFF\xFF\xFF", b"", false => "audio/mpeg";
+    b"OggS\0", b"\xFF\xFF\xFF\xFF\xFF", b"", false => "application/ogg";
+    b"MThd\0\0\0\x06", b"\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF", b"", false => "audio/midi";
+    b"RIFF\0\0\0\0AVI ", b"\xFF\xFF\xFF\xFF\0\0\0\0\xFF\xFF\xFF\xFF", b"", false => "video/avi";
+    b"RIFF\0\0\0\0WAVE", b"\xFF\xFF\xFF\xFF\0\0\0\0\xFF\xFF\xFF\xFF", b"", false => "audio/wave";
+};
+
+// https://mimesniff.spec.whatwg.org/#matching-a-font-type-pattern
+static FONT: &[Pattern] = patterns! {
+    b"\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0LP",
+        b"\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\xFF\xFF",
+        b"", false => "application/vnd.ms-fontobject";
+    b"\0\x01\0\0", b"\xFF\xFF\xFF\xFF", b"", false => "font/ttf";
+    b"OTTO", b"\xFF\xFF\xFF\xFF", b"", false => "font/otf";
+    b"ttcf", b"\xFF\xFF\xFF\xFF", b"", false => "font/collection";
+    b"wOFF", b"\xFF\xFF\xFF\xFF", b"", false => "font/woff";
+    b"wOF2", b"\xFF\xFF\xFF\xFF", b"", false => "font/woff2";
+};
+
+// https://mimesniff.spec.whatwg.org/#matching-an-archive-type-pattern
+static ARCHIVE: &[Pattern] = patterns! {
+    b"\x1F\x8B\x08", b"\xFF\xFF\xFF", b"", false => "application/x-gzip";
+    b"PK\x03\x04", b"\xFF\xFF\xFF\xFF", b"", false => "application/zip";
+    b"Rar!\x1A\x07\0", b"\xFF\xFF\xFF\xFF\xFF\xFF\xFF", b"", false => "application/x-rar-compressed";
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::*;
+
+    #[test]
+    fn unknown_html() {
+        assert_eq!(compute(b"<HtMl><body>", None), TEXT_HTML);
+        assert_eq!(compute(b"\r\n\t <p>hi</p>", None), TEXT_HTML);
+        assert_eq!(compute(b"<br/>", None), TEXT_PLAIN);
+        assert_eq!(compute(b"<b", None), TEXT_PLAIN);
+        assert_eq!(compute(b"<!-- x -->", None), TEXT_HTML);
+        assert_eq!(compute(b"<?xml version=\"1.0\"?>", None), TEXT_XML);
+
+        let unknown = MediaType::parse("unknown/unknown").unwrap();
+        assert_eq!(compute(b"<script>", Some(&unknown)), TEXT_HTML);
+    }
+
+    #[test]
+    fn unknown_binary() {
+        assert_eq!(compute(b"", None), TEXT_PLAIN);
+        assert_eq!(compute(b"\xEF\xBB\xBF\0\0", None), TEXT_PLAIN);
+        assert_eq!(compute(b"plain\x1B[0m text\x0C", None), TEXT_PLAIN);
+        assert_eq!(compute(b"binary\x1A", None), APPLICATION_OCTET_STREAM);
+        assert_eq!(compute(b"%!PS-Adobe-3.0", None), "application/postscript");
+        assert_eq!(compute(b"PK\x03\x04", None), "application/zip");
+    }
+
+    #[test]
+    fn unknown_atoms() {
+        let png = compute(b"\x89PNG\r\n\x1A\n", None);
+        assert_eq!(png.mime.private_atom(), IMAGE_PNG.mime.private_atom());
+    }
+
+    #[test]
+    fn apache_bug() {
+        let pdf = b"%PDF-1.4\n%\xE2\xE3\xCF\xD3\n1 0 obj\0";
+        let gif = b"GIF87a\x01\0\x01\0";
+
+        assert_eq!(compute(b"just text", Some(&TEXT_PLAIN)), TEXT_PLAIN);
+        assert_eq!(compute(b"\xFF\xFE\0\0", Some(&TEXT_PLAIN_UTF_8)), TEXT_PLAIN_UTF_8);
+        assert_eq!(compute(gif, Some(&TEXT_PLAIN)), IMAGE_GIF);
+        // scriptable types are not sniffed from text/plain
+        assert_eq!(compute(pdf, Some(&TEXT_PLAIN_UTF_8)), APPLICATION_OCTET_STREAM);
+
+        let latin1 = MediaType::parse("text/plain; charset=ISO-8859-1").unwrap();
+        assert_eq!(compute(gif, Some(&latin1)), IMAGE_GIF);
+
+        let ascii = MediaType::parse("text/plain; charset=us-ascii").unwrap();
+        assert_eq!(compute(gif, Some(&ascii)), ascii);
+    }
+
+    #[test]
+    fn supplied_kept() {
+        let png = b"\x89PNG\r\n\x1A\n";
+        assert_eq!(compute(png, Some(&APPLICATION_JSON)), APPLICATION_JSON);
+        assert_eq!(compute(png, Some(&TEXT_XML)), TEXT_XML);
+        assert_eq!(compute(png, Some(&IMAGE_SVG)), IMAGE_SVG);
+        assert_eq!(compute(b"not an image", Some(&IMAGE_JPEG)), IMAGE_JPEG);
+        assert_eq!(compute(b"<html>", Some(&TEXT_HTML)), TEXT_HTML);
+    }
+
+    #[test]
+    fn supplied_image_audio_video() {
+        assert_eq!(compute(b"\xFF\xD8\xFF\xE0", Some(&IMAGE_PNG)), IMAGE_JPEG);
+        assert_eq!(compute(b"RIFF\x10\0\0\0WEBPVP8 ", Some(&IMAGE_PNG)), "image/webp");
+        assert_eq!(compute(b"ID3\x03", Some(&IMAGE_PNG)), IMAGE_PNG);
+
+        let wav = MediaType::parse("audio/x-wav").unwrap();
+        assert_eq!(compute(b"RIFF\x10\0\0\0WAVEfmt ", Some(&wav)), "audio/wave");
+    }
+
+    #[test]
+    fn feeds() {
+        let rss = b"\xEF\xBB\xBF<?xml version=\"1.0\"?>\n<!-- a <feed> -->\n<rss version=\"2.0\">";
+        assert_eq!(compute(rss, Some(&TEXT_HTML)), "application/rss+xml");
+
+        let atom = b"<!DOCTYPE feed><feed xmlns=\"http://www.w3.org/2005/Atom\">";
+        assert_eq!(compute(atom, Some(&TEXT_HTML)), "application/atom+xml");
+
+        let rdf = b"<rdf:RDF xmlns:rdf=\"http://www.w3.org/1999/02/22-rdf-syntax-ns#\" xmlns=\"http://purl.org/rss/1.0/\">";
+        assert_eq!(compute(rdf, Some(&TEXT_HTML)), "application/rss+xml");
+
+        let rdf = b"<rdf:RDF xmlns:rdf=\"http://www.w3.org/1999/02/22-rdf-syntax-ns#\">";
+        assert_eq!(compute(rdf, Some(&TEXT_HTML)), TEXT_HTML);
+
+        assert_eq!(compute(b"<!-- <rss> unterminated", Some(&TEXT_HTML)), TEXT_HTML);
+        assert_eq!(compute(b"<html><rss>", Some(&TEXT_HTML)), TEXT_HTML);
+    }
+
+    #[test]
+    fn images() {
+        assert_eq!(image(b"\0\0\x01\0\x01\0").unwrap(), "image/x-icon");
+        assert_eq!(image(b"BM\x8A\0").unwrap(), IMAGE_BMP);
+        assert_eq!(image(b"RIFF\0\0\0\0WEBPVP").unwrap(), "image/webp");
+        assert_eq!(image(b"RIFF\0\0\0\0WEBP"), None);
+        assert_eq!(image(b"\x89PNG\r\n\x1A"), None);
+    }
+
+    #[test]
+    fn audio_video_patterns() {
+        assert_eq!(audio_video(b"FORM\0\0\0\0AIFF").unwrap(), "audio/aiff");
+        assert_eq!(audio_video(b"MThd\0\0\0\x06\0\x01").unwrap(), "audio/midi");
+        assert_eq!(audio_video(b"RIFF\0\0\0\0AVI LIST").unwrap(), "video/avi");
+        assert_eq!(audio_video(b"ID3\x04\0").unwrap(), "audio/mpeg");
+        assert_eq!(audio_video(b"OggS"), None);
+    }
+
+    #[test]
+    fn mp4() {
+        let mut isom = b"\0\0\0\x18ftypisom\0\0\x02\0isomiso2".to_vec();
+        assert_eq!(audio_video(&isom), None);
+        isom.extend_from_slice(b"mp41");
+        isom[3] = 0x1C;
+        assert_eq!(audio_video(&isom).unwrap(), "video/mp4");
+
+        assert_eq!(audio_video(b"\0\0\0\x0Cftypmp42").unwrap(), "video/mp4");
+        // box size is larger than the resource
+        assert_eq!(audio_video(b"\0\0\0\x20ftypmp42"), None);
+    }
+
+    #[test]
+    fn webm() {
+        let webm = b"\x1A\x45\xDF\xA3\x9F\x42\x86\x81\x01\x42\xF7\x81\x01\x42\x82\x84webm";
+        assert_eq!(audio_video(webm).unwrap(), "video/webm");
+
+        let mkv = b"\x1A\x45\xDF\xA3\x9F\x42\x86\x81\x01\x42\xF7\x81\x01\x42\x82\x88matroska";
+        assert_eq!(audio_video(mkv), None);
+        assert_eq!(audio_video(b"\x1A\x45\xDF\xA3\x42\x82"), None);
+    }
+
+    #[test]
+    fn mp3_without_id3() {
+        // MPEG-1 layer III, 128kbps, 44.1kHz: 417 byte frames
+        let mut mp3 = vec![0; 417 + 4];
+        mp3[..4].copy_from_slice(b"\xFF\xFB\x90\x00");
+        mp3[417..].copy_from_slice(b"\xFF\xFB\x90\x00");
+        assert_eq!(audio_video(&mp3).unwrap(), "audio/mpeg");
+
+        // the next frame is missing
+        assert_eq!(audio_video(&mp3[..417]), None);
+        mp3[417] = 0;
+        assert_eq!(audio_video(&mp3), None);
+
+        // free bitrate frames can't be measured
+        assert_eq!(audio_video(b"\xFF\xFB\x00\x00\xFF\xFB\x00\x00"), None);
+    }
+
+    #[test]
+    fn fonts() {
+        let mut eot = vec![0x11; 36];
+        eot[34..].copy_from_slice(b"LP");
+        assert_eq!(font(&eot).unwrap(), "application/vnd.ms-fontobject");
+        assert_eq!(font(b"\0\x01\0\0\0\x10").unwrap(), "font/ttf");
+        assert_eq!(font(b"OTTO").unwrap(), "font/otf");
+        assert_eq!(font(b"ttcf").unwrap(), "font/collection");
+        assert_eq!(font(b"wOFF").unwrap(), FONT_WOFF);
+
+        // fonts are only sniffed on request
+        assert_eq!(compute(b"wOFF\0\x01\0\0", None), APPLICATION_OCTET_STREAM);
+    }
+
+    #[test]
+    fn archives() {
+        assert_eq!(archive(b"\x1F\x8B\x08\0").unwrap(), "application/x-gzip");
+        assert_eq!(archive(b"Rar!\x1A\x07\0").unwrap(), "application/x-rar-compressed");
+        assert_eq!(archive(b"Rar!\x1A\x07\x01"), None);
+    }
+
+    #[test]
+    fn resource_header_only() {
+        let mut html = vec![b' '; RESOURCE_HEADER_LEN];
+        html.extend_from_slice(b"<html>");
+        assert_eq!(compute(&html, None), TEXT_PLAIN);
+
+        let mut text = b"text".to_vec();
+        text.resize(RESOURCE_HEADER_LEN, b'a');
+        text.push(0);
+        assert_eq!(compute(&text, None), TEXT_PLAIN);
+    }
+}
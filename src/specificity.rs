@@ -0,0 +1,66 @@
+/// How a `MediaRange` matches a `MediaType`.
+///
+/// The variants are ordered from least to most specific.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum MatchKind {
+    /// The range is `*/*`.
+    FullWildcard,
+    /// The range is `type/*`.
+    SubtypeWildcard,
+    /// The range is `type/*+suffix`.
+    SuffixWildcard,
+    /// The range has the exact type and subtype.
+    Exact,
+}
+
+/// How specific a `MediaRange` is, used to order ranges by precedence.
+///
+/// A `Specificity` is compared first by its [`MatchKind`], and then by how
+/// many parameters it has. So `text/plain;format=flowed` is more specific
+/// than `text/plain`, which is more specific than `text/*`, which is more
+/// specific than `*/*`.
+///
+/// # Example
+///
+/// ```
+/// use mime::{MatchKind, MediaRange};
+///
+/// let flowed = MediaRange::parse("text/plain; format=flowed").unwrap();
+/// let plain = MediaRange::from(mime::TEXT_PLAIN);
+///
+/// assert!(flowed.specificity() > plain.specificity());
+/// assert!(plain.specificity() > mime::TEXT_STAR.specificity());
+/// assert!(mime::TEXT_STAR.specificity() > mime::STAR_STAR.specificity());
+///
+/// assert_eq!(flowed.specificity().kind(), MatchKind::Exact);
+/// assert_eq!(flowed.specificity().params(), 1);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Specificity {
+    kind: MatchKind,
+    params: usize,
+}
+
+impl Specificity {
+    pub(crate) fn new(kind: MatchKind, params: usize) -> Specificity {
+        Specificity {
+            kind,
+            params,
+        }
+    }
+
+    /// Get whether this is an exact or wildcard match.
+    #[inline]
+    pub fn kind(&self) -> MatchKind {
+        self.kind
+    }
+
+    /// Get how many parameters are matched.
+    ///
+    /// The `q` parameter, and any extension parameters after it, are not
+    /// counted.
+    #[inline]
+    pub fn params(&self) -> usize {
+        self.params
+    }
+}
@@ -0,0 +1,210 @@
+use std::fmt;
+
+use crate::MediaType;
+
+/// A structured syntax suffix, such as the `+json` in
+/// `application/vnd.api+json`.
+///
+/// The suffix says that the media type is built on top of another format,
+/// so a generic parser for that format can still read it. The known
+/// suffixes are those in the [IANA registry](https://www.iana.org/assignments/media-type-structured-suffix/),
+/// from [RFC 6839](https://tools.ietf.org/html/rfc6839) and later.
+///
+/// # Example
+///
+/// ```
+/// use mime::StructuredSuffix;
+///
+/// let mt = mime::MediaType::parse("application/vnd.api+json").unwrap();
+/// assert_eq!(mt.structured_suffix(), Some(StructuredSuffix::Json));
+///
+/// let mt = mime::MediaType::parse("application/vnd.foo+bar").unwrap();
+/// assert_eq!(mt.structured_suffix(), Some(StructuredSuffix::Unknown("bar")));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum StructuredSuffix<'a> {
+    /// `+xml`, [RFC 7303](https://tools.ietf.org/html/rfc7303).
+    Xml,
+    /// `+json`, [RFC 6839](https://tools.ietf.org/html/rfc6839#section-3.1).
+    Json,
+    /// `+ber`, the ASN.1 Basic Encoding Rules.
+    Ber,
+    /// `+der`, the ASN.1 Distinguished Encoding Rules.
+    Der,
+    /// `+fastinfoset`, the binary encoding of XML.
+    FastInfoset,
+    /// `+wbxml`, WAP Binary XML.
+    Wbxml,
+    /// `+zip`, a ZIP archive.
+    Zip,
+    /// `+gzip`, a gzip compressed file.
+    Gzip,
+    /// `+cbor`, [RFC 8949](https://tools.ietf.org/html/rfc8949#section-9.5).
+    Cbor,
+    /// `+json-seq`, [RFC 8091](https://tools.ietf.org/html/rfc8091).
+    JsonSeq,
+    /// `+cbor-seq`, [RFC 8742](https://tools.ietf.org/html/rfc8742).
+    CborSeq,
+    /// `+sqlite3`, an SQLite database file.
+    Sqlite3,
+    /// `+jwt`, a JSON Web Token.
+    Jwt,
+    /// `+zstd`, a Zstandard compressed file.
+    Zstd,
+    /// Any other suffix.
+    Unknown(&'a str),
+}
+
+impl<'a> StructuredSuffix<'a> {
+    /// Get the `StructuredSuffix` for a suffix, without the `+`.
+    ///
+    /// Suffixes are case-insensitive.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mime::StructuredSuffix;
+    ///
+    /// assert_eq!(StructuredSuffix::new("cbor-seq"), StructuredSuffix::CborSeq);
+    /// assert_eq!(StructuredSuffix::new("XML"), StructuredSuffix::Xml);
+    /// assert_eq!(StructuredSuffix::new("yaml"), StructuredSuffix::Unknown("yaml"));
+    /// ```
+    pub fn new(suffix: &'a str) -> StructuredSuffix<'a> {
+        KNOWN
+            .iter()
+            .find(|known| known.as_str().eq_ignore_ascii_case(suffix))
+            .copied()
+            .unwrap_or(StructuredSuffix::Unknown(suffix))
+    }
+
+    /// Get the suffix as a string, without the `+`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// assert_eq!(mime::StructuredSuffix::JsonSeq.as_str(), "json-seq");
+    /// ```
+    pub fn as_str(&self) -> &'a str {
+        match *self {
+            StructuredSuffix::Xml => "xml",
+            StructuredSuffix::Json => "json",
+            StructuredSuffix::Ber => "ber",
+            StructuredSuffix::Der => "der",
+            StructuredSuffix::FastInfoset => "fastinfoset",
+            StructuredSuffix::Wbxml => "wbxml",
+            StructuredSuffix::Zip => "zip",
+            StructuredSuffix::Gzip => "gzip",
+            StructuredSuffix::Cbor => "cbor",
+            StructuredSuffix::JsonSeq => "json-seq",
+            StructuredSuffix::CborSeq => "cbor-seq",
+            StructuredSuffix::Sqlite3 => "sqlite3",
+            StructuredSuffix::Jwt => "jwt",
+            StructuredSuffix::Zstd => "zstd",
+            StructuredSuffix::Unknown(s) => s,
+        }
+    }
+
+    /// Get the media type of the format this suffix refers to.
+    ///
+    /// `+ber` and `+der` don't have a media type of their own, and an
+    /// unknown suffix can't be looked up, so those return `None`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mime::StructuredSuffix;
+    ///
+    /// assert_eq!(StructuredSuffix::Json.underlying_format(), Some(mime::APPLICATION_JSON));
+    /// assert_eq!(StructuredSuffix::Wbxml.underlying_format().unwrap(), "application/vnd.wap.wbxml");
+    /// assert!(StructuredSuffix::Der.underlying_format().is_none());
+    /// ```
+    pub fn underlying_format(&self) -> Option<MediaType> {
+        let essence = match *self {
+            StructuredSuffix::Json => return Some(crate::APPLICATION_JSON),
+            StructuredSuffix::Xml => "application/xml",
+            StructuredSuffix::FastInfoset => "application/fastinfoset",
+            StructuredSuffix::Wbxml => "application/vnd.wap.wbxml",
+            StructuredSuffix::Zip => "application/zip",
+            StructuredSuffix::Gzip => "application/gzip",
+            StructuredSuffix::Cbor => "application/cbor",
+            StructuredSuffix::JsonSeq => "application/json-seq",
+            StructuredSuffix::CborSeq => "application/cbor-seq",
+            StructuredSuffix::Sqlite3 => "application/vnd.sqlite3",
+            StructuredSuffix::Jwt => "application/jwt",
+            StructuredSuffix::Zstd => "application/zstd",
+            StructuredSuffix::Ber |
+            StructuredSuffix::Der |
+            StructuredSuffix::Unknown(_) => return None,
+        };
+        Some(MediaType::parse(essence).expect("underlying formats are valid"))
+    }
+}
+
+static KNOWN: &[StructuredSuffix<'static>] = &[
+    StructuredSuffix::Xml,
+    StructuredSuffix::Json,
+    StructuredSuffix::Ber,
+    StructuredSuffix::Der,
+    StructuredSuffix::FastInfoset,
+    StructuredSuffix::Wbxml,
+    StructuredSuffix::Zip,
+    StructuredSuffix::Gzip,
+    StructuredSuffix::Cbor,
+    StructuredSuffix::JsonSeq,
+    StructuredSuffix::CborSeq,
+    StructuredSuffix::Sqlite3,
+    StructuredSuffix::Jwt,
+    StructuredSuffix::Zstd,
+];
+
+impl<'a> fmt::Display for StructuredSuffix<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_known_roundtrip() {
+        for &known in super::KNOWN {
+            assert_eq!(StructuredSuffix::new(known.as_str()), known);
+            assert_ne!(known, StructuredSuffix::Unknown(known.as_str()));
+        }
+        assert_eq!(StructuredSuffix::new("Json-Seq"), StructuredSuffix::JsonSeq);
+        assert_eq!(StructuredSuffix::new(""), StructuredSuffix::Unknown(""));
+    }
+
+    #[test]
+    fn test_underlying_formats_parse() {
+        for &known in super::KNOWN {
+            if let Some(mt) = known.underlying_format() {
+                assert_eq!(mt.type_(), APPLICATION);
+                assert!(!mt.has_params());
+            }
+        }
+        assert!(StructuredSuffix::Unknown("yaml").underlying_format().is_none());
+    }
+
+    #[test]
+    fn test_media_type_underlying_format() {
+        let mt = MediaType::parse("application/vnd.api+json; charset=utf-8").unwrap();
+        assert_eq!(mt.structured_suffix(), Some(StructuredSuffix::Json));
+        assert_eq!(mt.underlying_format(), Some(APPLICATION_JSON));
+
+        assert_eq!(IMAGE_SVG.underlying_format().unwrap(), "application/xml");
+
+        let mt = MediaType::parse("application/EXAMPLE+ZSTD").unwrap();
+        assert_eq!(mt.structured_suffix(), Some(StructuredSuffix::Zstd));
+        assert_eq!(mt.underlying_format().unwrap(), "application/zstd");
+
+        let mt = MediaType::parse("application/vnd.foo+yaml").unwrap();
+        assert_eq!(mt.structured_suffix(), Some(StructuredSuffix::Unknown("yaml")));
+        assert!(mt.underlying_format().is_none());
+
+        assert!(APPLICATION_JSON.structured_suffix().is_none());
+        assert!(APPLICATION_JSON.underlying_format().is_none());
+    }
+}
@@ -0,0 +1,39 @@
+/// The grammar used to parse a `MediaType`.
+///
+/// Used with [`MediaType::parse_with`](crate::MediaType::parse_with).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Syntax {
+    /// The HTTP grammar of [RFC 7231](https://tools.ietf.org/html/rfc7231#section-3.1.1.1).
+    ///
+    /// This is what [`MediaType::parse`](crate::MediaType::parse) uses.
+    Http,
+    /// The MIME header grammar of [RFC 2045](https://tools.ietf.org/html/rfc2045#section-5.1),
+    /// as used by email.
+    ///
+    /// Comments, folding whitespace, and whitespace around the `/`, `;`,
+    /// and `=` separators are allowed.
+    Mime,
+    /// The [WHATWG](https://mimesniff.spec.whatwg.org/#parsing-a-mime-type)
+    /// algorithm used by browsers.
+    ///
+    /// This is what [`MediaType::parse_lenient`](crate::MediaType::parse_lenient)
+    /// uses.
+    Whatwg,
+}
+
+impl Syntax {
+    pub(crate) fn parser(self) -> mime_parse::Parser {
+        match self {
+            Syntax::Http => mime_parse::Parser::cannot_range(),
+            Syntax::Mime => mime_parse::Parser::rfc2045(),
+            Syntax::Whatwg => mime_parse::Parser::lenient(),
+        }
+    }
+}
+
+impl Default for Syntax {
+    #[inline]
+    fn default() -> Syntax {
+        Syntax::Http
+    }
+}
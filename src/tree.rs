@@ -0,0 +1,89 @@
+/// The registration tree of a media type's subtype.
+///
+/// From [RFC 6838](https://tools.ietf.org/html/rfc6838#section-3), the
+/// tree is declared by a facet at the start of the subtype, such as the
+/// `vnd.` in `application/vnd.github.v3+json`.
+///
+/// # Example
+///
+/// ```
+/// use mime::{MediaType, RegistrationTree};
+///
+/// let mt = MediaType::parse("application/vnd.github.v3+json").unwrap();
+/// assert_eq!(mt.tree(), RegistrationTree::Vendor);
+///
+/// assert_eq!(mime::TEXT_PLAIN.tree(), RegistrationTree::Standards);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum RegistrationTree {
+    /// No facet, such as `text/plain`.
+    Standards,
+    /// The `vnd.` facet, for types from a vendor.
+    Vendor,
+    /// The `prs.` facet, for personal or experimental types.
+    Personal,
+    /// The `x.` facet, for types that won't be registered.
+    ///
+    /// Subtypes with the `x-` prefix are also unregistered, though
+    /// RFC 6838 deprecates it.
+    Unregistered,
+}
+
+// Splits the facet off of a subtype without its suffix.
+pub(crate) fn split(subtype: &str) -> (RegistrationTree, Option<&str>) {
+    let facets = [
+        ("vnd.", RegistrationTree::Vendor),
+        ("prs.", RegistrationTree::Personal),
+        ("x.", RegistrationTree::Unregistered),
+        ("x-", RegistrationTree::Unregistered),
+    ];
+    for &(prefix, tree) in &facets {
+        if subtype.len() > prefix.len() && subtype[..prefix.len()].eq_ignore_ascii_case(prefix) {
+            return (tree, Some(&subtype[prefix.len()..]));
+        }
+    }
+    (RegistrationTree::Standards, None)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn parse(s: &str) -> MediaType {
+        MediaType::parse(s).unwrap()
+    }
+
+    #[test]
+    fn test_trees() {
+        let cases = [
+            ("text/plain", RegistrationTree::Standards, None),
+            ("image/svg+xml", RegistrationTree::Standards, None),
+            ("application/vnd.github.v3+json", RegistrationTree::Vendor, Some("github.v3")),
+            ("application/vnd.ms-excel", RegistrationTree::Vendor, Some("ms-excel")),
+            ("application/vnd.foo+bar+xml", RegistrationTree::Vendor, Some("foo+bar")),
+            ("text/prs.lines.tag", RegistrationTree::Personal, Some("lines.tag")),
+            ("application/x.foo", RegistrationTree::Unregistered, Some("foo")),
+            ("application/x-www-form-urlencoded", RegistrationTree::Unregistered, Some("www-form-urlencoded")),
+            ("application/X-Tar", RegistrationTree::Unregistered, Some("tar")),
+            // a facet needs a name after it
+            ("application/vnd.", RegistrationTree::Standards, None),
+            ("application/vnd.+json", RegistrationTree::Standards, None),
+            ("application/vndfoo", RegistrationTree::Standards, None),
+            ("application/xml", RegistrationTree::Standards, None),
+        ];
+        for &(src, tree, facet) in &cases {
+            let mt = parse(src);
+            assert_eq!(mt.tree(), tree, "{}", src);
+            assert_eq!(mt.facet_name(), facet, "{}", src);
+        }
+    }
+
+    #[test]
+    fn test_x_prefix() {
+        assert!(parse("application/x-tar").has_x_prefix());
+        assert!(APPLICATION_WWW_FORM_URLENCODED.has_x_prefix());
+        assert!(!parse("application/x.tar").has_x_prefix());
+        assert!(!parse("application/vnd.x-tar").has_x_prefix());
+        assert!(!TEXT_PLAIN.has_x_prefix());
+    }
+}
@@ -1,9 +1,11 @@
+use std::cmp::Ordering;
 use std::fmt;
+use std::hash::{Hash, Hasher};
 use std::str::FromStr;
 
 use mime_parse::{Mime, Parse};
 
-use crate::{InvalidMime, Value};
+use crate::{InvalidMime, RegistrationTree, StructuredSuffix, Syntax, Value};
 
 /// A parsed media type (or "MIME").
 ///
@@ -57,11 +59,21 @@ pub struct MediaType {
 impl MediaType {
     /// Parse a string as a `MediaType`.
     ///
+    /// The source can also be the bytes of a header value, or an owned
+    /// `String` or `Box<str>`, whose buffer is reused if the media type
+    /// needs to be stored.
+    ///
     /// # Example
     ///
     /// ```
     /// let mt = mime::MediaType::parse("text/plain").unwrap();
     /// assert_eq!(mt, mime::TEXT_PLAIN);
+    ///
+    /// let mt = mime::MediaType::parse(&b"text/html; charset=utf-8"[..]).unwrap();
+    /// assert_eq!(mt, mime::TEXT_HTML_UTF_8);
+    ///
+    /// let mt = mime::MediaType::parse(String::from("image/png; q=1")).unwrap();
+    /// assert_eq!(mt.param("q").unwrap(), "1");
     /// ```
     ///
     /// # Errors
@@ -75,6 +87,100 @@ impl MediaType {
             .map_err(|e| InvalidMime { inner: e })
     }
 
+    /// Parse a static string as a `MediaType`, at compile time if used in
+    /// a `const` or `static`.
+    ///
+    /// Unlike [`parse`](MediaType::parse), this can't lowercase or decode
+    /// anything, so the source must already be in the form that `parse`
+    /// would store: a lowercase type, subtype and parameter names, and a
+    /// lowercase `charset` value. At most two parameters are supported,
+    /// and not RFC 2231 extended ones.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mime::MediaType;
+    ///
+    /// const VND_MYAPP: MediaType = MediaType::from_static("application/vnd.myapp+json; v=2");
+    /// assert_eq!(VND_MYAPP.suffix(), Some(mime::JSON));
+    /// assert_eq!(VND_MYAPP.param("v").unwrap(), "2");
+    ///
+    /// static TEXT: MediaType = MediaType::from_static("text/plain; charset=utf-8");
+    /// assert_eq!(TEXT, mime::TEXT_PLAIN_UTF_8);
+    /// ```
+    ///
+    /// An invalid source fails to compile:
+    ///
+    /// ```compile_fail
+    /// const BAD: mime::MediaType = mime::MediaType::from_static("text/plain; nope");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the source is not a valid media type, or is not in the
+    /// form described above. In a `const`, that's a compile error instead.
+    pub const fn from_static(source: &'static str) -> Self {
+        MediaType {
+            mime: Mime::from_static(source),
+        }
+    }
+
+    /// Parse a string as a `MediaType`, the same way a browser would.
+    ///
+    /// This follows the [WHATWG](https://mimesniff.spec.whatwg.org/#parsing-a-mime-type)
+    /// algorithm instead of the stricter HTTP grammar. Surrounding
+    /// whitespace is trimmed, parameters that are not valid are ignored
+    /// instead of being an error, and if a parameter is repeated, the first
+    /// value is kept.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mt = mime::MediaType::parse_lenient(" text/html;; charset=utf-8; charset=latin1;foo").unwrap();
+    /// assert_eq!(mt, mime::TEXT_HTML_UTF_8);
+    ///
+    /// let mt = mime::MediaType::parse_lenient("text/plain; title = nope; size=\"big\"junk").unwrap();
+    /// assert_eq!(mt, "text/plain; size=big");
+    ///
+    /// // still strict
+    /// assert!(mime::MediaType::parse("text/plain; title = nope").is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the type or subtype is missing or not a valid
+    /// token.
+    pub fn parse_lenient(source: impl Parse) -> Result<Self, InvalidMime> {
+        MediaType::parse_with(source, Syntax::Whatwg)
+    }
+
+    /// Parse a string as a `MediaType`, using a specific [`Syntax`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mime::{MediaType, Syntax};
+    ///
+    /// // from an email header
+    /// let header = "multipart/mixed; (a comment)\r\n\tboundary = \"simple boundary\"";
+    /// let mt = MediaType::parse_with(header, Syntax::Mime).unwrap();
+    ///
+    /// assert_eq!(mt.param(mime::BOUNDARY).unwrap(), "simple boundary");
+    /// assert!(MediaType::parse_with(header, Syntax::Http).is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the source is not a valid media type in that
+    /// syntax.
+    pub fn parse_with(source: impl Parse, syntax: Syntax) -> Result<Self, InvalidMime> {
+        syntax
+            .parser()
+            .parse(source)
+            .map(|mime| MediaType { mime })
+            .map_err(|e| InvalidMime { inner: e })
+    }
+
     /// Get the top level media type for this `MediaType`.
     ///
     /// # Example
@@ -124,6 +230,203 @@ impl MediaType {
         self.mime.suffix()
     }
 
+    /// Get the +suffix of this `MediaType` as a [`StructuredSuffix`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mime::StructuredSuffix;
+    ///
+    /// assert_eq!(mime::IMAGE_SVG.structured_suffix(), Some(StructuredSuffix::Xml));
+    /// assert!(mime::TEXT_PLAIN.structured_suffix().is_none());
+    /// ```
+    #[inline]
+    pub fn structured_suffix(&self) -> Option<StructuredSuffix<'_>> {
+        self.suffix().map(StructuredSuffix::new)
+    }
+
+    /// Get the media type of the format the +suffix says this is built on.
+    ///
+    /// This lets a generic handler accept any type with a certain suffix,
+    /// instead of listing each one. Returns `None` if there is no suffix,
+    /// or it doesn't have a media type of its own.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let api = mime::MediaType::parse("application/vnd.api+json").unwrap();
+    /// assert_eq!(api.underlying_format(), Some(mime::APPLICATION_JSON));
+    ///
+    /// assert!(mime::APPLICATION_JSON.underlying_format().is_none());
+    /// ```
+    #[inline]
+    pub fn underlying_format(&self) -> Option<MediaType> {
+        self.structured_suffix()?.underlying_format()
+    }
+
+    /// Get the [`RegistrationTree`] of the subtype.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mime::{MediaType, RegistrationTree};
+    ///
+    /// let mt = MediaType::parse("text/prs.lines.tag").unwrap();
+    /// assert_eq!(mt.tree(), RegistrationTree::Personal);
+    ///
+    /// assert_eq!(mime::APPLICATION_WWW_FORM_URLENCODED.tree(), RegistrationTree::Unregistered);
+    /// assert_eq!(mime::APPLICATION_JSON.tree(), RegistrationTree::Standards);
+    /// ```
+    #[inline]
+    pub fn tree(&self) -> RegistrationTree {
+        crate::tree::split(self.subtype_without_suffix()).0
+    }
+
+    /// Get the name after the facet of the subtype, without the +suffix.
+    ///
+    /// Returns `None` for the standards tree, which has no facet.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mt = mime::MediaType::parse("application/vnd.github.v3+json").unwrap();
+    /// assert_eq!(mt.facet_name(), Some("github.v3"));
+    ///
+    /// assert!(mime::APPLICATION_JSON.facet_name().is_none());
+    /// ```
+    #[inline]
+    pub fn facet_name(&self) -> Option<&str> {
+        crate::tree::split(self.subtype_without_suffix()).1
+    }
+
+    /// Returns true if the subtype starts with `x-`.
+    ///
+    /// [RFC 6838](https://tools.ietf.org/html/rfc6838#section-3.4)
+    /// deprecates the `x-` prefix. Unregistered types should use the `x.`
+    /// facet instead, or better, be registered.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// assert!(mime::APPLICATION_WWW_FORM_URLENCODED.has_x_prefix());
+    ///
+    /// let mt = mime::MediaType::parse("application/x.foo").unwrap();
+    /// assert!(!mt.has_x_prefix());
+    /// ```
+    pub fn has_x_prefix(&self) -> bool {
+        let subtype = self.subtype_without_suffix();
+        subtype.len() > 2 && subtype[..2].eq_ignore_ascii_case("x-")
+    }
+
+    fn subtype_without_suffix(&self) -> &str {
+        let subtype = self.subtype();
+        match self.suffix() {
+            Some(suffix) => &subtype[..subtype.len() - suffix.len() - 1],
+            None => subtype,
+        }
+    }
+
+    /// Get the [`Charset`](crate::Charset) of the `charset` parameter.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mime::{Charset, MediaType};
+    ///
+    /// assert_eq!(mime::TEXT_PLAIN_UTF_8.charset(), Some(Charset::Utf8));
+    ///
+    /// let mt = MediaType::parse("text/plain; charset=\"ISO_8859-1:1987\"").unwrap();
+    /// assert_eq!(mt.charset(), Some(Charset::Iso8859_1));
+    ///
+    /// assert_eq!(mime::TEXT_PLAIN.charset(), None);
+    /// ```
+    pub fn charset(&self) -> Option<crate::Charset<'_>> {
+        crate::charset::charset(self)
+    }
+
+    /// Get the charset of this type, or its default if there's no
+    /// `charset` parameter.
+    ///
+    /// `text/*` types default to `US-ASCII`, from
+    /// [RFC 2046](https://tools.ietf.org/html/rfc2046#section-4.1.2), and
+    /// JSON to `UTF-8`. A few `text/*` registrations, such as
+    /// `text/javascript` and `text/calendar`, default to `UTF-8` too.
+    /// Other types don't have a default, and return `None`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mime::Charset;
+    ///
+    /// assert_eq!(mime::TEXT_PLAIN.effective_charset(), Some(Charset::UsAscii));
+    /// assert_eq!(mime::TEXT_PLAIN_UTF_8.effective_charset(), Some(Charset::Utf8));
+    /// assert_eq!(mime::APPLICATION_JSON.effective_charset(), Some(Charset::Utf8));
+    /// assert_eq!(mime::IMAGE_PNG.effective_charset(), None);
+    /// ```
+    pub fn effective_charset(&self) -> Option<crate::Charset<'_>> {
+        self.charset().or_else(|| crate::charset::default_charset(self))
+    }
+
+    /// Create a `multipart/*` type with a new random boundary.
+    ///
+    /// The boundary is 40 characters long, from those allowed by
+    /// [RFC 2046](https://tools.ietf.org/html/rfc2046#section-5.1.1), and
+    /// is quoted if it needs to be.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mt = mime::MediaType::multipart(mime::FORM_DATA).unwrap();
+    /// assert_eq!(mt.clone().without_params(), mime::MULTIPART_FORM_DATA);
+    /// assert_eq!(mt.boundary().unwrap().to_content().len(), 40);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the subtype is not a valid token.
+    pub fn multipart(subtype: &str) -> Result<MediaType, InvalidMime> {
+        crate::multipart::multipart(subtype)
+    }
+
+    /// Get the `boundary` parameter of a `multipart/*` type.
+    ///
+    /// Returns `None` if this isn't a multipart type, or the boundary is
+    /// missing or not valid. A valid boundary is 1 to 70 characters long,
+    /// from those allowed by [RFC 2046](https://tools.ietf.org/html/rfc2046#section-5.1.1),
+    /// and doesn't end with a space.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mime::MediaType;
+    ///
+    /// let mt = MediaType::parse("multipart/mixed; boundary=\"simple boundary\"").unwrap();
+    /// assert_eq!(mt.boundary().unwrap(), "simple boundary");
+    ///
+    /// let mt = MediaType::parse("multipart/mixed; boundary=\"no;semicolons\"").unwrap();
+    /// assert!(mt.boundary().is_none());
+    /// ```
+    pub fn boundary(&self) -> Option<Value<'_>> {
+        crate::multipart::boundary(self)
+    }
+
+    /// Get the codecs of the `codecs` parameter.
+    ///
+    /// The codecs are parsed as described in the [`codecs`](crate::codecs)
+    /// module. If there is no `codecs` parameter, the iterator is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mt = mime::MediaType::parse(r#"video/webm; codecs="vp09.00.10.08, opus""#).unwrap();
+    ///
+    /// let fourccs = mt.codecs().map(|codec| codec.fourcc().to_owned()).collect::<Vec<_>>();
+    /// assert_eq!(fourccs, ["vp09", "opus"]);
+    /// ```
+    pub fn codecs(&self) -> crate::codecs::Iter<'_> {
+        crate::codecs::codecs(self)
+    }
+
     /// Look up a parameter by name.
     ///
     /// # Example
@@ -137,6 +440,18 @@ impl MediaType {
     /// let mime = "multipart/form-data; boundary=ABCDEFG".parse::<mime::MediaType>().unwrap();
     /// assert_eq!(mime.param(mime::BOUNDARY).unwrap(), "ABCDEFG");
     /// ```
+    ///
+    /// [RFC 2231](https://tools.ietf.org/html/rfc2231) extended parameters,
+    /// such as `name*=utf-8''...` or continuations like `name*0`, `name*1`,
+    /// are joined and decoded, and looked up by their plain name. They are
+    /// preferred over a plain parameter of the same name.
+    ///
+    /// ```
+    /// let mime = mime::MediaType::parse(
+    ///     "application/pdf; name=\"EUR rates.pdf\"; name*0*=utf-8''%E2%82%AC; name*1=\" rates.pdf\""
+    /// ).unwrap();
+    /// assert_eq!(mime.param("name").unwrap(), "\u{20ac} rates.pdf");
+    /// ```
     pub fn param<'a>(&'a self, attr: &str) -> Option<Value<'a>> {
         crate::value::param(&self.mime, attr)
     }
@@ -164,7 +479,7 @@ impl MediaType {
     /// assert!(params.next().is_none());
     /// ```
     #[inline]
-    pub fn params(&self) -> impl Iterator<Item = (&str, Value)> {
+    pub fn params(&self) -> impl Iterator<Item = (&str, Value<'_>)> {
         crate::value::params(&self.mime)
     }
 
@@ -205,6 +520,60 @@ impl MediaType {
         self
     }
 
+    /// Get the preferred form of a legacy or duplicate media type.
+    ///
+    /// Clients still send types like `image/jpg`, `audio/mp3`, or
+    /// `application/x-javascript`. This maps those aliases to the type that
+    /// should be used instead, keeping any parameters, and returns any other
+    /// type unchanged.
+    ///
+    /// Equality is not affected, `image/jpg` is never equal to `image/jpeg`,
+    /// so compare canonicalized types if they should be treated the same.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mime::MediaType;
+    ///
+    /// let jpg = MediaType::parse("image/jpg").unwrap();
+    /// assert_ne!(jpg, mime::IMAGE_JPEG);
+    /// assert_eq!(jpg.canonicalize(), mime::IMAGE_JPEG);
+    ///
+    /// let js = MediaType::parse("application/x-javascript; charset=utf-8").unwrap();
+    /// assert_eq!(js.canonicalize(), "text/javascript; charset=utf-8");
+    ///
+    /// assert_eq!(mime::TEXT_PLAIN.canonicalize(), mime::TEXT_PLAIN);
+    /// ```
+    pub fn canonicalize(&self) -> MediaType {
+        match self.mime.canonicalize() {
+            Some(mime) => MediaType { mime },
+            None => self.clone(),
+        }
+    }
+
+    /// Returns true if this type can be treated as another type.
+    ///
+    /// This is true if they are the same, or if this type is a subclass of
+    /// the other in the built-in [`Hierarchy`](crate::Hierarchy), such as
+    /// every `text/*` type being `text/plain`, and every `+xml` type being
+    /// `application/xml`. Parameters of `other` must also be in this type.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mime::MediaType;
+    ///
+    /// let csv = MediaType::parse("text/csv; charset=utf-8").unwrap();
+    /// assert!(csv.is_a(&mime::TEXT_PLAIN));
+    /// assert!(csv.is_a(&mime::TEXT_PLAIN_UTF_8));
+    ///
+    /// assert!(mime::IMAGE_SVG.is_a(&MediaType::parse("application/xml").unwrap()));
+    /// assert!(!mime::TEXT_PLAIN.is_a(&csv));
+    /// ```
+    pub fn is_a(&self, other: &MediaType) -> bool {
+        crate::Hierarchy::new().is_a(self, other)
+    }
+
     #[cfg(test)]
     pub(super) fn test_assert_asterisks(&self) {
         assert!(!self.as_ref().contains('*'), "{:?} contains an asterisk", self);
@@ -217,6 +586,27 @@ impl PartialEq for MediaType {
     }
 }
 
+impl Eq for MediaType {}
+
+impl Hash for MediaType {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        crate::cmp::mime_hash(&self.mime, state);
+    }
+}
+
+impl PartialOrd for MediaType {
+    #[inline]
+    fn partial_cmp(&self, other: &MediaType) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for MediaType {
+    fn cmp(&self, other: &MediaType) -> Ordering {
+        crate::cmp::mime_cmp(&self.mime, &other.mime)
+    }
+}
+
 impl PartialEq<str> for MediaType {
     fn eq(&self, s: &str) -> bool {
         crate::cmp::str_eq(&self.mime, s)
@@ -290,6 +680,47 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_parse_sources() {
+        let sources: [&[u8]; 3] = [
+            b"text/plain; charset=utf-8",
+            b"TEXT/PLAIN; CHARSET=UTF-8",
+            b"text/plain; charset=\"utf-8\"",
+        ];
+        for &src in &sources {
+            let s = std::str::from_utf8(src).unwrap();
+            assert_eq!(MediaType::parse(src).unwrap(), TEXT_PLAIN_UTF_8);
+            assert_eq!(MediaType::parse(String::from(s)).unwrap(), TEXT_PLAIN_UTF_8);
+            assert_eq!(MediaType::parse(Box::<str>::from(s)).unwrap(), TEXT_PLAIN_UTF_8);
+            assert_eq!(MediaType::parse(std::borrow::Cow::Borrowed(s)).unwrap(), TEXT_PLAIN_UTF_8);
+        }
+
+        MediaType::parse(&b"text/\xffplain"[..]).unwrap_err();
+        MediaRange::parse(&b"text/*"[..]).unwrap();
+        MediaType::parse_lenient(&b" text/plain ; x=\"\xc3\xa9\""[..]).unwrap();
+
+        // non-ASCII outside a quoted-string is up to the grammar
+        let lenient = MediaType::parse_lenient(&b"text/plain; a=caf\xc3\xa9; b=c"[..]).unwrap();
+        assert_eq!(lenient, MediaType::parse_lenient("text/plain; a=caf\u{e9}; b=c").unwrap());
+        assert_eq!(lenient.param("a").unwrap(), "caf\u{e9}");
+
+        let src = &b"text/plain; (caf\xc3\xa9) b=c"[..];
+        let mime = MediaType::parse_with(src, Syntax::Mime).unwrap();
+        assert_eq!(mime, MediaType::parse_with("text/plain; (caf\u{e9}) b=c", Syntax::Mime).unwrap());
+        assert_eq!(mime.param("b").unwrap(), "c");
+
+        // but still invalid in the strict grammar
+        let err = MediaType::parse(&b"text/caf\xc3\xa9"[..]).unwrap_err();
+        assert_eq!(err.to_string(), MediaType::parse("text/caf\u{e9}").unwrap_err().to_string());
+        MediaType::parse_lenient(&b"text/plain; a=\xff"[..]).unwrap_err();
+
+        // only quoted-strings are checked for UTF-8 in the strict grammar
+        let mime = MediaType::parse(&b"text/plain; a=\"caf\xc3\xa9\""[..]).unwrap();
+        assert_eq!(mime.param("a").unwrap(), "caf\u{e9}");
+        MediaType::parse(&b"text/plain; a=\"\xff\""[..]).unwrap_err();
+        MediaRange::parse(&b"text/*; a=\xff"[..]).unwrap_err();
+    }
+
     #[test]
     fn test_type_() {
         assert_eq!(TEXT_PLAIN.type_(), TEXT);
@@ -395,6 +826,31 @@ mod tests {
         MediaType::parse(&s).expect_err("errors OVER max length");
     }
 
+    #[test]
+    fn test_from_static() {
+        const FORM: MediaType = MediaType::from_static("multipart/form-data; boundary=AbCd; charset=utf-8");
+        static PLAIN: MediaType = MediaType::from_static("text/plain; charset=utf-8; format=flowed");
+
+        let parsed = MediaType::parse("Multipart/Form-Data; charset=UTF-8; boundary=AbCd").unwrap();
+        assert_eq!(FORM, parsed);
+        assert_eq!(FORM.param(BOUNDARY).unwrap(), "AbCd");
+        assert_eq!(FORM.param(CHARSET).unwrap(), UTF_8);
+
+        let mut set = std::collections::HashSet::new();
+        set.insert(parsed);
+        assert!(set.contains(&FORM));
+
+        assert_eq!(PLAIN.param("format").unwrap(), "flowed");
+        assert_eq!(PLAIN.clone().without_params(), TEXT_PLAIN);
+        assert_eq!(MediaType::from_static("text/plain"), TEXT_PLAIN);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_from_static_uppercase() {
+        MediaType::from_static("Text/Plain");
+    }
+
     #[test]
     fn test_case_sensitive_values() {
         let mime = MediaType::parse("multipart/form-data; charset=BASE64; boundary=ABCDEFG").unwrap();
@@ -530,5 +986,42 @@ mod tests {
 
         assert_ne!(mime1, mime2);
     }
-}
 
+    #[test]
+    fn test_parse_with_syntax() {
+        let src = "text/plain (comment);\r\n charset = \"UTF-8\"";
+        MediaType::parse_with(src, Syntax::Http).unwrap_err();
+        MediaType::parse_with(src, Syntax::Whatwg).unwrap_err();
+        assert_eq!(MediaType::parse_with(src, Syntax::Mime).unwrap(), TEXT_PLAIN_UTF_8);
+
+        let src = "text/plain; charset=utf-8; charset =latin1; bad";
+        MediaType::parse_with(src, Syntax::Http).unwrap_err();
+        MediaType::parse_with(src, Syntax::Mime).unwrap_err();
+        assert_eq!(MediaType::parse_with(src, Syntax::Whatwg).unwrap(), TEXT_PLAIN_UTF_8);
+
+        assert_eq!(Syntax::default(), Syntax::Http);
+        MediaType::parse_with("image/*", Syntax::Mime).expect_err("image/star");
+    }
+
+    #[test]
+    fn test_canonicalize() {
+        let cases = [
+            ("image/pjpeg", "image/jpeg"),
+            ("Audio/MP3", "audio/mpeg"),
+            ("text/x-json; charset=utf-8", "application/json; charset=utf-8"),
+            ("application/x-gzip", "application/gzip"),
+            ("image/jpeg", "image/jpeg"),
+            ("application/x-tar", "application/x-tar"),
+        ];
+        for &(src, canonical) in &cases {
+            let mt = MediaType::parse(src).unwrap();
+            assert_eq!(mt.canonicalize(), canonical, "{}", src);
+        }
+
+        // the original is still its own type
+        let jpg = MediaType::parse("image/jpg").unwrap();
+        assert_ne!(jpg, IMAGE_JPEG);
+        assert_eq!(jpg.canonicalize().mime.private_atom(), IMAGE_JPEG.mime.private_atom());
+        assert_eq!(APPLICATION_JAVASCRIPT_UTF_8.canonicalize(), "text/javascript; charset=utf-8");
+    }
+}
@@ -0,0 +1,318 @@
+use std::fmt;
+
+use mime_parse::MimeRef;
+
+use crate::{InvalidMime, MediaType, Value};
+
+/// A media type borrowed from the string it was parsed from.
+///
+/// Parsing a [`MediaType`](super::MediaType) lowercases it into a new
+/// `String`, unless it is one of the known constants. A `MediaTypeRef`
+/// validates the same grammar, but only keeps offsets into the source, so
+/// it never allocates. That makes it cheap to inspect a header and then
+/// throw it away.
+///
+/// Since nothing is lowercased, the parts are returned the way they were
+/// written, and comparisons ignore ASCII case where a `MediaType` would
+/// have lowercased it.
+///
+/// # Example
+///
+/// ```
+/// use mime::MediaTypeRef;
+///
+/// let header = String::from("Text/HTML; Charset=UTF-8");
+/// let mt = MediaTypeRef::parse(&header).unwrap();
+///
+/// assert_eq!(mt.type_(), "Text");
+/// assert_eq!(mt, mime::TEXT_HTML_UTF_8);
+///
+/// let owned: mime::MediaType = mt.to_owned();
+/// assert_eq!(owned.as_ref(), "text/html; charset=utf-8");
+/// ```
+#[derive(Clone, Copy)]
+pub struct MediaTypeRef<'a> {
+    mime: MimeRef<'a>,
+}
+
+impl<'a> MediaTypeRef<'a> {
+    /// Parse a string as a `MediaTypeRef`, without allocating.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mt = mime::MediaTypeRef::parse("text/plain").unwrap();
+    /// assert_eq!(mt, mime::TEXT_PLAIN);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the source is not a valid media type.
+    #[inline]
+    pub fn parse(source: &'a str) -> Result<Self, InvalidMime> {
+        mime_parse::Parser::cannot_range()
+            .parse_ref(source)
+            .map(|mime| MediaTypeRef { mime })
+            .map_err(|e| InvalidMime { inner: e })
+    }
+
+    /// Get the top level media type, as it was written.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mt = mime::MediaTypeRef::parse("TEXT/plain").unwrap();
+    /// assert_eq!(mt.type_(), "TEXT");
+    /// assert!(mt.type_().eq_ignore_ascii_case(mime::TEXT));
+    /// ```
+    #[inline]
+    pub fn type_(&self) -> &'a str {
+        self.mime.type_()
+    }
+
+    /// Get the subtype, as it was written.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mt = mime::MediaTypeRef::parse("image/svg+xml; charset=utf-8").unwrap();
+    /// assert_eq!(mt.subtype(), "svg+xml");
+    /// ```
+    #[inline]
+    pub fn subtype(&self) -> &'a str {
+        self.mime.subtype()
+    }
+
+    /// Get an optional +suffix, as it was written.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mt = mime::MediaTypeRef::parse("image/svg+xml").unwrap();
+    /// assert_eq!(mt.suffix(), Some(mime::XML));
+    ///
+    /// let mt = mime::MediaTypeRef::parse("text/plain").unwrap();
+    /// assert!(mt.suffix().is_none());
+    /// ```
+    #[inline]
+    pub fn suffix(&self) -> Option<&'a str> {
+        self.mime.suffix()
+    }
+
+    /// Look up a parameter by name, ignoring ASCII case.
+    ///
+    /// Unlike [`MediaType::param`](super::MediaType::param), RFC 2231
+    /// extended parameters are not decoded, since that would need to
+    /// allocate. Convert with [`to_owned`](MediaTypeRef::to_owned) to get
+    /// those.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mt = mime::MediaTypeRef::parse("text/plain; Charset=UTF-8").unwrap();
+    /// assert_eq!(mt.param(mime::CHARSET), Some(mime::UTF_8));
+    /// assert!(mt.param("boundary").is_none());
+    /// ```
+    pub fn param(&self, attr: &str) -> Option<Value<'a>> {
+        crate::value::ref_params(&self.mime)
+            .find(|e| attr.eq_ignore_ascii_case(e.0))
+            .map(|e| e.1)
+    }
+
+    /// Returns an iterator over the parameters, with names as they were
+    /// written.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mt = mime::MediaTypeRef::parse("multipart/mixed; Boundary=abc").unwrap();
+    ///
+    /// let mut params = mt.params();
+    /// let (name, value) = params.next().unwrap();
+    /// assert_eq!(name, "Boundary");
+    /// assert_eq!(value, "abc");
+    /// assert!(params.next().is_none());
+    /// ```
+    #[inline]
+    pub fn params(&self) -> impl Iterator<Item = (&'a str, Value<'a>)> {
+        crate::value::ref_params(&self.mime)
+    }
+
+    /// Returns true if the media type has at least one parameter.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mt = mime::MediaTypeRef::parse("text/plain").unwrap();
+    /// assert!(!mt.has_params());
+    ///
+    /// let mt = mime::MediaTypeRef::parse("text/plain; charset=utf-8").unwrap();
+    /// assert!(mt.has_params());
+    /// ```
+    #[inline]
+    pub fn has_params(&self) -> bool {
+        self.mime.has_params()
+    }
+
+    /// Returns the source string this was parsed from.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mt = mime::MediaTypeRef::parse("Text/Plain").unwrap();
+    /// assert_eq!(mt.as_str(), "Text/Plain");
+    /// ```
+    #[inline]
+    pub fn as_str(&self) -> &'a str {
+        self.mime.as_str()
+    }
+
+    /// Copies this into an owned `MediaType`.
+    ///
+    /// The result is the same as if the source had been parsed with
+    /// [`MediaType::parse`](super::MediaType::parse).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mt = mime::MediaTypeRef::parse("TEXT/PLAIN; CHARSET=UTF-8").unwrap();
+    /// assert_eq!(mt.to_owned(), mime::TEXT_PLAIN_UTF_8);
+    /// ```
+    pub fn to_owned(&self) -> MediaType {
+        MediaType {
+            mime: self.mime.to_mime(),
+        }
+    }
+}
+
+impl<'a> From<MediaTypeRef<'a>> for MediaType {
+    #[inline]
+    fn from(mt: MediaTypeRef<'a>) -> MediaType {
+        mt.to_owned()
+    }
+}
+
+impl<'a, 'b> PartialEq<MediaTypeRef<'b>> for MediaTypeRef<'a> {
+    fn eq(&self, other: &MediaTypeRef<'b>) -> bool {
+        crate::cmp::refs_eq(&self.mime, &other.mime)
+    }
+}
+
+impl<'a> Eq for MediaTypeRef<'a> {}
+
+impl<'a> PartialEq<MediaType> for MediaTypeRef<'a> {
+    fn eq(&self, other: &MediaType) -> bool {
+        crate::cmp::ref_eq(&self.mime, &other.mime)
+    }
+}
+
+impl<'a> PartialEq<MediaTypeRef<'a>> for MediaType {
+    #[inline]
+    fn eq(&self, other: &MediaTypeRef<'a>) -> bool {
+        other == self
+    }
+}
+
+impl<'a> PartialEq<str> for MediaTypeRef<'a> {
+    fn eq(&self, s: &str) -> bool {
+        crate::cmp::ref_str_eq(&self.mime, s)
+    }
+}
+
+impl<'a, 'b> PartialEq<&'b str> for MediaTypeRef<'a> {
+    #[inline]
+    fn eq(&self, s: & &'b str) -> bool {
+        self == *s
+    }
+}
+
+impl<'a> AsRef<str> for MediaTypeRef<'a> {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.mime.as_str()
+    }
+}
+
+impl<'a> fmt::Debug for MediaTypeRef<'a> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&self.mime, f)
+    }
+}
+
+impl<'a> fmt::Display for MediaTypeRef<'a> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(self.mime.as_str(), f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_parts_keep_case() {
+        let mt = MediaTypeRef::parse("Application/Vnd.Foo+JSON; Q=1").unwrap();
+        assert_eq!(mt.type_(), "Application");
+        assert_eq!(mt.subtype(), "Vnd.Foo+JSON");
+        assert_eq!(mt.suffix(), Some("JSON"));
+        assert_eq!(mt.param("q").unwrap(), "1");
+        assert_eq!(mt.to_string(), "Application/Vnd.Foo+JSON; Q=1");
+    }
+
+    #[test]
+    fn test_eq_owned() {
+        let mt = MediaTypeRef::parse("TEXT/PLAIN; Charset=\"UTF-8\"").unwrap();
+        assert_eq!(mt, TEXT_PLAIN_UTF_8);
+        assert_eq!(TEXT_PLAIN_UTF_8, mt);
+        assert_ne!(mt, TEXT_PLAIN);
+        assert_ne!(mt, TEXT_HTML_UTF_8);
+
+        // values other than charset are still case-sensitive
+        let a = MediaTypeRef::parse("text/plain; Format=Flowed").unwrap();
+        assert_eq!(a, MediaType::parse("text/plain; format=Flowed").unwrap());
+        assert_ne!(a, MediaType::parse("text/plain; format=flowed").unwrap());
+    }
+
+    #[test]
+    fn test_eq_ref_and_str() {
+        let a = MediaTypeRef::parse("text/html; a=1; b=\"2\"").unwrap();
+        let b = MediaTypeRef::parse("Text/Html;B=2;A=1").unwrap();
+        assert_eq!(a, b);
+        assert_eq!(a, "TEXT/html; b=2; a=\"1\"");
+        assert_ne!(a, "text/html; a=1");
+        assert_ne!(a, "text/html; a=1; a=1");
+        assert_ne!(a, "not a mime");
+    }
+
+    #[test]
+    fn test_to_owned() {
+        let sources = [
+            "text/plain",
+            "Text/Plain",
+            "text/plain; charset=utf-8",
+            "image/svg+xml; A=\"b c\"; charset=UTF-8",
+            "application/pdf; name*=utf-8''%E2%82%AC.pdf",
+        ];
+        for src in &sources {
+            let owned = MediaType::parse(*src).unwrap();
+            let mt = MediaTypeRef::parse(src).unwrap();
+            assert_eq!(mt.to_owned(), owned);
+            assert_eq!(mt.to_owned().as_ref(), owned.as_ref());
+            assert_eq!(MediaType::from(mt), mt);
+        }
+
+        let pdf = MediaTypeRef::parse(sources[4]).unwrap();
+        assert_eq!(pdf.param("name*").unwrap(), "utf-8''%E2%82%AC.pdf");
+        assert_eq!(pdf.to_owned().param("name").unwrap(), "\u{20ac}.pdf");
+    }
+
+    #[test]
+    fn test_errors() {
+        MediaTypeRef::parse("text").unwrap_err();
+        MediaTypeRef::parse("text/*").unwrap_err();
+        MediaTypeRef::parse("*/*").unwrap_err();
+        MediaTypeRef::parse("text/plain; a").unwrap_err();
+    }
+}
@@ -2,7 +2,7 @@ use std::cmp::PartialEq;
 use std::fmt;
 use std::borrow::Cow;
 
-use mime_parse::Mime;
+use mime_parse::{Mime, MimeRef};
 use quoted_string::{self, ContentChars, AsciiCaseInsensitiveEq};
 
 /// a `Value` usable for a charset parameter.
@@ -15,6 +15,8 @@ use quoted_string::{self, ContentChars, AsciiCaseInsensitiveEq};
 pub const UTF_8: Value = Value {
     source: "utf-8",
     ascii_case_insensitive: true,
+    charset: None,
+    language: None,
 };
 
 /// A parameter value section of a `MediaType` or `MediaRange`.
@@ -24,9 +26,12 @@ pub const UTF_8: Value = Value {
 pub struct Value<'a> {
     source: &'a str,
     ascii_case_insensitive: bool,
+    // declared by RFC 2231 extended parameters
+    charset: Option<&'a str>,
+    language: Option<&'a str>,
 }
 
-pub(crate) fn params(mime: &Mime) -> impl Iterator<Item = (&str, Value)> {
+pub(crate) fn params(mime: &Mime) -> impl Iterator<Item = (&str, Value<'_>)> {
     mime.params().map(|(n, v)| {
         let value = Value::new(v).for_name(n);
         (n, value)
@@ -34,14 +39,29 @@ pub(crate) fn params(mime: &Mime) -> impl Iterator<Item = (&str, Value)> {
 }
 
 pub(crate) fn param<'a>(mime: &'a Mime, key: &str) -> Option<Value<'a>> {
+    if let Some(ext) = mime.ext_param(key) {
+        let mut value = Value::new(ext.value).for_name(key);
+        value.charset = ext.charset;
+        value.language = ext.language;
+        return Some(value);
+    }
     params(mime).find(|e| key == e.0).map(|e| e.1)
 }
 
+pub(crate) fn ref_params<'a>(mime: &MimeRef<'a>) -> impl Iterator<Item = (&'a str, Value<'a>)> {
+    mime.params().map(|(n, v)| {
+        let value = Value::new(v).for_raw_name(n);
+        (n, value)
+    })
+}
+
 impl<'a> Value<'a> {
     fn new(source: &'a str) -> Self {
         Value {
             source,
             ascii_case_insensitive: false,
+            charset: None,
+            language: None,
         }
     }
 
@@ -51,6 +71,12 @@ impl<'a> Value<'a> {
         self
     }
 
+    // The name of a `MediaTypeRef` parameter keeps its original case.
+    fn for_raw_name(mut self, name: &str) -> Self {
+        self.ascii_case_insensitive = name.eq_ignore_ascii_case(crate::CHARSET);
+        self
+    }
+
     /// Returns the underlying representation.
     ///
     /// The underlying representation differs from the content,
@@ -110,6 +136,43 @@ impl<'a> Value<'a> {
         quoted_string::unquote_unchecked(self.source)
     }
 
+    /// Returns the charset an extended parameter was encoded with.
+    ///
+    /// Parameters written as `name*=charset'language'value`, as described
+    /// by [RFC 8187](https://tools.ietf.org/html/rfc8187), declare the
+    /// charset their value was percent-encoded with. The value itself is
+    /// already decoded.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mime = mime::MediaType::parse(
+    ///     "application/pdf; name*=ISO-8859-1'fr'd%E9j%E0_vu.pdf"
+    /// ).unwrap();
+    ///
+    /// let name = mime.param("name").unwrap();
+    /// assert_eq!(name, "d\u{e9}j\u{e0}_vu.pdf");
+    /// assert_eq!(name.charset(), Some("ISO-8859-1"));
+    /// ```
+    pub fn charset(&self) -> Option<&'a str> {
+        self.charset
+    }
+
+    /// Returns the language tag of an extended parameter.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mime = mime::MediaType::parse(
+    ///     "application/pdf; name*=UTF-8'de'Bericht.pdf"
+    /// ).unwrap();
+    ///
+    /// assert_eq!(mime.param("name").unwrap().language(), Some("de"));
+    /// assert_eq!(mime.param("name*").unwrap().language(), None);
+    /// ```
+    pub fn language(&self) -> Option<&'a str> {
+        self.language
+    }
 }
 
 impl<'a, 'b> PartialEq<Value<'b>> for Value<'a> {
@@ -208,15 +271,21 @@ mod test {
     fn test_value_eq_str() {
         let value = Value {
             source: "abc",
-            ascii_case_insensitive: false
+            ascii_case_insensitive: false,
+            charset: None,
+            language: None,
         };
         let value_quoted = Value {
             source: "\"abc\"",
-            ascii_case_insensitive: false
+            ascii_case_insensitive: false,
+            charset: None,
+            language: None,
         };
         let value_quoted_with_esacpes = Value {
             source: "\"a\\bc\"",
-            ascii_case_insensitive: false
+            ascii_case_insensitive: false,
+            charset: None,
+            language: None,
         };
 
         bidi_eq(value, "abc");
@@ -241,15 +310,21 @@ mod test {
     fn test_value_eq_str_ascii_case_insensitive() {
         let value = Value {
             source: "abc",
-            ascii_case_insensitive: true
+            ascii_case_insensitive: true,
+            charset: None,
+            language: None,
         };
         let value_quoted = Value {
             source: "\"abc\"",
-            ascii_case_insensitive: true
+            ascii_case_insensitive: true,
+            charset: None,
+            language: None,
         };
         let value_quoted_with_esacpes = Value {
             source: "\"a\\bc\"",
-            ascii_case_insensitive: true
+            ascii_case_insensitive: true,
+            charset: None,
+            language: None,
         };
 
         //1st. all case sensitive checks which still apply
@@ -284,15 +359,21 @@ mod test {
     fn test_value_eq_value() {
         let value = Value {
             source: "abc",
-            ascii_case_insensitive: false
+            ascii_case_insensitive: false,
+            charset: None,
+            language: None,
         };
         let value_quoted = Value {
             source: "\"abc\"",
-            ascii_case_insensitive: false
+            ascii_case_insensitive: false,
+            charset: None,
+            language: None,
         };
         let value_quoted_with_esacpes = Value {
             source: "\"a\\bc\"",
-            ascii_case_insensitive: false
+            ascii_case_insensitive: false,
+            charset: None,
+            language: None,
         };
         assert_eq!(value, value);
         assert_eq!(value_quoted, value_quoted);
@@ -307,15 +388,21 @@ mod test {
     fn test_value_eq_value_case_insensitive() {
         let value = Value {
             source: "Abc",
-            ascii_case_insensitive: true
+            ascii_case_insensitive: true,
+            charset: None,
+            language: None,
         };
         let value_quoted = Value {
             source: "\"aBc\"",
-            ascii_case_insensitive: true
+            ascii_case_insensitive: true,
+            charset: None,
+            language: None,
         };
         let value_quoted_with_esacpes = Value {
             source: "\"a\\bC\"",
-            ascii_case_insensitive: true
+            ascii_case_insensitive: true,
+            charset: None,
+            language: None,
         };
         assert_eq!(value, value);
         assert_eq!(value_quoted, value_quoted);
@@ -330,15 +417,21 @@ mod test {
     fn test_value_eq_value_mixed_case_sensitivity() {
         let value = Value {
             source: "Abc",
-            ascii_case_insensitive: true
+            ascii_case_insensitive: true,
+            charset: None,
+            language: None,
         };
         let value_quoted = Value {
             source: "\"aBc\"",
-            ascii_case_insensitive: false
+            ascii_case_insensitive: false,
+            charset: None,
+            language: None,
         };
         let value_quoted_with_esacpes = Value {
             source: "\"a\\bC\"",
-            ascii_case_insensitive: false
+            ascii_case_insensitive: false,
+            charset: None,
+            language: None,
         };
 
         bidi_eq(value, value_quoted);
@@ -375,4 +468,31 @@ mod test {
         assert_eq!(value.to_content(), expected);
     }
 
+    #[test]
+    fn test_extended_param() {
+        let mime = crate::MediaType::parse("text/plain; title*=UTF-8'en'%22quoted%22%20%5Cbs; plain=x").unwrap();
+
+        let title = mime.param("title").unwrap();
+        assert_eq!(title, "\"quoted\" \\bs");
+        assert_eq!(title.to_content(), "\"quoted\" \\bs");
+        assert_eq!(title.charset(), Some("UTF-8"));
+        assert_eq!(title.language(), Some("en"));
+
+        let plain = mime.param("plain").unwrap();
+        assert_eq!(plain.charset(), None);
+        assert_eq!(plain.language(), None);
+    }
+
+    #[test]
+    fn test_extended_param_range() {
+        let range = crate::MediaRange::parse("text/*; a*0=\"x \"; a*1=y; q=0.5").unwrap();
+        assert_eq!(range.param("a").unwrap(), "x y");
+        assert_eq!(range.quality().thousandths(), 500);
+    }
+
+    #[test]
+    fn test_extended_charset_case_insensitive() {
+        let mime = crate::MediaType::parse("text/plain; charset*=''UTF-8").unwrap();
+        assert_eq!(mime.param(crate::CHARSET), Some(super::UTF_8));
+    }
 }